@@ -0,0 +1,102 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rand::{thread_rng, Rng};
+
+use ripple_address_codec as api;
+
+const BATCH_LEN: usize = 10_000;
+
+fn account_id(c: &mut Criterion) {
+    let bytes: [u8; 20] = thread_rng().gen();
+    let encoded = api::encode_account_id(&bytes);
+    let mut out = [0; 35];
+
+    let mut group = c.benchmark_group("account_id");
+
+    group.bench_function("encode", |b| {
+        b.iter(|| api::encode_account_id(black_box(&bytes)))
+    });
+    group.bench_function("encode_into", |b| {
+        b.iter(|| api::encode_account_id_into(black_box(&bytes), &mut out))
+    });
+    group.bench_function("decode", |b| {
+        b.iter(|| api::decode_account_id(black_box(&encoded)))
+    });
+    group.bench_function("is_valid", |b| {
+        b.iter(|| api::is_valid_account_id(black_box(&encoded)))
+    });
+
+    group.finish();
+}
+
+fn seed(c: &mut Criterion) {
+    let entropy: [u8; 16] = thread_rng().gen();
+    let mut group = c.benchmark_group("seed");
+
+    for &algorithm in api::Algorithm::all() {
+        let encoded = api::encode_seed(&entropy, algorithm);
+
+        group.bench_function(format!("encode/{}", algorithm), |b| {
+            b.iter(|| api::encode_seed(black_box(&entropy), algorithm))
+        });
+        group.bench_function(format!("decode/{}", algorithm), |b| {
+            b.iter(|| api::decode_seed(black_box(&encoded)))
+        });
+    }
+
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    let account_ids: Vec<[u8; 20]> = (0..BATCH_LEN).map(|_| thread_rng().gen()).collect();
+    let encoded = api::encode_account_ids(&account_ids);
+    let encoded: Vec<&str> = encoded.iter().map(String::as_str).collect();
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(BATCH_LEN as u64));
+
+    group.bench_function("encode_account_ids", |b| {
+        b.iter(|| api::encode_account_ids(black_box(&account_ids)))
+    });
+    group.bench_function("decode_account_ids", |b| {
+        b.iter(|| api::decode_account_ids(black_box(&encoded)))
+    });
+
+    #[cfg(feature = "rayon")]
+    {
+        group.bench_function("par_encode_account_ids", |b| {
+            b.iter(|| api::par_encode_account_ids(black_box(&account_ids)))
+        });
+        group.bench_function("par_decode_account_ids", |b| {
+            b.iter(|| api::par_decode_account_ids(black_box(&encoded)))
+        });
+    }
+
+    group.finish();
+}
+
+fn raw(c: &mut Criterion) {
+    let bytes: [u8; 32] = thread_rng().gen();
+    let encoded = api::encode_raw(&bytes);
+
+    let mut group = c.benchmark_group("raw");
+
+    group.bench_function("decode", |b| {
+        b.iter(|| api::decode_raw(black_box(&encoded)))
+    });
+    group.bench_function("contains_only_xrpl_alphabet", |b| {
+        b.iter(|| api::contains_only_xrpl_alphabet(black_box(&encoded)))
+    });
+
+    group.finish();
+}
+
+fn checksum(c: &mut Criterion) {
+    let bytes: [u8; 21] = thread_rng().gen();
+
+    c.bench_function("calc_checksum", |b| {
+        b.iter(|| api::calc_checksum(black_box(&bytes)))
+    });
+}
+
+criterion_group!(benches, account_id, seed, batch, raw, checksum);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+defmodule RippleAddressCodec do
+  @moduledoc """
+  Encodes/decodes base58 encoded XRP Ledger identifiers.
+
+  Backed by the ripple-address-codec Rust crate through a NIF.
+
+      iex> RippleAddressCodec.encode_account_id(<<0::160>>)
+      {:ok, "rrrrrrrrrrrrrrrrrrrrrhoLvTp"}
+  """
+
+  alias RippleAddressCodec.Native
+
+  @type algorithm :: :secp256k1 | :ed25519
+  @type error ::
+          :decode_error
+          | :bad_alphabet
+          | :bad_checksum
+          | :bad_prefix
+          | :bad_length
+          | :denied
+          | :unsupported_tag
+          | :non_canonical
+          | :wrong_algorithm
+          | :confusable
+          | :input_too_long
+          | :invalid_length
+          | atom()
+
+  @doc "Encode 20 account id bytes as a classic address (starting with r...)."
+  @spec encode_account_id(binary()) :: {:ok, String.t()} | {:error, error()}
+  defdelegate encode_account_id(bytes), to: Native
+
+  @doc "Decode a classic address (starting with r...) to its raw bytes."
+  @spec decode_account_id(String.t()) :: {:ok, binary()} | {:error, error()}
+  defdelegate decode_account_id(account_id), to: Native
+
+  @doc "Encode 16 bytes of entropy as a seed for the given algorithm."
+  @spec encode_seed(binary(), algorithm()) :: {:ok, String.t()} | {:error, error()}
+  defdelegate encode_seed(entropy, algorithm), to: Native
+
+  @doc "Decode a seed into its entropy and algorithm."
+  @spec decode_seed(String.t()) :: {:ok, {binary(), algorithm()}} | {:error, error()}
+  defdelegate decode_seed(seed), to: Native
+
+  @doc "Check whether the string is a valid classic address."
+  @spec valid_account_id?(String.t()) :: boolean()
+  def valid_account_id?(account_id), do: Native.valid_account_id(account_id)
+end
+
+defmodule RippleAddressCodec.Native do
+  @moduledoc false
+
+  use Rustler, otp_app: :ripple_address_codec, crate: "ripple_address_codec_nif"
+
+  def encode_account_id(_bytes), do: :erlang.nif_error(:nif_not_loaded)
+  def decode_account_id(_account_id), do: :erlang.nif_error(:nif_not_loaded)
+  def encode_seed(_entropy, _algorithm), do: :erlang.nif_error(:nif_not_loaded)
+  def decode_seed(_seed), do: :erlang.nif_error(:nif_not_loaded)
+  def valid_account_id(_account_id), do: :erlang.nif_error(:nif_not_loaded)
+end
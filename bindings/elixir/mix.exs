@@ -0,0 +1,21 @@
+defmodule RippleAddressCodec.MixProject do
+  use Mix.Project
+
+  def project do
+    [
+      app: :ripple_address_codec,
+      version: "0.1.1",
+      elixir: "~> 1.12",
+      description: "Encodes/decodes base58 encoded XRP Ledger identifiers",
+      deps: deps()
+    ]
+  end
+
+  def application do
+    [extra_applications: [:logger]]
+  end
+
+  defp deps do
+    [{:rustler, "~> 0.36", runtime: false}]
+  end
+end
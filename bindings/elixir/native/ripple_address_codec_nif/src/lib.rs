@@ -0,0 +1,82 @@
+//! Elixir NIF bindings for ripple-address-codec
+//!
+//! Loaded by the `RippleAddressCodec` Elixir module, see `lib/ripple_address_codec.ex`.
+
+use std::convert::TryInto;
+
+use ripple_address_codec as codec;
+use rustler::{Atom, Binary, Env, NewBinary};
+
+mod atoms {
+    rustler::atoms! {
+        decode_error,
+        invalid_length,
+        secp256k1,
+        ed25519,
+    }
+}
+
+type NifResult<T> = Result<T, Atom>;
+
+#[rustler::nif]
+fn encode_account_id(bytes: Binary) -> NifResult<String> {
+    let bytes = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| atoms::invalid_length())?;
+
+    Ok(codec::encode_account_id(&bytes))
+}
+
+#[rustler::nif]
+fn decode_account_id<'a>(env: Env<'a>, account_id: &str) -> NifResult<Binary<'a>> {
+    let bytes = codec::decode_account_id(account_id).map_err(|error| to_atom(env, error))?;
+
+    Ok(to_binary(env, &bytes))
+}
+
+#[rustler::nif]
+fn encode_seed(entropy: Binary, algorithm: Atom) -> NifResult<String> {
+    let entropy = entropy
+        .as_slice()
+        .try_into()
+        .map_err(|_| atoms::invalid_length())?;
+
+    let algorithm = if algorithm == atoms::ed25519() {
+        codec::Ed25519
+    } else if algorithm == atoms::secp256k1() {
+        codec::Secp256k1
+    } else {
+        return Err(rustler::types::atom::badarg());
+    };
+
+    Ok(codec::encode_seed(&entropy, algorithm))
+}
+
+#[rustler::nif]
+fn decode_seed<'a>(env: Env<'a>, seed: &str) -> NifResult<(Binary<'a>, Atom)> {
+    let (entropy, algorithm) = codec::decode_seed(seed).map_err(|error| to_atom(env, error))?;
+
+    let algorithm =
+        Atom::from_str(env, &algorithm.to_string()).map_err(|_| atoms::decode_error())?;
+
+    Ok((to_binary(env, &entropy), algorithm))
+}
+
+#[rustler::nif]
+fn valid_account_id(account_id: &str) -> bool {
+    codec::is_valid_account_id(account_id)
+}
+
+fn to_binary<'a>(env: Env<'a>, bytes: &[u8]) -> Binary<'a> {
+    let mut binary = NewBinary::new(env, bytes.len());
+    binary.as_mut_slice().copy_from_slice(bytes);
+    binary.into()
+}
+
+/// The atom named after [`Error::code`](codec::Error::code)
+fn to_atom(env: Env<'_>, error: codec::Error) -> Atom {
+    Atom::from_str(env, error.code()).unwrap_or_else(|_| atoms::decode_error())
+}
+
+rustler::init!("Elixir.RippleAddressCodec.Native");
@@ -0,0 +1,18 @@
+defmodule RippleAddressCodecTest do
+  use ExUnit.Case
+  doctest RippleAddressCodec
+
+  test "decodes a seed" do
+    assert RippleAddressCodec.decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE") ==
+             {:ok, {<<0::128>>, :ed25519}}
+  end
+
+  test "rejects a bad checksum" do
+    assert RippleAddressCodec.decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA") ==
+             {:error, :bad_checksum}
+  end
+
+  test "rejects a bad character" do
+    assert RippleAddressCodec.decode_account_id("r0") == {:error, :bad_alphabet}
+  end
+end
@@ -0,0 +1,29 @@
+package io.ntls.ripple;
+
+/** A classic address together with the destination tag and network of an X-address. */
+public final class ClassicAddressWithTag {
+    private final String classicAddress;
+    private final Long tag;
+    private final boolean test;
+
+    ClassicAddressWithTag(String classicAddress, Long tag, boolean test) {
+        this.classicAddress = classicAddress;
+        this.tag = tag;
+        this.test = test;
+    }
+
+    /** The classic address (starting with r...). */
+    public String getClassicAddress() {
+        return classicAddress;
+    }
+
+    /** The destination tag, or {@code null} for none. */
+    public Long getTag() {
+        return tag;
+    }
+
+    /** Whether the X-address is for the test network. */
+    public boolean isTest() {
+        return test;
+    }
+}
@@ -0,0 +1,19 @@
+package io.ntls.ripple;
+
+/** Thrown when an XRP Ledger identifier cannot be decoded. */
+public class DecodeException extends Exception {
+    private final String code;
+
+    public DecodeException(String code, String message) {
+        super(message);
+        this.code = code;
+    }
+
+    /**
+     * Stable code telling why decoding failed, such as {@code "bad_checksum"}
+     * or {@code "bad_length"}.
+     */
+    public String getCode() {
+        return code;
+    }
+}
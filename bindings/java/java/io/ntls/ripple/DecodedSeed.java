@@ -0,0 +1,22 @@
+package io.ntls.ripple;
+
+/** Seed entropy together with the algorithm it is intended to be used with. */
+public final class DecodedSeed {
+    private final byte[] entropy;
+    private final String algorithm;
+
+    DecodedSeed(byte[] entropy, String algorithm) {
+        this.entropy = entropy;
+        this.algorithm = algorithm;
+    }
+
+    /** The 16 bytes of seed entropy. */
+    public byte[] getEntropy() {
+        return entropy.clone();
+    }
+
+    /** Either {@code "secp256k1"} or {@code "ed25519"}. */
+    public String getAlgorithm() {
+        return algorithm;
+    }
+}
@@ -0,0 +1,29 @@
+package io.ntls.ripple;
+
+/** The account id, destination tag and network encoded in an X-address. */
+public final class DecodedXAddress {
+    private final byte[] accountId;
+    private final Long tag;
+    private final boolean test;
+
+    DecodedXAddress(byte[] accountId, Long tag, boolean test) {
+        this.accountId = accountId;
+        this.tag = tag;
+        this.test = test;
+    }
+
+    /** The 20 bytes of account id. */
+    public byte[] getAccountId() {
+        return accountId.clone();
+    }
+
+    /** The destination tag, or {@code null} for none. */
+    public Long getTag() {
+        return tag;
+    }
+
+    /** Whether the X-address is for the test network. */
+    public boolean isTest() {
+        return test;
+    }
+}
@@ -0,0 +1,89 @@
+package io.ntls.ripple;
+
+/**
+ * Encodes/decodes base58 encoded XRP Ledger identifiers.
+ *
+ * <p>Backed by the ripple-address-codec Rust crate through JNI. The
+ * {@code ripple_address_codec_jni} native library must be on
+ * {@code java.library.path}.
+ */
+public final class RippleAddressCodec {
+    static {
+        System.loadLibrary("ripple_address_codec_jni");
+    }
+
+    private RippleAddressCodec() {}
+
+    /**
+     * Encode 20 account id bytes as a classic address (starting with r...).
+     *
+     * @throws IllegalArgumentException if {@code bytes} is not 20 bytes long
+     */
+    public static native String encodeAccountId(byte[] bytes);
+
+    /**
+     * Decode a classic address (starting with r...) to its raw bytes.
+     *
+     * @throws DecodeException if the account id is invalid
+     */
+    public static native byte[] decodeAccountId(String accountId) throws DecodeException;
+
+    /** Check whether the string is a valid classic address. */
+    public static native boolean isValidAccountId(String accountId);
+
+    /**
+     * Encode 16 bytes of entropy as a seed.
+     *
+     * @param algorithm {@code "secp256k1"} or {@code "ed25519"}
+     * @throws IllegalArgumentException if {@code entropy} is not 16 bytes
+     *     long or the algorithm is unknown
+     */
+    public static native String encodeSeed(byte[] entropy, String algorithm);
+
+    /**
+     * Decode a seed into its entropy and algorithm.
+     *
+     * @throws DecodeException if the seed is invalid
+     */
+    public static native DecodedSeed decodeSeed(String seed) throws DecodeException;
+
+    /**
+     * Encode 20 account id bytes, a destination tag and network as an
+     * X-address (starting with X... or T...).
+     *
+     * @param tag the destination tag, or {@code null} for none
+     * @param test whether the X-address is for the test network
+     * @throws IllegalArgumentException if {@code accountId} is not 20 bytes
+     *     long or the tag doesn't fit in 32 bits
+     */
+    public static native String encodeXAddress(byte[] accountId, Long tag, boolean test);
+
+    /**
+     * Decode an X-address to its account id bytes, destination tag and network.
+     *
+     * @throws DecodeException if the X-address is invalid
+     */
+    public static native DecodedXAddress decodeXAddress(String xAddress) throws DecodeException;
+
+    /** Check whether the string is a valid X-address. */
+    public static native boolean isValidXAddress(String xAddress);
+
+    /**
+     * Convert a classic address and destination tag to an X-address.
+     *
+     * @param tag the destination tag, or {@code null} for none
+     * @param test whether the X-address is for the test network
+     * @throws DecodeException if the classic address is invalid
+     * @throws IllegalArgumentException if the tag doesn't fit in 32 bits
+     */
+    public static native String classicAddressToXAddress(String classicAddress, Long tag, boolean test)
+            throws DecodeException;
+
+    /**
+     * Convert an X-address to its classic address, destination tag and network.
+     *
+     * @throws DecodeException if the X-address is invalid
+     */
+    public static native ClassicAddressWithTag xAddressToClassicAddress(String xAddress)
+            throws DecodeException;
+}
@@ -0,0 +1,332 @@
+//! Java (JNI) bindings for ripple-address-codec
+//!
+//! Native side of `io.ntls.ripple.RippleAddressCodec`, see `java/`.
+//! Codec errors are thrown as `io.ntls.ripple.DecodeException` carrying
+//! the [`Error::code`](codec::Error::code), bad arguments as
+//! `java.lang.IllegalArgumentException` and internal JNI failures as
+//! `java.lang.IllegalStateException`.
+
+use std::convert::{TryFrom, TryInto};
+
+use jni::{
+    objects::{JByteArray, JClass, JObject, JString, JThrowable, JValue},
+    sys::{jboolean, jbyteArray, jlong, jobject, jstring, JNI_FALSE, JNI_TRUE},
+    JNIEnv,
+};
+use ripple_address_codec as codec;
+
+const DECODE_EXCEPTION: &str = "io/ntls/ripple/DecodeException";
+const ILLEGAL_ARGUMENT_EXCEPTION: &str = "java/lang/IllegalArgumentException";
+const ILLEGAL_STATE_EXCEPTION: &str = "java/lang/IllegalStateException";
+const DECODED_SEED: &str = "io/ntls/ripple/DecodedSeed";
+const DECODED_XADDRESS: &str = "io/ntls/ripple/DecodedXAddress";
+const CLASSIC_ADDRESS_WITH_TAG: &str = "io/ntls/ripple/ClassicAddressWithTag";
+const LONG: &str = "java/lang/Long";
+
+enum Failure {
+    Jni(jni::errors::Error),
+    Codec(codec::Error),
+    IllegalArgument(&'static str),
+}
+
+impl From<jni::errors::Error> for Failure {
+    fn from(error: jni::errors::Error) -> Self {
+        Failure::Jni(error)
+    }
+}
+
+impl From<codec::Error> for Failure {
+    fn from(error: codec::Error) -> Self {
+        Failure::Codec(error)
+    }
+}
+
+type Result<T> = std::result::Result<T, Failure>;
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_encodeAccountId<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    bytes: JByteArray<'local>,
+) -> jstring {
+    let result = (|| {
+        let bytes = to_array(&env, &bytes, "account id must be 20 bytes")?;
+
+        Ok(env.new_string(codec::encode_account_id(&bytes))?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_decodeAccountId<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    account_id: JString<'local>,
+) -> jbyteArray {
+    let result = (|| {
+        let account_id: String = env.get_string(&account_id)?.into();
+        let bytes = codec::decode_account_id(&account_id)?;
+
+        Ok(env.byte_array_from_slice(&bytes)?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_isValidAccountId<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    account_id: JString<'local>,
+) -> jboolean {
+    let account_id: String = match env.get_string(&account_id) {
+        Ok(account_id) => account_id.into(),
+        Err(_) => return JNI_FALSE,
+    };
+
+    if codec::decode_account_id(&account_id).is_ok() {
+        JNI_TRUE
+    } else {
+        JNI_FALSE
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_encodeSeed<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    entropy: JByteArray<'local>,
+    algorithm: JString<'local>,
+) -> jstring {
+    let result = (|| {
+        let entropy = to_array(&env, &entropy, "entropy must be 16 bytes")?;
+        let algorithm: String = env.get_string(&algorithm)?.into();
+
+        let algorithm = match algorithm.as_str() {
+            "secp256k1" => codec::Secp256k1,
+            "ed25519" => codec::Ed25519,
+            _ => return Err(Failure::IllegalArgument("unknown algorithm")),
+        };
+
+        Ok(env.new_string(codec::encode_seed(&entropy, algorithm))?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_decodeSeed<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    seed: JString<'local>,
+) -> jobject {
+    let result = (|| {
+        let seed: String = env.get_string(&seed)?.into();
+        let (entropy, algorithm) = codec::decode_seed(&seed)?;
+
+        let entropy = env.byte_array_from_slice(&entropy)?;
+        let algorithm = env.new_string(algorithm.to_string())?;
+
+        Ok(env.new_object(
+            DECODED_SEED,
+            "([BLjava/lang/String;)V",
+            &[JValue::Object(&entropy), JValue::Object(&algorithm)],
+        )?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_encodeXAddress<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    account_id: JByteArray<'local>,
+    tag: JObject<'local>,
+    test: jboolean,
+) -> jstring {
+    let result = (|| {
+        let account_id = to_array(&env, &account_id, "account id must be 20 bytes")?;
+        let tag = to_tag(&mut env, &tag)?;
+
+        Ok(env.new_string(codec::encode_xaddress(&account_id, tag, to_network(test)))?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_decodeXAddress<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    xaddress: JString<'local>,
+) -> jobject {
+    let result = (|| {
+        let xaddress: String = env.get_string(&xaddress)?.into();
+        let (account_id, tag, network) = codec::decode_xaddress(&xaddress)?;
+
+        let account_id = env.byte_array_from_slice(&account_id)?;
+        let tag = from_tag(&mut env, tag)?;
+
+        Ok(env.new_object(
+            DECODED_XADDRESS,
+            "([BLjava/lang/Long;Z)V",
+            &[
+                JValue::Object(&account_id),
+                JValue::Object(&tag),
+                JValue::Bool(from_network(network)),
+            ],
+        )?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_isValidXAddress<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    xaddress: JString<'local>,
+) -> jboolean {
+    let xaddress: String = match env.get_string(&xaddress) {
+        Ok(xaddress) => xaddress.into(),
+        Err(_) => return JNI_FALSE,
+    };
+
+    if codec::is_valid_xaddress(&xaddress) {
+        JNI_TRUE
+    } else {
+        JNI_FALSE
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_classicAddressToXAddress<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    classic_address: JString<'local>,
+    tag: JObject<'local>,
+    test: jboolean,
+) -> jstring {
+    let result = (|| {
+        let classic_address: String = env.get_string(&classic_address)?.into();
+        let tag = to_tag(&mut env, &tag)?;
+        let xaddress = codec::classic_address_to_xaddress(&classic_address, tag, to_network(test))?;
+
+        Ok(env.new_string(xaddress)?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_ntls_ripple_RippleAddressCodec_xAddressToClassicAddress<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    xaddress: JString<'local>,
+) -> jobject {
+    let result = (|| {
+        let xaddress: String = env.get_string(&xaddress)?.into();
+        let (classic_address, tag, network) = codec::xaddress_to_classic_address(&xaddress)?;
+
+        let classic_address = env.new_string(classic_address)?;
+        let tag = from_tag(&mut env, tag)?;
+
+        Ok(env.new_object(
+            CLASSIC_ADDRESS_WITH_TAG,
+            "(Ljava/lang/String;Ljava/lang/Long;Z)V",
+            &[
+                JValue::Object(&classic_address),
+                JValue::Object(&tag),
+                JValue::Bool(from_network(network)),
+            ],
+        )?)
+    })();
+
+    unwrap_or_throw(&mut env, result).into_raw()
+}
+
+fn to_array<const N: usize>(
+    env: &JNIEnv<'_>,
+    bytes: &JByteArray<'_>,
+    message: &'static str,
+) -> Result<[u8; N]> {
+    env.convert_byte_array(bytes)?
+        .try_into()
+        .map_err(|_| Failure::IllegalArgument(message))
+}
+
+/// A `null` Java `Long` is no tag
+fn to_tag(env: &mut JNIEnv<'_>, tag: &JObject<'_>) -> Result<codec::Tag> {
+    if tag.is_null() {
+        return Ok(codec::Tag::None);
+    }
+
+    let tag = env.call_method(tag, "longValue", "()J", &[])?.j()?;
+
+    u32::try_from(tag)
+        .map(codec::Tag::U32)
+        .map_err(|_| Failure::IllegalArgument("tag must be between 0 and 4294967295"))
+}
+
+fn from_tag<'local>(env: &mut JNIEnv<'local>, tag: codec::Tag) -> Result<JObject<'local>> {
+    let tag = match tag.value() {
+        Some(tag) => jlong::try_from(tag).map_err(|_| codec::Error::UnsupportedTag)?,
+        None => return Ok(JObject::null()),
+    };
+
+    Ok(env
+        .call_static_method(LONG, "valueOf", "(J)Ljava/lang/Long;", &[JValue::Long(tag)])?
+        .l()?)
+}
+
+fn to_network(test: jboolean) -> codec::Network {
+    if test == JNI_FALSE {
+        codec::Network::Main
+    } else {
+        codec::Network::Test
+    }
+}
+
+fn from_network(network: codec::Network) -> jboolean {
+    if network.is_test() {
+        JNI_TRUE
+    } else {
+        JNI_FALSE
+    }
+}
+
+fn throw_decode_exception(env: &mut JNIEnv<'_>, error: codec::Error) -> jni::errors::Result<()> {
+    let code = env.new_string(error.code())?;
+    let message = env.new_string(error.to_string())?;
+
+    let exception = env.new_object(
+        DECODE_EXCEPTION,
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+        &[JValue::Object(&code), JValue::Object(&message)],
+    )?;
+
+    env.throw(JThrowable::from(exception))
+}
+
+fn unwrap_or_throw<'local, T>(env: &mut JNIEnv<'local>, result: Result<T>) -> T
+where
+    T: From<JObject<'local>>,
+{
+    let thrown = match result {
+        Ok(value) => return value,
+        Err(Failure::Jni(jni::errors::Error::JavaException)) => Ok(()),
+        Err(Failure::Jni(error)) => env.throw_new(ILLEGAL_STATE_EXCEPTION, error.to_string()),
+        Err(Failure::Codec(error)) => throw_decode_exception(env, error),
+        Err(Failure::IllegalArgument(message)) => {
+            env.throw_new(ILLEGAL_ARGUMENT_EXCEPTION, message)
+        }
+    };
+
+    // If even throwing failed there is nothing left to report,
+    // the JVM sees a pending error or a null result
+    drop(thrown);
+
+    JObject::null().into()
+}
@@ -0,0 +1,103 @@
+//! PHP extension bindings for ripple-address-codec
+//!
+//! Codec errors are thrown as PHP `Exception`s carrying the error message.
+
+#![cfg_attr(windows, feature(abi_vectorcall))]
+
+use std::convert::TryInto;
+
+use ext_php_rs::{binary::Binary, boxed::ZBox, prelude::*, types::ZendHashTable};
+use ripple_address_codec as codec;
+
+/// Encode 20 account id bytes as a classic address (starting with r...)
+///
+/// @param string $bytes Raw account id bytes.
+///
+/// @return string Classic address.
+#[php_function]
+pub fn ripple_encode_account_id(bytes: Binary<u8>) -> PhpResult<String> {
+    let bytes = Vec::from(bytes)
+        .try_into()
+        .map_err(|_| "account id must be 20 bytes")?;
+
+    Ok(codec::encode_account_id(&bytes))
+}
+
+/// Decode a classic address (starting with r...) to its raw bytes
+///
+/// @param string $account_id Classic address.
+///
+/// @return string Raw account id bytes.
+#[php_function]
+pub fn ripple_decode_account_id(account_id: &str) -> PhpResult<Binary<u8>> {
+    let bytes = codec::decode_account_id(account_id).map_err(to_exception)?;
+
+    Ok(Binary::new(bytes.to_vec()))
+}
+
+/// Check whether the string is a valid classic address
+///
+/// @param string $account_id Classic address.
+///
+/// @return bool
+#[php_function]
+pub fn ripple_is_valid_account_id(account_id: &str) -> bool {
+    codec::decode_account_id(account_id).is_ok()
+}
+
+/// Encode 16 bytes of entropy as a seed
+///
+/// @param string $entropy Raw entropy bytes.
+/// @param string $algorithm Either "secp256k1" or "ed25519".
+///
+/// @return string Seed.
+#[php_function]
+pub fn ripple_encode_seed(entropy: Binary<u8>, algorithm: &str) -> PhpResult<String> {
+    let entropy = Vec::from(entropy)
+        .try_into()
+        .map_err(|_| "entropy must be 16 bytes")?;
+
+    let algorithm = match algorithm {
+        "secp256k1" => codec::Secp256k1,
+        "ed25519" => codec::Ed25519,
+        _ => return Err("unknown algorithm".into()),
+    };
+
+    Ok(codec::encode_seed(&entropy, algorithm))
+}
+
+/// Decode a seed into its entropy and algorithm
+///
+/// @param string $seed Seed.
+///
+/// @return array ["entropy" => string, "algorithm" => string]
+#[php_function]
+pub fn ripple_decode_seed(seed: &str) -> PhpResult<ZBox<ZendHashTable>> {
+    let (entropy, algorithm) = codec::decode_seed(seed).map_err(to_exception)?;
+
+    let algorithm = algorithm.to_string();
+
+    let mut decoded = ZendHashTable::new();
+    decoded
+        .insert("entropy", Binary::new(entropy.to_vec()))
+        .map_err(|error| error.to_string())?;
+    decoded
+        .insert("algorithm", algorithm)
+        .map_err(|error| error.to_string())?;
+
+    Ok(decoded)
+}
+
+fn to_exception(error: codec::Error) -> PhpException {
+    PhpException::default(error.to_string())
+}
+
+#[php_module]
+pub fn module(module: ModuleBuilder) -> ModuleBuilder {
+    module
+        .function(wrap_function!(ripple_encode_account_id))
+        .function(wrap_function!(ripple_decode_account_id))
+        .function(wrap_function!(ripple_is_valid_account_id))
+        .function(wrap_function!(ripple_encode_seed))
+        .function(wrap_function!(ripple_decode_seed))
+}
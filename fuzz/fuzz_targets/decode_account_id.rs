@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ripple_address_codec::{decode_account_id, encode_account_id};
+
+fuzz_target!(|address: &str| {
+    if let Ok(account_id) = decode_account_id(address) {
+        assert_eq!(encode_account_id(&account_id), address);
+    }
+});
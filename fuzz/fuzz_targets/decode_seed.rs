@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ripple_address_codec::{decode_seed, encode_seed};
+
+fuzz_target!(|seed: &str| {
+    if let Ok((entropy, algorithm)) = decode_seed(seed) {
+        assert_eq!(encode_seed(&entropy, algorithm), seed);
+    }
+});
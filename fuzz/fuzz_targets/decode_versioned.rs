@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ripple_address_codec::{decode_versioned, encode_versioned};
+
+// Payloads of up to 255 bytes, with any version prefix
+fuzz_target!(|input: (&str, &[u8], u8)| {
+    let (s, version, expected_len) = input;
+
+    if let Ok(payload) = decode_versioned(s, version, expected_len.into()) {
+        assert_eq!(payload.len(), usize::from(expected_len));
+        assert_eq!(encode_versioned(&payload, version), s);
+    }
+});
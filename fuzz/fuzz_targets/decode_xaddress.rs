@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ripple_address_codec::{decode_xaddress, encode_xaddress};
+
+fuzz_target!(|xaddress: &str| {
+    if let Ok((account_id, tag, network)) = decode_xaddress(xaddress) {
+        assert_eq!(encode_xaddress(&account_id, tag, network), xaddress);
+    }
+});
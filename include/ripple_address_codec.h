@@ -0,0 +1,199 @@
+/* ripple-address-codec C API, see src/ffi.rs */
+
+#ifndef RIPPLE_ADDRESS_CODEC_H
+#define RIPPLE_ADDRESS_CODEC_H
+
+/* Generated with cbindgen, don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Success
+ */
+#define RAC_OK 0
+
+/**
+ * Decoding error not covered by a more specific code
+ */
+#define RAC_ERROR_DECODE -1
+
+/**
+ * Input contains a character outside the base58 alphabet
+ */
+#define RAC_ERROR_BAD_ALPHABET -2
+
+/**
+ * Checksum doesn't match, most likely a mistyped character
+ */
+#define RAC_ERROR_BAD_CHECKSUM -3
+
+/**
+ * Input doesn't start with the version prefix of the expected token type
+ */
+#define RAC_ERROR_BAD_PREFIX -4
+
+/**
+ * Payload has an unexpected length
+ */
+#define RAC_ERROR_BAD_LENGTH -5
+
+/**
+ * Denied by policy
+ */
+#define RAC_ERROR_DENIED -6
+
+/**
+ * X-address tag uses a reserved flag or reserved bits
+ */
+#define RAC_ERROR_UNSUPPORTED_TAG -7
+
+/**
+ * Input decodes, but doesn't re-encode to the exact same string
+ */
+#define RAC_ERROR_NON_CANONICAL -8
+
+/**
+ * Output buffer is too small, `*written` is the required length
+ */
+#define RAC_ERROR_BUFFER_TOO_SMALL -9
+
+/**
+ * Binary format of an unknown version
+ */
+#define RAC_ERROR_UNSUPPORTED_VERSION -10
+
+/**
+ * Word outside the RFC 1751 dictionary or BIP39 wordlist
+ */
+#define RAC_ERROR_UNKNOWN_WORD -11
+
+/**
+ * Secret numbers row with a checksum mismatch or a value above 65535
+ */
+#define RAC_ERROR_BAD_SECRET_NUMBER -12
+
+/**
+ * Public key that isn't a point on its curve
+ */
+#define RAC_ERROR_INVALID_KEY -13
+
+/**
+ * Valid seed for another algorithm than the required one
+ */
+#define RAC_ERROR_WRONG_ALGORITHM -14
+
+/**
+ * Lookalike of an ASCII character or invisible character
+ */
+#define RAC_ERROR_CONFUSABLE -15
+
+/**
+ * Input longer than the maximum accepted length
+ */
+#define RAC_ERROR_INPUT_TOO_LONG -16
+
+/**
+ * A null pointer, a string that isn't UTF-8 or an unknown algorithm
+ */
+#define RAC_ERROR_INVALID_ARGUMENT -100
+
+/**
+ * secp256k1 algorithm
+ */
+#define RAC_SECP256K1 0
+
+/**
+ * Ed25519 algorithm
+ */
+#define RAC_ED25519 1
+
+/**
+ * Account id length in bytes
+ */
+#define RAC_ACCOUNT_ID_LEN 20
+
+/**
+ * Seed entropy length in bytes
+ */
+#define RAC_ENTROPY_LEN 16
+
+/**
+ * Buffer length fitting any classic address and its NUL
+ */
+#define RAC_ACCOUNT_ID_BUFFER_LEN 36
+
+/**
+ * Buffer length fitting any seed and its NUL
+ */
+#define RAC_SEED_BUFFER_LEN 32
+
+/**
+ * Buffer length fitting any X-address and its NUL
+ */
+#define RAC_XADDRESS_BUFFER_LEN 48
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Encode a 20-byte account id as a classic address (starting with r...)
+ */
+int32_t rac_encode_account_id(const uint8_t *account_id,
+                              char *out,
+                              size_t out_len,
+                              size_t *written);
+
+/**
+ * Decode a classic address (starting with r...) to its 20-byte account id
+ */
+int32_t rac_decode_account_id(const char *address, uint8_t *account_id);
+
+/**
+ * Encode 16 bytes of entropy as a seed (starting with s...)
+ */
+int32_t rac_encode_seed(const uint8_t *entropy,
+                        int32_t algorithm,
+                        char *out,
+                        size_t out_len,
+                        size_t *written);
+
+/**
+ * Decode a seed (starting with s...) to its 16 bytes of entropy and
+ */
+int32_t rac_decode_seed(const char *seed, uint8_t *entropy, int32_t *algorithm);
+
+/**
+ * Encode a 20-byte account id, optional destination tag and network as
+ */
+int32_t rac_encode_xaddress(const uint8_t *account_id,
+                            bool has_tag,
+                            uint32_t tag,
+                            bool test_network,
+                            char *out,
+                            size_t out_len,
+                            size_t *written);
+
+/**
+ * Decode an X-address to its 20-byte account id, optional destination
+ */
+int32_t rac_decode_xaddress(const char *xaddress,
+                            uint8_t *account_id,
+                            bool *has_tag,
+                            uint32_t *tag,
+                            bool *test_network);
+
+/**
+ * Static NUL-terminated description of an error code
+ */
+const char *rac_error_message(int32_t code);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RIPPLE_ADDRESS_CODEC_H */
@@ -0,0 +1,115 @@
+//! Compile-time checked XRP Ledger address literals
+//!
+//! Use them through the `macros` feature of `ripple-address-codec`,
+//! which re-exports them. A mistyped address fails the build instead of
+//! the first decode at runtime.
+
+#![deny(
+    warnings,
+    clippy::all,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    missing_docs,
+    rustdoc::missing_crate_level_docs,
+    non_ascii_idents,
+    unreachable_pub
+)]
+#![doc(test(attr(deny(warnings))))]
+
+use std::convert::TryInto;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+/// Version prefix of classic addresses
+const ACCOUNT_ID_PREFIX: u8 = 0;
+/// Account id length in bytes
+const ACCOUNT_ID_LEN: usize = 20;
+
+/// Classic address literal, checked at compile time, as an `AccountId`
+///
+/// Expands to a constant expression, so it can initialize `const`s and
+/// `static`s.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{address, AccountId};
+///
+/// const GENESIS: AccountId = address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+///
+/// assert_eq!(GENESIS.to_string(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// ```
+///
+/// A bad checksum is a compile error:
+///
+/// ```compile_fail
+/// use ripple_address_codec::{address, AccountId};
+///
+/// const GENESIS: AccountId = address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTr");
+/// ```
+#[proc_macro]
+pub fn address(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+
+    match decode_account_id(&literal) {
+        Ok(bytes) => quote!(::ripple_address_codec::AccountId::new([#(#bytes),*])).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Classic address literal, checked at compile time, as its `[u8; 20]`
+/// account id
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::address_bytes;
+///
+/// const ZERO: [u8; 20] = address_bytes!("rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+///
+/// assert_eq!(ZERO, [0; 20]);
+/// ```
+///
+/// Other tokens are a compile error:
+///
+/// ```compile_fail
+/// use ripple_address_codec::address_bytes;
+///
+/// const SEED: [u8; 20] = address_bytes!("sp6JS7f14BuwFY8Mw6bTtLKWauoUs");
+/// ```
+#[proc_macro]
+pub fn address_bytes(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+
+    match decode_account_id(&literal) {
+        Ok(bytes) => quote!([#(#bytes),*]).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn decode_account_id(literal: &LitStr) -> syn::Result<[u8; ACCOUNT_ID_LEN]> {
+    let address = literal.value();
+    let invalid = |reason: String| {
+        syn::Error::new(
+            literal.span(),
+            format!("invalid classic address {:?}: {}", address, reason),
+        )
+    };
+
+    let decoded = bs58::decode(&address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check(Some(ACCOUNT_ID_PREFIX))
+        .into_vec()
+        .map_err(|error| invalid(error.to_string()))?;
+    let payload = &decoded[1..];
+
+    payload.try_into().map_err(|_| {
+        invalid(format!(
+            "expected {} bytes, got {}",
+            ACCOUNT_ID_LEN,
+            payload.len()
+        ))
+    })
+}
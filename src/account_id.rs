@@ -0,0 +1,214 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{
+    decode_account_id, display, encode_account_id, to_array, Address, Error, Result, VersionedToken,
+};
+
+/// An account id, encoded as a classic address (starting with r...)
+///
+/// A typed wrapper around the raw 20 bytes, so they can't be mixed up
+/// with other 20 bytes hashes.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::AccountId;
+///
+/// let account_id: AccountId = "rrrrrrrrrrrrrrrrrrrrrhoLvTp".parse().unwrap();
+///
+/// assert_eq!(account_id.as_bytes(), &[0; 20]);
+/// assert_eq!(account_id.to_string(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+///
+/// Account ids are ordered byte-wise, like their raw bytes. This
+/// ordering is stable, but differs from the ordering of their
+/// encoded strings.
+///
+/// With the `borsh` feature, it's serialized as its 20 raw bytes.
+///
+/// With the `rkyv` feature, it's archived as its 20 raw bytes, which
+/// `ArchivedAccountId` borrows without parsing or allocating.
+///
+/// With the `serde` feature, it's serialized as the address string in
+/// human-readable formats and as its 20 raw bytes in binary formats.
+///
+/// With the `xrpl-types` and `xrpl-rust` features, it converts to and
+/// from the account id types of those crates, and from an `xrpl-rust`
+/// `Wallet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Clone, Copy, PartialEq, Eq, Hash), compare(PartialEq))
+)]
+#[cfg_attr(
+    feature = "uniffi",
+    derive(uniffi::Object),
+    uniffi::export(Display, Eq, Hash)
+)]
+pub struct AccountId([u8; Address::PAYLOAD_LEN]);
+
+impl AccountId {
+    /// Account id from raw bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AccountId;
+    ///
+    /// assert_eq!(AccountId::new([0; 20]).to_string(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+    /// ```
+    pub const fn new(bytes: [u8; Address::PAYLOAD_LEN]) -> Self {
+        AccountId(bytes)
+    }
+
+    /// Borrow the raw bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AccountId;
+    ///
+    /// assert_eq!(AccountId::new([1; 20]).as_bytes(), &[1; 20]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8; Address::PAYLOAD_LEN] {
+        &self.0
+    }
+
+    /// Unwrap the raw bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AccountId;
+    ///
+    /// assert_eq!(AccountId::new([1; 20]).into_bytes(), [1; 20]);
+    /// ```
+    pub fn into_bytes(self) -> [u8; Address::PAYLOAD_LEN] {
+        self.0
+    }
+
+    /// The classic address shortened to its first 6 and last 5
+    /// characters, for UIs
+    ///
+    /// The `…` between them isn't in the base58 alphabet, so the short
+    /// form never decodes as an address, and can't be pasted as one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AccountId;
+    ///
+    /// let account_id: AccountId = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN".parse().unwrap();
+    ///
+    /// assert_eq!(account_id.display_short(), "rJrRMg…35ErN");
+    /// ```
+    pub fn display_short(&self) -> String {
+        display::shorten(&self.to_string())
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = Error;
+
+    fn from_str(account_id: &str) -> Result<Self> {
+        decode_account_id(account_id).map(AccountId)
+    }
+}
+
+impl TryFrom<&str> for AccountId {
+    type Error = Error;
+
+    fn try_from(account_id: &str) -> Result<Self> {
+        account_id.parse()
+    }
+}
+
+impl TryFrom<&[u8]> for AccountId {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        to_array(bytes).map(AccountId)
+    }
+}
+
+impl From<[u8; Address::PAYLOAD_LEN]> for AccountId {
+    fn from(bytes: [u8; Address::PAYLOAD_LEN]) -> Self {
+        AccountId(bytes)
+    }
+}
+
+impl From<AccountId> for [u8; Address::PAYLOAD_LEN] {
+    fn from(account_id: AccountId) -> Self {
+        account_id.0
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&encode_account_id(&self.0))
+    }
+}
+
+impl AsRef<[u8]> for AccountId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Compares by decoding the string, without encoding the account id
+impl PartialEq<str> for AccountId {
+    fn eq(&self, other: &str) -> bool {
+        decode_account_id(other) == Ok(self.0)
+    }
+}
+
+impl PartialEq<&str> for AccountId {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedAccountId {
+    /// Borrow the raw bytes, in place in the archive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ArchivedAccountId};
+    ///
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&AccountId::new([1; 20])).unwrap();
+    /// let archived = rkyv::access::<ArchivedAccountId, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.as_bytes(), &[1; 20]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8; Address::PAYLOAD_LEN] {
+        &self.0
+    }
+
+    /// Copy out the account id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ArchivedAccountId};
+    ///
+    /// let account_id = AccountId::new([1; 20]);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&account_id).unwrap();
+    /// let archived = rkyv::access::<ArchivedAccountId, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.to_account_id(), account_id);
+    /// ```
+    pub fn to_account_id(&self) -> AccountId {
+        AccountId(self.0)
+    }
+}
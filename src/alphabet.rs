@@ -0,0 +1,186 @@
+use std::fmt;
+
+#[cfg(feature = "simd")]
+use crate::base58;
+use crate::{confusable, Error, Result, ALPHABET};
+
+const LEN: usize = 58;
+const INVALID: u8 = 0xFF;
+
+/// Base58 alphabet
+///
+/// The free functions always use [`Alphabet::XRPL`]; a different one
+/// can be set on a [`Codec`](crate::codec::Codec).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    chars: &'static str,
+    digits: [u8; 256],
+}
+
+impl Alphabet {
+    /// The XRP Ledger alphabet
+    pub const XRPL: Alphabet = Alphabet::from_ascii(ALPHABET);
+
+    /// The Bitcoin alphabet
+    pub const BITCOIN: Alphabet =
+        Alphabet::from_ascii("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// Custom alphabet, `None` unless `chars` are 58 distinct ASCII characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Alphabet;
+    ///
+    /// assert_eq!(
+    ///     Alphabet::new("rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz"),
+    ///     Some(Alphabet::XRPL)
+    /// );
+    /// assert_eq!(Alphabet::new("rpshnaf39w"), None);
+    /// ```
+    pub fn new(chars: &'static str) -> Option<Self> {
+        if chars.len() != LEN || !chars.is_ascii() {
+            return None;
+        }
+
+        let alphabet = Alphabet::from_ascii(chars);
+        let distinct = alphabet
+            .digits
+            .iter()
+            .filter(|&&digit| digit != INVALID)
+            .count();
+
+        if distinct == LEN {
+            Some(alphabet)
+        } else {
+            None
+        }
+    }
+
+    /// The alphabet's characters, in digit order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Alphabet;
+    ///
+    /// assert!(Alphabet::BITCOIN.as_str().starts_with("123"));
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        self.chars
+    }
+
+    /// Value of a character, used by the built-in base58 backend
+    #[cfg_attr(feature = "bs58-backend", allow(dead_code))]
+    pub(crate) fn digit(&self, character: char) -> Option<u8> {
+        match self.digits.get(character as usize) {
+            Some(&digit) if digit != INVALID => Some(digit),
+            _ => None,
+        }
+    }
+
+    /// Value of every byte, `0xFF` outside the alphabet, used by the
+    /// vectorised digit mapping
+    #[cfg(feature = "simd")]
+    pub(crate) fn digit_table(&self) -> &[u8; 256] {
+        &self.digits
+    }
+
+    /// Reject `s` if it has a character outside the alphabet, before any
+    /// base58 arithmetic
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadAlphabet`] for the first character outside the
+    /// alphabet, or [`Error::Confusable`] if it is a lookalike or an
+    /// invisible character.
+    pub(crate) fn check(&self, s: &str) -> Result<()> {
+        if self.contains_only(s) {
+            return Ok(());
+        }
+
+        let (index, character) = s
+            .chars()
+            .enumerate()
+            .find(|&(_, character)| self.digit(character).is_none())
+            .expect("a character outside the alphabet");
+
+        if confusable::is_invisible(character) {
+            return Err(Error::Confusable {
+                character,
+                index,
+                lookalike: None,
+            });
+        }
+
+        match confusable::ascii_lookalike(character) {
+            Some(lookalike) => Err(Error::Confusable {
+                character,
+                index,
+                lookalike: Some(lookalike),
+            }),
+            None => Err(Error::BadAlphabet { character, index }),
+        }
+    }
+
+    /// Whether every character of `s` is in the alphabet, one table lookup
+    /// per byte
+    ///
+    /// Bytes of non-ASCII characters are all outside the table.
+    #[cfg(not(feature = "simd"))]
+    fn contains_only(&self, s: &str) -> bool {
+        s.bytes()
+            .all(|byte| self.digits[usize::from(byte)] != INVALID)
+    }
+
+    /// Whether every character of `s` is in the alphabet, a few vector
+    /// lookups per token
+    #[cfg(feature = "simd")]
+    fn contains_only(&self, s: &str) -> bool {
+        base58::contains_only(self, s.as_bytes())
+    }
+
+    const fn from_ascii(chars: &'static str) -> Self {
+        let bytes = chars.as_bytes();
+        let mut digits = [INVALID; 256];
+        let mut digit = 0;
+
+        while digit < bytes.len() {
+            digits[bytes[digit] as usize] = digit as u8;
+            digit += 1;
+        }
+
+        Alphabet { chars, digits }
+    }
+}
+
+/// Whether `s` only has characters of the XRP Ledger base58 alphabet
+///
+/// A near-free first-stage filter for untrusted input: a single table
+/// lookup per byte, without any base58 decoding or hashing. Passing it
+/// doesn't make `s` a valid token, failing it means `s` can't be one.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::contains_only_xrpl_alphabet;
+///
+/// assert!(contains_only_xrpl_alphabet("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// assert!(!contains_only_xrpl_alphabet("rrrrrrrrrrrrrrrrrrrrrhoLvT0"));
+/// assert!(!contains_only_xrpl_alphabet("rrrrrrrrrrrrrrrrrrrrrhoLvTé"));
+/// ```
+pub fn contains_only_xrpl_alphabet(s: &str) -> bool {
+    Alphabet::XRPL.contains_only(s)
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::XRPL
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Alphabet").field(&self.chars).finish()
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use crate::{
+    decode_seed_untraced, first_match, seed::Redacted, trace, xaddress::decode_xaddress_untraced,
+    AccountPrivateKey, AccountPublicKey, Address, Algorithm, Entropy, FamilyGenerator, Network,
+    NodePrivateKey, NodePublicKey, Result, Tag, TokenType, VersionedToken, ACCOUNT_PRIVATE_KEY,
+    ACCOUNT_PUBLIC_KEY, ADDRESS, FAMILY_GENERATOR, NODE_PRIVATE_KEY, NODE_PUBLIC_KEY,
+};
+
+/// A decoded token of any known type, see [`decode_any`]
+///
+/// [`Debug`](fmt::Debug) output redacts seeds and private keys.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XrplToken {
+    /// Classic address (starting with r...)
+    AccountId([u8; Address::PAYLOAD_LEN]),
+    /// X-address (starting with X... or T...)
+    XAddress {
+        /// Account id bytes
+        account_id: [u8; Address::PAYLOAD_LEN],
+        /// Destination tag
+        tag: Tag,
+        /// Network of the X-address prefix
+        network: Network,
+    },
+    /// Seed (starting with s... or sEd...)
+    Seed {
+        /// Seed entropy
+        entropy: Entropy,
+        /// Algorithm the seed is intended to be used with
+        algorithm: Algorithm,
+    },
+    /// Node public key (starting with n...)
+    NodePublicKey([u8; NodePublicKey::PAYLOAD_LEN]),
+    /// Account public key (starting with a...)
+    AccountPublicKey([u8; AccountPublicKey::PAYLOAD_LEN]),
+    /// Node private key (starting with p...)
+    NodePrivateKey([u8; NodePrivateKey::PAYLOAD_LEN]),
+    /// Account private key (starting with p...)
+    AccountPrivateKey([u8; AccountPrivateKey::PAYLOAD_LEN]),
+    /// Family generator (starting with f...)
+    FamilyGenerator([u8; FamilyGenerator::PAYLOAD_LEN]),
+}
+
+impl XrplToken {
+    /// The type of the decoded token
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{decode_any, TokenType};
+    ///
+    /// let token = decode_any("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+    ///
+    /// assert_eq!(token.token_type(), TokenType::SeedEd25519);
+    /// # }
+    /// ```
+    pub fn token_type(&self) -> TokenType {
+        match self {
+            XrplToken::AccountId(_) => TokenType::AccountId,
+            XrplToken::XAddress { .. } => TokenType::XAddress,
+            XrplToken::Seed { algorithm, .. } => TokenType::seed(*algorithm),
+            XrplToken::NodePublicKey(_) => TokenType::NodePublicKey,
+            XrplToken::AccountPublicKey(_) => TokenType::AccountPublicKey,
+            XrplToken::NodePrivateKey(_) => TokenType::NodePrivateKey,
+            XrplToken::AccountPrivateKey(_) => TokenType::AccountPrivateKey,
+            XrplToken::FamilyGenerator(_) => TokenType::FamilyGenerator,
+        }
+    }
+}
+
+impl fmt::Debug for XrplToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XrplToken::AccountId(bytes) => f.debug_tuple("AccountId").field(bytes).finish(),
+            XrplToken::XAddress {
+                account_id,
+                tag,
+                network,
+            } => f
+                .debug_struct("XAddress")
+                .field("account_id", account_id)
+                .field("tag", tag)
+                .field("network", network)
+                .finish(),
+            XrplToken::Seed { algorithm, .. } => f
+                .debug_struct("Seed")
+                .field("entropy", &Redacted)
+                .field("algorithm", algorithm)
+                .finish(),
+            XrplToken::NodePublicKey(bytes) => f.debug_tuple("NodePublicKey").field(bytes).finish(),
+            XrplToken::AccountPublicKey(bytes) => {
+                f.debug_tuple("AccountPublicKey").field(bytes).finish()
+            }
+            XrplToken::NodePrivateKey(_) => {
+                f.debug_tuple("NodePrivateKey").field(&Redacted).finish()
+            }
+            XrplToken::AccountPrivateKey(_) => {
+                f.debug_tuple("AccountPrivateKey").field(&Redacted).finish()
+            }
+            XrplToken::FamilyGenerator(bytes) => {
+                f.debug_tuple("FamilyGenerator").field(bytes).finish()
+            }
+        }
+    }
+}
+
+/// Decode a token of any known type, identifying which type it is
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_any, XrplToken};
+///
+/// assert_eq!(
+///     decode_any("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+///     Ok(XrplToken::AccountId([0; 20]))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why the string is not
+/// a valid token of any known type.
+pub fn decode_any(token: &str) -> Result<XrplToken> {
+    let decoders: [fn(&str) -> Result<XrplToken>; 8] = [
+        |token| ADDRESS.decode(token).map(XrplToken::AccountId),
+        |token| {
+            decode_xaddress_untraced(token).map(|(account_id, tag, network)| XrplToken::XAddress {
+                account_id,
+                tag,
+                network,
+            })
+        },
+        |token| {
+            decode_seed_untraced(token)
+                .map(|(entropy, algorithm)| XrplToken::Seed { entropy, algorithm })
+        },
+        |token| NODE_PUBLIC_KEY.decode(token).map(XrplToken::NodePublicKey),
+        |token| {
+            ACCOUNT_PUBLIC_KEY
+                .decode(token)
+                .map(XrplToken::AccountPublicKey)
+        },
+        |token| {
+            NODE_PRIVATE_KEY
+                .decode(token)
+                .map(XrplToken::NodePrivateKey)
+        },
+        |token| {
+            ACCOUNT_PRIVATE_KEY
+                .decode(token)
+                .map(XrplToken::AccountPrivateKey)
+        },
+        |token| {
+            FAMILY_GENERATOR
+                .decode(token)
+                .map(XrplToken::FamilyGenerator)
+        },
+    ];
+
+    trace::decoded(
+        "any",
+        token,
+        first_match(decoders.iter().map(|decode| decode(token))),
+    )
+}
@@ -0,0 +1,83 @@
+//! Batch encoding into a bump arena
+//!
+//! Available with the `bumpalo` feature.
+
+use std::str;
+
+use bumpalo::Bump;
+
+use crate::{
+    encode_account_id_into, encode_seed_into, wipe, Address, Algorithm, Entropy, VersionedToken,
+    MAX_TOKEN_LEN,
+};
+
+/// Encode account ids as classic addresses allocated in the given arena
+///
+/// All output strings and the slice holding them live in `bump`, so
+/// encoding millions of addresses costs a handful of arena chunk
+/// allocations instead of one `String` per address.
+///
+/// # Examples
+///
+/// ```
+/// use bumpalo::Bump;
+/// use ripple_address_codec::arena::encode_account_ids_in;
+///
+/// let bump = Bump::new();
+///
+/// assert_eq!(
+///     encode_account_ids_in(&bump, &[[0; 20], [0; 20]]),
+///     ["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTp"]
+/// );
+/// ```
+pub fn encode_account_ids_in<'bump>(
+    bump: &'bump Bump,
+    account_ids: &[[u8; Address::PAYLOAD_LEN]],
+) -> &'bump [&'bump str] {
+    let mut buffer = [0; MAX_TOKEN_LEN];
+
+    bump.alloc_slice_fill_iter(account_ids.iter().map(|account_id| {
+        let len =
+            encode_account_id_into(account_id, &mut buffer).expect("buffer fits any account id");
+        &*bump.alloc_str(as_str(&buffer[..len]))
+    }))
+}
+
+/// Encode entropies as seeds allocated in the given arena
+///
+/// Every seed is encoded with the same `algorithm`, into one stack
+/// buffer that's wiped afterwards with the `zeroize` feature; the
+/// seeds in the arena are the only copies left.
+///
+/// # Examples
+///
+/// ```
+/// use bumpalo::Bump;
+/// use ripple_address_codec::{arena::encode_seeds_in, Ed25519};
+///
+/// let bump = Bump::new();
+///
+/// assert_eq!(
+///     encode_seeds_in(&bump, &[[0; 16]], Ed25519),
+///     ["sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]
+/// );
+/// ```
+pub fn encode_seeds_in<'bump>(
+    bump: &'bump Bump,
+    entropies: &[Entropy],
+    algorithm: Algorithm,
+) -> &'bump [&'bump str] {
+    let mut buffer = [0; MAX_TOKEN_LEN];
+
+    let seeds = bump.alloc_slice_fill_iter(entropies.iter().map(|entropy| {
+        let len = encode_seed_into(entropy, algorithm, &mut buffer).expect("buffer fits any seed");
+        &*bump.alloc_str(as_str(&buffer[..len]))
+    }));
+    wipe(&mut buffer);
+
+    seeds
+}
+
+fn as_str(encoded: &[u8]) -> &str {
+    str::from_utf8(encoded).expect("base58 alphabets are ASCII")
+}
@@ -0,0 +1,270 @@
+//! Decoding of ASCII byte slices, such as buffers read off a socket or
+//! protobuf `bytes` fields, without UTF-8 validation
+
+use std::str;
+
+use crate::{
+    decode_account_id, decode_account_private_key, decode_account_public_key, decode_any,
+    decode_family_generator, decode_node_private_key, decode_node_public_key, decode_seed,
+    decode_xaddress, trace, AccountPrivateKey, AccountPublicKey, Address, Algorithm, Alphabet,
+    Entropy, Error, FamilyGenerator, Network, NodePrivateKey, NodePublicKey, Result, Tag,
+    VersionedToken, XrplToken,
+};
+
+/// Decode an ASCII classic address (starting with r...) to its raw bytes
+///
+/// Same as [`decode_account_id`], without converting the bytes to a
+/// string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_account_id_bytes;
+///
+/// assert_eq!(decode_account_id_bytes(b"rrrrrrrrrrrrrrrrrrrrrhoLvTp"), Ok([0; 20]));
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_id`], with bytes that aren't UTF-8 reported
+/// as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_account_id_bytes(account_id: &[u8]) -> Result<[u8; Address::PAYLOAD_LEN]> {
+    decode_account_id(ascii_str("account_id", account_id)?)
+}
+
+/// Decode an ASCII seed to its entropy and algorithm
+///
+/// Same as [`decode_seed`], without converting the bytes to a string
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{decode_seed_bytes, Ed25519};
+///
+/// assert_eq!(decode_seed_bytes(b"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"), Ok(([0; 16], Ed25519)));
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_seed`], with bytes that aren't UTF-8 reported as
+/// [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_seed_bytes(seed: &[u8]) -> Result<(Entropy, Algorithm)> {
+    decode_seed(ascii_str("seed", seed)?)
+}
+
+/// Decode an ASCII X-address to its account id, destination tag and
+/// network
+///
+/// Same as [`decode_xaddress`](crate::decode_xaddress), without
+/// converting the bytes to a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_xaddress_bytes, Network, Tag};
+///
+/// assert_eq!(
+///     decode_xaddress_bytes(b"X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"),
+///     Ok(([0; 20], Tag::None, Network::Main))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_xaddress`](crate::decode_xaddress), with bytes that
+/// aren't UTF-8 reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_xaddress_bytes(
+    xaddress: &[u8],
+) -> Result<([u8; Address::PAYLOAD_LEN], Tag, Network)> {
+    decode_xaddress(ascii_str("xaddress", xaddress)?)
+}
+
+/// Decode an ASCII node public key (starting with n...) to its raw bytes
+///
+/// Same as [`decode_node_public_key`], without converting the bytes to
+/// a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_node_public_key_bytes;
+///
+/// let key =
+///     decode_node_public_key_bytes(b"n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH").unwrap();
+///
+/// assert_eq!(key[0], 0x03);
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_node_public_key`], with bytes that aren't UTF-8
+/// reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_node_public_key_bytes(
+    node_public_key: &[u8],
+) -> Result<[u8; NodePublicKey::PAYLOAD_LEN]> {
+    decode_node_public_key(ascii_str("node_public_key", node_public_key)?)
+}
+
+/// Decode an ASCII account public key (starting with a...) to its raw
+/// bytes
+///
+/// Same as [`decode_account_public_key`], without converting the bytes
+/// to a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_account_public_key_bytes;
+///
+/// let key = decode_account_public_key_bytes(
+///     b"aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(key[0], 0x02);
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_public_key`], with bytes that aren't UTF-8
+/// reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_account_public_key_bytes(
+    account_public_key: &[u8],
+) -> Result<[u8; AccountPublicKey::PAYLOAD_LEN]> {
+    decode_account_public_key(ascii_str("account_public_key", account_public_key)?)
+}
+
+/// Decode an ASCII node private key (starting with p...) to its raw
+/// bytes
+///
+/// Same as [`decode_node_private_key`], without converting the bytes to
+/// a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{
+///     decode_node_private_key_bytes, encode_node_private_key,
+/// };
+///
+/// let encoded = encode_node_private_key(&[7; 32]);
+///
+/// assert_eq!(decode_node_private_key_bytes(encoded.as_bytes()), Ok([7; 32]));
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_node_private_key`], with bytes that aren't UTF-8
+/// reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_node_private_key_bytes(
+    node_private_key: &[u8],
+) -> Result<[u8; NodePrivateKey::PAYLOAD_LEN]> {
+    decode_node_private_key(ascii_str("node_private_key", node_private_key)?)
+}
+
+/// Decode an ASCII account private key (starting with p...) to its raw
+/// bytes
+///
+/// Same as [`decode_account_private_key`], without converting the bytes
+/// to a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{
+///     decode_account_private_key_bytes, encode_account_private_key,
+/// };
+///
+/// let encoded = encode_account_private_key(&[7; 32]);
+///
+/// assert_eq!(decode_account_private_key_bytes(encoded.as_bytes()), Ok([7; 32]));
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_private_key`], with bytes that aren't UTF-8
+/// reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_account_private_key_bytes(
+    account_private_key: &[u8],
+) -> Result<[u8; AccountPrivateKey::PAYLOAD_LEN]> {
+    decode_account_private_key(ascii_str("account_private_key", account_private_key)?)
+}
+
+/// Decode an ASCII family generator (starting with f...) to its raw
+/// bytes
+///
+/// Same as [`decode_family_generator`], without converting the bytes to
+/// a string first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_family_generator_bytes, encode_family_generator};
+///
+/// let encoded = encode_family_generator(&[2; 33]);
+///
+/// assert_eq!(decode_family_generator_bytes(encoded.as_bytes()), Ok([2; 33]));
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_family_generator`], with bytes that aren't UTF-8
+/// reported as [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_family_generator_bytes(
+    family_generator: &[u8],
+) -> Result<[u8; FamilyGenerator::PAYLOAD_LEN]> {
+    decode_family_generator(ascii_str("family_generator", family_generator)?)
+}
+
+/// Decode an ASCII token of any known type, identifying which type it is
+///
+/// Same as [`decode_any`], without converting the bytes to a string
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_any_bytes, XrplToken};
+///
+/// assert_eq!(
+///     decode_any_bytes(b"rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+///     Ok(XrplToken::AccountId([0; 20]))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_any`], with bytes that aren't UTF-8 reported as
+/// [`Error::BadAlphabet`] for U+FFFD.
+pub fn decode_any_bytes(token: &[u8]) -> Result<XrplToken> {
+    decode_any(ascii_str("any", token)?)
+}
+
+/// `bytes` as a string slice, without UTF-8 validation when they're
+/// ASCII as any valid token is
+///
+/// Other UTF-8 is left to the string decoders, which report the first
+/// character outside the alphabet. Bytes that aren't UTF-8 are reported
+/// the same way, as U+FFFD, unless an earlier character is outside the
+/// alphabet.
+fn ascii_str<'a>(token_type: &'static str, bytes: &'a [u8]) -> Result<&'a str> {
+    if bytes.is_ascii() {
+        // SAFETY: ASCII is valid UTF-8
+        return Ok(unsafe { str::from_utf8_unchecked(bytes) });
+    }
+
+    let result = str::from_utf8(bytes).or_else(|error| {
+        let valid = str::from_utf8(&bytes[..error.valid_up_to()]).expect("valid up to there");
+        Alphabet::XRPL.check(valid)?;
+
+        Err(Error::BadAlphabet {
+            character: char::REPLACEMENT_CHARACTER,
+            index: valid.chars().count(),
+        })
+    });
+
+    trace::decoded(token_type, bytes, result)
+}
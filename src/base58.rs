@@ -0,0 +1,30 @@
+//! Base58 for the small, fixed-size payloads of XRP Ledger tokens
+//!
+//! The built-in backend is used by default, the `bs58-backend` feature
+//! swaps in the `bs58` crate. Both report the same errors. The `simd`
+//! feature vectorises the alphabet validation and the digit mapping of
+//! the built-in backend.
+
+#[cfg(feature = "bs58-backend")]
+mod bs58_backend;
+#[cfg(not(feature = "bs58-backend"))]
+mod native;
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(feature = "bs58-backend")]
+pub(crate) use self::bs58_backend::{decode, decode_into, encode, encode_into};
+#[cfg(not(feature = "bs58-backend"))]
+pub(crate) use self::native::{decode, decode_into, encode, encode_into};
+#[cfg(feature = "simd")]
+pub(crate) use self::simd::contains_only;
+
+/// Upper bound of the encoded length of `len` bytes (log 256 / log 58 < 1.37)
+pub(crate) const fn max_encoded_len(len: usize) -> usize {
+    len * 137 / 100 + 1
+}
+
+/// Upper bound of the decoded length of `len` characters (log 58 / log 256 < 0.74)
+pub(crate) const fn max_decoded_len(len: usize) -> usize {
+    len * 74 / 100 + 1
+}
@@ -0,0 +1,115 @@
+//! Base58 backend on top of the `bs58` crate
+//!
+//! Available with the `bs58-backend` feature. Errors are translated to
+//! the ones of the built-in backend, so callers can't tell them apart.
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::convert::TryInto;
+
+use super::{max_decoded_len, max_encoded_len};
+use crate::{wipe, Alphabet, Error, Result};
+
+/// Encode bytes with the alphabet
+pub(crate) fn encode(alphabet: &Alphabet, bytes: &[u8]) -> String {
+    let mut encoded = vec![0; max_encoded_len(bytes.len())];
+
+    let len = bs58::encode(bytes)
+        .with_alphabet(&to_bs58(alphabet))
+        .onto(&mut encoded[..])
+        .expect("buffer fits the longest encoding");
+    wipe(&mut encoded[len..]);
+    encoded.truncate(len);
+
+    String::from_utf8(encoded).expect("base58 alphabets are ASCII")
+}
+
+/// Encode bytes with the alphabet into `out`, returning the encoded length
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] if the output doesn't fit, leaving
+/// the contents of `out` unspecified.
+pub(crate) fn encode_into(alphabet: &Alphabet, bytes: &[u8], out: &mut [u8]) -> Result<usize> {
+    bs58::encode(bytes)
+        .with_alphabet(&to_bs58(alphabet))
+        .onto(&mut *out)
+        .map_err(|_| {
+            let mut encoded = encode(alphabet, bytes);
+            let required = encoded.len();
+            wipe(&mut encoded);
+
+            Error::BufferTooSmall {
+                required,
+                available: out.len(),
+            }
+        })
+}
+
+/// Decode a string with the alphabet
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] for the first character outside the alphabet.
+pub(crate) fn decode(alphabet: &Alphabet, s: &str) -> Result<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(max_decoded_len(s.len()));
+
+    bs58::decode(s)
+        .with_alphabet(&to_bs58(alphabet))
+        .onto(&mut decoded)
+        .map_err(|error| from_bs58(s, error))?;
+
+    Ok(decoded)
+}
+
+/// Decode a string with the alphabet into `out`, returning the decoded length
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] for the first character outside the
+/// alphabet and [`Error::BufferTooSmall`] if the output doesn't fit,
+/// leaving the contents of `out` unspecified.
+pub(crate) fn decode_into(alphabet: &Alphabet, s: &str, out: &mut [u8]) -> Result<usize> {
+    match bs58::decode(s)
+        .with_alphabet(&to_bs58(alphabet))
+        .onto(&mut *out)
+    {
+        Ok(len) => Ok(len),
+        // bs58 stops at the end of the buffer, possibly before an
+        // invalid character, so decode again to report the right error
+        Err(bs58::decode::Error::BufferTooSmall) => {
+            let mut decoded = decode(alphabet, s)?;
+            let required = decoded.len();
+            wipe(&mut decoded);
+
+            Err(Error::BufferTooSmall {
+                required,
+                available: out.len(),
+            })
+        }
+        Err(error) => Err(from_bs58(s, error)),
+    }
+}
+
+fn to_bs58(alphabet: &Alphabet) -> bs58::Alphabet {
+    let chars = alphabet
+        .as_str()
+        .as_bytes()
+        .try_into()
+        .expect("alphabets have 58 characters");
+
+    bs58::Alphabet::new(chars).expect("alphabets have distinct ASCII characters")
+}
+
+/// Translate a `bs58` error, whose positions are byte indices
+fn from_bs58(s: &str, error: bs58::decode::Error) -> Error {
+    match error {
+        bs58::decode::Error::InvalidCharacter { index, .. }
+        | bs58::decode::Error::NonAsciiCharacter { index } => Error::BadAlphabet {
+            character: s[index..].chars().next().expect("index of a character"),
+            index: s[..index].chars().count(),
+        },
+        _ => Error::DecodeError,
+    }
+}
@@ -0,0 +1,291 @@
+//! Built-in base58 backend
+//!
+//! Numbers are kept in stack allocated limbs, falling back to the heap
+//! only for inputs longer than any token. Encoding works in base 58^5
+//! limbs consuming 4 bytes at a time, and decoding in base 2^32 limbs
+//! consuming 5 digits at a time, so both do a fraction of the work of
+//! byte by byte conversion.
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+#[cfg(feature = "simd")]
+use super::simd;
+use super::{max_decoded_len, max_encoded_len};
+use crate::{wipe, Alphabet, Error, Result};
+
+/// 58^5, the largest power of 58 whose limbs fit in `u32` with room
+/// for carries in `u64`
+const LIMB_BASE: u64 = 656_356_768;
+const DIGITS_PER_LIMB: usize = 5;
+
+/// Limbs kept on the stack, enough for payloads of up to 80 bytes
+const STACK_LIMBS: usize = 24;
+
+/// Encode bytes with the alphabet
+pub(crate) fn encode(alphabet: &Alphabet, bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(max_encoded_len(bytes.len()));
+
+    encode_with(alphabet, bytes, |character| encoded.push(character as char));
+
+    encoded
+}
+
+/// Encode bytes with the alphabet into `out`, returning the encoded length
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] if the output doesn't fit, leaving
+/// the contents of `out` unspecified.
+pub(crate) fn encode_into(alphabet: &Alphabet, bytes: &[u8], out: &mut [u8]) -> Result<usize> {
+    let mut len = 0;
+
+    encode_with(alphabet, bytes, |character| {
+        if let Some(slot) = out.get_mut(len) {
+            *slot = character;
+        }
+        len += 1;
+    });
+
+    if len > out.len() {
+        return Err(Error::BufferTooSmall {
+            required: len,
+            available: out.len(),
+        });
+    }
+
+    Ok(len)
+}
+
+/// Decode a string with the alphabet
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] for the first character outside the alphabet.
+pub(crate) fn decode(alphabet: &Alphabet, s: &str) -> Result<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(max_decoded_len(s.len()));
+
+    decode_with(alphabet, s, |byte| decoded.push(byte))?;
+
+    Ok(decoded)
+}
+
+/// Decode a string with the alphabet into `out`, returning the decoded length
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] for the first character outside the
+/// alphabet and [`Error::BufferTooSmall`] if the output doesn't fit,
+/// leaving the contents of `out` unspecified.
+pub(crate) fn decode_into(alphabet: &Alphabet, s: &str, out: &mut [u8]) -> Result<usize> {
+    let mut len = 0;
+
+    decode_with(alphabet, s, |byte| {
+        if let Some(slot) = out.get_mut(len) {
+            *slot = byte;
+        }
+        len += 1;
+    })?;
+
+    if len > out.len() {
+        return Err(Error::BufferTooSmall {
+            required: len,
+            available: out.len(),
+        });
+    }
+
+    Ok(len)
+}
+
+/// Encode bytes, passing each ASCII output character to `push` in order
+fn encode_with(alphabet: &Alphabet, bytes: &[u8], mut push: impl FnMut(u8)) {
+    let digits = alphabet.as_str().as_bytes();
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+    let mut stack = [0; STACK_LIMBS];
+    let mut heap;
+    let needed = max_encoded_len(bytes.len() - zeros) / DIGITS_PER_LIMB + 1;
+    let limbs: &mut [u32] = if needed <= STACK_LIMBS {
+        &mut stack
+    } else {
+        heap = vec![0; needed];
+        &mut heap
+    };
+
+    let mut len = 0;
+
+    for chunk in bytes[zeros..].chunks(4) {
+        let shift = 8 * chunk.len();
+        let mut carry = chunk
+            .iter()
+            .fold(0, |value, &byte| value << 8 | u64::from(byte));
+
+        for limb in limbs[..len].iter_mut() {
+            carry += u64::from(*limb) << shift;
+            *limb = (carry % LIMB_BASE) as u32;
+            carry /= LIMB_BASE;
+        }
+
+        while carry > 0 {
+            limbs[len] = (carry % LIMB_BASE) as u32;
+            carry /= LIMB_BASE;
+            len += 1;
+        }
+    }
+
+    for _ in 0..zeros {
+        push(digits[0]);
+    }
+
+    for (position, &limb) in limbs[..len].iter().enumerate().rev() {
+        let mut limb_digits = [0; DIGITS_PER_LIMB];
+        let mut value = limb;
+
+        for digit in limb_digits.iter_mut().rev() {
+            *digit = (value % 58) as usize;
+            value /= 58;
+        }
+
+        // The most significant limb has no leading zero digits
+        let skip = if position == len - 1 {
+            limb_digits.iter().take_while(|&&digit| digit == 0).count()
+        } else {
+            0
+        };
+
+        for &digit in &limb_digits[skip..] {
+            push(digits[digit]);
+        }
+
+        wipe(&mut limb_digits);
+    }
+
+    wipe(limbs);
+}
+
+/// Decode a string, passing each output byte to `push` in order
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] for the first character outside the
+/// alphabet, before `push` is called.
+fn decode_with(alphabet: &Alphabet, s: &str, push: impl FnMut(u8)) -> Result<()> {
+    #[cfg(feature = "simd")]
+    {
+        let mut stack = [0; simd::MAX_LEN];
+
+        if let Some(digits) = stack.get_mut(..s.len()) {
+            // Characters outside the alphabet are reported by the scalar path
+            if simd::map_digits(alphabet, s.as_bytes(), digits) {
+                let decoded = decode_digits(digits.iter().map(|&digit| Ok(digit)), s.len(), push);
+                wipe(digits);
+
+                return decoded;
+            }
+        }
+    }
+
+    let digits = s.chars().enumerate().map(|(index, character)| {
+        alphabet
+            .digit(character)
+            .ok_or(Error::BadAlphabet { character, index })
+    });
+
+    decode_digits(digits, s.len(), push)
+}
+
+/// Decode base58 digits, at most `len` of them, passing each output
+/// byte to `push` in order
+///
+/// # Errors
+///
+/// Returns the first error of `digits`, before `push` is called.
+fn decode_digits(
+    digits: impl Iterator<Item = Result<u8>>,
+    len: usize,
+    mut push: impl FnMut(u8),
+) -> Result<()> {
+    let mut stack = [0; STACK_LIMBS];
+    let mut heap;
+    let needed = max_decoded_len(len) / 4 + 1;
+    let limbs: &mut [u32] = if needed <= STACK_LIMBS {
+        &mut stack
+    } else {
+        heap = vec![0; needed];
+        &mut heap
+    };
+
+    let mut zeros = 0;
+    let mut len = 0;
+    let mut chunk = 0;
+    let mut chunk_base = 1;
+
+    for digit in digits {
+        let digit = match digit {
+            Ok(digit) => digit,
+            Err(error) => {
+                wipe(limbs);
+                return Err(error);
+            }
+        };
+
+        // Leading zero digits are leading zero bytes
+        if digit == 0 && len == 0 && chunk_base == 1 {
+            zeros += 1;
+            continue;
+        }
+
+        chunk = chunk * 58 + u64::from(digit);
+        chunk_base *= 58;
+
+        if chunk_base == LIMB_BASE {
+            len = mul_add(limbs, len, chunk_base, chunk);
+            chunk = 0;
+            chunk_base = 1;
+        }
+    }
+
+    if chunk_base > 1 {
+        len = mul_add(limbs, len, chunk_base, chunk);
+    }
+
+    for _ in 0..zeros {
+        push(0);
+    }
+
+    let mut significant = false;
+
+    for &limb in limbs[..len].iter().rev() {
+        for byte in limb.to_be_bytes().iter() {
+            significant |= *byte != 0;
+
+            if significant {
+                push(*byte);
+            }
+        }
+    }
+
+    wipe(limbs);
+
+    Ok(())
+}
+
+/// `limbs[..len] * factor + addend`, returning the new length
+fn mul_add(limbs: &mut [u32], len: usize, factor: u64, addend: u64) -> usize {
+    let mut len = len;
+    let mut carry = addend;
+
+    for limb in limbs[..len].iter_mut() {
+        carry += u64::from(*limb) * factor;
+        *limb = carry as u32;
+        carry >>= 32;
+    }
+
+    while carry > 0 {
+        limbs[len] = carry as u32;
+        carry >>= 32;
+        len += 1;
+    }
+
+    len
+}
@@ -0,0 +1,159 @@
+//! Vectorised alphabet mapping, for validation and the built-in base58
+//! backend
+//!
+//! Maps a whole token to digits and validates it in one or two vector
+//! lookups: AVX2 on x86_64, detected at runtime, and NEON on aarch64.
+//! SGX enclaves can't trust `cpuid`, so they always take the scalar path,
+//! as do other targets.
+
+use crate::{wipe, Alphabet};
+
+/// Value of bytes outside the alphabet in the digit table
+const INVALID: u8 = 0xFF;
+
+/// Longest input mapped on the stack, any token is shorter
+pub(super) const MAX_LEN: usize = 128;
+
+/// Whether every byte of `s` is in the alphabet
+pub(crate) fn contains_only(alphabet: &Alphabet, s: &[u8]) -> bool {
+    let mut scratch = [0; MAX_LEN];
+    let valid = s
+        .chunks(MAX_LEN)
+        .all(|chunk| map_digits(alphabet, chunk, &mut scratch[..chunk.len()]));
+
+    wipe(&mut scratch);
+
+    valid
+}
+
+/// Map every byte of `s` to its digit in `digits`, of the same length,
+/// returning whether they are all in the alphabet
+///
+/// The contents of `digits` are unspecified if not.
+pub(super) fn map_digits(alphabet: &Alphabet, s: &[u8], digits: &mut [u8]) -> bool {
+    debug_assert_eq!(s.len(), digits.len());
+
+    let table = alphabet.digit_table();
+
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(target_env = "sgx"),
+        not(feature = "mesalock_sgx")
+    ))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // Safety: AVX2 is available
+            return unsafe { avx2::map_digits(table, s, digits) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // Safety: NEON is enabled for the whole target
+        unsafe { neon::map_digits(table, s, digits) }
+    }
+
+    #[cfg(not(all(target_arch = "aarch64", target_feature = "neon")))]
+    {
+        map_digits_scalar(table, s, digits)
+    }
+}
+
+/// One table lookup per byte, for targets and tails without vectors
+fn map_digits_scalar(table: &[u8; 256], s: &[u8], digits: &mut [u8]) -> bool {
+    let mut valid = true;
+
+    for (digit, &byte) in digits.iter_mut().zip(s) {
+        *digit = table[usize::from(byte)];
+        valid &= *digit != INVALID;
+    }
+
+    valid
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    not(target_env = "sgx"),
+    not(feature = "mesalock_sgx")
+))]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    use super::{map_digits_scalar, INVALID};
+
+    /// 32 bytes at a time: the table is split into 16 byte rows, each
+    /// looked up by the low nibble and kept where the high nibble selects
+    /// it. Non-ASCII bytes select no row and are caught by their high bit.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn map_digits(table: &[u8; 256], s: &[u8], digits: &mut [u8]) -> bool {
+        let nibble = _mm256_set1_epi8(0x0F);
+        let invalid = _mm256_set1_epi8(INVALID as i8);
+
+        let mut rows = [_mm256_setzero_si256(); 8];
+        for (row, entries) in rows.iter_mut().zip(table.chunks_exact(16)) {
+            *row = _mm256_broadcastsi128_si256(_mm_loadu_si128(entries.as_ptr().cast()));
+        }
+
+        let mut valid = true;
+        let mut chunks = s.chunks_exact(32);
+        let mut outs = digits.chunks_exact_mut(32);
+
+        for (chunk, out) in (&mut chunks).zip(&mut outs) {
+            let bytes = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let low = _mm256_and_si256(bytes, nibble);
+            let high = _mm256_and_si256(_mm256_srli_epi16(bytes, 4), nibble);
+
+            let mut mapped = _mm256_setzero_si256();
+            for (index, row) in rows.iter().enumerate() {
+                let selected = _mm256_cmpeq_epi8(high, _mm256_set1_epi8(index as i8));
+                let looked_up = _mm256_shuffle_epi8(*row, low);
+                mapped = _mm256_or_si256(mapped, _mm256_and_si256(selected, looked_up));
+            }
+
+            let rejected = _mm256_or_si256(bytes, _mm256_cmpeq_epi8(mapped, invalid));
+            valid &= _mm256_movemask_epi8(rejected) == 0;
+
+            _mm256_storeu_si256(out.as_mut_ptr().cast(), mapped);
+        }
+
+        map_digits_scalar(table, chunks.remainder(), outs.into_remainder()) && valid
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use std::arch::aarch64::*;
+
+    use super::{map_digits_scalar, INVALID};
+
+    /// 16 bytes at a time: each half of the ASCII table is one 64 byte
+    /// lookup, out of range indices give 0. Non-ASCII bytes are out of
+    /// range of both and caught by their high bit.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn map_digits(table: &[u8; 256], s: &[u8], digits: &mut [u8]) -> bool {
+        let low_half = vld1q_u8_x4(table.as_ptr());
+        let high_half = vld1q_u8_x4(table[64..].as_ptr());
+        let half = vdupq_n_u8(64);
+        let non_ascii = vdupq_n_u8(0x80);
+        let invalid = vdupq_n_u8(INVALID);
+
+        let mut valid = true;
+        let mut chunks = s.chunks_exact(16);
+        let mut outs = digits.chunks_exact_mut(16);
+
+        for (chunk, out) in (&mut chunks).zip(&mut outs) {
+            let bytes = vld1q_u8(chunk.as_ptr());
+            let mapped = vorrq_u8(
+                vqtbl4q_u8(low_half, bytes),
+                vqtbl4q_u8(high_half, vsubq_u8(bytes, half)),
+            );
+
+            let rejected = vorrq_u8(vcgeq_u8(bytes, non_ascii), vceqq_u8(mapped, invalid));
+            valid &= vmaxvq_u8(rejected) == 0;
+
+            vst1q_u8(out.as_mut_ptr(), mapped);
+        }
+
+        map_digits_scalar(table, chunks.remainder(), outs.into_remainder()) && valid
+    }
+}
@@ -0,0 +1,374 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{iter::FusedIterator, str};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    decode_account_id, decode_seed, encode_account_id_into, encode_seed_into, Address, Algorithm,
+    Entropy, Result, VersionedToken, MAX_TOKEN_LEN,
+};
+
+/// Items encoded per rayon task, reusing one stack buffer
+#[cfg(feature = "rayon")]
+const CHUNK_LEN: usize = 1024;
+
+/// Encode many account ids as classic addresses
+///
+/// Every address is encoded into one reused stack buffer and copied
+/// into an exactly sized `String`, so the only allocations are the
+/// output strings and the returned `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_account_ids;
+///
+/// assert_eq!(
+///     encode_account_ids(&[[0; 20], [0; 20]]),
+///     ["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTp"]
+/// );
+/// ```
+pub fn encode_account_ids(account_ids: &[[u8; Address::PAYLOAD_LEN]]) -> Vec<String> {
+    let mut buffer = [0; MAX_TOKEN_LEN];
+
+    account_ids
+        .iter()
+        .map(|account_id| {
+            let len = encode_account_id_into(account_id, &mut buffer)
+                .expect("buffer fits any account id");
+            to_string(&buffer[..len])
+        })
+        .collect()
+}
+
+/// Decode many classic addresses to account ids
+///
+/// Each address is decoded independently, so one malformed address
+/// doesn't fail the whole batch. Decoding doesn't allocate besides
+/// the returned `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_account_ids, Error};
+///
+/// assert_eq!(
+///     decode_account_ids(&["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTr"]),
+///     [Ok([0; 20]), Err(Error::BadChecksum)]
+/// );
+/// ```
+pub fn decode_account_ids(addresses: &[&str]) -> Vec<Result<[u8; Address::PAYLOAD_LEN]>> {
+    addresses
+        .iter()
+        .map(|address| decode_account_id(address))
+        .collect()
+}
+
+/// Encode many entropies as seeds
+///
+/// Every seed is encoded with the same `algorithm`, into one reused
+/// stack buffer like [`encode_account_ids`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{encode_seeds, Ed25519};
+///
+/// assert_eq!(
+///     encode_seeds(&[[0; 16]], Ed25519),
+///     ["sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]
+/// );
+/// # }
+/// ```
+pub fn encode_seeds(entropies: &[Entropy], algorithm: Algorithm) -> Vec<String> {
+    let mut buffer = [0; MAX_TOKEN_LEN];
+
+    entropies
+        .iter()
+        .map(|entropy| {
+            let len =
+                encode_seed_into(entropy, algorithm, &mut buffer).expect("buffer fits any seed");
+            to_string(&buffer[..len])
+        })
+        .collect()
+}
+
+/// Decode many seeds to their entropy and algorithm
+///
+/// Each seed is decoded independently, like [`decode_account_ids`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+/// use ripple_address_codec::{decode_seeds, Ed25519, Secp256k1};
+///
+/// assert_eq!(
+///     decode_seeds(&["sp6JS7f14BuwFY8Mw6bTtLKWauoUs", "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]),
+///     [Ok(([0; 16], Secp256k1)), Ok(([0; 16], Ed25519))]
+/// );
+/// # }
+/// ```
+pub fn decode_seeds(seeds: &[&str]) -> Vec<Result<(Entropy, Algorithm)>> {
+    seeds.iter().map(|seed| decode_seed(seed)).collect()
+}
+
+/// Parallel [`encode_account_ids`] on the rayon global thread pool
+///
+/// Available with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::par_encode_account_ids;
+///
+/// assert_eq!(
+///     par_encode_account_ids(&[[0; 20], [0; 20]]),
+///     ["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTp"]
+/// );
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_encode_account_ids(account_ids: &[[u8; Address::PAYLOAD_LEN]]) -> Vec<String> {
+    account_ids
+        .par_chunks(CHUNK_LEN)
+        .flat_map_iter(encode_account_ids)
+        .collect()
+}
+
+/// Parallel [`decode_account_ids`] on the rayon global thread pool
+///
+/// Available with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{par_decode_account_ids, Error};
+///
+/// assert_eq!(
+///     par_decode_account_ids(&["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTr"]),
+///     [Ok([0; 20]), Err(Error::BadChecksum)]
+/// );
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_decode_account_ids(addresses: &[&str]) -> Vec<Result<[u8; Address::PAYLOAD_LEN]>> {
+    addresses
+        .par_iter()
+        .map(|address| decode_account_id(address))
+        .collect()
+}
+
+/// Parallel [`encode_seeds`] on the rayon global thread pool
+///
+/// Available with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{par_encode_seeds, Ed25519};
+///
+/// assert_eq!(
+///     par_encode_seeds(&[[0; 16]], Ed25519),
+///     ["sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]
+/// );
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_encode_seeds(entropies: &[Entropy], algorithm: Algorithm) -> Vec<String> {
+    entropies
+        .par_chunks(CHUNK_LEN)
+        .flat_map_iter(|chunk| encode_seeds(chunk, algorithm))
+        .collect()
+}
+
+/// Parallel [`decode_seeds`] on the rayon global thread pool
+///
+/// Available with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{par_decode_seeds, Ed25519, Secp256k1};
+///
+/// assert_eq!(
+///     par_decode_seeds(&["sp6JS7f14BuwFY8Mw6bTtLKWauoUs", "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]),
+///     [Ok(([0; 16], Secp256k1)), Ok(([0; 16], Ed25519))]
+/// );
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_decode_seeds(seeds: &[&str]) -> Vec<Result<(Entropy, Algorithm)>> {
+    seeds.par_iter().map(|seed| decode_seed(seed)).collect()
+}
+
+/// Lazy decoding of the strings of an iterator
+///
+/// Implemented for every iterator of strings, borrowed or owned. Items
+/// are decoded one at a time on the stack as the adapters are advanced,
+/// so streaming pipelines don't allocate per token.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{DecodeIteratorExt, Error};
+///
+/// let addresses = ["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTr"];
+/// let mut decoded = addresses.iter().decode_account_ids();
+///
+/// assert_eq!(decoded.next(), Some(Ok([0; 20])));
+/// assert_eq!(decoded.next(), Some(Err(Error::BadChecksum)));
+/// assert_eq!(decoded.next(), None);
+/// ```
+pub trait DecodeIteratorExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Decode each item as a classic address, like [`decode_account_ids`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::DecodeIteratorExt;
+    ///
+    /// let lines = "rrrrrrrrrrrrrrrrrrrrrhoLvTp\nrrrrrrrrrrrrrrrrrrrrBZbvji";
+    /// let account_ids: Result<Vec<_>, _> = lines.lines().decode_account_ids().collect();
+    ///
+    /// assert_eq!(account_ids.unwrap()[1][19], 1);
+    /// ```
+    fn decode_account_ids(self) -> DecodeAccountIds<Self> {
+        DecodeAccountIds { iter: self }
+    }
+
+    /// Decode each item as a seed, like [`decode_seeds`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{DecodeIteratorExt, Ed25519};
+    ///
+    /// let seeds = vec!["sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".to_string()];
+    ///
+    /// assert_eq!(
+    ///     seeds.into_iter().decode_seeds().next(),
+    ///     Some(Ok(([0; 16], Ed25519)))
+    /// );
+    /// # }
+    /// ```
+    fn decode_seeds(self) -> DecodeSeeds<Self> {
+        DecodeSeeds { iter: self }
+    }
+}
+
+impl<I> DecodeIteratorExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator of decoded account ids, see
+/// [`DecodeIteratorExt::decode_account_ids`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DecodeAccountIds<I> {
+    iter: I,
+}
+
+impl<I> Iterator for DecodeAccountIds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<[u8; Address::PAYLOAD_LEN]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|address| decode_account_id(address.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for DecodeAccountIds<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|address| decode_account_id(address.as_ref()))
+    }
+}
+
+impl<I> ExactSizeIterator for DecodeAccountIds<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+impl<I> FusedIterator for DecodeAccountIds<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator of decoded seeds, see [`DecodeIteratorExt::decode_seeds`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DecodeSeeds<I> {
+    iter: I,
+}
+
+impl<I> Iterator for DecodeSeeds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<(Entropy, Algorithm)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|seed| decode_seed(seed.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for DecodeSeeds<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|seed| decode_seed(seed.as_ref()))
+    }
+}
+
+impl<I> ExactSizeIterator for DecodeSeeds<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+impl<I> FusedIterator for DecodeSeeds<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+fn to_string(encoded: &[u8]) -> String {
+    str::from_utf8(encoded)
+        .expect("base58 alphabets are ASCII")
+        .to_owned()
+}
@@ -0,0 +1,164 @@
+//! `validate-lines`: streaming validation of newline-delimited tokens
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::Args;
+use ripple_address_codec::decode_any;
+use serde_json::{Map, Value};
+
+use crate::{failure_fields, invalid, token_fields, token_type_name, Failure, Fields, Secrets};
+
+/// Longest line kept in memory, far longer than any token
+///
+/// Longer lines are reported as invalid without being buffered, so memory
+/// use doesn't depend on the input.
+const MAX_LINE_LEN: usize = 256;
+
+#[derive(Debug, Args)]
+pub(crate) struct ValidateLinesArgs {
+    /// File with one token per line, stdin if omitted or `-`
+    file: Option<PathBuf>,
+
+    /// Only print invalid lines
+    #[arg(long)]
+    invalid_only: bool,
+}
+
+/// Validate every line, printing a result per line and a summary to stderr
+///
+/// Exits with status 0 if all lines are valid, 1 if any is invalid and 2
+/// if the input can't be read or the output can't be written.
+pub(crate) fn validate_lines(args: ValidateLinesArgs, json: bool) -> ExitCode {
+    let result = match &args.file {
+        Some(path) if path.as_os_str() != "-" => match File::open(path) {
+            Ok(file) => run(BufReader::new(file), &args, json),
+            Err(error) => Err(io::Error::new(
+                error.kind(),
+                format!("can't open {}: {}", path.display(), error),
+            )),
+        },
+        _ => run(io::stdin().lock(), &args, json),
+    };
+
+    match result {
+        Ok(Summary { lines, invalid }) => {
+            eprintln!("{} lines, {} invalid", lines, invalid);
+            if invalid == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Summary {
+    lines: u64,
+    invalid: u64,
+}
+
+fn run(mut input: impl BufRead, args: &ValidateLinesArgs, json: bool) -> io::Result<Summary> {
+    let mut output = BufWriter::new(io::stdout().lock());
+    let mut summary = Summary {
+        lines: 0,
+        invalid: 0,
+    };
+    let mut line = Vec::with_capacity(MAX_LINE_LEN);
+    let mut number = 0;
+
+    while let Some(truncated) = read_bounded_line(&mut input, &mut line)? {
+        number += 1;
+
+        let token = std::str::from_utf8(&line).map(str::trim);
+        if token == Ok("") && !truncated {
+            continue;
+        }
+        summary.lines += 1;
+
+        let result = match token {
+            _ if truncated => Err(Failure::Input {
+                code: "too_long",
+                message: "line is too long".to_owned(),
+            }),
+            Err(_) => Err(Failure::input("line is not valid UTF-8".to_owned())),
+            Ok(token) => decode_any(token).map_err(|error| invalid(token, error)),
+        };
+
+        match result {
+            Ok(_) if args.invalid_only => {}
+            Ok(decoded) if json => {
+                let mut fields = vec![("valid", Value::Bool(true))];
+                fields.extend(token_fields(&decoded, Secrets::Withhold));
+                write_json_line(&mut output, number, fields)?;
+            }
+            Ok(decoded) => writeln!(output, "{}\tvalid\t{}", number, token_type_name(&decoded))?,
+            Err(failure) => {
+                summary.invalid += 1;
+                if json {
+                    write_json_line(&mut output, number, failure_fields(failure))?;
+                } else {
+                    let (code, message) = match failure {
+                        Failure::Invalid { error, .. } => (error.code(), error.to_string()),
+                        Failure::Input { code, message } => (code, message),
+                    };
+                    writeln!(output, "{}\tinvalid\t{}\t{}", number, code, message)?;
+                }
+            }
+        }
+    }
+    output.flush()?;
+
+    Ok(summary)
+}
+
+fn write_json_line(output: &mut impl Write, number: u64, fields: Fields) -> io::Result<()> {
+    let mut object = Map::new();
+    object.insert("line".to_owned(), number.into());
+    for (key, value) in fields {
+        object.insert(key.to_owned(), value);
+    }
+    serde_json::to_writer(&mut *output, &Value::Object(object))?;
+
+    writeln!(output)
+}
+
+/// Read the next line into `line` without its line ending, keeping at most
+/// [`MAX_LINE_LEN`] bytes
+///
+/// Returns `None` at the end of the input, otherwise whether the line was
+/// longer and got truncated.
+fn read_bounded_line(input: &mut impl BufRead, line: &mut Vec<u8>) -> io::Result<Option<bool>> {
+    line.clear();
+    let mut truncated = false;
+    let mut read_any = false;
+
+    loop {
+        let available = input.fill_buf()?;
+        if available.is_empty() {
+            return Ok(if read_any { Some(truncated) } else { None });
+        }
+        read_any = true;
+
+        let newline = available.iter().position(|&byte| byte == b'\n');
+        let chunk = &available[..newline.unwrap_or(available.len())];
+        let room = MAX_LINE_LEN - line.len();
+        truncated |= chunk.len() > room;
+        line.extend_from_slice(&chunk[..chunk.len().min(room)]);
+
+        let consumed = newline.map_or(available.len(), |newline| newline + 1);
+        input.consume(consumed);
+        if newline.is_some() {
+            return Ok(Some(truncated));
+        }
+    }
+}
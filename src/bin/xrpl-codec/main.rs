@@ -0,0 +1,587 @@
+//! `xrpl-codec` command line tool, available with the `cli` feature
+//!
+//! ```text
+//! $ xrpl-codec decode rrrrrrrrrrrrrrrrrrrrrhoLvTp
+//! type: account_id
+//! payload: 0000000000000000000000000000000000000000
+//! classic_address: rrrrrrrrrrrrrrrrrrrrrhoLvTp
+//! ```
+//!
+//! Pass `-` instead of a token to read it from stdin, which keeps secrets
+//! out of the shell history. Invalid input exits with status 1.
+//!
+//! `--json` prints a JSON object instead, for scripts. Decoded tokens have
+//! the same fields in every subcommand: `type`, hex `payload`, and
+//! `classic_address`, `tag`, `network` or `algorithm` where they apply.
+//! Only `decode` prints the payload of seeds and private keys. Failures
+//! have `valid: false`, the `error` code, a `message` and a `suggestion`.
+//!
+//! `validate-lines` checks a file or stdin with one token per line, in
+//! constant memory, printing tab-separated results:
+//!
+//! ```text
+//! $ xrpl-codec validate-lines addresses.txt
+//! 1       valid   account_id
+//! 2       invalid bad_checksum    checksum mismatch, did you mistype a character?
+//! ```
+//!
+//! `report` checks the address column of a CSV file, for reconciliation
+//! jobs, and writes it back with `canonical`, `tag`, `type`, `error` and
+//! `message` columns appended.
+//!
+//! `qr` shows an address or X-address as a QR code, in the terminal and
+//! optionally as a PNG, for air-gapped signing workflows.
+//!
+//! `generate` prints a new seed with its public key and addresses. With
+//! `--seed-file`, the seed goes to a new file instead of the terminal.
+
+#![deny(warnings, clippy::all, missing_debug_implementations)]
+
+use std::{
+    convert::TryInto,
+    fmt::Write as _,
+    fs::OpenOptions,
+    io::{self, BufRead, Write as _},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use ripple_address_codec::{
+    classic_address_to_xaddress, decode_any, decode_seed, encode_account_id, encode_seed,
+    keypair::propose_wallet,
+    suggest_correction,
+    vanity::{Pattern, VanitySearch},
+    xaddress_to_classic_address, Algorithm, Error, Network, Tag, XrplToken,
+};
+use serde_json::{Map, Value};
+
+use self::{
+    lines::{validate_lines, ValidateLinesArgs},
+    qr::{qr, QrArgs},
+    report::{report, ReportArgs},
+};
+
+mod lines;
+mod qr;
+mod report;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "xrpl-codec",
+    version,
+    about = "Encode, decode, validate and convert XRP Ledger addresses and seeds"
+)]
+struct Cli {
+    /// Print results as a JSON object, one per line for validate-lines
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Decode any address, X-address, seed or key and print its contents
+    Decode {
+        /// Token to decode, `-` to read it from stdin
+        token: String,
+    },
+    /// Check a token of any type, exiting with status 1 if it's invalid
+    Validate {
+        /// Token to check, `-` to read it from stdin
+        token: String,
+    },
+    /// Encode a hex account id as a classic address
+    EncodeAccountId {
+        /// 20 bytes of hex
+        hex: String,
+    },
+    /// Decode a seed to its entropy and algorithm
+    DecodeSeed {
+        /// Seed to decode, `-` to read it from stdin
+        seed: String,
+    },
+    /// Encode hex entropy as a seed
+    EncodeSeed {
+        /// 16 bytes of hex, `-` to read it from stdin
+        hex: String,
+
+        /// Algorithm the seed is intended to be used with
+        #[arg(long, default_value_t = Algorithm::Secp256k1)]
+        algorithm: Algorithm,
+    },
+    /// Convert between classic addresses and X-addresses
+    Convert(ConvertArgs),
+    /// Generate a new seed and print it with its public key and addresses
+    Generate(GenerateArgs),
+    /// Generate seeds until one's address matches a pattern
+    Vanity(VanityArgs),
+    /// Validate one token per line of a file or stdin, exiting with status 1 if any is invalid
+    ValidateLines(ValidateLinesArgs),
+    /// Check the address column of a CSV file, writing it back with canonical form, type and error columns
+    Report(ReportArgs),
+    /// Show an address or X-address as a QR code in the terminal, and optionally as a PNG
+    Qr(QrArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["to_xaddress", "to_classic"])))]
+struct ConvertArgs {
+    /// Classic address or X-address
+    address: String,
+
+    /// Convert a classic address to an X-address
+    #[arg(long)]
+    to_xaddress: bool,
+
+    /// Convert an X-address to a classic address
+    #[arg(long)]
+    to_classic: bool,
+
+    /// Destination tag of the X-address
+    #[arg(long, requires = "to_xaddress")]
+    tag: Option<u32>,
+
+    /// Encode the X-address for a test network
+    #[arg(long, requires = "to_xaddress")]
+    test: bool,
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// Algorithm of the seed
+    #[arg(long, default_value_t = Algorithm::Secp256k1)]
+    algorithm: Algorithm,
+
+    /// Write the seed to a new file, readable only by its owner, instead of printing it
+    #[arg(long)]
+    seed_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("position").args(["suffix", "contains"])))]
+struct VanityArgs {
+    /// Start of the address, including the leading `r`
+    pattern: String,
+
+    /// Match the end of the address instead
+    #[arg(long)]
+    suffix: bool,
+
+    /// Match anywhere in the address instead
+    #[arg(long)]
+    contains: bool,
+
+    /// Match letters regardless of case, which is much faster
+    #[arg(long, short)]
+    ignore_case: bool,
+
+    /// Algorithm of the seed
+    #[arg(long, default_value_t = Algorithm::Secp256k1)]
+    algorithm: Algorithm,
+
+    /// Number of threads, all cores by default
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Give up after this many seeds
+    #[arg(long)]
+    max_attempts: Option<u64>,
+}
+
+/// Result fields, in output order
+type Fields = Vec<(&'static str, Value)>;
+
+#[derive(Debug)]
+enum Failure {
+    /// The token or payload is invalid
+    Invalid { input: String, error: Error },
+    /// Malformed hex, unreadable stdin or overlong lines
+    Input { code: &'static str, message: String },
+}
+
+impl Failure {
+    fn input(message: String) -> Self {
+        Failure::Input {
+            code: "invalid_input",
+            message,
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(error: io::Error) -> Self {
+        Failure::input(format!("can't read stdin: {}", error))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let command = match cli.command {
+        Command::ValidateLines(args) => return validate_lines(args, cli.json),
+        Command::Report(args) => return report(args),
+        Command::Qr(args) => return qr(args, cli.json),
+        command => command,
+    };
+
+    match run(command) {
+        Ok(fields) => {
+            print_fields(fields, cli.json);
+            ExitCode::SUCCESS
+        }
+        Err(failure) => {
+            print_failure(failure, cli.json);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<Fields, Failure> {
+    match command {
+        Command::Decode { token } => {
+            let token = read_arg(token)?;
+            let decoded = decode_any(&token).map_err(|error| invalid(&token, error))?;
+
+            Ok(token_fields(&decoded, Secrets::Reveal))
+        }
+        Command::Validate { token } => {
+            let token = read_arg(token)?;
+            let decoded = decode_any(&token).map_err(|error| invalid(&token, error))?;
+
+            let mut fields = vec![("valid", Value::Bool(true))];
+            fields.extend(token_fields(&decoded, Secrets::Withhold));
+
+            Ok(fields)
+        }
+        Command::EncodeAccountId { hex } => {
+            let bytes = from_hex(&hex)?;
+            let account_id = to_array(&hex, &bytes)?;
+
+            Ok(vec![
+                ("account_id", to_hex(&account_id).into()),
+                ("address", encode_account_id(&account_id).into()),
+            ])
+        }
+        Command::DecodeSeed { seed } => {
+            let seed = read_arg(seed)?;
+            let (entropy, algorithm) = decode_seed(&seed).map_err(|error| invalid(&seed, error))?;
+
+            Ok(vec![
+                ("algorithm", algorithm.to_string().into()),
+                ("entropy", to_hex(&entropy).into()),
+            ])
+        }
+        Command::EncodeSeed { hex, algorithm } => {
+            let hex = read_arg(hex)?;
+            let bytes = from_hex(&hex)?;
+            let entropy = to_array(&hex, &bytes)?;
+
+            Ok(vec![
+                ("algorithm", algorithm.to_string().into()),
+                ("seed", encode_seed(&entropy, algorithm).into()),
+            ])
+        }
+        Command::Convert(args) => convert(args),
+        Command::Generate(args) => generate(args),
+        Command::Vanity(args) => vanity(args),
+        Command::ValidateLines(_) | Command::Report(_) | Command::Qr(_) => {
+            unreachable!("handled by main")
+        }
+    }
+}
+
+fn convert(args: ConvertArgs) -> Result<Fields, Failure> {
+    let address = args.address;
+
+    if args.to_xaddress {
+        let network = if args.test {
+            Network::Test
+        } else {
+            Network::Main
+        };
+        let xaddress = classic_address_to_xaddress(&address, args.tag.into(), network)
+            .map_err(|error| invalid(&address, error))?;
+
+        Ok(vec![
+            ("classic_address", address.into()),
+            ("xaddress", xaddress.into()),
+        ])
+    } else {
+        let (classic_address, tag, network) =
+            xaddress_to_classic_address(&address).map_err(|error| invalid(&address, error))?;
+
+        Ok(vec![
+            ("classic_address", classic_address.into()),
+            ("tag", tag_value(tag)),
+            ("network", network_name(network).into()),
+        ])
+    }
+}
+
+fn generate(args: GenerateArgs) -> Result<Fields, Failure> {
+    let wallet = propose_wallet(args.algorithm);
+
+    let seed = match args.seed_file {
+        Some(path) => {
+            write_secret(&path, wallet.seed()).map_err(|error| Failure::Input {
+                code: "io_error",
+                message: format!("can't write {}: {}", path.display(), error),
+            })?;
+            ("seed_file", path.display().to_string().into())
+        }
+        None => ("seed", wallet.seed().into()),
+    };
+
+    Ok(vec![
+        seed,
+        ("algorithm", wallet.algorithm().to_string().into()),
+        ("public_key", wallet.public_key_hex().into()),
+        ("classic_address", wallet.classic_address().into()),
+        ("xaddress", wallet.xaddress().into()),
+    ])
+}
+
+/// Write `secret` and a newline to a new file, readable only by its owner
+/// on Unix
+fn write_secret(path: &Path, secret: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)?;
+    writeln!(file, "{}", secret)?;
+    file.sync_all()
+}
+
+fn vanity(args: VanityArgs) -> Result<Fields, Failure> {
+    let pattern = if args.suffix {
+        Pattern::suffix(&args.pattern)
+    } else if args.contains {
+        Pattern::contains(&args.pattern)
+    } else {
+        Pattern::prefix(&args.pattern)
+    }
+    .map_err(|error| invalid(&args.pattern, error))?;
+    let pattern = if args.ignore_case {
+        pattern.ignore_case()
+    } else {
+        pattern
+    };
+
+    let mut search = VanitySearch::new(pattern).with_algorithm(args.algorithm);
+    if let Some(threads) = args.threads {
+        search = search.with_threads(threads);
+    }
+    if let Some(max_attempts) = args.max_attempts {
+        search = search.with_max_attempts(max_attempts);
+    }
+    let (seed, address) = search.run().ok_or_else(|| Failure::Input {
+        code: "not_found",
+        message: "no matching address within the maximum number of attempts".to_owned(),
+    })?;
+
+    Ok(vec![
+        ("seed", seed.expose_secret().into()),
+        ("algorithm", seed.algorithm().to_string().into()),
+        ("classic_address", address.into()),
+    ])
+}
+
+/// Whether [`token_fields`] includes the payload of seeds and private keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Secrets {
+    Reveal,
+    Withhold,
+}
+
+/// Fields describing a decoded token, the same for every subcommand
+///
+/// `type` and the hex `payload` are always present, except that the
+/// payload of seeds and private keys is withheld unless revealed.
+/// Addresses add `classic_address`, X-addresses `tag` and `network`, and
+/// seeds `algorithm`.
+fn token_fields(token: &XrplToken, secrets: Secrets) -> Fields {
+    let (payload, secret): (&[u8], bool) = match token {
+        XrplToken::AccountId(bytes) => (bytes, false),
+        XrplToken::XAddress { account_id, .. } => (account_id, false),
+        XrplToken::Seed { entropy, .. } => (entropy, true),
+        XrplToken::NodePublicKey(bytes) | XrplToken::AccountPublicKey(bytes) => (bytes, false),
+        XrplToken::NodePrivateKey(bytes) | XrplToken::AccountPrivateKey(bytes) => (bytes, true),
+        XrplToken::FamilyGenerator(bytes) => (bytes, false),
+        _ => (&[], false),
+    };
+
+    let mut fields = vec![("type", token_type_name(token).into())];
+    if !secret || secrets == Secrets::Reveal {
+        fields.push(("payload", to_hex(payload).into()));
+    }
+
+    match token {
+        XrplToken::AccountId(account_id) => {
+            fields.push(("classic_address", encode_account_id(account_id).into()));
+        }
+        XrplToken::XAddress {
+            account_id,
+            tag,
+            network,
+        } => {
+            fields.push(("classic_address", encode_account_id(account_id).into()));
+            fields.push(("tag", tag_value(*tag)));
+            fields.push(("network", network_name(*network).into()));
+        }
+        XrplToken::Seed { algorithm, .. } => {
+            fields.push(("algorithm", algorithm.to_string().into()));
+        }
+        _ => {}
+    }
+
+    fields
+}
+
+fn token_type_name(token: &XrplToken) -> &'static str {
+    match token {
+        XrplToken::AccountId(_) => "account_id",
+        XrplToken::XAddress { .. } => "xaddress",
+        XrplToken::Seed { .. } => "seed",
+        XrplToken::NodePublicKey(_) => "node_public_key",
+        XrplToken::AccountPublicKey(_) => "account_public_key",
+        XrplToken::NodePrivateKey(_) => "node_private_key",
+        XrplToken::AccountPrivateKey(_) => "account_private_key",
+        XrplToken::FamilyGenerator(_) => "family_generator",
+        _ => "unknown",
+    }
+}
+
+fn tag_value(tag: Tag) -> Value {
+    tag.value().map_or(Value::Null, Value::from)
+}
+
+fn network_name(network: Network) -> &'static str {
+    if network.is_test() {
+        "test"
+    } else {
+        "main"
+    }
+}
+
+fn print_fields(fields: Fields, json: bool) {
+    if json {
+        let object: Map<String, Value> = fields
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect();
+        println!("{}", Value::Object(object));
+    } else {
+        for (key, value) in fields {
+            match value {
+                Value::String(value) => println!("{}: {}", key, value),
+                Value::Null => println!("{}: none", key),
+                value => println!("{}: {}", key, value),
+            }
+        }
+    }
+}
+
+fn print_failure(failure: Failure, json: bool) {
+    let fields = failure_fields(failure);
+
+    if json {
+        print_fields(fields, true);
+    } else {
+        for (key, value) in fields {
+            match (key, value) {
+                ("message", Value::String(message)) => eprintln!("error: {}", message),
+                ("suggestion", Value::String(suggestion)) => {
+                    eprintln!("did you mean {}?", suggestion)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Fields describing a failure: `valid`, `error` code, `message` and
+/// `suggestion`, a correction for a single mistyped character
+fn failure_fields(failure: Failure) -> Fields {
+    let (code, message, suggestion) = match failure {
+        Failure::Invalid { input, error } => {
+            let suggestion = match error {
+                Error::BadChecksum | Error::BadAlphabet { .. } => suggest_correction(&input),
+                _ => None,
+            };
+            (error.code(), error.to_string(), suggestion)
+        }
+        Failure::Input { code, message } => (code, message, None),
+    };
+
+    vec![
+        ("valid", Value::Bool(false)),
+        ("error", code.into()),
+        ("message", message.into()),
+        ("suggestion", suggestion.map_or(Value::Null, Value::from)),
+    ]
+}
+
+fn invalid(input: &str, error: Error) -> Failure {
+    Failure::Invalid {
+        input: input.to_owned(),
+        error,
+    }
+}
+
+/// The argument, or a line of stdin if it's `-`
+fn read_arg(arg: String) -> Result<String, Failure> {
+    if arg != "-" {
+        return Ok(arg);
+    }
+
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+
+    Ok(line.trim().to_owned())
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, Failure> {
+    let hex = hex.trim();
+    let digits = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(Failure::input("hex has an odd number of digits".to_owned()));
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| {
+                    Failure::input(format!(
+                        "invalid hex digits {:?}",
+                        String::from_utf8_lossy(pair)
+                    ))
+                })
+        })
+        .collect()
+}
+
+fn to_array<const N: usize>(input: &str, bytes: &[u8]) -> Result<[u8; N], Failure> {
+    bytes.try_into().map_err(|_| {
+        invalid(
+            input,
+            Error::BadLength {
+                expected: N,
+                actual: bytes.len(),
+            },
+        )
+    })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02X}", byte);
+        hex
+    })
+}
@@ -0,0 +1,126 @@
+//! `qr`: QR codes of addresses, for moving them between air-gapped machines
+
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Args;
+use qrcode::{render::unicode::Dense1x2, Color, QrCode};
+use ripple_address_codec::{decode_any, XrplToken};
+use serde_json::Value;
+
+use crate::{
+    invalid, print_failure, print_fields, read_arg, token_fields, token_type_name, Failure, Secrets,
+};
+
+/// Width of the light border around the code, in modules
+const QUIET_ZONE: usize = 4;
+
+#[derive(Debug, Args)]
+pub(crate) struct QrArgs {
+    /// Classic address or X-address, `-` to read it from stdin
+    address: String,
+
+    /// Also write the QR code to a PNG file
+    #[arg(long)]
+    png: Option<PathBuf>,
+
+    /// Size of a module in the PNG, in pixels
+    #[arg(long, default_value_t = 8, requires = "png", value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+
+    /// Swap light and dark in the terminal, for dark backgrounds
+    #[arg(long)]
+    invert: bool,
+}
+
+/// Validate the address and print its QR code, exiting with status 1 if
+/// it's invalid or not an address
+///
+/// Seeds and keys are refused, so that secrets aren't put on screen.
+pub(crate) fn qr(args: QrArgs, json: bool) -> ExitCode {
+    match run(args, json) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            print_failure(failure, json);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: QrArgs, json: bool) -> Result<(), Failure> {
+    let address = read_arg(args.address)?.trim().to_owned();
+    let token = decode_any(&address).map_err(|error| invalid(&address, error))?;
+    if !matches!(token, XrplToken::AccountId(_) | XrplToken::XAddress { .. }) {
+        return Err(Failure::Input {
+            code: "unsupported_type",
+            message: format!(
+                "only addresses and X-addresses can be shown as QR codes, not {}",
+                token_type_name(&token)
+            ),
+        });
+    }
+
+    let code = QrCode::new(&address).map_err(|error| {
+        Failure::input(format!("can't encode {} as a QR code: {}", address, error))
+    })?;
+    if let Some(path) = &args.png {
+        write_png(&code, path, args.scale).map_err(|error| Failure::Input {
+            code: "io_error",
+            message: format!("can't write {}: {}", path.display(), error),
+        })?;
+    }
+
+    if json {
+        let mut fields = token_fields(&token, Secrets::Withhold);
+        let png = args.png.map(|path| path.display().to_string());
+        fields.push(("png", png.map_or(Value::Null, Value::from)));
+        print_fields(fields, true);
+    } else {
+        let (dark, light) = if args.invert {
+            (Dense1x2::Light, Dense1x2::Dark)
+        } else {
+            (Dense1x2::Dark, Dense1x2::Light)
+        };
+        let rendered = code
+            .render::<Dense1x2>()
+            .dark_color(dark)
+            .light_color(light)
+            .build();
+        println!("{}\n{}", rendered, address);
+    }
+
+    Ok(())
+}
+
+/// Write the code as a grayscale PNG, `scale` pixels per module
+fn write_png(code: &QrCode, path: &Path, scale: u32) -> Result<(), png::EncodingError> {
+    let modules = code.width();
+    let colors = code.to_colors();
+    let scale = scale as usize;
+    let size = (modules + 2 * QUIET_ZONE) * scale;
+
+    let mut pixels = vec![u8::MAX; size * size];
+    for (index, color) in colors.iter().enumerate() {
+        if *color == Color::Light {
+            continue;
+        }
+        let x = (index % modules + QUIET_ZONE) * scale;
+        let y = (index / modules + QUIET_ZONE) * scale;
+        for row in pixels[y * size..].chunks_mut(size).take(scale) {
+            row[x..x + scale].fill(0);
+        }
+    }
+
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(path)?),
+        size as u32,
+        size as u32,
+    );
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)
+}
@@ -0,0 +1,168 @@
+//! `report`: validation and normalization of the address column of a CSV
+
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::Args;
+use csv::{ByteRecord, ReaderBuilder, Writer};
+use ripple_address_codec::{decode_any, encode_account_id, XrplToken};
+
+use crate::token_type_name;
+
+/// Columns appended to every record of the report
+const REPORT_COLUMNS: [&str; 5] = ["canonical", "tag", "type", "error", "message"];
+
+#[derive(Debug, Args)]
+pub(crate) struct ReportArgs {
+    /// CSV file with a header row, stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Name of the column with the addresses, matched ignoring case
+    #[arg(long, default_value = "address")]
+    column: String,
+
+    /// File to write the report to, stdout if omitted
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+/// Write the input records with the result of checking their address
+///
+/// Exits with status 0 if all addresses are valid, 1 if any is invalid and
+/// 2 if the input can't be read, isn't CSV or has no such column.
+pub(crate) fn report(args: ReportArgs) -> ExitCode {
+    let input: Box<dyn Read> = match &args.input {
+        Some(path) if path.as_os_str() != "-" => match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                eprintln!("error: can't open {}: {}", path.display(), error);
+                return ExitCode::from(2);
+            }
+        },
+        _ => Box::new(io::stdin().lock()),
+    };
+    let output: Box<dyn Write> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                eprintln!("error: can't create {}: {}", path.display(), error);
+                return ExitCode::from(2);
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+
+    match run(input, output, &args.column) {
+        Ok(Summary { rows, invalid }) => {
+            eprintln!("{} rows, {} invalid", rows, invalid);
+            if invalid == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Summary {
+    rows: u64,
+    invalid: u64,
+}
+
+fn run(input: impl Read, output: impl Write, column: &str) -> csv::Result<Summary> {
+    let mut reader = ReaderBuilder::new().from_reader(input);
+    let mut writer = Writer::from_writer(output);
+    let mut summary = Summary {
+        rows: 0,
+        invalid: 0,
+    };
+
+    let mut header = reader.byte_headers()?.clone();
+    let index = header
+        .iter()
+        .position(|name| {
+            String::from_utf8_lossy(name)
+                .trim()
+                .eq_ignore_ascii_case(column)
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no {:?} column in the header", column),
+            )
+        })?;
+    header.extend(REPORT_COLUMNS.iter());
+    writer.write_byte_record(&header)?;
+
+    let mut record = ByteRecord::new();
+    while reader.read_byte_record(&mut record)? {
+        summary.rows += 1;
+
+        let address = std::str::from_utf8(record.get(index).unwrap_or_default()).map(str::trim);
+        let columns = match address {
+            Ok(address) => match decode_any(address) {
+                Ok(token) => valid_columns(address, &token),
+                Err(error) => {
+                    summary.invalid += 1;
+                    invalid_columns(error.code(), &error.to_string())
+                }
+            },
+            Err(_) => {
+                summary.invalid += 1;
+                invalid_columns("invalid_input", "address is not valid UTF-8")
+            }
+        };
+
+        record.extend(columns.iter());
+        writer.write_byte_record(&record)?;
+    }
+    writer.flush()?;
+
+    Ok(summary)
+}
+
+/// Report columns of a valid token
+///
+/// The canonical form of X-addresses is their classic address, with the
+/// tag in its own column. Seeds and private keys are left out of the report.
+fn valid_columns(address: &str, token: &XrplToken) -> [String; 5] {
+    let (canonical, tag) = match token {
+        XrplToken::XAddress {
+            account_id, tag, ..
+        } => (
+            encode_account_id(account_id),
+            tag.value().map_or_else(String::new, |tag| tag.to_string()),
+        ),
+        XrplToken::Seed { .. } | XrplToken::NodePrivateKey(_) | XrplToken::AccountPrivateKey(_) => {
+            (String::new(), String::new())
+        }
+        _ => (address.to_owned(), String::new()),
+    };
+
+    [
+        canonical,
+        tag,
+        token_type_name(token).to_owned(),
+        String::new(),
+        String::new(),
+    ]
+}
+
+fn invalid_columns(code: &str, message: &str) -> [String; 5] {
+    [
+        String::new(),
+        String::new(),
+        String::new(),
+        code.to_owned(),
+        message.to_owned(),
+    ]
+}
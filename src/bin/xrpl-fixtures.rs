@@ -0,0 +1,245 @@
+//! `xrpl-fixtures` test vector generator, available with the `dev-tool`
+//! feature
+//!
+//! Writes one JSON file per token type, named after its type name, with
+//! the all-zero and all-0xFF payloads followed by random ones:
+//!
+//! ```text
+//! $ xrpl-fixtures --count 100 --rng-seed 7 --out-dir fixtures
+//! $ head fixtures/account_id.json
+//! {
+//!   "generator": "ripple-address-codec 0.1.1",
+//!   "rng_seed": 7,
+//!   "type": "account_id",
+//!   "prefix": "00",
+//!   "payload_len": 20,
+//!   "vectors": [
+//!     {
+//!       "payload": "0000000000000000000000000000000000000000",
+//!       "encoded": "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+//! ```
+//!
+//! X-address vectors have `account_id`, `classic_address`, `tag` (`null`
+//! without one) and `network` (`main` or `test`) instead of `payload`.
+//!
+//! Every vector is cross-checked before it's written: the token type's
+//! own encoder and the generic `encode_versioned` must agree, and
+//! `decode_any` must give back the type and payload. The same
+//! `--rng-seed` always gives the same files.
+
+#![deny(warnings, clippy::all, missing_debug_implementations)]
+
+use std::{
+    convert::TryInto,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+use rand_chacha::{
+    rand_core::{OsRng, RngCore, SeedableRng},
+    ChaCha20Rng,
+};
+use ripple_address_codec::{
+    decode_any, decode_versioned, encode_account_id, encode_account_private_key,
+    encode_account_public_key, encode_family_generator, encode_node_private_key,
+    encode_node_public_key, encode_seed, encode_versioned, encode_xaddress, Algorithm, Network,
+    Tag, TokenType, XrplToken,
+};
+use serde_json::{json, Value};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "xrpl-fixtures",
+    version,
+    about = "Write cross-checked JSON test vectors for every XRP Ledger token type"
+)]
+struct Cli {
+    /// Vectors per token type, including the all-zero and all-0xFF payloads
+    #[arg(long, default_value_t = 100)]
+    count: usize,
+
+    /// Seed of the random payloads, a random one by default; it is recorded in every file
+    #[arg(long)]
+    rng_seed: Option<u64>,
+
+    /// Directory of the JSON files, created if needed
+    #[arg(long, default_value = "fixtures")]
+    out_dir: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let rng_seed = cli.rng_seed.unwrap_or_else(|| OsRng.next_u64());
+    let mut rng = ChaCha20Rng::seed_from_u64(rng_seed);
+
+    for &token_type in TokenType::all() {
+        let vectors = (0..cli.count)
+            .map(|index| match token_type {
+                TokenType::XAddress => xaddress_vector(index, &mut rng),
+                _ => payload_vector(token_type, index, &mut rng),
+            })
+            .collect::<Result<Vec<_>, _>>();
+
+        let vectors = match vectors {
+            Ok(vectors) => vectors,
+            Err(message) => {
+                eprintln!("error: {}: {}", token_type.type_name(), message);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let fixture = json!({
+            "generator": concat!("ripple-address-codec ", env!("CARGO_PKG_VERSION")),
+            "rng_seed": rng_seed,
+            "type": token_type.type_name(),
+            "prefix": to_hex(token_type.prefix_bytes()),
+            "payload_len": token_type.payload_len(),
+            "vectors": vectors,
+        });
+
+        if let Err(message) = write_fixture(&cli.out_dir, token_type, &fixture) {
+            eprintln!("error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// The all-zero payload first, then the all-0xFF one, then random ones
+fn payload(len: usize, index: usize, rng: &mut ChaCha20Rng) -> Vec<u8> {
+    let mut payload = vec![0; len];
+
+    match index {
+        0 => {}
+        1 => payload.fill(0xFF),
+        _ => rng.fill_bytes(&mut payload),
+    }
+
+    payload
+}
+
+fn payload_vector(
+    token_type: TokenType,
+    index: usize,
+    rng: &mut ChaCha20Rng,
+) -> Result<Value, String> {
+    let payload = payload(token_type.payload_len(), index, rng);
+    let encoded = encode(token_type, &payload);
+
+    let generic = encode_versioned(&payload, token_type.prefix_bytes());
+    if generic != encoded {
+        return Err(format!(
+            "{} encodes as {} with encode_versioned",
+            encoded, generic
+        ));
+    }
+
+    let decoded =
+        decode_any(&encoded).map_err(|error| format!("{} doesn't decode: {}", encoded, error))?;
+    if decoded.token_type() != token_type {
+        return Err(format!(
+            "{} decodes as {}",
+            encoded,
+            decoded.token_type().type_name()
+        ));
+    }
+
+    let decoded = decode_versioned(&encoded, token_type.prefix_bytes(), payload.len())
+        .map_err(|error| format!("{} doesn't decode: {}", encoded, error))?;
+    if decoded != payload {
+        return Err(format!("{} decodes to {}", encoded, to_hex(&decoded)));
+    }
+
+    Ok(json!({
+        "payload": to_hex(&payload),
+        "encoded": encoded,
+    }))
+}
+
+/// Encode with the token type's own encoder
+fn encode(token_type: TokenType, payload: &[u8]) -> String {
+    match token_type {
+        TokenType::AccountId => encode_account_id(array(payload)),
+        TokenType::NodePublicKey => encode_node_public_key(array(payload)),
+        TokenType::AccountPublicKey => encode_account_public_key(array(payload)),
+        TokenType::NodePrivateKey => encode_node_private_key(array(payload)),
+        TokenType::AccountPrivateKey => encode_account_private_key(array(payload)),
+        TokenType::FamilyGenerator => encode_family_generator(array(payload)),
+        TokenType::SeedSecp256k1 => encode_seed(array(payload), Algorithm::Secp256k1),
+        TokenType::SeedEd25519 => encode_seed(array(payload), Algorithm::Ed25519),
+        token_type => unreachable!("no payload encoder for {}", token_type.type_name()),
+    }
+}
+
+fn array<const N: usize>(payload: &[u8]) -> &[u8; N] {
+    payload
+        .try_into()
+        .expect("payload of the token type's length")
+}
+
+/// No tag on the main network first, then the largest tag on a test
+/// network, then random ones
+fn xaddress_vector(index: usize, rng: &mut ChaCha20Rng) -> Result<Value, String> {
+    let account_id: [u8; 20] = payload(20, index, rng).try_into().expect("20 bytes");
+    let (tag, network) = match index {
+        0 => (Tag::None, Network::Main),
+        1 => (Tag::U32(u32::MAX), Network::Test),
+        _ => {
+            let random = rng.next_u64();
+            let tag = if random & 1 == 0 {
+                Tag::None
+            } else {
+                Tag::U32((random >> 32) as u32)
+            };
+            let network = if random & 2 == 0 {
+                Network::Main
+            } else {
+                Network::Test
+            };
+
+            (tag, network)
+        }
+    };
+
+    let encoded = encode_xaddress(&account_id, tag, network);
+    let expected = XrplToken::XAddress {
+        account_id,
+        tag,
+        network,
+    };
+
+    match decode_any(&encoded) {
+        Ok(decoded) if decoded == expected => {}
+        Ok(decoded) => return Err(format!("{} decodes to {:?}", encoded, decoded)),
+        Err(error) => return Err(format!("{} doesn't decode: {}", encoded, error)),
+    }
+
+    Ok(json!({
+        "account_id": to_hex(&account_id),
+        "classic_address": encode_account_id(&account_id),
+        "tag": tag.value(),
+        "network": if network.is_test() { "test" } else { "main" },
+        "encoded": encoded,
+    }))
+}
+
+fn write_fixture(out_dir: &Path, token_type: TokenType, fixture: &Value) -> Result<(), String> {
+    let path = out_dir.join(format!("{}.json", token_type.type_name()));
+    let mut contents = serde_json::to_string_pretty(fixture).expect("JSON values serialize");
+    contents.push('\n');
+
+    fs::create_dir_all(out_dir)
+        .and_then(|()| fs::write(&path, contents))
+        .map_err(|error| format!("can't write {}: {}", path.display(), error))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02X}", byte);
+        hex
+    })
+}
@@ -0,0 +1,293 @@
+//! Memoized address validation
+//!
+//! Available with the `cache` feature. [`CachedCodec`] remembers the
+//! outcome of decoding the most recently used classic addresses, so the
+//! same hot addresses aren't base58 decoded and hashed twice with SHA-256
+//! on every request.
+//!
+//! Only public data is cached: seeds are never memoized, as that would
+//! keep secrets in memory.
+//!
+//! # Examples
+//!
+//! ```
+//! use ripple_address_codec::cache::CachedCodec;
+//!
+//! let mut codec = CachedCodec::new(10_000);
+//!
+//! for _ in 0..3 {
+//!     assert_eq!(codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"), Ok([0; 20]));
+//! }
+//!
+//! assert_eq!((codec.hits(), codec.misses()), (2, 1));
+//! ```
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{collections::HashMap, fmt};
+
+use crate::{decode_account_id, Address, Result, VersionedToken, ACCOUNT_ID_MAX_LEN};
+
+type AccountId = [u8; Address::PAYLOAD_LEN];
+
+/// Marks the ends of the recency list
+const NONE: usize = usize::MAX;
+
+/// Cached input with its decoded account id, linked in recency order
+#[derive(Debug)]
+struct Entry {
+    input: String,
+    decoded: Result<AccountId>,
+    newer: usize,
+    older: usize,
+}
+
+/// Classic address decoder with a least recently used cache of results
+///
+/// Both valid and invalid inputs are cached, keyed by the exact input
+/// string. Inputs longer than any classic address are decoded without
+/// being cached, so junk can't bloat the cache, though it can still evict
+/// hot entries.
+///
+/// Lookups take `&mut self` to update recency; share a codec between
+/// threads behind a `Mutex`, or give each thread its own.
+pub struct CachedCodec {
+    entries: Vec<Entry>,
+    index: HashMap<String, usize>,
+    capacity: usize,
+    newest: usize,
+    oldest: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl CachedCodec {
+    /// Codec caching up to `capacity` results, at least 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// assert_eq!(CachedCodec::new(0).capacity(), 1);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        CachedCodec {
+            entries: Vec::new(),
+            index: HashMap::new(),
+            capacity: capacity.max(1),
+            newest: NONE,
+            oldest: NONE,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Decode a classic address, see [`decode_account_id`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{cache::CachedCodec, Error};
+    ///
+    /// let mut codec = CachedCodec::new(16);
+    ///
+    /// assert_eq!(
+    ///     codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTr"),
+    ///     Err(Error::BadChecksum)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`decode_account_id`].
+    pub fn decode_account_id(&mut self, account_id: &str) -> Result<AccountId> {
+        if let Some(&slot) = self.index.get(account_id) {
+            self.hits += 1;
+            self.touch(slot);
+            return self.entries[slot].decoded;
+        }
+
+        self.misses += 1;
+        let decoded = decode_account_id(account_id);
+        if account_id.len() <= ACCOUNT_ID_MAX_LEN {
+            self.insert(account_id, decoded);
+        }
+
+        decoded
+    }
+
+    /// Whether `account_id` is a valid classic address, see
+    /// [`is_valid_account_id`](crate::is_valid_account_id)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// let mut codec = CachedCodec::new(16);
+    ///
+    /// assert!(codec.is_valid_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+    /// assert!(!codec.is_valid_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTr"));
+    /// ```
+    pub fn is_valid_account_id(&mut self, account_id: &str) -> bool {
+        self.decode_account_id(account_id).is_ok()
+    }
+
+    /// Number of cached results
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// let mut codec = CachedCodec::new(1);
+    /// codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    /// codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrBZbvji").unwrap();
+    ///
+    /// assert_eq!(codec.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing is cached
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// assert!(CachedCodec::new(16).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maximum number of cached results
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// assert_eq!(CachedCodec::new(16).capacity(), 16);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of lookups answered from the cache
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// assert_eq!(CachedCodec::new(16).hits(), 0);
+    /// ```
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of lookups that had to decode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// let mut codec = CachedCodec::new(16);
+    /// codec.decode_account_id("not an address").unwrap_err();
+    ///
+    /// assert_eq!(codec.misses(), 1);
+    /// ```
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Forget all cached results and reset the statistics
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::cache::CachedCodec;
+    ///
+    /// let mut codec = CachedCodec::new(16);
+    /// codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    /// codec.clear();
+    ///
+    /// assert!(codec.is_empty());
+    /// assert_eq!(codec.misses(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        *self = CachedCodec::new(self.capacity);
+    }
+
+    fn insert(&mut self, input: &str, decoded: Result<AccountId>) {
+        let slot = if self.entries.len() < self.capacity {
+            self.entries.push(Entry {
+                input: input.to_owned(),
+                decoded,
+                newer: NONE,
+                older: NONE,
+            });
+            self.entries.len() - 1
+        } else {
+            let slot = self.oldest;
+            self.unlink(slot);
+            let entry = &mut self.entries[slot];
+            self.index.remove(&entry.input);
+            entry.input.clear();
+            entry.input.push_str(input);
+            entry.decoded = decoded;
+            slot
+        };
+
+        self.index.insert(input.to_owned(), slot);
+        self.push_newest(slot);
+    }
+
+    fn touch(&mut self, slot: usize) {
+        if slot != self.newest {
+            self.unlink(slot);
+            self.push_newest(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Entry { newer, older, .. } = self.entries[slot];
+
+        match newer {
+            NONE => self.newest = older,
+            newer => self.entries[newer].older = older,
+        }
+        match older {
+            NONE => self.oldest = newer,
+            older => self.entries[older].newer = newer,
+        }
+    }
+
+    fn push_newest(&mut self, slot: usize) {
+        self.entries[slot].newer = NONE;
+        self.entries[slot].older = self.newest;
+
+        match self.newest {
+            NONE => self.oldest = slot,
+            newest => self.entries[newest].newer = slot,
+        }
+        self.newest = slot;
+    }
+}
+
+impl fmt::Debug for CachedCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCodec")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity)
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
@@ -0,0 +1,891 @@
+//! Policy-aware codec with audit hooks
+//!
+//! [`Codec`] wraps the free encode/decode functions, reports every
+//! operation to the registered [`AuditHook`]s and enforces a [`Policy`]
+//! restricting which token types and account ids may pass through it.
+//! It can also use a base58 [`Alphabet`] other than the XRPL one, and
+//! restrict X-addresses to one [`Network`].
+//!
+//! Configure a codec once and share it, rather than passing the same
+//! flags to every call. The free functions don't go through a codec, so
+//! they pay for neither policy checks nor hooks.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "seed-secp256k1")] {
+//! use ripple_address_codec::{
+//!     codec::{AuditEvent, Codec, Policy},
+//!     Error, TokenType,
+//! };
+//!
+//! let codec = Codec::new()
+//!     .with_policy(Policy::new().deny_token_types(&[TokenType::SeedSecp256k1]))
+//!     .with_hook(|event: &AuditEvent<'_>| println!("{:?}", event));
+//!
+//! assert_eq!(
+//!     codec.decode_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"),
+//!     Err(Error::Denied)
+//! );
+//! # }
+//! ```
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{borrow::Cow, collections::HashSet, fmt};
+
+use crate::{
+    base58, check_input_len, decode_seed_from_decoded, decode_with_alphabet,
+    encode_bytes_with_prefix_in, first_match,
+    sha256::{self, Sha256},
+    wipe, xaddress, Address, Algorithm, Alphabet, Entropy, Error, Network, Result, Tag, TokenType,
+    VersionedToken, ADDRESS,
+};
+
+type AccountId = [u8; Address::PAYLOAD_LEN];
+
+/// Default [`Codec::max_input_len`], in bytes
+///
+/// Several times the longest token, [`XADDRESS_MAX_LEN`](crate::XADDRESS_MAX_LEN)
+/// characters, leaving room for whitespace removed by lenient decoding.
+/// The free decoding functions, such as
+/// [`decode_account_id`](crate::decode_account_id), reject longer input
+/// too.
+pub const DEFAULT_MAX_INPUT_LEN: usize = 256;
+
+/// Zero width space, non-joiner, joiner, word joiner and byte order mark
+const ZERO_WIDTH: [char; 5] = ['\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}'];
+
+/// Codec operation reported to audit hooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Encoding bytes to a token string
+    Encode,
+    /// Decoding a token string to bytes
+    Decode,
+}
+
+/// What an audit hook is told about a single operation
+///
+/// Secret material (seed strings and entropy) is never part of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AuditEvent<'a> {
+    /// The operation performed
+    pub operation: Operation,
+    /// Token type, unknown if decoding failed before it was determined
+    pub token_type: Option<TokenType>,
+    /// Account id, for account id operations that got as far as having one
+    pub account_id: Option<&'a AccountId>,
+    /// Outcome of the operation, including policy denials
+    pub outcome: Result<()>,
+}
+
+/// Receiver of audit events, invoked on every [`Codec`] operation
+///
+/// Implemented for closures taking an [`AuditEvent`].
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::codec::{AuditEvent, AuditHook, Codec};
+///
+/// #[derive(Debug)]
+/// struct Logger;
+///
+/// impl AuditHook for Logger {
+///     fn on_event(&self, event: &AuditEvent<'_>) {
+///         println!("{:?} {:?}", event.operation, event.outcome);
+///     }
+/// }
+///
+/// let codec = Codec::new().with_hook(Logger);
+///
+/// assert!(codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_ok());
+/// ```
+pub trait AuditHook: Send + Sync {
+    /// Called once per operation, after it completed or failed
+    fn on_event(&self, event: &AuditEvent<'_>);
+}
+
+impl<F> AuditHook for F
+where
+    F: Fn(&AuditEvent<'_>) + Send + Sync,
+{
+    fn on_event(&self, event: &AuditEvent<'_>) {
+        self(event)
+    }
+}
+
+/// Allow/deny rules enforced by a [`Codec`]
+///
+/// By default everything is allowed. A deny rule always wins over an
+/// allow rule.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{codec::Policy, TokenType};
+///
+/// let policy = Policy::new()
+///     .allow_only_token_types(&[TokenType::AccountId])
+///     .deny_account_ids(&[[0; 20]]);
+///
+/// assert!(policy.permits(TokenType::AccountId, Some(&[1; 20])));
+/// assert!(!policy.permits(TokenType::AccountId, Some(&[0; 20])));
+/// assert!(!policy.permits(TokenType::SeedEd25519, None));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Policy {
+    allowed_token_types: Option<HashSet<TokenType>>,
+    denied_token_types: HashSet<TokenType>,
+    allowed_account_ids: Option<HashSet<AccountId>>,
+    denied_account_ids: HashSet<AccountId>,
+}
+
+impl Policy {
+    /// Policy allowing everything
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// assert!(Policy::new().permits(TokenType::SeedSecp256k1, None));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow only the given token types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// let policy = Policy::new().allow_only_token_types(&[TokenType::AccountId]);
+    ///
+    /// assert!(!policy.permits(TokenType::SeedEd25519, None));
+    /// ```
+    pub fn allow_only_token_types(mut self, token_types: &[TokenType]) -> Self {
+        self.allowed_token_types = Some(token_types.iter().copied().collect());
+        self
+    }
+
+    /// Deny the given token types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// let policy = Policy::new().deny_token_types(&[TokenType::SeedSecp256k1]);
+    ///
+    /// assert!(!policy.permits(TokenType::SeedSecp256k1, None));
+    /// assert!(policy.permits(TokenType::SeedEd25519, None));
+    /// ```
+    pub fn deny_token_types(mut self, token_types: &[TokenType]) -> Self {
+        self.denied_token_types.extend(token_types);
+        self
+    }
+
+    /// Allow only the given account ids
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// let policy = Policy::new().allow_only_account_ids(&[[1; 20]]);
+    ///
+    /// assert!(policy.permits(TokenType::AccountId, Some(&[1; 20])));
+    /// assert!(!policy.permits(TokenType::AccountId, Some(&[2; 20])));
+    /// ```
+    pub fn allow_only_account_ids(mut self, account_ids: &[AccountId]) -> Self {
+        self.allowed_account_ids = Some(account_ids.iter().copied().collect());
+        self
+    }
+
+    /// Deny the given account ids
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// let policy = Policy::new().deny_account_ids(&[[0; 20]]);
+    ///
+    /// assert!(!policy.permits(TokenType::AccountId, Some(&[0; 20])));
+    /// ```
+    pub fn deny_account_ids(mut self, account_ids: &[AccountId]) -> Self {
+        self.denied_account_ids.extend(account_ids);
+        self
+    }
+
+    /// Check a token type, and the account id for account id tokens
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Policy, TokenType};
+    ///
+    /// assert!(Policy::new().permits(TokenType::AccountId, Some(&[0; 20])));
+    /// ```
+    pub fn permits(&self, token_type: TokenType, account_id: Option<&AccountId>) -> bool {
+        let token_type_allowed = self
+            .allowed_token_types
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&token_type))
+            && !self.denied_token_types.contains(&token_type);
+
+        let account_id_allowed = account_id.is_none_or(|account_id| {
+            self.allowed_account_ids
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(account_id))
+                && !self.denied_account_ids.contains(account_id)
+        });
+
+        token_type_allowed && account_id_allowed
+    }
+}
+
+/// Codec enforcing a [`Policy`] and reporting to [`AuditHook`]s
+///
+/// Methods mirror the crate's free functions. Encoding also returns
+/// a [`Result`] here, since the policy may deny it.
+pub struct Codec {
+    policy: Policy,
+    hooks: Vec<Box<dyn AuditHook>>,
+    alphabet: Alphabet,
+    strict: bool,
+    lenient: bool,
+    network: Option<Network>,
+    max_input_len: usize,
+    sha256: Option<Box<dyn Sha256>>,
+}
+
+impl Codec {
+    /// Codec allowing everything, without hooks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::Codec;
+    ///
+    /// assert_eq!(
+    ///     Codec::new().encode_account_id(&[0; 20]),
+    ///     Ok("rrrrrrrrrrrrrrrrrrrrrhoLvTp".to_string())
+    /// );
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::{Codec, Policy}, Error};
+    ///
+    /// let codec = Codec::new().with_policy(Policy::new().deny_account_ids(&[[0; 20]]));
+    ///
+    /// assert_eq!(codec.encode_account_id(&[0; 20]), Err(Error::Denied));
+    /// ```
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Register an audit hook
+    ///
+    /// Hooks are invoked in registration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use ripple_address_codec::codec::{AuditEvent, Codec};
+    ///
+    /// let count = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&count);
+    ///
+    /// let codec = Codec::new().with_hook(move |_: &AuditEvent<'_>| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// codec.encode_account_id(&[0; 20]).unwrap();
+    ///
+    /// assert_eq!(count.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn with_hook(mut self, hook: impl AuditHook + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Replace the base58 alphabet, [`Alphabet::XRPL`] by default
+    ///
+    /// Version bytes and checksum are unchanged, only the
+    /// characters differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Alphabet};
+    ///
+    /// let bitcoin = Codec::new().with_alphabet(Alphabet::BITCOIN);
+    ///
+    /// // Account ids share version byte 0x00 with Bitcoin P2PKH addresses
+    /// assert_eq!(
+    ///     bitcoin.decode_account_id("1111111111111111111114oLvT2"),
+    ///     Ok([0; 20])
+    /// );
+    /// ```
+    pub fn with_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Enable or disable strict decoding, disabled by default
+    ///
+    /// In strict mode decoded input is re-encoded and rejected with
+    /// [`Error::NonCanonical`] unless it matches the input exactly, so
+    /// each token has a single accepted string form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::Codec;
+    ///
+    /// let codec = Codec::new().with_strict_decoding(true);
+    ///
+    /// assert_eq!(
+    ///     codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+    ///     Ok([0; 20])
+    /// );
+    /// ```
+    pub fn with_strict_decoding(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Enable or disable lenient decoding, disabled by default
+    ///
+    /// In lenient mode surrounding whitespace (including newlines) and
+    /// zero-width characters anywhere in the input are removed before
+    /// decoding, as they are often carried along when copy-pasting from
+    /// documents and chat apps. Strict mode then checks the cleaned input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::Codec;
+    ///
+    /// let codec = Codec::new().with_lenient_decoding(true);
+    ///
+    /// assert_eq!(
+    ///     codec.decode_account_id(" rrrrrrrrrrrrrrrrrrrrr\u{200B}hoLvTp\n"),
+    ///     Ok([0; 20])
+    /// );
+    /// ```
+    pub fn with_lenient_decoding(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Restrict X-addresses to `network`, any network by default
+    ///
+    /// X-addresses of other networks are rejected with
+    /// [`Error::BadPrefix`], as their prefix differs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Error, Network};
+    ///
+    /// let codec = Codec::new().with_network(Network::Main);
+    ///
+    /// assert_eq!(
+    ///     codec.decode_xaddress("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"),
+    ///     Err(Error::BadPrefix { expected: &[0x05, 0x44] })
+    /// );
+    /// ```
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Replace the maximum input length, [`DEFAULT_MAX_INPUT_LEN`] bytes
+    /// by default
+    ///
+    /// Longer input is rejected with [`Error::InputTooLong`] before
+    /// anything else, as base58 decoding takes time quadratic in its
+    /// length. It bounds the work of decoding input from untrusted
+    /// sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Error};
+    ///
+    /// let codec = Codec::new().with_max_input_len(30);
+    ///
+    /// assert!(codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_ok());
+    /// assert_eq!(
+    ///     codec.decode_account_id(&"r".repeat(31)),
+    ///     Err(Error::InputTooLong {
+    ///         max_len: 30,
+    ///         actual: 31
+    ///     })
+    /// );
+    /// ```
+    pub fn with_max_input_len(mut self, max_input_len: usize) -> Self {
+        self.max_input_len = max_input_len;
+        self
+    }
+
+    /// Replace the SHA-256 implementation used for checksums,
+    /// [`sha256::DEFAULT`] by default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, sha256::DEFAULT};
+    ///
+    /// let codec = Codec::new().with_sha256(|data: &[u8]| DEFAULT.digest(data));
+    ///
+    /// assert_eq!(
+    ///     codec.encode_account_id(&[0; 20]),
+    ///     Ok("rrrrrrrrrrrrrrrrrrrrrhoLvTp".to_string())
+    /// );
+    /// ```
+    pub fn with_sha256(mut self, sha256: impl Sha256 + 'static) -> Self {
+        self.sha256 = Some(Box::new(sha256));
+        self
+    }
+
+    /// The base58 alphabet used by this codec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Alphabet};
+    ///
+    /// assert_eq!(Codec::new().alphabet(), Alphabet::XRPL);
+    /// ```
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// The network X-addresses are restricted to, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Network};
+    ///
+    /// assert_eq!(Codec::new().network(), None);
+    /// assert_eq!(
+    ///     Codec::new().with_network(Network::Test).network(),
+    ///     Some(Network::Test)
+    /// );
+    /// ```
+    pub fn network(&self) -> Option<Network> {
+        self.network
+    }
+
+    /// The maximum input length in bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::{Codec, DEFAULT_MAX_INPUT_LEN};
+    ///
+    /// assert_eq!(Codec::new().max_input_len(), DEFAULT_MAX_INPUT_LEN);
+    /// ```
+    pub fn max_input_len(&self) -> usize {
+        self.max_input_len
+    }
+
+    /// The policy enforced by this codec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::{Codec, Policy};
+    ///
+    /// assert_eq!(Codec::new().policy(), &Policy::new());
+    /// ```
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Same as [`encode_account_id`](crate::encode_account_id), subject to the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::Codec;
+    ///
+    /// assert_eq!(
+    ///     Codec::new().encode_account_id(&[0; 20]).unwrap(),
+    ///     "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Denied`] if the policy denies the account id.
+    pub fn encode_account_id(&self, bytes: &AccountId) -> Result<String> {
+        let outcome = self.check(TokenType::AccountId, Some(bytes));
+        self.audit(
+            Operation::Encode,
+            Some(TokenType::AccountId),
+            Some(bytes),
+            outcome,
+        );
+        outcome?;
+
+        Ok(encode_bytes_with_prefix_in(
+            &self.alphabet,
+            self.sha256(),
+            ADDRESS.prefix,
+            bytes,
+        ))
+    }
+
+    /// Same as [`decode_account_id`](crate::decode_account_id), subject to the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::codec::Codec;
+    ///
+    /// assert_eq!(
+    ///     Codec::new().decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+    ///     Ok([0; 20])
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputTooLong`] if it's longer than the
+    /// [`max_input_len`](Codec::max_input_len), an [`Error`]
+    /// describing why account id string is invalid,
+    /// [`Error::NonCanonical`] if it's not canonical in strict mode
+    /// and [`Error::Denied`] if the policy denies it.
+    pub fn decode_account_id(&self, account_id: &str) -> Result<AccountId> {
+        let decoded = self
+            .decode_bytes(account_id)
+            .and_then(|decoded_bytes| ADDRESS.decode_bytes(self.sha256(), &decoded_bytes));
+
+        let bytes: AccountId = match decoded {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                self.audit(Operation::Decode, None, None, Err(error));
+                return Err(error);
+            }
+        };
+
+        let outcome = self.check(TokenType::AccountId, Some(&bytes));
+        self.audit(
+            Operation::Decode,
+            Some(TokenType::AccountId),
+            Some(&bytes),
+            outcome,
+        );
+        outcome?;
+
+        Ok(bytes)
+    }
+
+    /// Same as [`encode_seed`](crate::encode_seed), subject to the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{codec::Codec, Ed25519};
+    ///
+    /// assert_eq!(
+    ///     Codec::new().encode_seed(&[0; 16], Ed25519).unwrap(),
+    ///     "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Denied`] if the policy denies the seed type.
+    pub fn encode_seed(&self, entropy: &Entropy, algorithm: Algorithm) -> Result<String> {
+        let token_type = TokenType::seed(algorithm);
+
+        let outcome = self.check(token_type, None);
+        self.audit(Operation::Encode, Some(token_type), None, outcome);
+        outcome?;
+
+        Ok(encode_bytes_with_prefix_in(
+            &self.alphabet,
+            self.sha256(),
+            algorithm.seed_prefix(),
+            entropy,
+        ))
+    }
+
+    /// Same as [`decode_seed`](crate::decode_seed), subject to the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{codec::Codec, Ed25519};
+    ///
+    /// assert_eq!(
+    ///     Codec::new().decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+    ///     Ok(([0; 16], Ed25519))
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputTooLong`] if it's longer than the
+    /// [`max_input_len`](Codec::max_input_len), an [`Error`]
+    /// describing why seed is invalid,
+    /// [`Error::NonCanonical`] if it's not canonical in strict mode
+    /// and [`Error::Denied`] if the policy denies the seed type.
+    pub fn decode_seed(&self, seed: &str) -> Result<(Entropy, Algorithm)> {
+        let decoded = self.decode_bytes(seed).and_then(|mut decoded_bytes| {
+            let decoded = decode_seed_from_decoded(self.sha256(), &decoded_bytes);
+            wipe(&mut decoded_bytes);
+            decoded
+        });
+
+        let (entropy, algorithm) = match decoded {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                self.audit(Operation::Decode, None, None, Err(error));
+                return Err(error);
+            }
+        };
+
+        let token_type = TokenType::seed(algorithm);
+
+        let outcome = self.check(token_type, None);
+        self.audit(Operation::Decode, Some(token_type), None, outcome);
+        outcome?;
+
+        Ok((entropy, algorithm))
+    }
+
+    /// Same as [`encode_xaddress`](crate::encode_xaddress), for the
+    /// codec's network or [`Network::Main`] if it has none, subject to
+    /// the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Network, Tag};
+    ///
+    /// let codec = Codec::new().with_network(Network::Test);
+    ///
+    /// assert!(codec.encode_xaddress(&[0; 20], Tag::U32(1)).unwrap().starts_with('T'));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Denied`] if the policy denies X-addresses or the
+    /// account id.
+    pub fn encode_xaddress(&self, account_id: &AccountId, tag: Tag) -> Result<String> {
+        let outcome = self.check(TokenType::XAddress, Some(account_id));
+        self.audit(
+            Operation::Encode,
+            Some(TokenType::XAddress),
+            Some(account_id),
+            outcome,
+        );
+        outcome?;
+
+        Ok(encode_bytes_with_prefix_in(
+            &self.alphabet,
+            self.sha256(),
+            xaddress::prefix(self.network.unwrap_or_default()),
+            &xaddress::payload(account_id, tag),
+        ))
+    }
+
+    /// Same as [`decode_xaddress`](crate::decode_xaddress), restricted to
+    /// the codec's network and subject to the policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{codec::Codec, Network, Tag};
+    ///
+    /// let (_, tag, network) = Codec::new()
+    ///     .decode_xaddress("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(tag, Tag::U32(1));
+    /// assert_eq!(network, Network::Test);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputTooLong`] if it's longer than the
+    /// [`max_input_len`](Codec::max_input_len), an [`Error`]
+    /// describing why X-address is
+    /// invalid, [`Error::BadPrefix`] if it's for another network than the
+    /// codec's, [`Error::NonCanonical`] if it's not canonical in strict
+    /// mode and [`Error::Denied`] if the policy denies it.
+    pub fn decode_xaddress(&self, xaddress: &str) -> Result<(AccountId, Tag, Network)> {
+        let decoded = self.decode_bytes(xaddress).and_then(|decoded_bytes| {
+            let (payload, network) = first_match(
+                [Network::Main, Network::Test]
+                    .iter()
+                    .filter(|&&network| self.network.is_none_or(|only| only == network))
+                    .map(|&network| {
+                        xaddress::token(network)
+                            .decode_bytes(self.sha256(), &decoded_bytes)
+                            .map(|payload| (payload, network))
+                    }),
+            )?;
+            let (account_id, flag_and_tag) = xaddress::split_payload(&payload);
+
+            Ok((account_id, xaddress::decode_tag(flag_and_tag)?, network))
+        });
+
+        let (account_id, tag, network) = match decoded {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                self.audit(Operation::Decode, None, None, Err(error));
+                return Err(error);
+            }
+        };
+
+        let outcome = self.check(TokenType::XAddress, Some(&account_id));
+        self.audit(
+            Operation::Decode,
+            Some(TokenType::XAddress),
+            Some(&account_id),
+            outcome,
+        );
+        outcome?;
+
+        Ok((account_id, tag, network))
+    }
+
+    fn decode_bytes(&self, s: &str) -> Result<Vec<u8>> {
+        check_input_len(s, self.max_input_len)?;
+
+        let s = if self.lenient {
+            Cow::Owned(strip_paste_artifacts(s))
+        } else {
+            Cow::Borrowed(s)
+        };
+
+        let decoded = self.decode_canonical(&s);
+
+        if let Cow::Owned(mut stripped) = s {
+            wipe(&mut stripped);
+        }
+
+        decoded
+    }
+
+    fn decode_canonical(&self, s: &str) -> Result<Vec<u8>> {
+        let mut decoded_bytes = decode_with_alphabet(&self.alphabet, s)?;
+
+        if self.strict {
+            let mut encoded = base58::encode(&self.alphabet, &decoded_bytes);
+            let canonical = encoded == s;
+            wipe(&mut encoded);
+
+            if !canonical {
+                wipe(&mut decoded_bytes);
+                return Err(Error::NonCanonical);
+            }
+        }
+
+        Ok(decoded_bytes)
+    }
+
+    fn sha256(&self) -> &dyn Sha256 {
+        self.sha256.as_deref().unwrap_or(sha256::DEFAULT)
+    }
+
+    fn check(&self, token_type: TokenType, account_id: Option<&AccountId>) -> Result<()> {
+        if self.policy.permits(token_type, account_id) {
+            return Ok(());
+        }
+
+        Err(Error::Denied)
+    }
+
+    fn audit(
+        &self,
+        operation: Operation,
+        token_type: Option<TokenType>,
+        account_id: Option<&AccountId>,
+        outcome: Result<()>,
+    ) {
+        let event = AuditEvent {
+            operation,
+            token_type,
+            account_id,
+            outcome,
+        };
+
+        for hook in &self.hooks {
+            hook.on_event(&event);
+        }
+    }
+}
+
+impl Default for Codec {
+    fn default() -> Self {
+        Codec {
+            policy: Policy::default(),
+            hooks: Vec::new(),
+            alphabet: Alphabet::default(),
+            strict: false,
+            lenient: false,
+            network: None,
+            max_input_len: DEFAULT_MAX_INPUT_LEN,
+            sha256: None,
+        }
+    }
+}
+
+impl fmt::Debug for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Codec")
+            .field("policy", &self.policy)
+            .field("hooks", &self.hooks.len())
+            .field("alphabet", &self.alphabet)
+            .field("strict", &self.strict)
+            .field("lenient", &self.lenient)
+            .field("network", &self.network)
+            .field("max_input_len", &self.max_input_len)
+            .field("custom_sha256", &self.sha256.is_some())
+            .finish()
+    }
+}
+
+fn strip_paste_artifacts(s: &str) -> String {
+    // Sized upfront, so growing doesn't leave copies of secrets behind
+    let mut stripped = String::with_capacity(s.len());
+
+    stripped.extend(
+        s.trim_matches(|character: char| {
+            character.is_whitespace() || ZERO_WIDTH.contains(&character)
+        })
+        .chars()
+        .filter(|character| !ZERO_WIDTH.contains(character)),
+    );
+
+    stripped
+}
@@ -0,0 +1,340 @@
+//! Functions named after the JavaScript ripple-address-codec API
+//!
+//! Every function here is a thin wrapper over its idiomatic counterpart
+//! in the crate root, kept under the JavaScript name so code ported from
+//! Node can be migrated mechanically first and moved over to the
+//! idiomatic API later.
+//!
+//! # Examples
+//!
+//! ```
+//! use ripple_address_codec::compat::*;
+//!
+//! let address = encodeAccountID(&[0; 20]);
+//!
+//! assert!(isValidClassicAddress(&address));
+//! ```
+
+#![allow(non_snake_case)]
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::fmt;
+
+use crate::{
+    classic_address_to_xaddress, decode_account_id, decode_account_public_key,
+    decode_node_public_key, decode_seed, decode_xaddress, encode_account_id,
+    encode_account_public_key, encode_node_public_key, encode_seed, encode_xaddress,
+    seed::Redacted, xaddress_to_classic_address, AccountPublicKey, Address, Algorithm, Entropy,
+    Network, NodePublicKey, Result, Tag, VersionedToken,
+};
+
+/// Decoded seed, shaped like the object returned by JavaScript `decodeSeed`
+///
+/// [`Debug`](fmt::Debug) output redacts the entropy.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodedSeed {
+    /// Seed entropy
+    pub bytes: Entropy,
+    /// Algorithm the seed is intended to be used with
+    pub r#type: Algorithm,
+}
+
+impl fmt::Debug for DecodedSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodedSeed")
+            .field("bytes", &Redacted)
+            .field("type", &self.r#type)
+            .finish()
+    }
+}
+
+/// Decoded X-address, shaped like the object returned by JavaScript `decodeXAddress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodedXAddress {
+    /// Account id bytes
+    pub accountId: [u8; Address::PAYLOAD_LEN],
+    /// Destination tag
+    pub tag: Option<u32>,
+    /// Whether the X-address is for a test network
+    pub test: bool,
+}
+
+/// Classic address, shaped like the object returned by JavaScript `xAddressToClassicAddress`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassicAddress {
+    /// Classic address (starting with r...)
+    pub classicAddress: String,
+    /// Destination tag
+    pub tag: Option<u32>,
+    /// Whether the X-address was for a test network
+    pub test: bool,
+}
+
+/// Same as JavaScript `encodeAccountID`, see [`encode_account_id`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::encodeAccountID;
+///
+/// assert_eq!(encodeAccountID(&[0; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+pub fn encodeAccountID(bytes: &[u8; Address::PAYLOAD_LEN]) -> String {
+    encode_account_id(bytes)
+}
+
+/// Same as JavaScript `decodeAccountID`, see [`decode_account_id`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::decodeAccountID;
+///
+/// assert_eq!(decodeAccountID("rrrrrrrrrrrrrrrrrrrrrhoLvTp"), Ok([0; 20]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why account id string is invalid.
+pub fn decodeAccountID(accountId: &str) -> Result<[u8; Address::PAYLOAD_LEN]> {
+    decode_account_id(accountId)
+}
+
+/// Same as JavaScript `encodeNodePublic`, see [`encode_node_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::encodeNodePublic;
+///
+/// assert!(encodeNodePublic(&[3; 33]).starts_with('n'));
+/// ```
+pub fn encodeNodePublic(bytes: &[u8; NodePublicKey::PAYLOAD_LEN]) -> String {
+    encode_node_public_key(bytes)
+}
+
+/// Same as JavaScript `decodeNodePublic`, see [`decode_node_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::{decodeNodePublic, encodeNodePublic};
+///
+/// assert_eq!(decodeNodePublic(&encodeNodePublic(&[3; 33])), Ok([3; 33]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why node public key is invalid.
+pub fn decodeNodePublic(base58string: &str) -> Result<[u8; NodePublicKey::PAYLOAD_LEN]> {
+    decode_node_public_key(base58string)
+}
+
+/// Same as JavaScript `encodeAccountPublic`, see [`encode_account_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::encodeAccountPublic;
+///
+/// assert!(encodeAccountPublic(&[2; 33]).starts_with('a'));
+/// ```
+pub fn encodeAccountPublic(bytes: &[u8; AccountPublicKey::PAYLOAD_LEN]) -> String {
+    encode_account_public_key(bytes)
+}
+
+/// Same as JavaScript `decodeAccountPublic`, see [`decode_account_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::{decodeAccountPublic, encodeAccountPublic};
+///
+/// assert_eq!(decodeAccountPublic(&encodeAccountPublic(&[2; 33])), Ok([2; 33]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why account public key is invalid.
+pub fn decodeAccountPublic(base58string: &str) -> Result<[u8; AccountPublicKey::PAYLOAD_LEN]> {
+    decode_account_public_key(base58string)
+}
+
+/// Same as JavaScript `encodeSeed`, see [`encode_seed`]
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{compat::encodeSeed, Ed25519};
+///
+/// assert_eq!(encodeSeed(&[0; 16], Ed25519), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// # }
+/// ```
+pub fn encodeSeed(entropy: &Entropy, r#type: Algorithm) -> String {
+    encode_seed(entropy, r#type)
+}
+
+/// Same as JavaScript `decodeSeed`, see [`decode_seed`]
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{compat::decodeSeed, Ed25519};
+///
+/// let decoded = decodeSeed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+///
+/// assert_eq!(decoded.bytes, [0; 16]);
+/// assert_eq!(decoded.r#type, Ed25519);
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why seed is invalid.
+pub fn decodeSeed(seed: &str) -> Result<DecodedSeed> {
+    let (bytes, r#type) = decode_seed(seed)?;
+
+    Ok(DecodedSeed { bytes, r#type })
+}
+
+/// Same as JavaScript `isValidClassicAddress`
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::isValidClassicAddress;
+///
+/// assert!(isValidClassicAddress("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// assert!(!isValidClassicAddress("rrrrrrrrrrrrrrrrrrrrrhoLvTP"));
+/// ```
+pub fn isValidClassicAddress(address: &str) -> bool {
+    decode_account_id(address).is_ok()
+}
+
+/// Same as JavaScript `encodeXAddress`, see [`encode_xaddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::encodeXAddress;
+///
+/// assert!(encodeXAddress(&[0; 20], Some(1), true).starts_with('T'));
+/// ```
+pub fn encodeXAddress(
+    accountId: &[u8; Address::PAYLOAD_LEN],
+    tag: Option<u32>,
+    test: bool,
+) -> String {
+    encode_xaddress(accountId, tag.into(), to_network(test))
+}
+
+/// Same as JavaScript `decodeXAddress`, see [`decode_xaddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::{decodeXAddress, encodeXAddress};
+///
+/// let decoded = decodeXAddress(&encodeXAddress(&[0; 20], None, false)).unwrap();
+///
+/// assert_eq!(decoded.accountId, [0; 20]);
+/// assert_eq!(decoded.tag, None);
+/// assert!(!decoded.test);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why X-address is invalid.
+pub fn decodeXAddress(xAddress: &str) -> Result<DecodedXAddress> {
+    let (accountId, tag, network) = decode_xaddress(xAddress)?;
+
+    Ok(DecodedXAddress {
+        accountId,
+        tag: to_u32(tag),
+        test: network.is_test(),
+    })
+}
+
+/// Same as JavaScript `isValidXAddress`
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::isValidXAddress;
+///
+/// assert!(isValidXAddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"));
+/// assert!(!isValidXAddress("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// ```
+pub fn isValidXAddress(xAddress: &str) -> bool {
+    decode_xaddress(xAddress).is_ok()
+}
+
+/// Same as JavaScript `classicAddressToXAddress`, see [`classic_address_to_xaddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::classicAddressToXAddress;
+///
+/// assert_eq!(
+///     classicAddressToXAddress("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", None, false).unwrap(),
+///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why classic address is invalid.
+pub fn classicAddressToXAddress(
+    classicAddress: &str,
+    tag: Option<u32>,
+    test: bool,
+) -> Result<String> {
+    classic_address_to_xaddress(classicAddress, tag.into(), to_network(test))
+}
+
+/// Same as JavaScript `xAddressToClassicAddress`, see [`xaddress_to_classic_address`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::compat::xAddressToClassicAddress;
+///
+/// let decoded =
+///     xAddressToClassicAddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb").unwrap();
+///
+/// assert_eq!(decoded.classicAddress, "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+/// assert_eq!(decoded.tag, None);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why X-address is invalid.
+pub fn xAddressToClassicAddress(xAddress: &str) -> Result<ClassicAddress> {
+    let (classicAddress, tag, network) = xaddress_to_classic_address(xAddress)?;
+
+    Ok(ClassicAddress {
+        classicAddress,
+        tag: to_u32(tag),
+        test: network.is_test(),
+    })
+}
+
+fn to_u32(tag: Tag) -> Option<u32> {
+    match tag {
+        Tag::U32(tag) => Some(tag),
+        Tag::None => None,
+    }
+}
+
+fn to_network(test: bool) -> Network {
+    if test {
+        Network::Test
+    } else {
+        Network::Main
+    }
+}
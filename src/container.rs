@@ -0,0 +1,261 @@
+//! Versioned binary container for exported seeds
+//!
+//! A standard framing for seeds encrypted for export, e.g. out of an
+//! enclave, so blobs can be imported by any deployment. Encryption is
+//! up to the caller: this module only frames the ciphertext and MAC of
+//! an AEAD such as AES-GCM, which should authenticate the
+//! [`header`](SeedContainer::header) as associated data.
+//!
+//! The layout is fixed for a format version:
+//!
+//! | Bytes | Field                                                 |
+//! |-------|-------------------------------------------------------|
+//! | 4     | [`MAGIC`]                                             |
+//! | 1     | [`VERSION`]                                           |
+//! | 1     | Algorithm: 0 for secp256k1, 1 for Ed25519             |
+//! | 2     | Ciphertext length `n`, big-endian                     |
+//! | n     | Ciphertext                                            |
+//! | 16    | MAC                                                   |
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "seed-ed25519")] {
+//! use ripple_address_codec::{container::SeedContainer, Ed25519};
+//!
+//! # let (ciphertext, mac) = ([0; 16], [0; 16]);
+//! let exported = SeedContainer::new(Ed25519, &ciphertext, mac).unwrap().encode();
+//!
+//! let imported = SeedContainer::decode(&exported).unwrap();
+//!
+//! assert_eq!(imported.algorithm(), Ed25519);
+//! assert_eq!(imported.ciphertext(), &ciphertext);
+//! # }
+//! ```
+
+use std::convert::{TryFrom, TryInto};
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{Algorithm, DecodeError, Error, Result};
+
+/// Magic bytes starting every container
+pub const MAGIC: [u8; 4] = *b"XRPS";
+/// Format version written by this crate
+pub const VERSION: u8 = 1;
+/// Header length in bytes: magic, version, algorithm and ciphertext length
+pub const HEADER_LEN: usize = 8;
+/// MAC length in bytes
+pub const MAC_LEN: usize = 16;
+/// Longest ciphertext in bytes, as its length is stored in 2 bytes
+pub const MAX_CIPHERTEXT_LEN: usize = u16::MAX as usize;
+
+/// Encrypted seed with its algorithm, borrowing the ciphertext
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{container::SeedContainer, Secp256k1};
+///
+/// let container = SeedContainer::new(Secp256k1, &[1, 2, 3], [0; 16]).unwrap();
+///
+/// assert_eq!(container.encode().len(), 8 + 3 + 16);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeedContainer<'a> {
+    algorithm: Algorithm,
+    ciphertext: &'a [u8],
+    mac: [u8; MAC_LEN],
+}
+
+impl<'a> SeedContainer<'a> {
+    /// Container of an encrypted seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{container::SeedContainer, Ed25519};
+    ///
+    /// let container = SeedContainer::new(Ed25519, &[0; 16], [0; 16]).unwrap();
+    ///
+    /// assert_eq!(container.mac(), &[0; 16]);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputTooLong`] if the ciphertext is longer than
+    /// [`MAX_CIPHERTEXT_LEN`].
+    pub fn new(algorithm: Algorithm, ciphertext: &'a [u8], mac: [u8; MAC_LEN]) -> Result<Self> {
+        if ciphertext.len() > MAX_CIPHERTEXT_LEN {
+            return Err(Error::InputTooLong {
+                max_len: MAX_CIPHERTEXT_LEN,
+                actual: ciphertext.len(),
+            });
+        }
+
+        Ok(SeedContainer {
+            algorithm,
+            ciphertext,
+            mac,
+        })
+    }
+
+    /// Decode a container
+    ///
+    /// Only the framing is checked; the MAC is verified by the caller
+    /// when decrypting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{container::SeedContainer, Error};
+    ///
+    /// assert_eq!(SeedContainer::decode(b"XRPS\x02"), Err(Error::UnsupportedVersion { version: 2 }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadPrefix`] if the bytes don't start with
+    /// [`MAGIC`], [`Error::UnsupportedVersion`] for another format
+    /// version, [`Error::DecodeError`] for an unknown algorithm and
+    /// [`Error::BadLength`] if the length doesn't match the header.
+    pub fn decode(bytes: &'a [u8]) -> Result<Self> {
+        if !bytes.starts_with(&MAGIC) {
+            return Err(Error::BadPrefix { expected: &MAGIC });
+        }
+
+        match bytes.get(MAGIC.len()) {
+            Some(&VERSION) | None => {}
+            Some(&version) => return Err(Error::UnsupportedVersion { version }),
+        }
+
+        if bytes.len() < HEADER_LEN {
+            return Err(Error::BadLength {
+                expected: HEADER_LEN + MAC_LEN,
+                actual: bytes.len(),
+            });
+        }
+
+        let algorithm = Algorithm::from_tag(bytes[5]).ok_or(DecodeError)?;
+        let ciphertext_len = u16::from_be_bytes([bytes[6], bytes[7]]) as usize;
+
+        let expected = HEADER_LEN + ciphertext_len + MAC_LEN;
+        if bytes.len() != expected {
+            return Err(Error::BadLength {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let (ciphertext, mac) = bytes[HEADER_LEN..].split_at(ciphertext_len);
+
+        Ok(SeedContainer {
+            algorithm,
+            ciphertext,
+            mac: mac.try_into().expect("length checked above"),
+        })
+    }
+
+    /// Encode the container
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{container::SeedContainer, Ed25519};
+    ///
+    /// let encoded = SeedContainer::new(Ed25519, &[0xAA], [0xBB; 16]).unwrap().encode();
+    ///
+    /// assert_eq!(&encoded[..9], b"XRPS\x01\x01\x00\x01\xAA");
+    /// # }
+    /// ```
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(HEADER_LEN + self.ciphertext.len() + MAC_LEN);
+
+        encoded.extend_from_slice(&self.header());
+        encoded.extend_from_slice(self.ciphertext);
+        encoded.extend_from_slice(&self.mac);
+
+        encoded
+    }
+
+    /// The header, to authenticate as associated data when encrypting
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{container::SeedContainer, Secp256k1};
+    ///
+    /// let container = SeedContainer::new(Secp256k1, &[0; 16], [0; 16]).unwrap();
+    ///
+    /// assert_eq!(&container.header(), b"XRPS\x01\x00\x00\x10");
+    /// # }
+    /// ```
+    pub fn header(&self) -> [u8; HEADER_LEN] {
+        let len = u16::try_from(self.ciphertext.len())
+            .expect("ciphertext length checked by new")
+            .to_be_bytes();
+
+        [
+            MAGIC[0],
+            MAGIC[1],
+            MAGIC[2],
+            MAGIC[3],
+            VERSION,
+            self.algorithm.tag(),
+            len[0],
+            len[1],
+        ]
+    }
+
+    /// The algorithm of the seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{container::SeedContainer, Ed25519};
+    ///
+    /// assert_eq!(SeedContainer::new(Ed25519, &[], [0; 16]).unwrap().algorithm(), Ed25519);
+    /// # }
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// The encrypted seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{container::SeedContainer, Ed25519};
+    ///
+    /// assert_eq!(SeedContainer::new(Ed25519, &[1, 2], [0; 16]).unwrap().ciphertext(), &[1, 2]);
+    /// # }
+    /// ```
+    pub fn ciphertext(&self) -> &'a [u8] {
+        self.ciphertext
+    }
+
+    /// The MAC of the ciphertext and header
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{container::SeedContainer, Ed25519};
+    ///
+    /// assert_eq!(SeedContainer::new(Ed25519, &[], [7; 16]).unwrap().mac(), &[7; 16]);
+    /// # }
+    /// ```
+    pub fn mac(&self) -> &[u8; MAC_LEN] {
+        &self.mac
+    }
+}
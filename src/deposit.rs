@@ -0,0 +1,172 @@
+use crate::{is_well_known, parse_account, AccountId, Error, Network, Tag, WellKnownAccount};
+
+/// Destination tag rule of a [deposit check](validate_deposit_destination)
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{validate_deposit_destination, TagPolicy};
+///
+/// let address = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+///
+/// assert!(validate_deposit_destination(address, TagPolicy::Any).is_accepted());
+/// assert!(!validate_deposit_destination(address, TagPolicy::Required).is_accepted());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagPolicy {
+    /// A destination tag is required, as by exchanges crediting
+    /// customers by tag
+    Required,
+    /// A destination tag is rejected, as by accounts that would ignore it
+    Forbidden,
+    /// A destination tag is optional
+    Any,
+}
+
+/// Verdict of [`validate_deposit_destination`]
+///
+/// Only the first problem found is reported, in the order of the
+/// variants.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{validate_deposit_destination, DepositCheck, Tag, TagPolicy};
+///
+/// let check = validate_deposit_destination(
+///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi",
+///     TagPolicy::Required,
+/// );
+///
+/// match check {
+///     DepositCheck::Accepted { account_id, tag } => {
+///         assert_eq!(account_id.to_string(), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+///         assert_eq!(tag, Tag::U32(4294967295));
+///     }
+///     check => panic!("rejected: {}", check.code()),
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepositCheck {
+    /// Neither a valid classic address nor a valid X-address
+    Invalid(Error),
+    /// X-address of a test network, not the main network
+    TestNetwork {
+        /// Account id of the X-address
+        account_id: AccountId,
+    },
+    /// Well-known account nobody can spend from, so deposits to it are
+    /// lost
+    BurnAccount(WellKnownAccount),
+    /// No destination tag, but the policy requires one
+    MissingTag {
+        /// Account id of the destination
+        account_id: AccountId,
+    },
+    /// A destination tag, but the policy forbids it
+    UnexpectedTag {
+        /// Account id of the destination
+        account_id: AccountId,
+        /// The rejected tag
+        tag: Tag,
+    },
+    /// Valid destination satisfying the policy
+    Accepted {
+        /// Account id to deposit to
+        account_id: AccountId,
+        /// Destination tag, from the X-address
+        tag: Tag,
+    },
+}
+
+impl DepositCheck {
+    /// Whether the deposit can go ahead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{validate_deposit_destination, TagPolicy};
+    ///
+    /// let check = validate_deposit_destination("rrrrrrrrrrrrrrrrrNAMEtxvNvQ", TagPolicy::Any);
+    ///
+    /// assert!(!check.is_accepted());
+    /// ```
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, DepositCheck::Accepted { .. })
+    }
+
+    /// Stable snake_case code identifying the variant, for reports and
+    /// logs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{validate_deposit_destination, TagPolicy};
+    ///
+    /// let check = validate_deposit_destination("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", TagPolicy::Required);
+    ///
+    /// assert_eq!(check.code(), "missing_tag");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            DepositCheck::Invalid(_) => "invalid",
+            DepositCheck::TestNetwork { .. } => "test_network",
+            DepositCheck::BurnAccount(_) => "burn_account",
+            DepositCheck::MissingTag { .. } => "missing_tag",
+            DepositCheck::UnexpectedTag { .. } => "unexpected_tag",
+            DepositCheck::Accepted { .. } => "accepted",
+        }
+    }
+}
+
+/// Check a customer-supplied deposit destination, a classic address or
+/// an X-address, against a destination tag policy
+///
+/// Besides decoding, it rejects test network X-addresses and well-known
+/// accounts nobody can spend from (all of them but the genesis account,
+/// see [`WellKnownAccount`]), then applies `policy` to the tag of
+/// X-addresses. Classic addresses have no tag: pass a separately
+/// entered tag as an X-address, e.g. with
+/// [`classic_address_to_xaddress`](crate::classic_address_to_xaddress).
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{
+///     validate_deposit_destination, DepositCheck, TagPolicy, WellKnownAccount,
+/// };
+///
+/// assert_eq!(
+///     validate_deposit_destination("rrrrrrrrrrrrrrrrrrrrrhoLvTp", TagPolicy::Any),
+///     DepositCheck::BurnAccount(WellKnownAccount::Zero)
+/// );
+/// assert_eq!(
+///     validate_deposit_destination("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw", TagPolicy::Any)
+///         .code(),
+///     "test_network"
+/// );
+/// ```
+pub fn validate_deposit_destination(input: &str, policy: TagPolicy) -> DepositCheck {
+    let (account_id, tag, network) = match parse_account(input) {
+        Ok(parsed) => parsed,
+        Err(error) => return DepositCheck::Invalid(error),
+    };
+
+    if network != Network::Main {
+        return DepositCheck::TestNetwork { account_id };
+    }
+
+    match is_well_known(&account_id) {
+        Some(WellKnownAccount::Genesis) | None => {}
+        Some(account) => return DepositCheck::BurnAccount(account),
+    }
+
+    match (policy, tag) {
+        (TagPolicy::Required, Tag::None) => DepositCheck::MissingTag { account_id },
+        (TagPolicy::Forbidden, tag) if tag != Tag::None => {
+            DepositCheck::UnexpectedTag { account_id, tag }
+        }
+        _ => DepositCheck::Accepted { account_id, tag },
+    }
+}
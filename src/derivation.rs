@@ -0,0 +1,158 @@
+//! Deterministic namespaced seed derivation
+//!
+//! Derives per-tenant seed entropy from one master secret with HKDF-SHA256
+//! ([RFC 5869]), so a single (sealed) master secret can back many isolated
+//! accounts. The derivation path is a list of labels, e.g. a tenant id
+//! followed by a purpose; distinct paths give independent entropies.
+//!
+//! The scheme is fixed: salt `ripple-address-codec seed derivation v1`,
+//! and as HKDF info every label prefixed with its length as a 4 byte
+//! big-endian integer. Changing any of that would change every derived
+//! seed, so it never changes within a version.
+//!
+//! HMAC is built on the [`sha256::DEFAULT`](crate::sha256::DEFAULT)
+//! implementation, so derivation is available with either the `ring`
+//! or the `sha2` feature, also inside an enclave.
+//!
+//! [RFC 5869]: https://tools.ietf.org/html/rfc5869
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::convert::TryFrom;
+
+use crate::{
+    encode_seed,
+    sha256::{Sha256, DEFAULT, DIGEST_LEN},
+    wipe, Algorithm, Entropy, Error, Result, ENTROPY_LEN,
+};
+
+const SALT: &[u8] = b"ripple-address-codec seed derivation v1";
+
+/// SHA-256 block length in bytes
+const BLOCK_LEN: usize = 64;
+
+/// Minimum master secret length in bytes, the length of the entropy
+pub const MIN_MASTER_SECRET_LEN: usize = ENTROPY_LEN;
+
+/// Derive seed entropy for the given path from a master secret
+///
+/// The master secret must be uniformly random; a low entropy master
+/// secret makes every derived seed guessable.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::derivation::derive_entropy;
+///
+/// // In the real world you **must** use a random master secret
+/// let master_secret = [7; 32];
+///
+/// let alice = derive_entropy(&master_secret, &["tenant-1", "deposits"]).unwrap();
+/// let bob = derive_entropy(&master_secret, &["tenant-2", "deposits"]).unwrap();
+///
+/// assert_ne!(alice, bob);
+/// assert_eq!(Ok(alice), derive_entropy(&master_secret, &["tenant-1", "deposits"]));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] with the minimum length as `expected` if
+/// the master secret is shorter than [`MIN_MASTER_SECRET_LEN`], and
+/// [`Error::InputTooLong`] if a label is longer than 2<sup>32</sup> - 1
+/// bytes.
+pub fn derive_entropy(master_secret: &[u8], path: &[&str]) -> Result<Entropy> {
+    if master_secret.len() < MIN_MASTER_SECRET_LEN {
+        return Err(Error::BadLength {
+            expected: MIN_MASTER_SECRET_LEN,
+            actual: master_secret.len(),
+        });
+    }
+
+    let mut info = encode_path(path)?;
+    info.push(1);
+
+    let mut prk = hmac(DEFAULT, SALT, master_secret);
+    let mut okm = hmac(DEFAULT, &prk, &info);
+
+    let mut entropy = [0; ENTROPY_LEN];
+    entropy.copy_from_slice(&okm[..ENTROPY_LEN]);
+
+    wipe(&mut prk);
+    wipe(&mut okm);
+
+    Ok(entropy)
+}
+
+/// Derive a seed for the given path from a master secret
+///
+/// Same as [`derive_entropy`] followed by [`encode_seed`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{decode_seed, derivation::derive_seed, Ed25519};
+///
+/// let seed = derive_seed(&[7; 32], &["tenant-1"], Ed25519).unwrap();
+///
+/// assert_eq!(decode_seed(&seed).unwrap().1, Ed25519);
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] like [`derive_entropy`] does.
+pub fn derive_seed(master_secret: &[u8], path: &[&str], algorithm: Algorithm) -> Result<String> {
+    let mut entropy = derive_entropy(master_secret, path)?;
+    let seed = encode_seed(&entropy, algorithm);
+    wipe(&mut entropy);
+
+    Ok(seed)
+}
+
+/// HMAC ([RFC 2104]) of `message` keyed with `key`
+///
+/// [RFC 2104]: https://tools.ietf.org/html/rfc2104
+fn hmac(sha256: &dyn Sha256, key: &[u8], message: &[u8]) -> [u8; DIGEST_LEN] {
+    let mut block = [0; BLOCK_LEN];
+    if key.len() > BLOCK_LEN {
+        block[..DIGEST_LEN].copy_from_slice(&sha256.digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner: Vec<u8> = block.iter().map(|byte| byte ^ 0x36).collect();
+    inner.extend_from_slice(message);
+    let mut inner_hash = sha256.digest(&inner);
+
+    let mut outer = [0; BLOCK_LEN + DIGEST_LEN];
+    for (outer, byte) in outer.iter_mut().zip(&block) {
+        *outer = byte ^ 0x5C;
+    }
+    outer[BLOCK_LEN..].copy_from_slice(&inner_hash);
+    let mac = sha256.digest(&outer);
+
+    wipe(&mut block);
+    wipe(&mut inner);
+    wipe(&mut inner_hash);
+    wipe(&mut outer);
+
+    mac
+}
+
+fn encode_path(path: &[&str]) -> Result<Vec<u8>> {
+    let mut info = Vec::new();
+
+    for label in path {
+        let len = u32::try_from(label.len()).map_err(|_| Error::InputTooLong {
+            max_len: u32::MAX as usize,
+            actual: label.len(),
+        })?;
+
+        info.extend_from_slice(&len.to_be_bytes());
+        info.extend_from_slice(label.as_bytes());
+    }
+
+    Ok(info)
+}
@@ -0,0 +1,62 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+/// Characters kept at the start of a shortened token
+const SHORT_HEAD: usize = 6;
+/// Characters kept at the end of a shortened token
+const SHORT_TAIL: usize = 5;
+/// Marks the cut of a shortened token, outside the base58 alphabet
+const ELLIPSIS: char = '\u{2026}';
+
+/// Shorten a token to its first and last characters around an ellipsis
+///
+/// The ellipsis isn't in the base58 alphabet, so the result never
+/// decodes as a token, not even as another one with the same ends.
+pub(crate) fn shorten(token: &str) -> String {
+    let len = token.chars().count();
+    if len <= SHORT_HEAD + SHORT_TAIL + 1 {
+        return token.to_owned();
+    }
+
+    token
+        .chars()
+        .take(SHORT_HEAD)
+        .chain(Some(ELLIPSIS))
+        .chain(token.chars().skip(len - SHORT_TAIL))
+        .collect()
+}
+
+/// Split a token into space separated groups of `group_len` characters,
+/// for reading out or writing down
+///
+/// The last group may be shorter. Spaces aren't in the base58 alphabet,
+/// so the result never decodes as a token: strip them before decoding.
+/// A `group_len` of 0 leaves the token as is.
+///
+/// Seeds are secrets, so are their grouped forms.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::format_grouped;
+///
+/// assert_eq!(
+///     format_grouped("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN", 4),
+///     "rJrR MgiR grU6 hDF4 pgu5 DXQd WyPb Y35E rN"
+/// );
+/// ```
+pub fn format_grouped(token: &str, group_len: usize) -> String {
+    if group_len == 0 {
+        return token.to_owned();
+    }
+
+    let mut grouped = String::with_capacity(token.len() + token.len() / group_len);
+    for (index, character) in token.chars().enumerate() {
+        if index > 0 && index % group_len == 0 {
+            grouped.push(' ');
+        }
+        grouped.push(character);
+    }
+
+    grouped
+}
@@ -1,36 +1,292 @@
-use std::{error, fmt};
+use std::{convert::TryFrom, error, fmt};
 
-use Error::DecodeError;
+use crate::Algorithm;
 
-/// Error type with a single DecodeError variant
+use Error::{
+    BadAlphabet, BadChecksum, BadLength, BadPrefix, BadSecretNumber, BufferTooSmall, Confusable,
+    DecodeError, Denied, InputTooLong, InvalidKey, NonCanonical, UnknownWord, UnsupportedTag,
+    UnsupportedVersion, WrongAlgorithm,
+};
+
+/// [`Error::code`]s, indexed by [`Error::numeric_code`] minus one
+const CODES: [&str; 16] = [
+    "decode_error",
+    "bad_alphabet",
+    "bad_checksum",
+    "bad_prefix",
+    "bad_length",
+    "denied",
+    "unsupported_tag",
+    "non_canonical",
+    "buffer_too_small",
+    "unsupported_version",
+    "unknown_word",
+    "bad_secret_number",
+    "invalid_key",
+    "wrong_algorithm",
+    "confusable",
+    "input_too_long",
+];
+
+/// Error type
+///
+/// Decoding failures are reported by the first failed check, in
+/// pipeline order: [`BadAlphabet`], [`BadChecksum`], [`BadPrefix`]
+/// and [`BadLength`]. Malformed input is always reported this way,
+/// decoding never panics.
+#[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Error {
-    /// Decoding error
+    /// Decoding error not covered by a more specific variant
     ///
-    /// This error appears in various cases: bad alphabet,
-    /// prefix, payload length or bad checksum.
+    /// For example an unknown algorithm name.
     DecodeError,
+    /// Input contains a character outside the base58 alphabet
+    BadAlphabet {
+        /// The first offending character
+        character: char,
+        /// Position of `character` in the input, counted in characters
+        index: usize,
+    },
+    /// Checksum doesn't match, most likely a mistyped character
+    BadChecksum,
+    /// Input doesn't start with the version prefix of the expected token type
+    BadPrefix {
+        /// Version prefix bytes of the expected token type, empty if
+        /// several prefixes are accepted, as for seeds, or the prefix was
+        /// supplied by the caller
+        expected: &'static [u8],
+    },
+    /// Payload has an unexpected length
+    BadLength {
+        /// Expected payload length in bytes
+        expected: usize,
+        /// Actual payload length in bytes
+        actual: usize,
+    },
+    /// The operation was denied by a [`Policy`](crate::codec::Policy)
+    Denied,
+    /// X-address tag uses a reserved flag or reserved bits
+    ///
+    /// 64-bit tags are reserved by the X-address format
+    /// but not enabled yet, so they are reported this way too.
+    UnsupportedTag,
+    /// Input decodes, but doesn't re-encode to the exact same string
+    ///
+    /// Only reported by a [`Codec`](crate::codec::Codec)
+    /// with strict decoding enabled.
+    NonCanonical,
+    /// Output buffer is too small for the encoded token
+    BufferTooSmall {
+        /// Required buffer length in bytes
+        required: usize,
+        /// Available buffer length in bytes
+        available: usize,
+    },
+    /// Binary container of an unknown format version
+    ///
+    /// Only reported when decoding a [`container`](crate::container).
+    UnsupportedVersion {
+        /// Version of the container
+        version: u8,
+    },
+    /// Word outside the RFC 1751 dictionary or BIP39 wordlist
+    ///
+    /// Only reported when decoding seeds from words, e.g. by
+    /// [`rfc1751_to_seed`](crate::rfc1751_to_seed).
+    UnknownWord {
+        /// Position of the word in the input, counted in words
+        index: usize,
+    },
+    /// Secret numbers row with a checksum mismatch or a value above 65535
+    ///
+    /// Only reported by
+    /// [`secret_numbers_to_entropy`](crate::secret_numbers_to_entropy).
+    BadSecretNumber {
+        /// Position of the row, counted from 0
+        index: usize,
+    },
+    /// Public key whose first byte isn't a known key type (`0x02`, `0x03`
+    /// or `0xED`), or that isn't a point on the curve of its key type
+    ///
+    /// Only reported for raw public keys, by
+    /// [`account_id_from_public_key`](crate::account_id_from_public_key)
+    /// for the key type and when checking public keys, e.g. by
+    /// `keypair::check_public_key` (`secp256k1` or `ed25519` feature).
+    InvalidKey,
+    /// Valid seed for another algorithm than the required one
+    ///
+    /// Only reported by the per-algorithm seed decoders, e.g.
+    /// [`decode_ed25519_seed`](crate::decode_ed25519_seed).
+    WrongAlgorithm {
+        /// The required algorithm
+        expected: Algorithm,
+        /// The algorithm of the seed
+        actual: Algorithm,
+    },
+    /// Input contains a non-ASCII lookalike of an ASCII character, such
+    /// as Cyrillic `'о'`, or an invisible character, such as a zero-width
+    /// space
+    ///
+    /// Reported instead of [`BadAlphabet`] when the first offending
+    /// character is one of them, so wallets can warn about a likely
+    /// phishing attempt rather than a typo.
+    Confusable {
+        /// The first offending character
+        character: char,
+        /// Position of `character` in the input, counted in characters
+        index: usize,
+        /// The ASCII character `character` looks like, `None` if it is
+        /// invisible
+        lookalike: Option<char>,
+    },
+    /// Input longer than the configured maximum, rejected before decoding
+    ///
+    /// Reported for input longer than
+    /// [`DEFAULT_MAX_INPUT_LEN`](crate::codec::DEFAULT_MAX_INPUT_LEN)
+    /// bytes, or the limit of a [`Codec`](crate::codec::Codec), see
+    /// [`with_max_input_len`](crate::codec::Codec::with_max_input_len),
+    /// and for a ciphertext too long for a
+    /// [`SeedContainer`](crate::container::SeedContainer).
+    InputTooLong {
+        /// Maximum accepted input length in bytes
+        max_len: usize,
+        /// Actual input length in bytes
+        actual: usize,
+    },
 }
 
-impl error::Error for Error {}
+impl Error {
+    /// Stable snake_case code identifying the variant
+    ///
+    /// Meant for reporting errors across API boundaries, where the
+    /// [`Display`](fmt::Display) message may change between releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::decode_account_id;
+    ///
+    /// let error = decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err();
+    ///
+    /// assert_eq!(error.code(), "bad_checksum");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        CODES[self.numeric_code() as usize - 1]
+    }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Stable numeric code identifying the variant, from 1
+    ///
+    /// Meant for C APIs, metrics and structured logs. Codes are never
+    /// reused: new variants get the next free one. The C API returns
+    /// them negated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::decode_account_id;
+    ///
+    /// let error = decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err();
+    ///
+    /// assert_eq!(error.numeric_code(), 3);
+    /// ```
+    pub fn numeric_code(&self) -> u32 {
         match self {
-            DecodeError => f.write_str("decode error"),
+            DecodeError => 1,
+            BadAlphabet { .. } => 2,
+            BadChecksum => 3,
+            BadPrefix { .. } => 4,
+            BadLength { .. } => 5,
+            Denied => 6,
+            UnsupportedTag => 7,
+            NonCanonical => 8,
+            BufferTooSmall { .. } => 9,
+            UnsupportedVersion { .. } => 10,
+            UnknownWord { .. } => 11,
+            BadSecretNumber { .. } => 12,
+            InvalidKey => 13,
+            WrongAlgorithm { .. } => 14,
+            Confusable { .. } => 15,
+            InputTooLong { .. } => 16,
         }
     }
+
+    /// The [`code`](Error::code) of a [`numeric_code`](Error::numeric_code),
+    /// `None` if no variant has it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Error;
+    ///
+    /// assert_eq!(Error::code_from_numeric(3), Some("bad_checksum"));
+    /// assert_eq!(Error::code_from_numeric(0), None);
+    /// ```
+    pub fn code_from_numeric(numeric_code: u32) -> Option<&'static str> {
+        let index = usize::try_from(numeric_code).ok()?.checked_sub(1)?;
+
+        CODES.get(index).copied()
+    }
 }
 
-macro_rules! impl_from_error {
-    ($t:ty => $m:ident) => {
-        #[doc(hidden)]
-        impl From<$t> for Error {
-            fn from(_: $t) -> Self {
-                $m
+impl error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError => f.write_str("decode error"),
+            BadAlphabet { character, index } => {
+                write!(f, "invalid character {:?} at position {}", character, index)
             }
+            BadChecksum => f.write_str("checksum mismatch, did you mistype a character?"),
+            BadPrefix { expected: [] } => f.write_str("unexpected version prefix"),
+            BadPrefix { expected } => {
+                write!(f, "unexpected version prefix, expected {:02X?}", expected)
+            }
+            BadLength { expected, actual } => {
+                write!(f, "payload is {} bytes long, expected {}", actual, expected)
+            }
+            Denied => f.write_str("denied by policy"),
+            UnsupportedTag => f.write_str("unsupported X-address tag"),
+            NonCanonical => f.write_str("non-canonical encoding"),
+            BufferTooSmall {
+                required,
+                available,
+            } => write!(
+                f,
+                "output buffer is {} bytes long, {} required",
+                available, required
+            ),
+            UnsupportedVersion { version } => write!(f, "unsupported format version {}", version),
+            UnknownWord { index } => write!(f, "unknown word at position {}", index),
+            BadSecretNumber { index } => write!(f, "invalid secret numbers row {}", index),
+            InvalidKey => f.write_str("unknown public key type or not a point on its curve"),
+            WrongAlgorithm { expected, actual } => {
+                write!(f, "{} seed, expected {}", actual, expected)
+            }
+            Confusable {
+                character,
+                index,
+                lookalike: Some(lookalike),
+            } => write!(
+                f,
+                "lookalike {:?} of {:?} at position {}",
+                character, lookalike, index
+            ),
+            Confusable {
+                character,
+                index,
+                lookalike: None,
+            } => write!(
+                f,
+                "invisible character {:?} at position {}",
+                character, index
+            ),
+            InputTooLong { max_len, actual } => write!(
+                f,
+                "input is {} bytes long, at most {} accepted",
+                actual, max_len
+            ),
         }
-    };
+    }
 }
-
-impl_from_error!(base_x::DecodeError => DecodeError);
@@ -0,0 +1,463 @@
+//! C API
+//!
+//! Available with the `ffi` feature. Functions return [`RAC_OK`] or a
+//! negative error code, the negated [`Error::numeric_code`], and write
+//! their results to caller-provided buffers. Encoded strings are written
+//! NUL-terminated; if the buffer is too small, [`RAC_ERROR_BUFFER_TOO_SMALL`]
+//! is returned with the required buffer length, NUL included, in `*written`.
+//!
+//! The C header is `include/ripple_address_codec.h`, generated with
+//! cbindgen. Build a shared or static library for a host application with
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+//!
+//! # Examples
+//!
+//! ```c
+//! char address[RAC_ACCOUNT_ID_BUFFER_LEN];
+//! size_t written;
+//!
+//! if (rac_encode_account_id(account_id, address, sizeof address, &written) != RAC_OK) {
+//!     return -1;
+//! }
+//! ```
+
+use core::{
+    convert::TryFrom,
+    ffi::{c_char, CStr},
+};
+
+use crate::{
+    decode_account_id, decode_seed, decode_xaddress, encode_account_id_into, encode_seed_into,
+    encode_xaddress_into, wipe, Algorithm, Entropy, Error, Network, Result, Tag,
+};
+
+/// Success
+pub const RAC_OK: i32 = 0;
+/// Decoding error not covered by a more specific code
+pub const RAC_ERROR_DECODE: i32 = -1;
+/// Input contains a character outside the base58 alphabet
+pub const RAC_ERROR_BAD_ALPHABET: i32 = -2;
+/// Checksum doesn't match, most likely a mistyped character
+pub const RAC_ERROR_BAD_CHECKSUM: i32 = -3;
+/// Input doesn't start with the version prefix of the expected token type
+pub const RAC_ERROR_BAD_PREFIX: i32 = -4;
+/// Payload has an unexpected length
+pub const RAC_ERROR_BAD_LENGTH: i32 = -5;
+/// Denied by policy
+pub const RAC_ERROR_DENIED: i32 = -6;
+/// X-address tag uses a reserved flag or reserved bits
+pub const RAC_ERROR_UNSUPPORTED_TAG: i32 = -7;
+/// Input decodes, but doesn't re-encode to the exact same string
+pub const RAC_ERROR_NON_CANONICAL: i32 = -8;
+/// Output buffer is too small, `*written` is the required length
+pub const RAC_ERROR_BUFFER_TOO_SMALL: i32 = -9;
+/// Binary format of an unknown version
+pub const RAC_ERROR_UNSUPPORTED_VERSION: i32 = -10;
+/// Word outside the RFC 1751 dictionary or BIP39 wordlist
+pub const RAC_ERROR_UNKNOWN_WORD: i32 = -11;
+/// Secret numbers row with a checksum mismatch or a value above 65535
+pub const RAC_ERROR_BAD_SECRET_NUMBER: i32 = -12;
+/// Public key that isn't a point on its curve
+pub const RAC_ERROR_INVALID_KEY: i32 = -13;
+/// Valid seed for another algorithm than the required one
+pub const RAC_ERROR_WRONG_ALGORITHM: i32 = -14;
+/// Lookalike of an ASCII character or invisible character
+pub const RAC_ERROR_CONFUSABLE: i32 = -15;
+/// Input longer than the maximum accepted length
+pub const RAC_ERROR_INPUT_TOO_LONG: i32 = -16;
+/// A null pointer, a string that isn't UTF-8 or an unknown algorithm
+pub const RAC_ERROR_INVALID_ARGUMENT: i32 = -100;
+
+/// secp256k1 algorithm
+pub const RAC_SECP256K1: i32 = 0;
+/// Ed25519 algorithm
+pub const RAC_ED25519: i32 = 1;
+
+/// Account id length in bytes
+pub const RAC_ACCOUNT_ID_LEN: usize = 20;
+/// Seed entropy length in bytes
+pub const RAC_ENTROPY_LEN: usize = 16;
+/// Buffer length fitting any classic address and its NUL
+pub const RAC_ACCOUNT_ID_BUFFER_LEN: usize = 36;
+/// Buffer length fitting any seed and its NUL
+pub const RAC_SEED_BUFFER_LEN: usize = 32;
+/// Buffer length fitting any X-address and its NUL
+pub const RAC_XADDRESS_BUFFER_LEN: usize = 48;
+
+/// Encode a 20-byte account id as a classic address (starting with r...)
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let mut out = [0; RAC_ACCOUNT_ID_BUFFER_LEN];
+/// let mut written = 0;
+///
+/// let code = unsafe { rac_encode_account_id([0; 20].as_ptr(), out.as_mut_ptr(), out.len(), &mut written) };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!(written, "rrrrrrrrrrrrrrrrrrrrrhoLvTp".len());
+/// ```
+///
+/// # Safety
+///
+/// `account_id` must point to 20 readable bytes, `out` to `out_len`
+/// writable bytes and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rac_encode_account_id(
+    account_id: *const u8,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let account_id = match account_id.cast::<[u8; RAC_ACCOUNT_ID_LEN]>().as_ref() {
+        Some(account_id) => account_id,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    write_string(out, out_len, written, |out| {
+        encode_account_id_into(account_id, out)
+    })
+}
+
+/// Decode a classic address (starting with r...) to its 20-byte account id
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let mut account_id = [0xFF; 20];
+///
+/// let code = unsafe { rac_decode_account_id(b"rrrrrrrrrrrrrrrrrrrrrhoLvTp\0".as_ptr().cast(), account_id.as_mut_ptr()) };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!(account_id, [0; 20]);
+/// ```
+///
+/// # Safety
+///
+/// `address` must be a NUL-terminated string and `account_id` must
+/// point to 20 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rac_decode_account_id(address: *const c_char, account_id: *mut u8) -> i32 {
+    let out = match account_id.cast::<[u8; RAC_ACCOUNT_ID_LEN]>().as_mut() {
+        Some(out) => out,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    let address = match read_string(address) {
+        Some(address) => address,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    to_code(decode_account_id(address).map(|account_id| *out = account_id))
+}
+
+/// Encode 16 bytes of entropy as a seed (starting with s...)
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let mut out = [0; RAC_SEED_BUFFER_LEN];
+/// let mut written = 0;
+///
+/// let code = unsafe { rac_encode_seed([0; 16].as_ptr(), RAC_ED25519, out.as_mut_ptr(), out.len(), &mut written) };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!(written, "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".len());
+/// ```
+///
+/// # Safety
+///
+/// `entropy` must point to 16 readable bytes, `out` to `out_len`
+/// writable bytes and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rac_encode_seed(
+    entropy: *const u8,
+    algorithm: i32,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let (entropy, algorithm) = match (entropy.cast::<Entropy>().as_ref(), to_algorithm(algorithm)) {
+        (Some(entropy), Some(algorithm)) => (entropy, algorithm),
+        _ => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    write_string(out, out_len, written, |out| {
+        encode_seed_into(entropy, algorithm, out)
+    })
+}
+
+/// Decode a seed (starting with s...) to its 16 bytes of entropy and
+/// algorithm
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let mut entropy = [0xFF; 16];
+/// let mut algorithm = -1;
+///
+/// let code = unsafe { rac_decode_seed(b"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE\0".as_ptr().cast(), entropy.as_mut_ptr(), &mut algorithm) };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!((entropy, algorithm), ([0; 16], RAC_ED25519));
+/// ```
+///
+/// # Safety
+///
+/// `seed` must be a NUL-terminated string, `entropy` must point to 16
+/// writable bytes and `algorithm` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rac_decode_seed(
+    seed: *const c_char,
+    entropy: *mut u8,
+    algorithm: *mut i32,
+) -> i32 {
+    let (out, algorithm) = match (entropy.cast::<Entropy>().as_mut(), algorithm.as_mut()) {
+        (Some(out), Some(algorithm)) => (out, algorithm),
+        _ => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    let seed = match read_string(seed) {
+        Some(seed) => seed,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    to_code(decode_seed(seed).map(|(mut entropy, decoded_algorithm)| {
+        *out = entropy;
+        wipe(&mut entropy);
+
+        *algorithm = match decoded_algorithm {
+            #[cfg(feature = "seed-secp256k1")]
+            Algorithm::Secp256k1 => RAC_SECP256K1,
+            #[cfg(feature = "seed-ed25519")]
+            Algorithm::Ed25519 => RAC_ED25519,
+        };
+    }))
+}
+
+/// Encode a 20-byte account id, optional destination tag and network as
+/// an X-address
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let mut out = [0; RAC_XADDRESS_BUFFER_LEN];
+/// let mut written = 0;
+///
+/// let code = unsafe {
+///     rac_encode_xaddress([0; 20].as_ptr(), true, 1, false, out.as_mut_ptr(), out.len(), &mut written)
+/// };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!(written, 47);
+/// ```
+///
+/// # Safety
+///
+/// `account_id` must point to 20 readable bytes, `out` to `out_len`
+/// writable bytes and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rac_encode_xaddress(
+    account_id: *const u8,
+    has_tag: bool,
+    tag: u32,
+    test_network: bool,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let account_id = match account_id.cast::<[u8; RAC_ACCOUNT_ID_LEN]>().as_ref() {
+        Some(account_id) => account_id,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+    let tag = if has_tag { Tag::U32(tag) } else { Tag::None };
+    let network = if test_network {
+        Network::Test
+    } else {
+        Network::Main
+    };
+
+    write_string(out, out_len, written, |out| {
+        encode_xaddress_into(account_id, tag, network, out)
+    })
+}
+
+/// Decode an X-address to its 20-byte account id, optional destination
+/// tag and network
+///
+/// `*tag` is 0 if there's no tag.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ffi::*;
+///
+/// let xaddress = b"TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw\0";
+/// let (mut account_id, mut has_tag, mut tag, mut test_network) = ([0; 20], false, 0, false);
+///
+/// let code = unsafe {
+///     rac_decode_xaddress(xaddress.as_ptr().cast(), account_id.as_mut_ptr(), &mut has_tag, &mut tag, &mut test_network)
+/// };
+///
+/// assert_eq!(code, RAC_OK);
+/// assert_eq!((has_tag, tag, test_network), (true, 1, true));
+/// ```
+///
+/// # Safety
+///
+/// `xaddress` must be a NUL-terminated string, `account_id` must point
+/// to 20 writable bytes and the other arguments must be valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn rac_decode_xaddress(
+    xaddress: *const c_char,
+    account_id: *mut u8,
+    has_tag: *mut bool,
+    tag: *mut u32,
+    test_network: *mut bool,
+) -> i32 {
+    let (out, has_tag, tag, test_network) = match (
+        account_id.cast::<[u8; RAC_ACCOUNT_ID_LEN]>().as_mut(),
+        has_tag.as_mut(),
+        tag.as_mut(),
+        test_network.as_mut(),
+    ) {
+        (Some(out), Some(has_tag), Some(tag), Some(test_network)) => {
+            (out, has_tag, tag, test_network)
+        }
+        _ => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    let xaddress = match read_string(xaddress) {
+        Some(xaddress) => xaddress,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+
+    to_code(
+        decode_xaddress(xaddress).map(|(account_id, decoded_tag, network)| {
+            *out = account_id;
+            let (decoded_has_tag, decoded_tag) = match decoded_tag {
+                Tag::None => (false, 0),
+                Tag::U32(tag) => (true, tag),
+            };
+            *has_tag = decoded_has_tag;
+            *tag = decoded_tag;
+            *test_network = network.is_test();
+        }),
+    )
+}
+
+/// Static NUL-terminated description of an error code
+///
+/// The descriptions may change between releases, unlike the codes.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::CStr;
+///
+/// use ripple_address_codec::ffi::*;
+///
+/// let message = unsafe { CStr::from_ptr(rac_error_message(RAC_ERROR_BAD_CHECKSUM)) };
+///
+/// assert_eq!(message.to_str(), Ok("bad checksum"));
+/// ```
+#[no_mangle]
+pub extern "C" fn rac_error_message(code: i32) -> *const c_char {
+    let message: &'static [u8] = match code {
+        RAC_OK => b"ok\0",
+        RAC_ERROR_DECODE => b"decode error\0",
+        RAC_ERROR_BAD_ALPHABET => b"bad alphabet\0",
+        RAC_ERROR_BAD_CHECKSUM => b"bad checksum\0",
+        RAC_ERROR_BAD_PREFIX => b"bad prefix\0",
+        RAC_ERROR_BAD_LENGTH => b"bad length\0",
+        RAC_ERROR_DENIED => b"denied\0",
+        RAC_ERROR_UNSUPPORTED_TAG => b"unsupported tag\0",
+        RAC_ERROR_NON_CANONICAL => b"non-canonical encoding\0",
+        RAC_ERROR_BUFFER_TOO_SMALL => b"buffer too small\0",
+        RAC_ERROR_UNSUPPORTED_VERSION => b"unsupported version\0",
+        RAC_ERROR_UNKNOWN_WORD => b"unknown word\0",
+        RAC_ERROR_BAD_SECRET_NUMBER => b"bad secret number\0",
+        RAC_ERROR_INVALID_KEY => b"invalid key\0",
+        RAC_ERROR_WRONG_ALGORITHM => b"wrong algorithm\0",
+        RAC_ERROR_CONFUSABLE => b"confusable character\0",
+        RAC_ERROR_INPUT_TOO_LONG => b"input too long\0",
+        RAC_ERROR_INVALID_ARGUMENT => b"invalid argument\0",
+        _ => b"unknown error code\0",
+    };
+
+    message.as_ptr().cast()
+}
+
+fn error_code(error: Error) -> i32 {
+    -i32::try_from(error.numeric_code()).expect("numeric codes are small")
+}
+
+fn to_code(result: Result<()>) -> i32 {
+    match result {
+        Ok(()) => RAC_OK,
+        Err(error) => error_code(error),
+    }
+}
+
+unsafe fn read_string<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(s).to_str().ok()
+}
+
+unsafe fn write_string(
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+    encode: impl FnOnce(&mut [u8]) -> Result<usize>,
+) -> i32 {
+    let written = match written.as_mut() {
+        Some(written) => written,
+        None => return RAC_ERROR_INVALID_ARGUMENT,
+    };
+    if out.is_null() {
+        return RAC_ERROR_INVALID_ARGUMENT;
+    }
+
+    let out = std::slice::from_raw_parts_mut(out.cast::<u8>(), out_len);
+
+    // Keep a byte for the NUL
+    match encode(&mut out[..out_len.saturating_sub(1)]) {
+        Ok(len) => {
+            out[len] = 0;
+            *written = len;
+            RAC_OK
+        }
+        Err(Error::BufferTooSmall { required, .. }) => {
+            *written = required + 1;
+            RAC_ERROR_BUFFER_TOO_SMALL
+        }
+        Err(error) => error_code(error),
+    }
+}
+
+fn to_algorithm(algorithm: i32) -> Option<Algorithm> {
+    match algorithm {
+        #[cfg(feature = "seed-secp256k1")]
+        RAC_SECP256K1 => Some(Algorithm::Secp256k1),
+        #[cfg(feature = "seed-ed25519")]
+        RAC_ED25519 => Some(Algorithm::Ed25519),
+        _ => None,
+    }
+}
@@ -0,0 +1,367 @@
+//! Hierarchical deterministic keys
+//!
+//! Available with the `hd` feature. Derives keypairs from a master seed,
+//! e.g. the seed of a BIP39 mnemonic, along derivation paths like other
+//! wallets do: secp256k1 keys follow BIP32 and Ed25519 keys SLIP-0010.
+//! The XRPL path of BIP44 is `m/44'/144'/account'/0/index`. SLIP-0010
+//! only has hardened Ed25519 children, so Ed25519 paths are hardened
+//! throughout, e.g. `m/44'/144'/account'/0'/index'`.
+//!
+//! The master seed isn't a family seed: HD keys have no `s...` encoding.
+//!
+//! # Examples
+//!
+//! Deriving deposit addresses in bulk:
+//!
+//! ```
+//! use ripple_address_codec::{hd::ExtendedPrivateKey, Secp256k1};
+//!
+//! # let master_seed = [0; 64];
+//! let account = ExtendedPrivateKey::master(&master_seed, Secp256k1)
+//!     .derive_path("m/44'/144'/0'/0")
+//!     .unwrap();
+//!
+//! let addresses: Vec<String> = (0..10)
+//!     .map(|index| account.derive_child(index).unwrap().keypair().address())
+//!     .collect();
+//!
+//! assert_eq!(addresses.len(), 10);
+//! ```
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{error, fmt};
+
+use hmac::{Hmac, Mac};
+use k256::{elliptic_curve::PrimeField, FieldBytes, Scalar};
+use sha2::Sha512;
+
+use crate::{
+    keypair::{self, Keypair, PRIVATE_KEY_LEN},
+    seed::Redacted,
+    wipe, Algorithm, Ed25519, Secp256k1,
+};
+
+use DerivationError::{BadPath, NotHardened};
+
+/// Offset of hardened child indexes, written `'` in paths
+pub const HARDENED: u32 = 1 << 31;
+/// Chain code length in bytes
+pub const CHAIN_CODE_LEN: usize = 32;
+
+const HASH_LEN: usize = PRIVATE_KEY_LEN + CHAIN_CODE_LEN;
+
+/// Derivation error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DerivationError {
+    /// The path isn't of the form `m/44'/144'/0'/0/0`
+    BadPath,
+    /// Ed25519 keys only have hardened children
+    NotHardened(u32),
+}
+
+impl error::Error for DerivationError {}
+
+impl fmt::Display for DerivationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BadPath => f.write_str("malformed derivation path"),
+            NotHardened(index) => write!(f, "Ed25519 child index {} isn't hardened", index),
+        }
+    }
+}
+
+/// A private key and chain code, to derive child keys from
+///
+/// [`Debug`](fmt::Debug) output redacts the private key and chain code.
+///
+/// With the `zeroize` feature, both are overwritten with zeros on drop.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{hd::ExtendedPrivateKey, Ed25519};
+///
+/// let key = ExtendedPrivateKey::master(&[0; 64], Ed25519);
+///
+/// assert!(format!("{:?}", key).contains("****"));
+/// ```
+// Not `Copy`, so secrets are only duplicated by explicit clones
+#[allow(missing_copy_implementations)]
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    algorithm: Algorithm,
+    private_key: [u8; PRIVATE_KEY_LEN],
+    chain_code: [u8; CHAIN_CODE_LEN],
+}
+
+impl ExtendedPrivateKey {
+    /// The master key of a seed
+    ///
+    /// BIP32 recommends seeds of 16 to 64 bytes; a BIP39 mnemonic gives
+    /// 64 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Secp256k1};
+    ///
+    /// let seed: Vec<u8> = (0..16).collect();
+    /// let master = ExtendedPrivateKey::master(&seed, Secp256k1);
+    ///
+    /// assert_eq!(master.private_key()[..4], [0xE8, 0xF3, 0x2E, 0x72]);
+    /// ```
+    pub fn master(seed: &[u8], algorithm: Algorithm) -> Self {
+        let key: &[u8] = match algorithm {
+            Secp256k1 => b"Bitcoin seed",
+            Ed25519 => b"ed25519 seed",
+        };
+        let mut hash = hmac_sha512(key, &[seed]);
+
+        // SLIP-0010: hash again until the key is a valid non-zero scalar
+        while algorithm == Secp256k1
+            && secp256k1_scalar(&hash[..PRIVATE_KEY_LEN])
+                .is_none_or(|scalar| bool::from(scalar.is_zero()))
+        {
+            let mut previous = hash;
+            hash = hmac_sha512(key, &[&previous]);
+            wipe(&mut previous);
+        }
+
+        ExtendedPrivateKey::from_hash(algorithm, hash)
+    }
+
+    /// Derive a child key
+    ///
+    /// Indexes from [`HARDENED`] on derive hardened children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::hd::{DerivationError, ExtendedPrivateKey, HARDENED};
+    /// use ripple_address_codec::Ed25519;
+    ///
+    /// let master = ExtendedPrivateKey::master(&[0; 64], Ed25519);
+    ///
+    /// assert!(master.derive_child(HARDENED).is_ok());
+    /// assert_eq!(master.derive_child(0).err(), Some(DerivationError::NotHardened(0)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DerivationError::NotHardened`] for a non-hardened index
+    /// of an Ed25519 key.
+    pub fn derive_child(&self, index: u32) -> Result<Self, DerivationError> {
+        let index_bytes = index.to_be_bytes();
+        let hardened = index >= HARDENED;
+
+        match self.algorithm {
+            Ed25519 if !hardened => Err(NotHardened(index)),
+            Ed25519 => {
+                let hash = hmac_sha512(&self.chain_code, &[&[0], &self.private_key, &index_bytes]);
+
+                Ok(ExtendedPrivateKey::from_hash(Ed25519, hash))
+            }
+            Secp256k1 => {
+                let parent = self.secp256k1_scalar();
+                let mut hash = if hardened {
+                    hmac_sha512(&self.chain_code, &[&[0], &self.private_key, &index_bytes])
+                } else {
+                    let public_key = keypair::secp256k1_public_key(&parent);
+                    hmac_sha512(&self.chain_code, &[&public_key, &index_bytes])
+                };
+
+                loop {
+                    let child = secp256k1_scalar(&hash[..PRIVATE_KEY_LEN])
+                        .map(|tweak| parent + tweak)
+                        .filter(|child| !bool::from(child.is_zero()));
+
+                    if let Some(child) = child {
+                        hash[..PRIVATE_KEY_LEN].copy_from_slice(&child.to_bytes());
+
+                        return Ok(ExtendedPrivateKey::from_hash(Secp256k1, hash));
+                    }
+
+                    // SLIP-0010: derive again from the chain code part
+                    let mut previous = hash;
+                    hash = hmac_sha512(
+                        &self.chain_code,
+                        &[&[1], &previous[PRIVATE_KEY_LEN..], &index_bytes],
+                    );
+                    wipe(&mut previous);
+                }
+            }
+        }
+    }
+
+    /// Derive the key at a path like `m/44'/144'/0'/0/0`
+    ///
+    /// The path is relative to this key, which is `m`. Hardened indexes
+    /// are marked with `'` or `h`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Secp256k1};
+    ///
+    /// let master = ExtendedPrivateKey::master(&[0; 64], Secp256k1);
+    ///
+    /// assert_eq!(
+    ///     master.derive_path("m/44'/144'/0'/0/0").unwrap().keypair().address(),
+    ///     master.derive_path("m/44h/144h/0h/0").unwrap().derive_child(0).unwrap().keypair().address(),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DerivationError::BadPath`] for a malformed path and
+    /// [`DerivationError::NotHardened`] for a non-hardened index of an
+    /// Ed25519 key.
+    pub fn derive_path(&self, path: &str) -> Result<Self, DerivationError> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(BadPath);
+        }
+
+        components.try_fold(self.clone(), |key, component| {
+            key.derive_child(parse_index(component)?)
+        })
+    }
+
+    /// The keypair of this key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Ed25519};
+    ///
+    /// let keypair = ExtendedPrivateKey::master(&[0; 64], Ed25519).keypair();
+    ///
+    /// assert_eq!(keypair.public_key()[0], 0xED);
+    /// ```
+    pub fn keypair(&self) -> Keypair {
+        match self.algorithm {
+            Secp256k1 => keypair::secp256k1_keypair(self.secp256k1_scalar()),
+            Ed25519 => keypair::ed25519_keypair(self.private_key),
+        }
+    }
+
+    /// The algorithm of the key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Ed25519};
+    ///
+    /// assert_eq!(ExtendedPrivateKey::master(&[0; 64], Ed25519).algorithm(), Ed25519);
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// The private key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Ed25519};
+    ///
+    /// let key = ExtendedPrivateKey::master(&[0; 64], Ed25519);
+    ///
+    /// assert_eq!(key.private_key(), key.keypair().private_key());
+    /// ```
+    pub fn private_key(&self) -> &[u8; PRIVATE_KEY_LEN] {
+        &self.private_key
+    }
+
+    /// The chain code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{hd::ExtendedPrivateKey, Secp256k1};
+    ///
+    /// assert_eq!(ExtendedPrivateKey::master(&[0; 64], Secp256k1).chain_code().len(), 32);
+    /// ```
+    pub fn chain_code(&self) -> &[u8; CHAIN_CODE_LEN] {
+        &self.chain_code
+    }
+
+    fn from_hash(algorithm: Algorithm, mut hash: [u8; HASH_LEN]) -> Self {
+        let mut key = ExtendedPrivateKey {
+            algorithm,
+            private_key: [0; PRIVATE_KEY_LEN],
+            chain_code: [0; CHAIN_CODE_LEN],
+        };
+        key.private_key.copy_from_slice(&hash[..PRIVATE_KEY_LEN]);
+        key.chain_code.copy_from_slice(&hash[PRIVATE_KEY_LEN..]);
+        wipe(&mut hash);
+
+        key
+    }
+
+    fn secp256k1_scalar(&self) -> Scalar {
+        secp256k1_scalar(&self.private_key).expect("checked when derived")
+    }
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("algorithm", &self.algorithm)
+            .field("private_key", &Redacted)
+            .field("chain_code", &Redacted)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ExtendedPrivateKey {
+    fn zeroize(&mut self) {
+        self.private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for ExtendedPrivateKey {}
+
+fn parse_index(component: &str) -> Result<u32, DerivationError> {
+    let (digits, offset) = match component.strip_suffix(&['\'', 'h'][..]) {
+        Some(digits) => (digits, HARDENED),
+        None => (component, 0),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(BadPath);
+    }
+
+    match digits.parse::<u32>() {
+        Ok(index) if index < HARDENED => Ok(index + offset),
+        _ => Err(BadPath),
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; HASH_LEN] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts any key length");
+    for data in data {
+        mac.update(data);
+    }
+
+    let mut hash = [0; HASH_LEN];
+    hash.copy_from_slice(&mac.finalize().into_bytes());
+
+    hash
+}
+
+fn secp256k1_scalar(bytes: &[u8]) -> Option<Scalar> {
+    Scalar::from_repr(*FieldBytes::from_slice(bytes)).into()
+}
@@ -0,0 +1,155 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{
+    decode_account_id, decode_seed, encode_account_id, encode_seed, wipe, Address, Algorithm,
+    Error, Result, VersionedToken, ENTROPY_LEN,
+};
+
+const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Decode a classic address to the 40 characters hex form of its
+/// account id, as used by XRPL JSON APIs
+///
+/// The hex is upper case.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_account_id_to_hex;
+///
+/// assert_eq!(
+///     decode_account_id_to_hex("rrrrrrrrrrrrrrrrrrrrBZbvji"),
+///     Ok("0000000000000000000000000000000000000001".to_string())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_id`].
+pub fn decode_account_id_to_hex(account_id: &str) -> Result<String> {
+    Ok(to_hex(&decode_account_id(account_id)?))
+}
+
+/// Encode the hex form of an account id as a classic address
+///
+/// The hex may be upper or lower case.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_account_id_from_hex;
+///
+/// assert_eq!(
+///     encode_account_id_from_hex("0000000000000000000000000000000000000001"),
+///     Ok("rrrrrrrrrrrrrrrrrrrrBZbvji".to_string())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::DecodeError`] if `hex` has an odd number of digits or
+/// a character that isn't a hex digit, and [`Error::BadLength`] if it
+/// isn't 20 bytes long.
+pub fn encode_account_id_from_hex(hex: &str) -> Result<String> {
+    Ok(encode_account_id(&from_hex::<{ Address::PAYLOAD_LEN }>(
+        hex,
+    )?))
+}
+
+/// Decode a seed to the 32 characters hex form of its entropy and its
+/// algorithm
+///
+/// The hex is upper case. Note that it is the full secret.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{decode_seed_to_hex, Ed25519};
+///
+/// assert_eq!(
+///     decode_seed_to_hex("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+///     Ok(("00000000000000000000000000000000".to_string(), Ed25519))
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_seed`].
+pub fn decode_seed_to_hex(seed: &str) -> Result<(String, Algorithm)> {
+    let (mut entropy, algorithm) = decode_seed(seed)?;
+    let hex = to_hex(&entropy);
+    wipe(&mut entropy);
+
+    Ok((hex, algorithm))
+}
+
+/// Encode the hex form of seed entropy as a seed for `algorithm`
+///
+/// The hex may be upper or lower case.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{encode_seed_from_hex, Secp256k1};
+///
+/// assert_eq!(
+///     encode_seed_from_hex("00000000000000000000000000000000", Secp256k1),
+///     Ok("sp6JS7f14BuwFY8Mw6bTtLKWauoUs".to_string())
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::DecodeError`] if `hex` has an odd number of digits or
+/// a character that isn't a hex digit, and [`Error::BadLength`] if it
+/// isn't 16 bytes long.
+pub fn encode_seed_from_hex(hex: &str, algorithm: Algorithm) -> Result<String> {
+    let mut entropy = from_hex::<ENTROPY_LEN>(hex)?;
+    let seed = encode_seed(&entropy, algorithm);
+    wipe(&mut entropy);
+
+    Ok(seed)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 * bytes.len());
+    for byte in bytes {
+        hex.push(DIGITS[usize::from(byte >> 4)].into());
+        hex.push(DIGITS[usize::from(byte & 0xF)].into());
+    }
+
+    hex
+}
+
+pub(crate) fn from_hex<const N: usize>(hex: &str) -> Result<[u8; N]> {
+    let digits = hex.as_bytes();
+    if !digits.len().is_multiple_of(2) || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return Err(Error::DecodeError);
+    }
+    if digits.len() != 2 * N {
+        return Err(Error::BadLength {
+            expected: N,
+            actual: digits.len() / 2,
+        });
+    }
+
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+        *byte = nibble(pair[0]) << 4 | nibble(pair[1]);
+    }
+
+    Ok(bytes)
+}
+
+fn nibble(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{
+    encode_seed, xaddress, AccountId, Seed, TokenType, XAddress, XrplToken, ACCOUNT_PRIVATE_KEY,
+    ACCOUNT_PUBLIC_KEY, ADDRESS, FAMILY_GENERATOR, NODE_PRIVATE_KEY, NODE_PUBLIC_KEY,
+};
+
+/// An identifier of any token type, usable as a trait object
+///
+/// Implemented by [`AccountId`], [`Seed`], [`XAddress`] and
+/// [`XrplToken`], which holds keys and family generators, so identifiers
+/// of different types can be handled together, for instance in an audit
+/// log. Its encoding is the version [`prefix`](XrplIdentifier::prefix)
+/// and [`payload`](XrplIdentifier::payload) with a checksum.
+///
+/// Seeds and private keys are secrets, and so are their encodings and
+/// payloads; check the [token type](XrplIdentifier::token_type) before
+/// logging them.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{
+///     decode_any, encode_versioned, AccountId, Seed, XrplIdentifier,
+/// };
+///
+/// let identifiers: Vec<Box<dyn XrplIdentifier>> = vec![
+///     Box::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp".parse::<AccountId>().unwrap()),
+///     Box::new("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".parse::<Seed>().unwrap()),
+///     Box::new(decode_any("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH").unwrap()),
+/// ];
+///
+/// for identifier in &identifiers {
+///     assert_eq!(
+///         encode_versioned(&identifier.payload(), identifier.prefix()),
+///         identifier.encode()
+///     );
+/// }
+///
+/// let names: Vec<_> = identifiers
+///     .iter()
+///     .map(|identifier| identifier.token_type().type_name())
+///     .collect();
+///
+/// assert_eq!(names, ["account_id", "seed_ed25519", "node_public_key"]);
+/// # }
+/// ```
+pub trait XrplIdentifier {
+    /// The encoded token
+    fn encode(&self) -> String;
+
+    /// The type of the token
+    fn token_type(&self) -> TokenType;
+
+    /// The bytes between the version prefix and the checksum
+    fn payload(&self) -> Vec<u8>;
+
+    /// Version bytes prepended to the payload, those of the token type
+    /// except for X-addresses of test networks
+    fn prefix(&self) -> &'static [u8] {
+        self.token_type().prefix_bytes()
+    }
+}
+
+impl XrplIdentifier for AccountId {
+    fn encode(&self) -> String {
+        self.to_string()
+    }
+
+    fn token_type(&self) -> TokenType {
+        TokenType::AccountId
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+impl XrplIdentifier for Seed {
+    fn encode(&self) -> String {
+        self.expose_secret()
+    }
+
+    fn token_type(&self) -> TokenType {
+        TokenType::seed(self.algorithm())
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        self.entropy().to_vec()
+    }
+}
+
+impl XrplIdentifier for XAddress {
+    fn encode(&self) -> String {
+        self.to_string()
+    }
+
+    fn token_type(&self) -> TokenType {
+        TokenType::XAddress
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        xaddress::payload(self.account_id().as_bytes(), self.tag()).to_vec()
+    }
+
+    fn prefix(&self) -> &'static [u8] {
+        xaddress::prefix(self.network())
+    }
+}
+
+impl XrplIdentifier for XrplToken {
+    fn encode(&self) -> String {
+        match self {
+            XrplToken::AccountId(bytes) => ADDRESS.encode(bytes),
+            XrplToken::XAddress {
+                account_id,
+                tag,
+                network,
+            } => xaddress::encode_xaddress(account_id, *tag, *network),
+            XrplToken::Seed { entropy, algorithm } => encode_seed(entropy, *algorithm),
+            XrplToken::NodePublicKey(bytes) => NODE_PUBLIC_KEY.encode(bytes),
+            XrplToken::AccountPublicKey(bytes) => ACCOUNT_PUBLIC_KEY.encode(bytes),
+            XrplToken::NodePrivateKey(bytes) => NODE_PRIVATE_KEY.encode(bytes),
+            XrplToken::AccountPrivateKey(bytes) => ACCOUNT_PRIVATE_KEY.encode(bytes),
+            XrplToken::FamilyGenerator(bytes) => FAMILY_GENERATOR.encode(bytes),
+        }
+    }
+
+    fn token_type(&self) -> TokenType {
+        XrplToken::token_type(self)
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        match self {
+            XrplToken::AccountId(bytes) => bytes.to_vec(),
+            XrplToken::XAddress {
+                account_id, tag, ..
+            } => xaddress::payload(account_id, *tag).to_vec(),
+            XrplToken::Seed { entropy, .. } => entropy.to_vec(),
+            XrplToken::NodePublicKey(bytes) => bytes.to_vec(),
+            XrplToken::AccountPublicKey(bytes) => bytes.to_vec(),
+            XrplToken::NodePrivateKey(bytes) => bytes.to_vec(),
+            XrplToken::AccountPrivateKey(bytes) => bytes.to_vec(),
+            XrplToken::FamilyGenerator(bytes) => bytes.to_vec(),
+        }
+    }
+
+    fn prefix(&self) -> &'static [u8] {
+        match self {
+            XrplToken::XAddress { network, .. } => xaddress::prefix(*network),
+            token => token.token_type().prefix_bytes(),
+        }
+    }
+}
@@ -0,0 +1,692 @@
+//! Keypair derivation from seeds
+//!
+//! Derives the keypairs of a seed like rippled and `ripple-keypairs`,
+//! so a decoded seed can be turned into keys and an address without
+//! leaving Rust, e.g. inside an enclave.
+//!
+//! Ed25519 keys are available with the `ed25519` feature. The private
+//! key is the SHA-512-half (the first 32 bytes of the SHA-512) of the
+//! entropy, the public key is its Ed25519 public key prefixed with `0xED`.
+//!
+//! secp256k1 keys are available with the `secp256k1` feature. The root
+//! private key is the first SHA-512-half of the entropy followed by a
+//! 4 byte big-endian sequence number that is a valid secp256k1 scalar.
+//! The account private key is the root one plus the first valid
+//! SHA-512-half of the root public key, the account index 0 and another
+//! sequence number.
+//!
+//! Decoded public keys can be checked to be points on their curve with
+//! [`check_public_key`], or while decoding with
+//! [`decode_account_public_key_checked`] and
+//! [`decode_node_public_key_checked`].
+//!
+//! With both features, [`WalletProposal`] gathers the seed, keys and
+//! addresses of an account like rippled's `wallet_propose`.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "secp256k1")] {
+//! use ripple_address_codec::{keypair::derive_secp256k1, Seed};
+//!
+//! let seed: Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+//! let keypair = derive_secp256k1(seed.entropy());
+//!
+//! assert_eq!(keypair.address(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+//! # }
+//! ```
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::fmt;
+
+#[cfg(feature = "secp256k1")]
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    FieldBytes, ProjectivePoint, Scalar,
+};
+use sha2::{Digest, Sha512};
+
+use crate::{
+    account_id_from_public_key, decode_account_public_key, decode_node_public_key,
+    encode_account_id, seed::Redacted, wipe, Address, Entropy, Error, Result, VersionedToken,
+};
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+use crate::{encode_xaddress, AccountId, Algorithm, Ed25519, Network, Secp256k1, Seed, Tag};
+
+/// Private key length in bytes
+pub const PRIVATE_KEY_LEN: usize = 32;
+/// Public key length in bytes, including the `0x02`/`0x03`/`0xED` prefix
+pub const PUBLIC_KEY_LEN: usize = 33;
+
+/// Account index of the account keypair, the only one rippled uses
+#[cfg(feature = "secp256k1")]
+const ACCOUNT_INDEX: u32 = 0;
+
+/// A private key and its public key
+///
+/// [`Debug`](fmt::Debug) output redacts the private key.
+///
+/// With the `zeroize` feature, the private key is overwritten with
+/// zeros on drop.
+///
+/// # Examples
+///
+/// ```
+/// # use ripple_address_codec::keypair;
+/// # #[cfg(feature = "secp256k1")]
+/// # let derive = keypair::derive_secp256k1;
+/// # #[cfg(not(feature = "secp256k1"))]
+/// # let derive = keypair::derive_ed25519;
+/// let keypair = derive(&[0; 16]);
+///
+/// assert!(format!("{:?}", keypair).contains("****"));
+/// ```
+// Not `Copy`, so secrets are only duplicated by explicit clones
+#[allow(missing_copy_implementations)]
+#[derive(Clone)]
+pub struct Keypair {
+    private_key: [u8; PRIVATE_KEY_LEN],
+    public_key: [u8; PUBLIC_KEY_LEN],
+}
+
+impl Keypair {
+    /// The private key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ripple_address_codec::keypair;
+    /// # #[cfg(feature = "secp256k1")]
+    /// # let derive = keypair::derive_secp256k1;
+    /// # #[cfg(not(feature = "secp256k1"))]
+    /// # let derive = keypair::derive_ed25519;
+    /// assert_eq!(derive(&[0; 16]).private_key().len(), 32);
+    /// ```
+    pub fn private_key(&self) -> &[u8; PRIVATE_KEY_LEN] {
+        &self.private_key
+    }
+
+    /// The public key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::encode_account_public_key;
+    /// # use ripple_address_codec::keypair;
+    /// # #[cfg(feature = "secp256k1")]
+    /// # let derive = keypair::derive_secp256k1;
+    /// # #[cfg(not(feature = "secp256k1"))]
+    /// # let derive = keypair::derive_ed25519;
+    ///
+    /// let keypair = derive(&[0; 16]);
+    ///
+    /// assert!(encode_account_public_key(keypair.public_key()).starts_with('a'));
+    /// ```
+    pub fn public_key(&self) -> &[u8; PUBLIC_KEY_LEN] {
+        &self.public_key
+    }
+
+    /// The account id of the public key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::encode_account_id;
+    /// # use ripple_address_codec::keypair;
+    /// # #[cfg(feature = "secp256k1")]
+    /// # let derive = keypair::derive_secp256k1;
+    /// # #[cfg(not(feature = "secp256k1"))]
+    /// # let derive = keypair::derive_ed25519;
+    ///
+    /// let keypair = derive(&[0; 16]);
+    ///
+    /// assert_eq!(encode_account_id(&keypair.account_id()), keypair.address());
+    /// ```
+    pub fn account_id(&self) -> [u8; Address::PAYLOAD_LEN] {
+        account_id_from_public_key(&self.public_key).expect("derived public keys are valid")
+    }
+
+    /// The classic address (starting with r...) of the public key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ripple_address_codec::keypair;
+    /// # #[cfg(feature = "secp256k1")]
+    /// # let derive = keypair::derive_secp256k1;
+    /// # #[cfg(not(feature = "secp256k1"))]
+    /// # let derive = keypair::derive_ed25519;
+    /// assert!(derive(&[0; 16]).address().starts_with('r'));
+    /// ```
+    pub fn address(&self) -> String {
+        encode_account_id(&self.account_id())
+    }
+}
+
+impl fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keypair")
+            .field("private_key", &Redacted)
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Keypair {
+    fn zeroize(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Keypair {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Keypair {}
+
+/// Derive the account keypair of a seed with the seed's algorithm
+///
+/// Available with both the `secp256k1` and `ed25519` features.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{keypair, Seed};
+///
+/// let seed: Seed = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r".parse().unwrap();
+///
+/// assert_eq!(keypair::derive(&seed).address(), "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD");
+/// ```
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+pub fn derive(seed: &Seed) -> Keypair {
+    match seed.algorithm() {
+        Secp256k1 => derive_secp256k1(seed.entropy()),
+        Ed25519 => derive_ed25519(seed.entropy()),
+    }
+}
+
+/// Everything about a new account, like rippled's `wallet_propose`
+///
+/// The X-address is the mainnet one, without a tag. Available with
+/// both the `secp256k1` and `ed25519` features.
+///
+/// [`Debug`](fmt::Debug) output redacts the seed. With the `zeroize`
+/// feature, the seed is overwritten with zeros on drop.
+///
+/// With the `serde` feature, it serializes to a struct with the field
+/// names of rippled's `wallet_propose` result (`account_id`,
+/// `key_type`, `master_seed`, `public_key_hex`) and `x_address`. Note
+/// that this includes the full seed.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{keypair::WalletProposal, Seed};
+///
+/// let seed: Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+/// let wallet = WalletProposal::from_seed(&seed);
+///
+/// assert_eq!(wallet.classic_address(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// assert_eq!(wallet.xaddress(), "XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc");
+/// ```
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct WalletProposal {
+    seed: String,
+    algorithm: Algorithm,
+    public_key_hex: String,
+    account_id: AccountId,
+    classic_address: String,
+    xaddress: String,
+}
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+impl WalletProposal {
+    /// Wallet of an existing seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Ed25519));
+    ///
+    /// assert_eq!(wallet.seed(), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+    /// ```
+    pub fn from_seed(seed: &Seed) -> Self {
+        let keypair = derive(seed);
+        let account_id = AccountId::from(keypair.account_id());
+
+        WalletProposal {
+            seed: seed.expose_secret(),
+            algorithm: seed.algorithm(),
+            public_key_hex: keypair
+                .public_key()
+                .iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect(),
+            account_id,
+            classic_address: account_id.to_string(),
+            xaddress: encode_xaddress(account_id.as_bytes(), Tag::None, Network::Main),
+        }
+    }
+
+    /// The encoded seed (starting with s...)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Secp256k1, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Secp256k1));
+    ///
+    /// assert_eq!(wallet.seed(), "sp6JS7f14BuwFY8Mw6bTtLKWauoUs");
+    /// ```
+    pub fn seed(&self) -> &str {
+        &self.seed
+    }
+
+    /// The algorithm of the keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// assert_eq!(WalletProposal::from_seed(&Seed::new([0; 16], Ed25519)).algorithm(), Ed25519);
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// The public key as upper case hex
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Ed25519));
+    ///
+    /// assert!(wallet.public_key_hex().starts_with("ED"));
+    /// ```
+    pub fn public_key_hex(&self) -> &str {
+        &self.public_key_hex
+    }
+
+    /// The account id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Ed25519));
+    ///
+    /// assert_eq!(wallet.account_id(), wallet.classic_address());
+    /// ```
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// The classic address (starting with r...)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Ed25519));
+    ///
+    /// assert_eq!(wallet.classic_address(), "r9zRhGr7b6xPekLvT6wP4qNdWMryaumZS7");
+    /// ```
+    pub fn classic_address(&self) -> &str {
+        &self.classic_address
+    }
+
+    /// The mainnet X-address without a tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{keypair::WalletProposal, Ed25519, Seed};
+    ///
+    /// let wallet = WalletProposal::from_seed(&Seed::new([0; 16], Ed25519));
+    ///
+    /// assert!(wallet.xaddress().starts_with('X'));
+    /// ```
+    pub fn xaddress(&self) -> &str {
+        &self.xaddress
+    }
+}
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+impl fmt::Debug for WalletProposal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalletProposal")
+            .field("seed", &Redacted)
+            .field("algorithm", &self.algorithm)
+            .field("public_key_hex", &self.public_key_hex)
+            .field("classic_address", &self.classic_address)
+            .field("xaddress", &self.xaddress)
+            .finish()
+    }
+}
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519", feature = "zeroize"))]
+impl Drop for WalletProposal {
+    fn drop(&mut self) {
+        wipe(&mut self.seed);
+    }
+}
+
+/// Propose a new wallet from a freshly generated seed
+///
+/// Like rippled's `wallet_propose`. Available with both the `secp256k1`
+/// and `ed25519` features, and a source of randomness for
+/// [`generate_seed`](crate::generate_seed).
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_seed, keypair::propose_wallet, Ed25519};
+///
+/// let wallet = propose_wallet(Ed25519);
+///
+/// assert_eq!(decode_seed(wallet.seed()).unwrap().1, Ed25519);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the random number generator fails.
+#[cfg(all(
+    feature = "secp256k1",
+    feature = "ed25519",
+    any(feature = "mesalock_sgx", feature = "os-rng")
+))]
+pub fn propose_wallet(algorithm: Algorithm) -> WalletProposal {
+    let (seed, _) = crate::generate_seed(algorithm);
+
+    WalletProposal::from_seed(&seed)
+}
+
+/// Propose `n` new wallets from freshly generated seeds
+///
+/// For pre-generating deposit accounts in one call, also inside an
+/// enclave with the `mesalock_sgx` feature. Each record is a
+/// [`WalletProposal`] like [`propose_wallet`] returns; with the `serde`
+/// feature the batch serializes to JSON, e.g. with `serde_json`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{keypair::generate_batch, Secp256k1};
+///
+/// let wallets = generate_batch(3, Secp256k1);
+///
+/// assert_eq!(wallets.len(), 3);
+/// assert!(wallets.iter().all(|wallet| wallet.xaddress().starts_with('X')));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the random number generator fails.
+#[cfg(all(
+    feature = "secp256k1",
+    feature = "ed25519",
+    any(feature = "mesalock_sgx", feature = "os-rng")
+))]
+pub fn generate_batch(n: usize, algorithm: Algorithm) -> Vec<WalletProposal> {
+    (0..n).map(|_| propose_wallet(algorithm)).collect()
+}
+
+/// Derive the Ed25519 keypair of a seed
+///
+/// Available with the `ed25519` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_seed, keypair::derive_ed25519};
+///
+/// let (entropy, _) = decode_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+///
+/// assert_eq!(derive_ed25519(&entropy).address(), "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD");
+/// ```
+#[cfg(feature = "ed25519")]
+pub fn derive_ed25519(entropy: &Entropy) -> Keypair {
+    let mut hash = Sha512::digest(entropy);
+
+    let mut private_key = [0; PRIVATE_KEY_LEN];
+    private_key.copy_from_slice(&hash[..PRIVATE_KEY_LEN]);
+    wipe(hash.as_mut_slice());
+
+    ed25519_keypair(private_key)
+}
+
+#[cfg(feature = "ed25519")]
+pub(crate) fn ed25519_keypair(private_key: [u8; PRIVATE_KEY_LEN]) -> Keypair {
+    let mut public_key = [0; PUBLIC_KEY_LEN];
+    public_key[0] = 0xED;
+    public_key[1..].copy_from_slice(
+        ed25519_dalek::SigningKey::from_bytes(&private_key)
+            .verifying_key()
+            .as_bytes(),
+    );
+
+    Keypair {
+        private_key,
+        public_key,
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+/// Derive the secp256k1 account keypair of a seed
+///
+/// Available with the `secp256k1` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_seed, keypair::derive_secp256k1};
+///
+/// let (entropy, _) = decode_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+///
+/// assert_eq!(derive_secp256k1(&entropy).address(), "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1");
+/// ```
+pub fn derive_secp256k1(entropy: &Entropy) -> Keypair {
+    let root = secp256k1_root_scalar(entropy);
+    let root_public_key = secp256k1_public_key(&root);
+
+    let tweak = first_valid_scalar(|hasher| {
+        hasher.update(root_public_key);
+        hasher.update(ACCOUNT_INDEX.to_be_bytes());
+    });
+
+    secp256k1_keypair(root + tweak)
+}
+
+#[cfg(feature = "secp256k1")]
+/// Derive the secp256k1 root keypair of a seed
+///
+/// The root keypair is the one of validators and peers (node keys), not
+/// of accounts. Available with the `secp256k1` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_seed, encode_node_public_key, keypair::derive_secp256k1_root};
+///
+/// let (entropy, _) = decode_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+/// let keypair = derive_secp256k1_root(&entropy);
+///
+/// assert!(encode_node_public_key(keypair.public_key()).starts_with('n'));
+/// ```
+pub fn derive_secp256k1_root(entropy: &Entropy) -> Keypair {
+    secp256k1_keypair(secp256k1_root_scalar(entropy))
+}
+
+#[cfg(feature = "secp256k1")]
+fn secp256k1_root_scalar(entropy: &Entropy) -> Scalar {
+    first_valid_scalar(|hasher| hasher.update(entropy))
+}
+
+#[cfg(feature = "secp256k1")]
+pub(crate) fn secp256k1_keypair(private_key: Scalar) -> Keypair {
+    Keypair {
+        private_key: private_key.to_bytes().into(),
+        public_key: secp256k1_public_key(&private_key),
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+pub(crate) fn secp256k1_public_key(private_key: &Scalar) -> [u8; PUBLIC_KEY_LEN] {
+    let mut public_key = [0; PUBLIC_KEY_LEN];
+    public_key.copy_from_slice(
+        (ProjectivePoint::GENERATOR * private_key)
+            .to_affine()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+
+    public_key
+}
+
+/// The first SHA-512-half of the input followed by a sequence number
+/// that is a valid non-zero scalar
+#[cfg(feature = "secp256k1")]
+fn first_valid_scalar(input: impl Fn(&mut Sha512)) -> Scalar {
+    (0..=u32::MAX)
+        .find_map(|sequence| {
+            let mut hasher = Sha512::new();
+            input(&mut hasher);
+            hasher.update(sequence.to_be_bytes());
+
+            let mut hash = hasher.finalize();
+            let scalar: Option<Scalar> =
+                Scalar::from_repr(*FieldBytes::from_slice(&hash[..PRIVATE_KEY_LEN])).into();
+            wipe(hash.as_mut_slice());
+
+            scalar.filter(|scalar| !bool::from(scalar.is_zero()))
+        })
+        .expect("almost every hash is a valid scalar")
+}
+
+/// Check that a public key is a point on its curve
+///
+/// The curve is declared by the first byte: `0xED` for Ed25519 and
+/// `0x02`/`0x03` for compressed secp256k1 keys. Keys on a curve whose
+/// feature is disabled are only checked for their prefix.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{keypair::check_public_key, Error};
+///
+/// let mut key = [0; 33];
+/// key[0] = 0x04;
+///
+/// assert_eq!(check_public_key(&key), Err(Error::InvalidKey));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidKey`] if the prefix isn't one of the above or
+/// the key isn't a point on its curve.
+pub fn check_public_key(public_key: &[u8; PUBLIC_KEY_LEN]) -> Result<()> {
+    let valid = match public_key[0] {
+        0xED => is_ed25519_point(public_key),
+        0x02 | 0x03 => is_secp256k1_point(public_key),
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidKey)
+    }
+}
+
+/// Decode an account public key (starting with a...) and check that it
+/// is a point on its curve, see [`check_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::keypair::decode_account_public_key_checked;
+///
+/// let key = decode_account_public_key_checked(
+///     "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(key[0], 0x02);
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_public_key`], or [`Error::InvalidKey`] if the
+/// checksum-valid key isn't on its curve.
+pub fn decode_account_public_key_checked(account_public_key: &str) -> Result<[u8; PUBLIC_KEY_LEN]> {
+    let public_key = decode_account_public_key(account_public_key)?;
+    check_public_key(&public_key)?;
+
+    Ok(public_key)
+}
+
+/// Decode a node public key (starting with n...) and check that it is a
+/// point on its curve, see [`check_public_key`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::keypair::decode_node_public_key_checked;
+///
+/// let key = decode_node_public_key_checked(
+///     "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(key[0], 0x03);
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_node_public_key`], or [`Error::InvalidKey`] if the
+/// checksum-valid key isn't on its curve.
+pub fn decode_node_public_key_checked(node_public_key: &str) -> Result<[u8; PUBLIC_KEY_LEN]> {
+    let public_key = decode_node_public_key(node_public_key)?;
+    check_public_key(&public_key)?;
+
+    Ok(public_key)
+}
+
+#[cfg(feature = "ed25519")]
+fn is_ed25519_point(public_key: &[u8; PUBLIC_KEY_LEN]) -> bool {
+    let mut point = [0; PUBLIC_KEY_LEN - 1];
+    point.copy_from_slice(&public_key[1..]);
+
+    ed25519_dalek::VerifyingKey::from_bytes(&point).is_ok()
+}
+
+#[cfg(not(feature = "ed25519"))]
+fn is_ed25519_point(_: &[u8; PUBLIC_KEY_LEN]) -> bool {
+    true
+}
+
+#[cfg(feature = "secp256k1")]
+fn is_secp256k1_point(public_key: &[u8; PUBLIC_KEY_LEN]) -> bool {
+    k256::PublicKey::from_sec1_bytes(public_key).is_ok()
+}
+
+#[cfg(not(feature = "secp256k1"))]
+fn is_secp256k1_point(_: &[u8; PUBLIC_KEY_LEN]) -> bool {
+    true
+}
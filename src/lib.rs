@@ -1,6 +1,6 @@
 //! Encodes/decodes base58 encoded XRP Ledger identifiers
 //!
-//! Functions for encoding and decoding XRP Ledger addresses and seeds.
+//! Functions for encoding and decoding XRP Ledger addresses, X-addresses and seeds.
 //!
 //! # Examples
 //!
@@ -12,27 +12,200 @@
     missing_debug_implementations,
     missing_copy_implementations,
     missing_docs,
-    missing_crate_level_docs,
-    missing_doc_code_examples,
+    rustdoc::missing_crate_level_docs,
     non_ascii_idents,
     unreachable_pub
 )]
 #![doc(test(attr(deny(warnings))))]
 #![doc(html_root_url = "https://docs.rs/ripple-address-codec/0.1.1")]
+#![cfg_attr(all(feature = "mesalock_sgx", not(target_env = "sgx")), no_std)]
 
-use std::{convert::TryInto, result};
+#[cfg(not(any(feature = "seed-secp256k1", feature = "seed-ed25519")))]
+compile_error!("enable at least one of the `seed-secp256k1` and `seed-ed25519` features");
 
-use base_x;
-use ring::digest::{digest, SHA256};
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+#[macro_use]
+extern crate sgx_tstd as std;
 
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{convert::TryInto, fmt, result, str::FromStr};
+
+use subtle::ConstantTimeEq;
+
+mod account_id;
+mod alphabet;
+mod any;
+mod ascii;
+mod base58;
+mod batch;
+mod confusable;
+mod deposit;
+mod display;
 mod error;
+mod hex;
+mod identifier;
+#[cfg(feature = "bip39")]
+mod mnemonic;
+mod network_registry;
+#[cfg(any(feature = "ring", feature = "sha2"))]
+mod passphrase;
+mod payment_uri;
+mod preset;
+#[cfg(feature = "protected-memory")]
+mod protected;
+mod reader;
+mod rfc1751;
+#[cfg(feature = "schemars")]
+mod schemars_impls;
+mod secret;
+mod secret_numbers;
+mod seed;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "serde")]
+mod serde_with;
+#[cfg(feature = "futures")]
+mod stream;
+mod token_type;
+mod trace;
+mod typo;
+#[cfg(feature = "uniffi")]
+mod uniffi_impls;
+mod validated;
+mod well_known;
+mod xaddress;
+#[cfg(any(feature = "xrpl-rust", feature = "xrpl-types"))]
+mod xrpl_interop;
+
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod codec;
+pub mod compat;
+pub mod container;
+pub mod derivation;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "hd")]
+pub mod hd;
+#[cfg(any(feature = "secp256k1", feature = "ed25519"))]
+pub mod keypair;
+#[cfg(feature = "mesalock_sgx")]
+pub mod sealing;
+pub mod sha256;
+#[cfg(feature = "heapless")]
+pub mod stack;
+#[cfg(feature = "vanity")]
+pub mod vanity;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+pub use self::account_id::AccountId;
+#[cfg(feature = "rkyv")]
+pub use self::account_id::ArchivedAccountId;
+pub use self::alphabet::{contains_only_xrpl_alphabet, Alphabet};
+pub use self::any::{decode_any, XrplToken};
+pub use self::ascii::{
+    decode_account_id_bytes, decode_account_private_key_bytes, decode_account_public_key_bytes,
+    decode_any_bytes, decode_family_generator_bytes, decode_node_private_key_bytes,
+    decode_node_public_key_bytes, decode_seed_bytes, decode_xaddress_bytes,
+};
+pub use self::batch::{
+    decode_account_ids, decode_seeds, encode_account_ids, encode_seeds, DecodeAccountIds,
+    DecodeIteratorExt, DecodeSeeds,
+};
+#[cfg(feature = "rayon")]
+pub use self::batch::{
+    par_decode_account_ids, par_decode_seeds, par_encode_account_ids, par_encode_seeds,
+};
+use self::codec::DEFAULT_MAX_INPUT_LEN;
+pub use self::deposit::{validate_deposit_destination, DepositCheck, TagPolicy};
+pub use self::display::format_grouped;
 pub use self::error::{Error, Error::DecodeError};
-pub use self::Algorithm::{Ed25519, Secp256k1};
+pub use self::hex::{
+    decode_account_id_to_hex, decode_seed_to_hex, encode_account_id_from_hex, encode_seed_from_hex,
+};
+pub use self::identifier::XrplIdentifier;
+#[cfg(feature = "bip39")]
+pub use self::mnemonic::{bip39_to_seed, seed_to_bip39};
+pub use self::network_registry::NetworkRegistry;
+#[cfg(any(feature = "ring", feature = "sha2"))]
+pub use self::passphrase::{passphrase_to_seed, LowEntropy};
+pub use self::payment_uri::PaymentRequest;
+pub use self::preset::Preset;
+pub use self::reader::AddressReader;
+pub use self::rfc1751::{rfc1751_to_seed, seed_to_rfc1751};
+pub use self::secret::{parse_secret, SecretFormat};
+pub use self::secret_numbers::{entropy_to_secret_numbers, secret_numbers_to_entropy};
+#[cfg(any(feature = "mesalock_sgx", feature = "os-rng"))]
+pub use self::seed::generate_seed;
+#[cfg(feature = "rand")]
+pub use self::seed::generate_seed_with_rng;
+#[cfg(feature = "rkyv")]
+pub use self::seed::ArchivedSeed;
+pub use self::seed::Seed;
+#[cfg(feature = "serde")]
+pub use self::serde_with::{as_classic_address, as_seed, as_xaddress};
+use self::sha256::Sha256;
+#[cfg(feature = "futures")]
+pub use self::stream::validate_account_ids_stream;
+pub use self::token_type::TokenType;
+pub use self::typo::suggest_correction;
+pub use self::validated::{AccountIdStr, ClassicAddressStr, SeedStr, XAddressStr};
+pub use self::well_known::{
+    is_well_known, WellKnownAccount, ACCOUNT_ONE, ACCOUNT_ZERO, GENESIS_ACCOUNT,
+    NAME_RESERVATION_ACCOUNT, NAN_ACCOUNT,
+};
+pub use self::xaddress::{
+    classic_address_to_xaddress, decode_xaddress, encode_xaddress, encode_xaddress_into,
+    is_valid_xaddress, normalize_xaddress, parse_account, parse_destination,
+    xaddress_to_classic_address, Network, Tag, XAddress,
+};
+#[cfg(feature = "rkyv")]
+pub use self::xaddress::{ArchivedDestinationTag, ArchivedNetwork, ArchivedXAddress};
+#[cfg(feature = "seed-ed25519")]
+pub use self::Algorithm::Ed25519;
+#[cfg(feature = "seed-secp256k1")]
+pub use self::Algorithm::Secp256k1;
+#[cfg(feature = "macros")]
+pub use ripple_address_codec_macros::{address, address_bytes};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
 const ALPHABET: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
-const CHECKSUM_LENGTH: usize = 4;
+/// Checksum length in bytes
+pub const CHECKSUM_LENGTH: usize = 4;
 const ENTROPY_LEN: usize = 16;
+/// Longest known token in bytes: 3 bytes seed prefix or 33 bytes
+/// public key payload, plus checksum
+const MAX_TOKEN_LEN: usize = 40;
+
+/// Longest classic address, in characters
+pub const ACCOUNT_ID_MAX_LEN: usize = max_token_len(Address::PREFIX, Address::PAYLOAD_LEN);
+/// Longest X-address, in characters
+pub const XADDRESS_MAX_LEN: usize =
+    max_token_len(xaddress::XAddressMain::PREFIX, xaddress::PAYLOAD_LEN);
+/// Longest seed of either algorithm, in characters
+pub const SEED_MAX_LEN: usize = max_token_len(SeedEd25519::PREFIX, ENTROPY_LEN);
+/// Longest node public key, in characters
+pub const NODE_PUBLIC_KEY_MAX_LEN: usize =
+    max_token_len(NodePublicKey::PREFIX, NodePublicKey::PAYLOAD_LEN);
+/// Longest account public key, in characters
+pub const ACCOUNT_PUBLIC_KEY_MAX_LEN: usize =
+    max_token_len(AccountPublicKey::PREFIX, AccountPublicKey::PAYLOAD_LEN);
+/// Longest node private key, in characters
+pub const NODE_PRIVATE_KEY_MAX_LEN: usize =
+    max_token_len(NodePrivateKey::PREFIX, NodePrivateKey::PAYLOAD_LEN);
+/// Longest account private key, in characters
+pub const ACCOUNT_PRIVATE_KEY_MAX_LEN: usize =
+    max_token_len(AccountPrivateKey::PREFIX, AccountPrivateKey::PAYLOAD_LEN);
+/// Longest family generator, in characters
+pub const FAMILY_GENERATOR_MAX_LEN: usize =
+    max_token_len(FamilyGenerator::PREFIX, FamilyGenerator::PAYLOAD_LEN);
 
 /// Seed entropy array
 ///
@@ -44,17 +217,192 @@ pub type Result<T> = result::Result<T, Error>;
 
 /// The elliptic curve digital signature algorithm
 /// with which the seed is intended to be used
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// New signing schemes may be added with their own seed version prefix,
+/// so matches on it need a wildcard arm.
+///
+/// With the `xrpl-rust` feature, it converts to and from `xrpl-rust`'s
+/// `CryptoAlgorithm`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Clone, Copy, PartialEq, Eq, Hash), compare(PartialEq))
+)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[non_exhaustive]
 pub enum Algorithm {
     /// Elliptic Curve Digital Signature Algorithm (ECDSA): secp256k1
+    ///
+    /// Available with the `seed-secp256k1` feature, on by default.
+    #[cfg(feature = "seed-secp256k1")]
+    #[default]
     Secp256k1,
     /// Edwards-curve Digital Signature Algorithm (EdDSA): Ed25519
+    ///
+    /// Available with the `seed-ed25519` feature, on by default.
+    #[cfg(feature = "seed-ed25519")]
+    #[cfg_attr(not(feature = "seed-secp256k1"), default)]
     Ed25519,
 }
 
-impl Default for Algorithm {
-    fn default() -> Self {
-        Self::Secp256k1
+impl Algorithm {
+    /// All algorithms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+    /// use ripple_address_codec::{Algorithm, Ed25519, Secp256k1};
+    ///
+    /// assert_eq!(Algorithm::all(), &[Secp256k1, Ed25519]);
+    /// # }
+    /// ```
+    pub const fn all() -> &'static [Algorithm] {
+        &[
+            #[cfg(feature = "seed-secp256k1")]
+            Secp256k1,
+            #[cfg(feature = "seed-ed25519")]
+            Ed25519,
+        ]
+    }
+
+    /// Version prefix of seeds for the algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+    /// use ripple_address_codec::{Ed25519, Secp256k1};
+    ///
+    /// assert_eq!(Secp256k1.seed_prefix(), [0x21]);
+    /// assert_eq!(Ed25519.seed_prefix(), [0x01, 0xE1, 0x4B]);
+    /// # }
+    /// ```
+    pub fn seed_prefix(self) -> &'static [u8] {
+        self.registration().seed_prefix
+    }
+
+    /// The algorithm with the seed version prefix `prefix`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{Algorithm, Ed25519};
+    ///
+    /// assert_eq!(Algorithm::from_seed_prefix(&[0x01, 0xE1, 0x4B]), Some(Ed25519));
+    /// assert_eq!(Algorithm::from_seed_prefix(&[0x00]), None);
+    /// # }
+    /// ```
+    pub fn from_seed_prefix(prefix: &[u8]) -> Option<Self> {
+        REGISTRY
+            .iter()
+            .find(|registration| registration.seed_prefix == prefix)
+            .map(|registration| registration.algorithm)
+    }
+
+    fn name(&self) -> &'static str {
+        self.registration().name
+    }
+
+    /// Stable one byte tag in binary formats
+    pub(crate) fn tag(self) -> u8 {
+        self.registration().tag
+    }
+
+    pub(crate) fn from_tag(tag: u8) -> Option<Self> {
+        REGISTRY
+            .iter()
+            .find(|registration| registration.tag == tag)
+            .map(|registration| registration.algorithm)
+    }
+
+    fn registration(self) -> &'static Registration {
+        REGISTRY
+            .iter()
+            .find(|registration| registration.algorithm == self)
+            .expect("every algorithm is registered")
+    }
+}
+
+/// What identifies an [`Algorithm`] in names, binary formats and seeds
+struct Registration {
+    algorithm: Algorithm,
+    /// Lowercase name, parsed ignoring case
+    name: &'static str,
+    /// Stable one byte tag in binary formats, never reused
+    tag: u8,
+    /// Seed version prefix, none may be a prefix of another
+    seed_prefix: &'static [u8],
+}
+
+/// Every algorithm, in the order of [`Algorithm::all`]
+///
+/// Supporting a new signing scheme takes a new variant and its entry
+/// here. Seed encoding and decoding, names and binary tags all go
+/// through this table, so algorithms compiled out with their feature
+/// aren't even probed when decoding.
+const REGISTRY: &[Registration] = &[
+    #[cfg(feature = "seed-secp256k1")]
+    Registration {
+        algorithm: Secp256k1,
+        name: "secp256k1",
+        tag: 0,
+        seed_prefix: SeedSecP256K1::PREFIX,
+    },
+    #[cfg(feature = "seed-ed25519")]
+    Registration {
+        algorithm: Ed25519,
+        name: "ed25519",
+        tag: 1,
+        seed_prefix: SeedEd25519::PREFIX,
+    },
+];
+
+/// Parses algorithm names, ignoring ASCII case
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{Algorithm, Ed25519};
+///
+/// assert_eq!("Ed25519".parse::<Algorithm>(), Ok(Ed25519));
+/// assert!("rsa".parse::<Algorithm>().is_err());
+/// # }
+/// ```
+impl FromStr for Algorithm {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        Algorithm::all()
+            .iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or(DecodeError)
+    }
+}
+
+/// Lowercase algorithm name
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::Secp256k1;
+///
+/// assert_eq!(Secp256k1.to_string(), "secp256k1");
+/// # }
+/// ```
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
 }
 
@@ -68,21 +416,74 @@ impl Default for Algorithm {
 /// # Examples
 ///
 /// ```
+/// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
 /// use ripple_address_codec::{encode_seed, Secp256k1, Ed25519};
 ///
 /// // In the real world you **must** generate random entropy
 /// let naive_entropy = [0; 16];
 ///
-/// assert_eq!(encode_seed(&naive_entropy, &Secp256k1), "sp6JS7f14BuwFY8Mw6bTtLKWauoUs");
-/// assert_eq!(encode_seed(&naive_entropy, &Ed25519), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// assert_eq!(encode_seed(&naive_entropy, Secp256k1), "sp6JS7f14BuwFY8Mw6bTtLKWauoUs");
+/// assert_eq!(encode_seed(&naive_entropy, Ed25519), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// # }
 /// ```
-pub fn encode_seed(entropy: &Entropy, algorithm: &Algorithm) -> String {
-    let prefix = match algorithm {
-        Secp256k1 => SeedSecP256K1.prefix(),
-        Ed25519 => SeedEd25519.prefix(),
-    };
+pub fn encode_seed(entropy: &Entropy, algorithm: Algorithm) -> String {
+    encode_bytes_with_prefix(algorithm.seed_prefix(), entropy)
+}
+
+/// Encode a seed into `out` without allocating, returning the encoded length
+///
+/// Seeds are at most [`SEED_MAX_LEN`] characters long.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{encode_seed_into, Ed25519, SEED_MAX_LEN};
+///
+/// let mut out = [0; SEED_MAX_LEN];
+/// let len = encode_seed_into(&[0; 16], Ed25519, &mut out).unwrap();
+///
+/// assert_eq!(&out[..len], b"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] if `out` is too small, leaving its
+/// contents unspecified.
+pub fn encode_seed_into(entropy: &Entropy, algorithm: Algorithm, out: &mut [u8]) -> Result<usize> {
+    encode_bytes_with_prefix_into(algorithm.seed_prefix(), entropy, out)
+}
+
+/// Encode entropy from a slice as a seed, checking its length at runtime
+///
+/// Same as [`encode_seed`], for entropy read from the wire or a
+/// database.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{encode_seed_slice, Ed25519, Error};
+///
+/// assert_eq!(
+///     encode_seed_slice(&[0; 16], Ed25519),
+///     Ok("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".to_string())
+/// );
+/// assert_eq!(
+///     encode_seed_slice(&[0; 20], Ed25519),
+///     Err(Error::BadLength { expected: 16, actual: 20 })
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] if `entropy` isn't 16 bytes long.
+pub fn encode_seed_slice(entropy: &[u8], algorithm: Algorithm) -> Result<String> {
+    verify_payload_len(entropy, ENTROPY_LEN)?;
 
-    encode_bytes_with_prefix(prefix, entropy)
+    Ok(encode_bytes_with_prefix(algorithm.seed_prefix(), entropy))
 }
 
 /// Decode a seed into a tuple with seed's entropy bytes and algorithm
@@ -90,17 +491,149 @@ pub fn encode_seed(entropy: &Entropy, algorithm: &Algorithm) -> String {
 /// # Examples
 ///
 /// ```
+/// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
 /// use ripple_address_codec::{decode_seed, Secp256k1, Ed25519};
 ///
-/// assert_eq!(decode_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"), Ok(([0; 16], &Secp256k1)));
-/// assert_eq!(decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"), Ok(([0; 16], &Ed25519)));
+/// assert_eq!(decode_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"), Ok(([0; 16], Secp256k1)));
+/// assert_eq!(decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"), Ok(([0; 16], Ed25519)));
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why seed is invalid.
+///
+/// # Timing
+///
+/// The checksum and version prefix are compared in constant time, so
+/// how long decoding takes doesn't reveal how many of their bytes
+/// match. Base58 conversion isn't constant time: its running time
+/// depends on the length of the seed and number of leading zeros.
+pub fn decode_seed(seed: &str) -> Result<(Entropy, Algorithm)> {
+    trace::decoded("seed", seed, decode_seed_untraced(seed))
+}
+
+/// [`decode_seed`] without a failure event, for callers trying several
+/// token types
+fn decode_seed_untraced(seed: &str) -> Result<(Entropy, Algorithm)> {
+    with_decoded_bytes(seed, |decoded_bytes| {
+        decode_seed_from_decoded(sha256::DEFAULT, decoded_bytes)
+    })
+}
+
+/// Decode a secp256k1 seed to its entropy bytes
+///
+/// Available with the `seed-secp256k1` feature, on by default.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{decode_secp256k1_seed, Ed25519, Error, Secp256k1};
+///
+/// assert_eq!(decode_secp256k1_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"), Ok([0; 16]));
+/// assert_eq!(
+///     decode_secp256k1_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+///     Err(Error::WrongAlgorithm { expected: Secp256k1, actual: Ed25519 })
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_seed`], and [`Error::WrongAlgorithm`] for a valid
+/// Ed25519 seed.
+#[cfg(feature = "seed-secp256k1")]
+pub fn decode_secp256k1_seed(seed: &str) -> Result<Entropy> {
+    decode_seed_of(seed, Algorithm::Secp256k1)
+}
+
+/// Decode an Ed25519 seed to its entropy bytes
+///
+/// Available with the `seed-ed25519` feature, on by default.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{decode_ed25519_seed, Ed25519, Error, Secp256k1};
+///
+/// assert_eq!(decode_ed25519_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"), Ok([0; 16]));
+/// assert_eq!(
+///     decode_ed25519_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"),
+///     Err(Error::WrongAlgorithm { expected: Ed25519, actual: Secp256k1 })
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_seed`], and [`Error::WrongAlgorithm`] for a valid
+/// secp256k1 seed.
+#[cfg(feature = "seed-ed25519")]
+pub fn decode_ed25519_seed(seed: &str) -> Result<Entropy> {
+    decode_seed_of(seed, Algorithm::Ed25519)
+}
+
+/// Decode a seed, rejecting seeds of other algorithms than `expected`
+fn decode_seed_of(seed: &str, expected: Algorithm) -> Result<Entropy> {
+    let decoded = decode_seed_untraced(seed).and_then(|(mut entropy, actual)| {
+        if actual != expected {
+            wipe(&mut entropy);
+            return Err(Error::WrongAlgorithm { expected, actual });
+        }
+
+        Ok(entropy)
+    });
+
+    trace::decoded(TokenType::seed(expected).type_name(), seed, decoded)
+}
+
+/// Check whether a string is a valid seed of either algorithm
+///
+/// Rejects strings too long to be a seed before decoding, and
+/// otherwise agrees with [`decode_seed`] without allocating.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+/// use ripple_address_codec::is_valid_seed;
+///
+/// assert!(is_valid_seed("sp6JS7f14BuwFY8Mw6bTtLKWauoUs"));
+/// assert!(is_valid_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"));
+/// assert!(!is_valid_seed("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// # }
+/// ```
+pub fn is_valid_seed(seed: &str) -> bool {
+    fits_seed(seed) && decode_seed(seed).is_ok()
+}
+
+/// Decode and re-encode a seed, returning its canonical string form
+///
+/// Use it before storing seeds keyed on their string form, so
+/// equivalent inputs always map to the same string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::normalize_seed;
+///
+/// assert_eq!(
+///     normalize_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+///     Ok("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".to_string())
+/// );
+/// # }
 /// ```
 ///
 /// # Errors
 ///
-/// Returns [`DecodeError`] if seed is invalid.
-pub fn decode_seed(seed: &str) -> Result<(Entropy, &'static Algorithm)> {
-    decode_seed_secp256k1(seed).or(decode_seed_ed25519(seed))
+/// Same as [`decode_seed`].
+pub fn normalize_seed(seed: &str) -> Result<String> {
+    let (entropy, algorithm) = decode_seed(seed)?;
+
+    Ok(encode_seed(&entropy, algorithm))
 }
 
 /// Encode bytes as a classic address (starting with r...)
@@ -113,7 +646,59 @@ pub fn decode_seed(seed: &str) -> Result<(Entropy, &'static Algorithm)> {
 /// assert_eq!(encode_account_id(&[0; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
 /// ```
 pub fn encode_account_id(bytes: &[u8; Address::PAYLOAD_LEN]) -> String {
-    encode_bytes_with_prefix(Address.prefix(), bytes)
+    ADDRESS.encode(bytes)
+}
+
+/// Encode bytes as a classic address into `out` without allocating,
+/// returning the encoded length
+///
+/// Classic addresses are at most [`ACCOUNT_ID_MAX_LEN`] characters long.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{encode_account_id_into, ACCOUNT_ID_MAX_LEN};
+///
+/// let mut out = [0; ACCOUNT_ID_MAX_LEN];
+/// let len = encode_account_id_into(&[0; 20], &mut out).unwrap();
+///
+/// assert_eq!(&out[..len], b"rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] if `out` is too small, leaving its
+/// contents unspecified.
+pub fn encode_account_id_into(bytes: &[u8; Address::PAYLOAD_LEN], out: &mut [u8]) -> Result<usize> {
+    ADDRESS.encode_into(bytes, out)
+}
+
+/// Encode bytes from a slice as a classic address, checking their length
+/// at runtime
+///
+/// Same as [`encode_account_id`], for account ids read from the wire or
+/// a database.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{encode_account_id_slice, Error};
+///
+/// assert_eq!(
+///     encode_account_id_slice(&[0; 20]),
+///     Ok("rrrrrrrrrrrrrrrrrrrrrhoLvTp".to_string())
+/// );
+/// assert_eq!(
+///     encode_account_id_slice(&[0; 16]),
+///     Err(Error::BadLength { expected: 20, actual: 16 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] if `bytes` isn't 20 bytes long.
+pub fn encode_account_id_slice(bytes: &[u8]) -> Result<String> {
+    Ok(ADDRESS.encode(&to_array(bytes)?))
 }
 
 /// Decode a classic address (starting with r...) to its raw bytes
@@ -128,27 +713,598 @@ pub fn encode_account_id(bytes: &[u8; Address::PAYLOAD_LEN]) -> String {
 ///
 /// # Errors
 ///
-/// Returns [`DecodeError`] if account id string is invalid.
+/// Returns an [`Error`] describing why account id string is invalid.
 pub fn decode_account_id(account_id: &str) -> Result<[u8; Address::PAYLOAD_LEN]> {
-    let decoded_bytes = decode_with_xrp_alphabet(account_id)?;
+    trace::decoded("account_id", account_id, ADDRESS.decode(account_id))
+}
+
+/// Decode and re-encode a classic address, returning its canonical string form
+///
+/// Use it before storing addresses keyed on their string form, so
+/// equivalent inputs always map to the same string.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::normalize_account_id;
+///
+/// assert_eq!(
+///     normalize_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+///     Ok("rrrrrrrrrrrrrrrrrrrrrhoLvTp".to_string())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_account_id`].
+pub fn normalize_account_id(account_id: &str) -> Result<String> {
+    Ok(encode_account_id(&decode_account_id(account_id)?))
+}
+
+/// Check whether a string is a valid classic address
+///
+/// Rejects strings too long to be an address before decoding, and
+/// otherwise agrees with [`decode_account_id`] without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::is_valid_account_id;
+///
+/// assert!(is_valid_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// assert!(!is_valid_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTr"));
+/// ```
+pub fn is_valid_account_id(account_id: &str) -> bool {
+    ADDRESS.fits(account_id) && decode_account_id(account_id).is_ok()
+}
 
-    let payload = get_payload(decoded_bytes, Address)?;
+/// Encode a node public key (starting with n...)
+///
+/// The key is a 33 bytes compressed secp256k1 public key,
+/// as used to identify validators and peers.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_node_public_key;
+///
+/// let mut key = [0; 33];
+/// key[0] = 0x02;
+///
+/// assert!(encode_node_public_key(&key).starts_with('n'));
+/// ```
+pub fn encode_node_public_key(bytes: &[u8; NodePublicKey::PAYLOAD_LEN]) -> String {
+    NODE_PUBLIC_KEY.encode(bytes)
+}
 
-    Ok(payload.try_into().unwrap())
+/// Decode a node public key (starting with n...) to its raw bytes
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_node_public_key;
+///
+/// let key = decode_node_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH").unwrap();
+///
+/// assert_eq!(key[0], 0x03);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why node public key string is invalid.
+pub fn decode_node_public_key(node_public_key: &str) -> Result<[u8; NodePublicKey::PAYLOAD_LEN]> {
+    trace::decoded(
+        "node_public_key",
+        node_public_key,
+        NODE_PUBLIC_KEY.decode(node_public_key),
+    )
 }
 
-trait Settings {
+/// Encode an account public key (starting with a...)
+///
+/// The key is 33 bytes: a compressed secp256k1 public key or an
+/// Ed25519 public key prefixed with `0xED`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_account_public_key;
+///
+/// let mut key = [0; 33];
+/// key[0] = 0xED;
+///
+/// assert!(encode_account_public_key(&key).starts_with('a'));
+/// ```
+pub fn encode_account_public_key(bytes: &[u8; AccountPublicKey::PAYLOAD_LEN]) -> String {
+    ACCOUNT_PUBLIC_KEY.encode(bytes)
+}
+
+/// Decode an account public key (starting with a...) to its raw bytes
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_account_public_key;
+///
+/// let key =
+///     decode_account_public_key("aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3").unwrap();
+///
+/// assert_eq!(key[0], 0x02);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why account public key string is invalid.
+pub fn decode_account_public_key(
+    account_public_key: &str,
+) -> Result<[u8; AccountPublicKey::PAYLOAD_LEN]> {
+    trace::decoded(
+        "account_public_key",
+        account_public_key,
+        ACCOUNT_PUBLIC_KEY.decode(account_public_key),
+    )
+}
+
+/// Account id of an account public key
+///
+/// The account id is the RIPEMD-160 of the SHA-256 of the 33 bytes key,
+/// a compressed secp256k1 public key or an Ed25519 public key prefixed
+/// with `0xED`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{account_id_from_public_key, encode_account_id};
+///
+/// let key = hex::decode("0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020").unwrap();
+/// let account_id = account_id_from_public_key(&key).unwrap();
+///
+/// assert_eq!(encode_account_id(&account_id), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] if the key isn't 33 bytes long and
+/// [`Error::InvalidKey`] if it doesn't start with `0x02`, `0x03` or `0xED`.
+pub fn account_id_from_public_key(public_key: &[u8]) -> Result<[u8; Address::PAYLOAD_LEN]> {
+    use ripemd::{Digest, Ripemd160};
+
+    if public_key.len() != AccountPublicKey::PAYLOAD_LEN {
+        return Err(Error::BadLength {
+            expected: AccountPublicKey::PAYLOAD_LEN,
+            actual: public_key.len(),
+        });
+    }
+
+    if !matches!(public_key[0], 0x02 | 0x03 | 0xED) {
+        return Err(Error::InvalidKey);
+    }
+
+    let hash = Ripemd160::digest(sha256::DEFAULT.digest(public_key));
+
+    Ok(hash.into())
+}
+
+/// Classic address (starting with r...) of an account public key
+///
+/// Like [`account_id_from_public_key`] followed by [`encode_account_id`].
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_address_from_public_key;
+///
+/// let key = hex::decode("ED9434799226374926EDA3B54B1B461B4ABF7237962EAE18528FEA67595397FA32").unwrap();
+///
+/// assert_eq!(
+///     encode_address_from_public_key(&key),
+///     Ok("rDTXLQ7ZKZVKz33zJbHjgVShjsBnqMBhmN".to_owned())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] if the key isn't 33 bytes long and
+/// [`Error::InvalidKey`] if it doesn't start with `0x02`, `0x03` or `0xED`.
+pub fn encode_address_from_public_key(public_key: &[u8]) -> Result<String> {
+    account_id_from_public_key(public_key).map(|account_id| encode_account_id(&account_id))
+}
+
+/// Encode a node private key (starting with p...)
+///
+/// The key is the 32 bytes secp256k1 secret key of a validator or peer.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_node_private_key;
+///
+/// assert_eq!(
+///     encode_node_private_key(&[0; 32]),
+///     "pnUFeR4pGceEKEb2rYiyXGB8Je6yoXypBTGSoorgoyDQ8xSeCAx"
+/// );
+/// ```
+pub fn encode_node_private_key(bytes: &[u8; NodePrivateKey::PAYLOAD_LEN]) -> String {
+    NODE_PRIVATE_KEY.encode(bytes)
+}
+
+/// Decode a node private key (starting with p...) to its raw bytes
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_node_private_key;
+///
+/// assert_eq!(
+///     decode_node_private_key("pnUFeR4pGceEKEb2rYiyXGB8Je6yoXypBTGSoorgoyDQ8xSeCAx"),
+///     Ok([0; 32])
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why node private key string is invalid.
+pub fn decode_node_private_key(
+    node_private_key: &str,
+) -> Result<[u8; NodePrivateKey::PAYLOAD_LEN]> {
+    trace::decoded(
+        "node_private_key",
+        node_private_key,
+        NODE_PRIVATE_KEY.decode(node_private_key),
+    )
+}
+
+/// Encode an account private key (starting with p...)
+///
+/// The key is the 32 bytes secret key of an account.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_account_private_key;
+///
+/// assert_eq!(
+///     encode_account_private_key(&[0; 32]),
+///     "p9aJztGnyTz62tkyF5ayRuPL31ARaAemkjQCJaP7oHNB8BaPhbg"
+/// );
+/// ```
+pub fn encode_account_private_key(bytes: &[u8; AccountPrivateKey::PAYLOAD_LEN]) -> String {
+    ACCOUNT_PRIVATE_KEY.encode(bytes)
+}
+
+/// Decode an account private key (starting with p...) to its raw bytes
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_account_private_key;
+///
+/// assert_eq!(
+///     decode_account_private_key("p9aJztGnyTz62tkyF5ayRuPL31ARaAemkjQCJaP7oHNB8BaPhbg"),
+///     Ok([0; 32])
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why account private key string is invalid.
+pub fn decode_account_private_key(
+    account_private_key: &str,
+) -> Result<[u8; AccountPrivateKey::PAYLOAD_LEN]> {
+    trace::decoded(
+        "account_private_key",
+        account_private_key,
+        ACCOUNT_PRIVATE_KEY.decode(account_private_key),
+    )
+}
+
+/// Encode a family generator (starting with f...)
+///
+/// Family generators are the 33 bytes compressed secp256k1 root public
+/// generators exported by old-style rippled wallets.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_family_generator;
+///
+/// let mut generator = [0; 33];
+/// generator[0] = 0x02;
+///
+/// assert_eq!(
+///     encode_family_generator(&generator),
+///     "fhqjvyN8ErQzQVBCnvLiesXwkeTyCTXVRgFVwfWEvcDUKZSZBkAz"
+/// );
+/// ```
+pub fn encode_family_generator(bytes: &[u8; FamilyGenerator::PAYLOAD_LEN]) -> String {
+    FAMILY_GENERATOR.encode(bytes)
+}
+
+/// Decode a family generator (starting with f...) to its raw bytes
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_family_generator;
+///
+/// let generator =
+///     decode_family_generator("fhqjvyN8ErQzQVBCnvLiesXwkeTyCTXVRgFVwfWEvcDUKZSZBkAz").unwrap();
+///
+/// assert_eq!(generator[0], 0x02);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why family generator string is invalid.
+pub fn decode_family_generator(
+    family_generator: &str,
+) -> Result<[u8; FamilyGenerator::PAYLOAD_LEN]> {
+    trace::decoded(
+        "family_generator",
+        family_generator,
+        FAMILY_GENERATOR.decode(family_generator),
+    )
+}
+
+/// Encode a payload as token type `T`
+///
+/// # Examples
+///
+/// See [`VersionedToken`].
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] if `payload` length is not one of
+/// `T::PAYLOAD_LENS`, expecting the nearest of them, or 0 if there are
+/// none.
+pub fn encode_token<T: VersionedToken>(payload: &[u8]) -> Result<String> {
+    if !T::PAYLOAD_LENS.contains(&payload.len()) {
+        return Err(Error::BadLength {
+            expected: nearest_len(T::PAYLOAD_LENS, payload.len()),
+            actual: payload.len(),
+        });
+    }
+
+    Ok(encode_versioned(payload, T::PREFIX))
+}
+
+/// Decode a token of type `T` to its payload
+///
+/// The payload may have any of `T::PAYLOAD_LENS` lengths; check
+/// its `len()` to tell which one was found.
+///
+/// # Examples
+///
+/// See [`VersionedToken`].
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why the string is not a valid
+/// token of type `T`.
+pub fn decode_token<T: VersionedToken>(s: &str) -> Result<Vec<u8>> {
+    let max_len = T::PAYLOAD_LENS
+        .iter()
+        .map(|&payload_len| max_token_len(T::PREFIX, payload_len))
+        .max()
+        .unwrap_or_default();
+    let decoded_bytes = decode_with_xrp_alphabet(s, max_len)?;
+
+    first_match(T::PAYLOAD_LENS.iter().map(|&payload_len| {
+        get_payload_with_prefix(sha256::DEFAULT, &decoded_bytes, T::PREFIX, payload_len)
+            .map(<[u8]>::to_vec)
+    }))
+}
+
+/// Upper bound of the base58 encoded length of `payload_len` bytes
+///
+/// For a token, count its version bytes, payload and
+/// [`CHECKSUM_LENGTH`] checksum bytes. It is a `const fn`, so it sizes
+/// stack buffers for the `_into` encoders at compile time; the bounds
+/// of the XRP Ledger tokens are [`ACCOUNT_ID_MAX_LEN`],
+/// [`SEED_MAX_LEN`], [`XADDRESS_MAX_LEN`] and friends.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{encode_account_id_into, max_encoded_len, CHECKSUM_LENGTH};
+///
+/// const VERSION_LEN: usize = 1;
+///
+/// let mut out = [0; max_encoded_len(VERSION_LEN + 20 + CHECKSUM_LENGTH)];
+/// let len = encode_account_id_into(&[0; 20], &mut out).unwrap();
+///
+/// assert_eq!(&out[..len], b"rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+pub const fn max_encoded_len(payload_len: usize) -> usize {
+    base58::max_encoded_len(payload_len)
+}
+
+/// Upper bound of the encoded length of a token
+const fn max_token_len(prefix: &[u8], payload_len: usize) -> usize {
+    max_encoded_len(prefix.len() + payload_len + CHECKSUM_LENGTH)
+}
+
+/// Encode bytes as base58 with the XRPL alphabet, without version
+/// bytes or checksum
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_raw;
+///
+/// assert_eq!(encode_raw(&[0, 0, 1]), "rrp");
+/// ```
+pub fn encode_raw(bytes: &[u8]) -> String {
+    base58::encode(&Alphabet::XRPL, bytes)
+}
+
+/// Decode a base58 string with the XRPL alphabet to its bytes,
+/// without checking version bytes or checksum
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_raw;
+///
+/// assert_eq!(decode_raw("rrp"), Ok(vec![0, 0, 1]));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadAlphabet`] if the string contains characters
+/// outside the alphabet, [`Error::Confusable`] if the first of them is a
+/// lookalike or invisible character.
+///
+/// Unlike the token decoders, it accepts input of any length, and
+/// decoding takes time quadratic in it: bound the length of untrusted
+/// input before calling it.
+pub fn decode_raw(s: &str) -> Result<Vec<u8>> {
+    decode_with_alphabet(&Alphabet::XRPL, s)
+}
+
+/// Encode a payload with caller-supplied version bytes
+///
+/// Same framing as the other encoders (version bytes, payload,
+/// 4 bytes checksum), for token types this crate doesn't know about.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::encode_versioned;
+///
+/// assert_eq!(
+///     encode_versioned(&[0; 20], &[0x00]),
+///     "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+/// );
+/// ```
+pub fn encode_versioned(payload: &[u8], version: &[u8]) -> String {
+    encode_bytes_with_prefix(version, payload)
+}
+
+/// Decode a string encoded with caller-supplied version bytes
+/// to its payload
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_versioned;
+///
+/// assert_eq!(
+///     decode_versioned("rrrrrrrrrrrrrrrrrrrrrhoLvTp", &[0x00], 20),
+///     Ok(vec![0; 20])
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why the string is invalid, doesn't
+/// start with `version` or its payload is not `expected_len` bytes long.
+pub fn decode_versioned(s: &str, version: &[u8], expected_len: usize) -> Result<Vec<u8>> {
+    let decoded_bytes = decode_with_xrp_alphabet(s, max_token_len(version, expected_len))?;
+
+    get_payload_with_custom_prefix(sha256::DEFAULT, &decoded_bytes, version, &[], expected_len)
+        .map(<[u8]>::to_vec)
+}
+
+/// Decode a string encoded with any of the caller-supplied version
+/// bytes to a tuple with the index of the matching version and payload
+///
+/// Versions are tried in order, the first match wins.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::decode_multi_versioned;
+///
+/// let seed_versions: [&[u8]; 2] = [&[0x21], &[0x01, 0xE1, 0x4B]];
+///
+/// assert_eq!(
+///     decode_multi_versioned("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE", &seed_versions, 16),
+///     Ok((1, vec![0; 16]))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] describing why the string is invalid, doesn't
+/// start with any of `versions` or its payload is not `expected_len`
+/// bytes long.
+pub fn decode_multi_versioned(
+    s: &str,
+    versions: &[&[u8]],
+    expected_len: usize,
+) -> Result<(usize, Vec<u8>)> {
+    let max_len = versions
+        .iter()
+        .map(|version| max_token_len(version, expected_len))
+        .max()
+        .unwrap_or_default();
+    let decoded_bytes = decode_with_xrp_alphabet(s, max_len)?;
+
+    first_match(versions.iter().enumerate().map(|(index, version)| {
+        get_payload_with_custom_prefix(sha256::DEFAULT, &decoded_bytes, version, &[], expected_len)
+            .map(|payload| (index, payload.to_vec()))
+    }))
+}
+
+/// Version bytes and payload length of a token type
+///
+/// Implement it to plug a custom token type into [`encode_token`]
+/// and [`decode_token`]. Token types accepting several payload
+/// lengths list them in [`PAYLOAD_LENS`](VersionedToken::PAYLOAD_LENS).
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_token, encode_token, VersionedToken};
+///
+/// struct Ticket;
+///
+/// impl VersionedToken for Ticket {
+///     const PREFIX: &'static [u8] = &[0x12, 0x34];
+///     const PAYLOAD_LEN: usize = 8;
+/// }
+///
+/// let encoded = encode_token::<Ticket>(&[7; 8]).unwrap();
+///
+/// assert_eq!(decode_token::<Ticket>(&encoded), Ok(vec![7; 8]));
+/// ```
+pub trait VersionedToken {
+    /// Payload length in bytes
     const PAYLOAD_LEN: usize;
+    /// All accepted payload lengths, just `PAYLOAD_LEN` by default
+    ///
+    /// If it's empty, [`encode_token`] and [`decode_token`] reject every
+    /// payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{decode_token, encode_token, VersionedToken};
+    ///
+    /// struct KeyMaterial;
+    ///
+    /// impl VersionedToken for KeyMaterial {
+    ///     const PREFIX: &'static [u8] = &[0x42];
+    ///     const PAYLOAD_LEN: usize = 33;
+    ///     const PAYLOAD_LENS: &'static [usize] = &[32, 33];
+    /// }
+    ///
+    /// let encoded = encode_token::<KeyMaterial>(&[7; 32]).unwrap();
+    ///
+    /// assert_eq!(decode_token::<KeyMaterial>(&encoded).unwrap().len(), 32);
+    /// assert!(encode_token::<KeyMaterial>(&[7; 20]).is_err());
+    /// ```
+    const PAYLOAD_LENS: &'static [usize] = &[Self::PAYLOAD_LEN];
+    /// Version bytes prepended to the payload
     const PREFIX: &'static [u8] = &[];
 
+    /// Version bytes prepended to the payload
     fn prefix(&self) -> &'static [u8] {
         Self::PREFIX
     }
 
-    fn prefix_len(&self) -> usize {
-        Self::PREFIX.len()
-    }
-
+    /// Payload length in bytes
     fn payload_len(&self) -> usize {
         Self::PAYLOAD_LEN
     }
@@ -156,103 +1312,424 @@ trait Settings {
 
 struct Address;
 
-impl Settings for Address {
+impl VersionedToken for Address {
     const PREFIX: &'static [u8] = &[0x00];
     const PAYLOAD_LEN: usize = 20;
 }
 
-struct SeedSecP256K1;
+struct NodePublicKey;
+
+impl VersionedToken for NodePublicKey {
+    const PREFIX: &'static [u8] = &[0x1C];
+    const PAYLOAD_LEN: usize = 33;
+}
+
+struct AccountPublicKey;
+
+impl VersionedToken for AccountPublicKey {
+    const PREFIX: &'static [u8] = &[0x23];
+    const PAYLOAD_LEN: usize = 33;
+}
+
+struct NodePrivateKey;
 
-impl SeedSecP256K1 {
-    const ALG: Algorithm = Secp256k1;
+impl VersionedToken for NodePrivateKey {
+    const PREFIX: &'static [u8] = &[0x20];
+    const PAYLOAD_LEN: usize = 32;
 }
 
-impl Settings for SeedSecP256K1 {
+struct AccountPrivateKey;
+
+impl VersionedToken for AccountPrivateKey {
+    const PREFIX: &'static [u8] = &[0x22];
+    const PAYLOAD_LEN: usize = 32;
+}
+
+struct FamilyGenerator;
+
+impl VersionedToken for FamilyGenerator {
+    const PREFIX: &'static [u8] = &[0x29];
+    const PAYLOAD_LEN: usize = 33;
+}
+
+struct SeedSecP256K1;
+
+impl VersionedToken for SeedSecP256K1 {
     const PREFIX: &'static [u8] = &[0x21];
     const PAYLOAD_LEN: usize = ENTROPY_LEN;
 }
 
 struct SeedEd25519;
 
-impl SeedEd25519 {
-    const ALG: Algorithm = Ed25519;
-}
-
-impl Settings for SeedEd25519 {
+impl VersionedToken for SeedEd25519 {
     const PREFIX: &'static [u8] = &[0x01, 0xE1, 0x4B];
     const PAYLOAD_LEN: usize = ENTROPY_LEN;
 }
 
-fn decode_seed_secp256k1(s: &str) -> Result<(Entropy, &'static Algorithm)> {
-    let decoded_bytes = decode_with_xrp_alphabet(s)?;
+/// Encoding of a token type with a fixed `PAYLOAD` bytes long payload
+///
+/// The payload length is part of the type, so payloads are arrays of
+/// the right length at compile time and decoding checks the length once,
+/// when converting to the array. Adding a token type takes its
+/// [`VersionedToken`] marker and a `Token` constant.
+#[derive(Clone, Copy)]
+struct Token<const PAYLOAD: usize> {
+    prefix: &'static [u8],
+}
+
+impl<const PAYLOAD: usize> Token<PAYLOAD> {
+    const fn new(prefix: &'static [u8]) -> Self {
+        Token { prefix }
+    }
+
+    fn encode(self, payload: &[u8; PAYLOAD]) -> String {
+        encode_bytes_with_prefix(self.prefix, payload)
+    }
+
+    fn encode_into(self, payload: &[u8; PAYLOAD], out: &mut [u8]) -> Result<usize> {
+        encode_bytes_with_prefix_into(self.prefix, payload, out)
+    }
+
+    fn decode(self, s: &str) -> Result<[u8; PAYLOAD]> {
+        with_decoded_bytes(s, |decoded_bytes| {
+            self.decode_bytes(sha256::DEFAULT, decoded_bytes)
+        })
+    }
+
+    /// Verify the checksum and prefix of base58 decoded bytes and return
+    /// the payload
+    fn decode_bytes(self, sha256: &dyn Sha256, decoded_bytes: &[u8]) -> Result<[u8; PAYLOAD]> {
+        let checked_bytes = get_checked_bytes(sha256, decoded_bytes)?;
+        let payload = checked_bytes
+            .strip_prefix(self.prefix)
+            .ok_or(Error::BadPrefix {
+                expected: self.prefix,
+            })?;
+
+        to_array(payload)
+    }
+
+    /// Whether `s` is short enough to encode a token of this type
+    ///
+    /// Valid tokens are ASCII, so comparing the byte length is exact
+    /// enough to skip decoding strings that can't possibly match.
+    fn fits(self, s: &str) -> bool {
+        s.len() <= base58::max_encoded_len(self.prefix.len() + PAYLOAD + CHECKSUM_LENGTH)
+    }
+}
+
+const ADDRESS: Token<{ Address::PAYLOAD_LEN }> = Token::new(Address::PREFIX);
+const NODE_PUBLIC_KEY: Token<{ NodePublicKey::PAYLOAD_LEN }> = Token::new(NodePublicKey::PREFIX);
+const ACCOUNT_PUBLIC_KEY: Token<{ AccountPublicKey::PAYLOAD_LEN }> =
+    Token::new(AccountPublicKey::PREFIX);
+const NODE_PRIVATE_KEY: Token<{ NodePrivateKey::PAYLOAD_LEN }> = Token::new(NodePrivateKey::PREFIX);
+const ACCOUNT_PRIVATE_KEY: Token<{ AccountPrivateKey::PAYLOAD_LEN }> =
+    Token::new(AccountPrivateKey::PREFIX);
+const FAMILY_GENERATOR: Token<{ FamilyGenerator::PAYLOAD_LEN }> =
+    Token::new(FamilyGenerator::PREFIX);
+
+/// Verify the checksum once, then pick the algorithm by version prefix
+///
+/// Both comparisons are constant time, see [`decode_seed`].
+fn decode_seed_from_decoded(
+    sha256: &dyn Sha256,
+    decoded_bytes: &[u8],
+) -> Result<(Entropy, Algorithm)> {
+    let checked_bytes = get_checked_bytes(sha256, decoded_bytes)?;
 
-    let payload = get_payload(decoded_bytes, SeedSecP256K1)?;
+    let (algorithm, payload) = REGISTRY
+        .iter()
+        .find_map(|registration| {
+            let payload = strip_prefix_ct(checked_bytes, registration.seed_prefix)?;
+            Some((registration.algorithm, payload))
+        })
+        .ok_or(Error::BadPrefix { expected: &[] })?;
 
-    Ok((payload.try_into().unwrap(), &SeedSecP256K1::ALG))
+    Ok((to_array(payload)?, algorithm))
 }
 
-fn decode_seed_ed25519(s: &str) -> Result<(Entropy, &'static Algorithm)> {
-    let decoded_bytes = decode_with_xrp_alphabet(s)?;
+/// [`slice::strip_prefix`] comparing all prefix bytes in constant time
+fn strip_prefix_ct<'a>(bytes: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if bytes.len() < prefix.len() {
+        return None;
+    }
 
-    let payload = get_payload(decoded_bytes, SeedEd25519)?;
+    let (head, tail) = bytes.split_at(prefix.len());
 
-    Ok((payload.try_into().unwrap(), &SeedEd25519::ALG))
+    if bool::from(head.ct_eq(prefix)) {
+        Some(tail)
+    } else {
+        None
+    }
 }
 
 fn encode_bytes_with_prefix(prefix: &[u8], bytes: &[u8]) -> String {
-    encode_bytes(&[prefix, bytes].concat())
+    encode_bytes_with_prefix_in(&Alphabet::XRPL, sha256::DEFAULT, prefix, bytes)
 }
 
-fn encode_bytes(bytes: &[u8]) -> String {
-    let checked_bytes = [bytes, &calc_checksum(bytes)].concat();
-    base_x::encode(ALPHABET, &checked_bytes)
+fn encode_bytes_with_prefix_in(
+    alphabet: &Alphabet,
+    sha256: &dyn Sha256,
+    prefix: &[u8],
+    bytes: &[u8],
+) -> String {
+    with_checked_bytes(sha256, prefix, bytes, |checked_bytes| {
+        base58::encode(alphabet, checked_bytes)
+    })
 }
 
-fn decode_with_xrp_alphabet(s: &str) -> Result<Vec<u8>> {
-    Ok(base_x::decode(ALPHABET, s)?)
+fn encode_bytes_with_prefix_into(prefix: &[u8], payload: &[u8], out: &mut [u8]) -> Result<usize> {
+    with_checked_bytes(sha256::DEFAULT, prefix, payload, |checked_bytes| {
+        base58::encode_into(&Alphabet::XRPL, checked_bytes, out)
+    })
 }
 
-fn get_payload(bytes: Vec<u8>, settings: impl Settings) -> Result<Vec<u8>> {
-    verify_payload_len(&bytes, settings.prefix_len(), settings.payload_len())?;
-    verify_prefix(settings.prefix(), &bytes)?;
-    let checked_bytes = get_checked_bytes(bytes)?;
+/// Pass prefix, payload and checksum to `f` as one slice, built on the stack
+///
+/// Payloads too long for any known token fall back to the heap.
+fn with_checked_bytes<T>(
+    sha256: &dyn Sha256,
+    prefix: &[u8],
+    payload: &[u8],
+    f: impl FnOnce(&[u8]) -> T,
+) -> T {
+    let len = prefix.len() + payload.len();
+    let mut stack = [0; MAX_TOKEN_LEN];
+    let mut heap;
+    let buffer: &mut [u8] = if len + CHECKSUM_LENGTH <= MAX_TOKEN_LEN {
+        &mut stack[..len + CHECKSUM_LENGTH]
+    } else {
+        heap = vec![0; len + CHECKSUM_LENGTH];
+        &mut heap
+    };
+
+    buffer[..prefix.len()].copy_from_slice(prefix);
+    buffer[prefix.len()..len].copy_from_slice(payload);
+    let checksum = sha256::checksum(sha256, &buffer[..len]);
+    buffer[len..].copy_from_slice(&checksum);
 
-    Ok(checked_bytes[settings.prefix_len()..].try_into().unwrap())
+    let result = f(buffer);
+    wipe(buffer);
+
+    result
 }
 
-fn verify_prefix(prefix: &[u8], bytes: &[u8]) -> Result<()> {
-    if bytes.starts_with(prefix) {
-        return Ok(());
+/// Decode a token with the XRPL alphabet, rejecting input longer than
+/// [`DEFAULT_MAX_INPUT_LEN`] or `max_token_len` if that's longer
+fn decode_with_xrp_alphabet(s: &str, max_token_len: usize) -> Result<Vec<u8>> {
+    check_input_len(s, DEFAULT_MAX_INPUT_LEN.max(max_token_len))?;
+    decode_with_alphabet(&Alphabet::XRPL, s)
+}
+
+fn decode_with_alphabet(alphabet: &Alphabet, s: &str) -> Result<Vec<u8>> {
+    alphabet.check(s)?;
+    base58::decode(alphabet, s)
+}
+
+fn with_decoded_bytes<T>(s: &str, f: impl FnOnce(&[u8]) -> Result<T>) -> Result<T> {
+    with_decoded_bytes_in(&Alphabet::XRPL, s, f)
+}
+
+/// Pass the decoded bytes of `s` to `f`, decoding on the stack
+///
+/// Strings longer than [`DEFAULT_MAX_INPUT_LEN`] are rejected. Shorter
+/// ones too long for any known token fall back to the heap, so they
+/// still fail with the same error as with [`decode_with_alphabet`].
+fn with_decoded_bytes_in<T>(
+    alphabet: &Alphabet,
+    s: &str,
+    f: impl FnOnce(&[u8]) -> Result<T>,
+) -> Result<T> {
+    check_input_len(s, DEFAULT_MAX_INPUT_LEN)?;
+    alphabet.check(s)?;
+    let mut buffer = [0; MAX_TOKEN_LEN];
+
+    let result = match base58::decode_into(alphabet, s, &mut buffer) {
+        Ok(len) => f(&buffer[..len]),
+        Err(Error::BufferTooSmall { .. }) => {
+            let mut decoded_bytes = decode_with_alphabet(alphabet, s)?;
+            let result = f(&decoded_bytes);
+            wipe(&mut decoded_bytes);
+            result
+        }
+        Err(error) => Err(error),
+    };
+    wipe(&mut buffer);
+
+    result
+}
+
+/// Reject `s` if it's longer than `max_len` bytes
+///
+/// Base58 decoding takes time quadratic in the input length, so it
+/// runs before anything else.
+fn check_input_len(s: &str, max_len: usize) -> Result<()> {
+    if s.len() > max_len {
+        return Err(Error::InputTooLong {
+            max_len,
+            actual: s.len(),
+        });
     }
 
-    Err(DecodeError)
+    Ok(())
+}
+
+/// Overwrite a buffer that may hold secret material with zeros
+///
+/// Only with the `zeroize` feature, otherwise it does nothing.
+#[cfg(feature = "zeroize")]
+fn wipe<Z: zeroize::Zeroize + ?Sized>(secret: &mut Z) {
+    secret.zeroize();
+}
+
+#[cfg(not(feature = "zeroize"))]
+fn wipe<Z: ?Sized>(_secret: &mut Z) {}
+
+/// Whether `s` is short enough to encode a seed of any algorithm
+fn fits_seed(s: &str) -> bool {
+    let prefix_len = REGISTRY
+        .iter()
+        .map(|registration| registration.seed_prefix.len())
+        .max()
+        .unwrap_or_default();
+
+    s.len() <= base58::max_encoded_len(prefix_len + ENTROPY_LEN + CHECKSUM_LENGTH)
 }
 
-fn verify_payload_len(bytes: &[u8], prefix_len: usize, expected_len: usize) -> Result<()> {
-    if bytes[prefix_len..bytes.len() - CHECKSUM_LENGTH].len() == expected_len {
+/// First successful decoding attempt, or the most specific error
+///
+/// Attempts failing with [`Error::BadPrefix`] or [`Error::BadLength`]
+/// are candidates that didn't match, so the next one is tried. Any
+/// other error is shared by all candidates and returned right away.
+/// Of several length mismatches, the one expecting the length nearest
+/// to the actual one is returned. Prefix mismatches expecting different
+/// prefixes are reported without an expected prefix.
+fn first_match<T>(attempts: impl IntoIterator<Item = Result<T>>) -> Result<T> {
+    let mut error = None;
+
+    for attempt in attempts {
+        match attempt {
+            Ok(value) => return Ok(value),
+            Err(Error::BadPrefix { expected }) => {
+                error = match error {
+                    None => Some(Error::BadPrefix { expected }),
+                    Some(Error::BadPrefix { expected: first }) if first != expected => {
+                        Some(Error::BadPrefix { expected: &[] })
+                    }
+                    other => other,
+                };
+            }
+            Err(Error::BadLength { expected, actual }) => {
+                let nearer = match error {
+                    Some(Error::BadLength {
+                        expected: nearest, ..
+                    }) => expected.abs_diff(actual) < nearest.abs_diff(actual),
+                    _ => true,
+                };
+                if nearer {
+                    error = Some(Error::BadLength { expected, actual });
+                }
+            }
+            Err(other) => return Err(other),
+        }
+    }
+
+    Err(error.unwrap_or(Error::BadPrefix { expected: &[] }))
+}
+
+/// The length in `lens` nearest to `len`, the first one of a tie
+///
+/// 0 if `lens` is empty, as for a [`VersionedToken`] accepting no
+/// payload length.
+fn nearest_len(lens: &[usize], len: usize) -> usize {
+    lens.iter()
+        .copied()
+        .min_by_key(|expected| expected.abs_diff(len))
+        .unwrap_or_default()
+}
+
+fn get_payload_with_prefix<'a>(
+    sha256: &dyn Sha256,
+    bytes: &'a [u8],
+    prefix: &'static [u8],
+    payload_len: usize,
+) -> Result<&'a [u8]> {
+    get_payload_with_custom_prefix(sha256, bytes, prefix, prefix, payload_len)
+}
+
+/// [`get_payload_with_prefix`] for a prefix that may not be `'static`,
+/// reporting `expected` on a mismatch
+fn get_payload_with_custom_prefix<'a>(
+    sha256: &dyn Sha256,
+    bytes: &'a [u8],
+    prefix: &[u8],
+    expected: &'static [u8],
+    payload_len: usize,
+) -> Result<&'a [u8]> {
+    let checked_bytes = get_checked_bytes(sha256, bytes)?;
+    let payload = checked_bytes
+        .strip_prefix(prefix)
+        .ok_or(Error::BadPrefix { expected })?;
+    verify_payload_len(payload, payload_len)?;
+
+    Ok(payload)
+}
+
+fn verify_payload_len(payload: &[u8], expected_len: usize) -> Result<()> {
+    if payload.len() == expected_len {
         return Ok(());
     }
 
-    Err(DecodeError)
+    Err(Error::BadLength {
+        expected: expected_len,
+        actual: payload.len(),
+    })
+}
+
+fn to_array<const N: usize>(payload: &[u8]) -> Result<[u8; N]> {
+    payload.try_into().map_err(|_| Error::BadLength {
+        expected: N,
+        actual: payload.len(),
+    })
 }
 
-fn get_checked_bytes(mut bytes_with_checksum: Vec<u8>) -> Result<Vec<u8>> {
-    verify_checksum_lenght(&bytes_with_checksum)?;
+fn get_checked_bytes<'a>(sha256: &dyn Sha256, bytes_with_checksum: &'a [u8]) -> Result<&'a [u8]> {
+    verify_checksum_lenght(bytes_with_checksum)?;
 
     //Split bytes with checksum to checked bytes and checksum
-    let checksum = bytes_with_checksum.split_off(bytes_with_checksum.len() - CHECKSUM_LENGTH);
-    let bytes = bytes_with_checksum;
+    let (bytes, checksum) =
+        bytes_with_checksum.split_at(bytes_with_checksum.len() - CHECKSUM_LENGTH);
 
-    verify_checksum(&bytes, &checksum)?;
+    verify_checksum_with(sha256, bytes, checksum)?;
 
     Ok(bytes)
 }
 
-fn verify_checksum(input: &[u8], checksum: &[u8]) -> Result<()> {
-    if calc_checksum(input) == checksum {
+/// Verify that `checksum` is the checksum of `input`
+///
+/// The comparison takes the same time wherever the checksums differ.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{calc_checksum, verify_checksum};
+///
+/// assert!(verify_checksum(b"123456789", &calc_checksum(b"123456789")).is_ok());
+/// assert!(verify_checksum(b"123456789", &[0; 4]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadChecksum`] if the checksum doesn't match.
+pub fn verify_checksum(input: &[u8], checksum: &[u8]) -> Result<()> {
+    verify_checksum_with(sha256::DEFAULT, input, checksum)
+}
+
+fn verify_checksum_with(sha256: &dyn Sha256, input: &[u8], checksum: &[u8]) -> Result<()> {
+    if bool::from(sha256::checksum(sha256, input)[..].ct_eq(checksum)) {
         Ok(())
     } else {
-        Err(DecodeError)
+        Err(Error::BadChecksum)
     }
 }
 
@@ -260,18 +1737,22 @@ fn verify_checksum_lenght(bytes: &[u8]) -> Result<()> {
     let len = bytes.len();
 
     if len < CHECKSUM_LENGTH + 1 {
-        return Err(DecodeError);
+        return Err(Error::BadChecksum);
     }
 
     Ok(())
 }
 
-fn calc_checksum(bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
-    sha256_digest(&sha256_digest(bytes))[..CHECKSUM_LENGTH]
-        .try_into()
-        .unwrap()
-}
-
-fn sha256_digest(data: &[u8]) -> Vec<u8> {
-    digest(&SHA256, data).as_ref().to_vec()
+/// Checksum used by all XRP Ledger base58 tokens: the first
+/// 4 bytes of the double SHA-256 of `bytes`
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::calc_checksum;
+///
+/// assert_eq!(calc_checksum(b"123456789"), [0x29, 0x2B, 0x0D, 0x00]);
+/// ```
+pub fn calc_checksum(bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    sha256::checksum(sha256::DEFAULT, bytes)
 }
@@ -0,0 +1,90 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use bip39::{Language, Mnemonic};
+
+use crate::{wipe, Algorithm, Entropy, Error, Result, Seed, ENTROPY_LEN};
+
+/// Encode a seed's entropy as a 12-word English BIP39 mnemonic
+///
+/// Available with the `bip39` feature. The algorithm isn't encoded.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{seed_to_bip39, Seed};
+///
+/// let seed: Seed = "sp6JS7f14BuwFY8Mw6bTtLKWauoUs".parse().unwrap();
+///
+/// assert_eq!(
+///     seed_to_bip39(&seed),
+///     "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+/// );
+/// ```
+pub fn seed_to_bip39(seed: &Seed) -> String {
+    let mut mnemonic = Mnemonic::from_entropy_in(Language::English, seed.entropy())
+        .expect("16 bytes are valid BIP39 entropy");
+
+    let words = mnemonic.to_string();
+    wipe(&mut mnemonic);
+
+    words
+}
+
+/// Decode a seed from a 12-word English BIP39 mnemonic
+///
+/// Available with the `bip39` feature. The inverse of [`seed_to_bip39`].
+/// Words are separated by whitespace and matched case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{bip39_to_seed, Ed25519};
+///
+/// let seed = bip39_to_seed(
+///     "legal winner thank year wave sausage worth useful legal winner thank yellow",
+///     Ed25519,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(seed.entropy(), &[0x7F; 16]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BadLength`] for a valid mnemonic of another entropy
+/// length than 16 bytes, e.g. of 24 words, [`Error::UnknownWord`] for a
+/// word outside the English wordlist, [`Error::BadChecksum`] if the
+/// checksum doesn't match and [`Error::DecodeError`] if the number of
+/// words isn't valid for BIP39.
+pub fn bip39_to_seed(mnemonic: &str, algorithm: Algorithm) -> Result<Seed> {
+    let mut normalized = mnemonic.to_lowercase();
+    let parsed = Mnemonic::parse_in_normalized(Language::English, &normalized);
+    wipe(&mut normalized);
+
+    let mut parsed = parsed.map_err(|error| match error {
+        bip39::Error::UnknownWord(index) => Error::UnknownWord { index },
+        bip39::Error::InvalidChecksum => Error::BadChecksum,
+        _ => Error::DecodeError,
+    })?;
+
+    let (mut bytes, len) = parsed.to_entropy_array();
+    wipe(&mut parsed);
+
+    if len != ENTROPY_LEN {
+        wipe(&mut bytes);
+        return Err(Error::BadLength {
+            expected: ENTROPY_LEN,
+            actual: len,
+        });
+    }
+
+    let mut entropy: Entropy = [0; ENTROPY_LEN];
+    entropy.copy_from_slice(&bytes[..ENTROPY_LEN]);
+    wipe(&mut bytes);
+
+    let seed = Seed::new(entropy, algorithm);
+    wipe(&mut entropy);
+
+    Ok(seed)
+}
@@ -0,0 +1,217 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::convert::TryInto;
+
+use crate::{
+    encode_bytes_with_prefix, first_match, get_payload_with_custom_prefix, sha256,
+    with_decoded_bytes,
+    xaddress::{decode_tag, payload, split_payload, XAddressMain, XAddressTest, PAYLOAD_LEN},
+    Address, Error, Result, Tag, VersionedToken,
+};
+
+/// Name of the built-in mainnet network
+const MAIN: &str = "main";
+/// Name of the built-in test network
+const TEST: &str = "test";
+
+/// X-address network registered under a name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Registration {
+    name: String,
+    prefix: [u8; 2],
+}
+
+/// X-address networks by name, for sidechains with their own prefixes
+///
+/// Sidechains reuse the X-address format with their own two version
+/// bytes. A registry starts with the XRP Ledger networks, `"main"` and
+/// `"test"`, and encodes and decodes X-addresses of any network
+/// registered with [`with_network`](NetworkRegistry::with_network).
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{NetworkRegistry, Tag};
+///
+/// let networks = NetworkRegistry::new().with_network("sidechain", [0x05, 0x45]);
+///
+/// let xaddress = networks.encode_xaddress(&[7; 20], Tag::U32(42), "sidechain").unwrap();
+///
+/// assert_eq!(
+///     networks.decode_xaddress(&xaddress),
+///     Ok(([7; 20], Tag::U32(42), "sidechain"))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NetworkRegistry {
+    networks: Vec<Registration>,
+}
+
+impl NetworkRegistry {
+    /// Registry of the XRP Ledger mainnet (`"main"`) and test (`"test"`)
+    /// networks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::NetworkRegistry;
+    ///
+    /// assert_eq!(NetworkRegistry::new().prefix("main"), Some([0x05, 0x44]));
+    /// ```
+    pub fn new() -> Self {
+        NetworkRegistry::empty()
+            .with_network(MAIN, to_prefix(XAddressMain::PREFIX))
+            .with_network(TEST, to_prefix(XAddressTest::PREFIX))
+    }
+
+    /// Registry without any network, not even the XRP Ledger ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::NetworkRegistry;
+    ///
+    /// assert_eq!(NetworkRegistry::empty().prefix("main"), None);
+    /// ```
+    pub fn empty() -> Self {
+        NetworkRegistry {
+            networks: Vec::new(),
+        }
+    }
+
+    /// Register the network `name` with version bytes `prefix`
+    ///
+    /// Replaces any network with the same name or prefix, so a prefix
+    /// always decodes to a single network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::NetworkRegistry;
+    ///
+    /// let networks = NetworkRegistry::new().with_network("devnet", [0x04, 0x93]);
+    ///
+    /// assert_eq!(networks.prefix("devnet"), Some([0x04, 0x93]));
+    /// assert_eq!(networks.prefix("test"), None);
+    /// ```
+    pub fn with_network(mut self, name: impl Into<String>, prefix: [u8; 2]) -> Self {
+        let name = name.into();
+        self.networks
+            .retain(|network| network.name != name && network.prefix != prefix);
+        self.networks.push(Registration { name, prefix });
+
+        self
+    }
+
+    /// Version bytes of the network `name`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::NetworkRegistry;
+    ///
+    /// assert_eq!(NetworkRegistry::new().prefix("test"), Some([0x04, 0x93]));
+    /// assert_eq!(NetworkRegistry::new().prefix("sidechain"), None);
+    /// ```
+    pub fn prefix(&self, name: &str) -> Option<[u8; 2]> {
+        self.networks
+            .iter()
+            .find(|network| network.name == name)
+            .map(|network| network.prefix)
+    }
+
+    /// Names of the registered networks, in registration order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::NetworkRegistry;
+    ///
+    /// let networks = NetworkRegistry::new().with_network("sidechain", [0x05, 0x45]);
+    ///
+    /// assert_eq!(networks.names().collect::<Vec<_>>(), ["main", "test", "sidechain"]);
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.networks.iter().map(|network| network.name.as_str())
+    }
+
+    /// Encode an account id and destination tag as an X-address of the
+    /// network `name`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{encode_xaddress, Network, NetworkRegistry, Tag};
+    ///
+    /// assert_eq!(
+    ///     NetworkRegistry::new().encode_xaddress(&[0; 20], Tag::None, "main"),
+    ///     Ok(encode_xaddress(&[0; 20], Tag::None, Network::Main))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DecodeError`] if no network is registered as
+    /// `name`.
+    pub fn encode_xaddress(
+        &self,
+        account_id: &[u8; Address::PAYLOAD_LEN],
+        tag: Tag,
+        name: &str,
+    ) -> Result<String> {
+        let prefix = self.prefix(name).ok_or(Error::DecodeError)?;
+
+        Ok(encode_bytes_with_prefix(&prefix, &payload(account_id, tag)))
+    }
+
+    /// Decode an X-address of any registered network into a tuple with
+    /// account id, destination tag and network name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{NetworkRegistry, Tag};
+    ///
+    /// assert_eq!(
+    ///     NetworkRegistry::new().decode_xaddress("X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"),
+    ///     Ok(([0; 20], Tag::None, "main"))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`decode_xaddress`](crate::decode_xaddress), with
+    /// [`Error::BadPrefix`] if its prefix isn't registered.
+    pub fn decode_xaddress(
+        &self,
+        xaddress: &str,
+    ) -> Result<([u8; Address::PAYLOAD_LEN], Tag, &str)> {
+        with_decoded_bytes(xaddress, |decoded_bytes| {
+            let (payload, name) = first_match(self.networks.iter().map(|network| {
+                get_payload_with_custom_prefix(
+                    sha256::DEFAULT,
+                    decoded_bytes,
+                    &network.prefix,
+                    &[],
+                    PAYLOAD_LEN,
+                )
+                .map(|payload| (payload, network.name.as_str()))
+            }))?;
+            let payload = payload.try_into().expect("payload of PAYLOAD_LEN bytes");
+            let (account_id, flag_and_tag) = split_payload(&payload);
+
+            Ok((account_id, decode_tag(flag_and_tag)?, name))
+        })
+    }
+}
+
+impl Default for NetworkRegistry {
+    fn default() -> Self {
+        NetworkRegistry::new()
+    }
+}
+
+fn to_prefix(prefix: &[u8]) -> [u8; 2] {
+    prefix.try_into().expect("X-address prefixes are 2 bytes")
+}
@@ -0,0 +1,110 @@
+use std::{convert::TryInto, fmt};
+
+use crate::{wipe, Algorithm, Entropy, Seed, ENTROPY_LEN};
+
+/// The warning of rippled's `wallet_propose` for passphrase seeds
+const WARNING: &str = "This wallet was generated using a user-supplied passphrase that has low \
+                       entropy and is vulnerable to brute-force attacks.";
+
+/// A value derived from a low entropy secret, such as a passphrase
+///
+/// Anyone who can guess the secret can derive the value, so it is
+/// wrapped until the caller explicitly accepts that with
+/// [`accept_low_entropy`](LowEntropy::accept_low_entropy), for instance
+/// to migrate a legacy account to a random seed.
+#[must_use = "a low entropy value is only usable after accepting it"]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LowEntropy<T>(T);
+
+impl<T> LowEntropy<T> {
+    /// The warning rippled gives with passphrase seeds, to show to users
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{passphrase_to_seed, Secp256k1};
+    ///
+    /// let seed = passphrase_to_seed("masterpassphrase", Secp256k1);
+    ///
+    /// assert!(seed.warning().contains("vulnerable to brute-force attacks"));
+    /// # }
+    /// ```
+    pub fn warning(&self) -> &'static str {
+        WARNING
+    }
+
+    /// The value, accepting that it can be derived by anyone who guesses
+    /// its secret
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{passphrase_to_seed, Secp256k1};
+    ///
+    /// let seed = passphrase_to_seed("masterpassphrase", Secp256k1).accept_low_entropy();
+    ///
+    /// assert_eq!(seed.expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+    /// # }
+    /// ```
+    pub fn accept_low_entropy(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LowEntropy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LowEntropy").field(&self.0).finish()
+    }
+}
+
+/// Derive a seed from a passphrase, like rippled's `wallet_propose`
+///
+/// The entropy is the first 16 bytes of the SHA-512 hash of the
+/// passphrase, as rippled's `generateSeed`; for the same passphrase
+/// both algorithms share it. Passphrases are easily guessed, so the seed
+/// comes wrapped in a [`LowEntropy`].
+///
+/// Available with the `ring` feature, enabled by default, or the `sha2`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+/// use ripple_address_codec::{passphrase_to_seed, Ed25519, Secp256k1};
+///
+/// let seed = passphrase_to_seed("masterpassphrase", Secp256k1).accept_low_entropy();
+/// let ed25519_seed = passphrase_to_seed("masterpassphrase", Ed25519).accept_low_entropy();
+///
+/// assert_eq!(seed.expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+/// assert_eq!(ed25519_seed.entropy(), seed.entropy());
+/// # }
+/// ```
+pub fn passphrase_to_seed(passphrase: &str, algorithm: Algorithm) -> LowEntropy<Seed> {
+    let mut hash = sha512(passphrase.as_bytes());
+    let mut entropy: Entropy = hash[..ENTROPY_LEN]
+        .try_into()
+        .expect("SHA-512 is longer than the entropy");
+    let seed = Seed::new(entropy, algorithm);
+    wipe(&mut hash);
+    wipe(&mut entropy);
+
+    LowEntropy(seed)
+}
+
+#[cfg(feature = "ring")]
+fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut hash = [0; 64];
+    hash.copy_from_slice(ring::digest::digest(&ring::digest::SHA512, data).as_ref());
+
+    hash
+}
+
+#[cfg(all(feature = "sha2", not(feature = "ring")))]
+fn sha512(data: &[u8]) -> [u8; 64] {
+    use sha2::Digest;
+
+    sha2::Sha512::digest(data).into()
+}
@@ -0,0 +1,440 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{
+    encode_xaddress, parse_account, xaddress::parse_tag, AccountId, Error, Network, Result, Tag,
+};
+
+/// URI schemes of payment requests, the first one is emitted
+const SCHEMES: [&str; 2] = ["xrpl", "ripple"];
+
+/// Length of a hex currency code, in characters
+const HEX_CURRENCY_LEN: usize = 40;
+
+/// A payment request, as encoded in `xrpl:` payment URIs, e.g. in QR
+/// codes
+///
+/// The URI is the destination, a classic address or an X-address,
+/// followed by optional query parameters:
+///
+/// * `dt`: the destination tag, decimal
+/// * `amount`: a decimal amount, in XRP unless a currency is given
+/// * `currency`: 3 ASCII letters or digits other than `XRP`, or 40 hex
+///   digits
+/// * `issuer`: the classic address of the currency issuer, only with
+///   `currency`
+///
+/// The `ripple:` scheme is accepted too. Unknown parameters are ignored,
+/// unless their name starts with `req-`, which marks them as required.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{PaymentRequest, Tag};
+///
+/// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=12345&amount=2.5"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(request.destination().to_string(), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+/// assert_eq!(request.tag(), Tag::U32(12345));
+/// assert_eq!(request.amount(), Some("2.5"));
+/// assert_eq!(request.currency(), None);
+/// ```
+///
+/// Payment requests are emitted with the classic address and `dt` on
+/// the main network, and with the X-address on test networks, which
+/// classic addresses can't express:
+///
+/// ```
+/// use ripple_address_codec::{Network, PaymentRequest};
+///
+/// let request: PaymentRequest = "ripple:TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(request.network(), Network::Test);
+/// assert_eq!(
+///     request.on_network(Network::Main).to_string(),
+///     "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=1"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentRequest {
+    destination: AccountId,
+    tag: Tag,
+    network: Network,
+    amount: Option<String>,
+    issued_currency: Option<(String, Option<AccountId>)>,
+}
+
+impl PaymentRequest {
+    /// Request for a payment of any amount to `destination` on the main
+    /// network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, PaymentRequest};
+    ///
+    /// let request = PaymentRequest::new(AccountId::new([0; 20]));
+    ///
+    /// assert_eq!(request.to_string(), "xrpl:rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+    /// ```
+    pub fn new(destination: AccountId) -> Self {
+        PaymentRequest {
+            destination,
+            tag: Tag::None,
+            network: Network::Main,
+            amount: None,
+            issued_currency: None,
+        }
+    }
+
+    /// The same request with a destination tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, PaymentRequest, Tag};
+    ///
+    /// let request = PaymentRequest::new(AccountId::new([0; 20])).with_tag(Tag::U32(7));
+    ///
+    /// assert_eq!(request.to_string(), "xrpl:rrrrrrrrrrrrrrrrrrrrrhoLvTp?dt=7");
+    /// ```
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// The same request for another network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, Network, PaymentRequest};
+    ///
+    /// let request = PaymentRequest::new(AccountId::new([0; 20])).on_network(Network::Test);
+    ///
+    /// assert_eq!(
+    ///     request.to_string(),
+    ///     "xrpl:T7PnNYsX9KwKKyMSW8M3hgHuJs9NRqL4V1TgJnZnMNYFgg8"
+    /// );
+    /// ```
+    pub fn on_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// The same request for a decimal amount, such as `"10"` or `"2.5"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, PaymentRequest};
+    ///
+    /// let request = PaymentRequest::new(AccountId::new([0; 20]))
+    ///     .with_amount("2.5")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.to_string(), "xrpl:rrrrrrrrrrrrrrrrrrrrrhoLvTp?amount=2.5");
+    /// assert!(PaymentRequest::new(AccountId::new([0; 20])).with_amount("-1").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DecodeError`] unless `amount` is decimal digits
+    /// with at most one `.` between them.
+    pub fn with_amount(mut self, amount: &str) -> Result<Self> {
+        self.amount = Some(parse_amount(amount)?.to_owned());
+        Ok(self)
+    }
+
+    /// The same request for an amount of an issued currency instead of
+    /// XRP, optionally of a given issuer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, PaymentRequest};
+    ///
+    /// let issuer: AccountId = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+    /// let request = PaymentRequest::new(AccountId::new([0; 20]))
+    ///     .with_issued_currency("USD", Some(issuer))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     request.to_string(),
+    ///     "xrpl:rrrrrrrrrrrrrrrrrrrrrhoLvTp?currency=USD&issuer=rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DecodeError`] unless `currency` is 3 ASCII
+    /// letters or digits other than `XRP`, or 40 hex digits.
+    pub fn with_issued_currency(
+        mut self,
+        currency: &str,
+        issuer: Option<AccountId>,
+    ) -> Result<Self> {
+        self.issued_currency = Some((parse_currency(currency)?.to_owned(), issuer));
+        Ok(self)
+    }
+
+    /// Account to pay
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::PaymentRequest;
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+    ///
+    /// assert_eq!(request.destination().to_string(), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+    /// ```
+    pub fn destination(&self) -> AccountId {
+        self.destination
+    }
+
+    /// Destination tag, from `dt` or the X-address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{PaymentRequest, Tag};
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=9".parse().unwrap();
+    ///
+    /// assert_eq!(request.tag(), Tag::U32(9));
+    /// ```
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Network, [`Network::Test`] only for test network X-addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Network, PaymentRequest};
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+    ///
+    /// assert_eq!(request.network(), Network::Main);
+    /// ```
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Requested decimal amount, any amount if `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::PaymentRequest;
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=10"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.amount(), Some("10"));
+    /// ```
+    pub fn amount(&self) -> Option<&str> {
+        self.amount.as_deref()
+    }
+
+    /// Currency code of the amount, XRP if `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::PaymentRequest;
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=1&currency=EUR"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.currency(), Some("EUR"));
+    /// ```
+    pub fn currency(&self) -> Option<&str> {
+        self.issued_currency
+            .as_ref()
+            .map(|(currency, _)| currency.as_str())
+    }
+
+    /// Issuer of the currency, any issuer if `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::PaymentRequest;
+    ///
+    /// let request: PaymentRequest = "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?currency=EUR\
+    ///                                &issuer=rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.issuer().unwrap().to_string(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+    /// ```
+    pub fn issuer(&self) -> Option<AccountId> {
+        self.issued_currency
+            .as_ref()
+            .and_then(|(_, issuer)| *issuer)
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = Error;
+
+    /// Parse a payment URI
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DecodeError`] for an unknown scheme, a repeated
+    /// or required unknown parameter, an invalid amount or currency, a
+    /// `dt` or `issuer` where they don't belong, and a malformed `dt`,
+    /// [`Error::UnsupportedTag`] for a `dt` that doesn't fit in a `u32`,
+    /// and otherwise an [`Error`] describing why an address is invalid.
+    fn from_str(uri: &str) -> Result<Self> {
+        let (scheme, rest) = uri.split_once(':').ok_or(Error::DecodeError)?;
+        if !SCHEMES
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(scheme))
+        {
+            return Err(Error::DecodeError);
+        }
+
+        let (account, query) = match rest.split_once('?') {
+            Some((account, query)) => (account, Some(query)),
+            None => (rest, None),
+        };
+        let (destination, tag, network) = parse_account(account)?;
+
+        let mut dt = None;
+        let mut amount = None;
+        let mut currency = None;
+        let mut issuer = None;
+
+        for parameter in query.into_iter().flat_map(|query| query.split('&')) {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            let slot = match name {
+                "dt" => &mut dt,
+                "amount" => &mut amount,
+                "currency" => &mut currency,
+                "issuer" => &mut issuer,
+                name if name.starts_with("req-") => return Err(Error::DecodeError),
+                _ => continue,
+            };
+
+            if slot.replace(value).is_some() {
+                return Err(Error::DecodeError);
+            }
+        }
+
+        let tag = match (tag, dt) {
+            (tag, None) => tag,
+            (Tag::None, Some(dt)) => Tag::U32(parse_tag(dt)?),
+            (_, Some(_)) => return Err(Error::DecodeError),
+        };
+        let issued_currency = match (currency, issuer) {
+            (Some(currency), issuer) => Some((
+                parse_currency(currency)?.to_owned(),
+                issuer.map(str::parse).transpose()?,
+            )),
+            (None, None) => None,
+            (None, Some(_)) => return Err(Error::DecodeError),
+        };
+
+        Ok(PaymentRequest {
+            destination,
+            tag,
+            network,
+            amount: amount.map(parse_amount).transpose()?.map(str::to_owned),
+            issued_currency,
+        })
+    }
+}
+
+impl TryFrom<&str> for PaymentRequest {
+    type Error = Error;
+
+    fn try_from(uri: &str) -> Result<Self> {
+        uri.parse()
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", SCHEMES[0])?;
+
+        let mut separator = '?';
+        let mut parameter = |f: &mut fmt::Formatter<'_>, name: &str, value: &dyn fmt::Display| {
+            let result = write!(f, "{}{}={}", separator, name, value);
+            separator = '&';
+            result
+        };
+
+        match self.network {
+            Network::Main => {
+                write!(f, "{}", self.destination)?;
+                if let Some(tag) = self.tag.value() {
+                    parameter(f, "dt", &tag)?;
+                }
+            }
+            Network::Test => f.write_str(&encode_xaddress(
+                self.destination.as_bytes(),
+                self.tag,
+                self.network,
+            ))?,
+        }
+
+        if let Some(amount) = &self.amount {
+            parameter(f, "amount", amount)?;
+        }
+        if let Some((currency, issuer)) = &self.issued_currency {
+            parameter(f, "currency", currency)?;
+            if let Some(issuer) = issuer {
+                parameter(f, "issuer", issuer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decimal digits with at most one `.` between them
+fn parse_amount(amount: &str) -> Result<&str> {
+    let is_digits =
+        |digits: &str| !digits.is_empty() && digits.bytes().all(|digit| digit.is_ascii_digit());
+    let valid = match amount.split_once('.') {
+        Some((integer, fraction)) => is_digits(integer) && is_digits(fraction),
+        None => is_digits(amount),
+    };
+
+    if valid {
+        Ok(amount)
+    } else {
+        Err(Error::DecodeError)
+    }
+}
+
+/// A standard currency code, other than XRP, or a hex one
+///
+/// rippled allows some symbols in standard codes too, they are left out
+/// as they would need escaping in URIs.
+fn parse_currency(currency: &str) -> Result<&str> {
+    let standard = currency.len() == 3
+        && currency.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        && !currency.eq_ignore_ascii_case("XRP");
+    let hex = currency.len() == HEX_CURRENCY_LEN
+        && currency.bytes().all(|digit| digit.is_ascii_hexdigit());
+
+    if standard || hex {
+        Ok(currency)
+    } else {
+        Err(Error::DecodeError)
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{
+    encode_bytes_with_prefix_in, first_match, get_payload_with_prefix, sha256,
+    with_decoded_bytes_in, xaddress::XAddressMain, AccountPrivateKey, AccountPublicKey, Address,
+    Alphabet, Error, FamilyGenerator, NodePrivateKey, NodePublicKey, Result, TokenType,
+    VersionedToken, ENTROPY_LEN,
+};
+
+/// Suffix of the payloads of compressed WIF private keys
+const WIF_COMPRESSED: u8 = 0x01;
+
+/// Alphabet, version bytes and payload lengths of a base58check format
+///
+/// The XRP Ledger presets are its token types, the default is classic
+/// addresses. The others are formats of other networks sharing the
+/// base58check layout: version bytes, payload and a 4 bytes double
+/// SHA-256 checksum. They go through the same checked code path as the
+/// XRP Ledger tokens, so tooling handling several assets can use one
+/// codec.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{encode_account_id, Preset};
+///
+/// let hash160 = [
+///     0x01, 0x09, 0x66, 0x77, 0x60, 0x06, 0x95, 0x3D, 0x55, 0x67, 0x43, 0x9E, 0x5E, 0x39, 0xF8,
+///     0x6A, 0x0D, 0x27, 0x3B, 0xEE,
+/// ];
+///
+/// assert_eq!(
+///     Preset::BitcoinP2pkh.encode(&hash160).unwrap(),
+///     "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"
+/// );
+/// assert_eq!(
+///     Preset::default().encode(&hash160).unwrap(),
+///     encode_account_id(&hash160)
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// An XRP Ledger token type, X-addresses of the main network only
+    Xrpl(TokenType),
+    /// Bitcoin pay to public key hash address (starting with 1...)
+    BitcoinP2pkh,
+    /// Bitcoin pay to script hash address (starting with 3...)
+    BitcoinP2sh,
+    /// Bitcoin private key in wallet import format (starting with 5...,
+    /// or K... or L... for compressed keys)
+    BitcoinWif,
+    /// Bitcoin test network pay to public key hash address (starting with
+    /// m... or n...)
+    BitcoinTestnetP2pkh,
+    /// Bitcoin test network pay to script hash address (starting with 2...)
+    BitcoinTestnetP2sh,
+    /// Bitcoin test network private key in wallet import format
+    /// (starting with 9..., or c... for compressed keys)
+    BitcoinTestnetWif,
+}
+
+impl Default for Preset {
+    fn default() -> Self {
+        Preset::Xrpl(TokenType::AccountId)
+    }
+}
+
+impl Preset {
+    /// The base58 alphabet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Alphabet, Preset};
+    ///
+    /// assert_eq!(Preset::default().alphabet(), Alphabet::XRPL);
+    /// assert_eq!(Preset::BitcoinWif.alphabet(), Alphabet::BITCOIN);
+    /// ```
+    pub fn alphabet(self) -> Alphabet {
+        match self {
+            Preset::Xrpl(_) => Alphabet::XRPL,
+            _ => Alphabet::BITCOIN,
+        }
+    }
+
+    /// Version bytes prepended to the payload
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Preset;
+    ///
+    /// assert_eq!(Preset::BitcoinWif.prefix(), &[0x80]);
+    /// ```
+    pub fn prefix(self) -> &'static [u8] {
+        match self {
+            Preset::Xrpl(token_type) => token_type.prefix_bytes(),
+            Preset::BitcoinP2pkh => &[0x00],
+            Preset::BitcoinP2sh => &[0x05],
+            Preset::BitcoinWif => &[0x80],
+            Preset::BitcoinTestnetP2pkh => &[0x6F],
+            Preset::BitcoinTestnetP2sh => &[0xC4],
+            Preset::BitcoinTestnetWif => &[0xEF],
+        }
+    }
+
+    /// All accepted payload lengths in bytes
+    ///
+    /// WIF private keys are 32 bytes long, or 33 bytes ending with
+    /// `0x01` for keys of compressed public keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Preset;
+    ///
+    /// assert_eq!(Preset::BitcoinP2pkh.payload_lens(), &[20]);
+    /// assert_eq!(Preset::BitcoinWif.payload_lens(), &[32, 33]);
+    /// ```
+    pub fn payload_lens(self) -> &'static [usize] {
+        match self {
+            Preset::Xrpl(token_type) => match token_type {
+                TokenType::AccountId => &[Address::PAYLOAD_LEN],
+                TokenType::XAddress => &[XAddressMain::PAYLOAD_LEN],
+                TokenType::NodePublicKey => &[NodePublicKey::PAYLOAD_LEN],
+                TokenType::AccountPublicKey => &[AccountPublicKey::PAYLOAD_LEN],
+                TokenType::NodePrivateKey => &[NodePrivateKey::PAYLOAD_LEN],
+                TokenType::AccountPrivateKey => &[AccountPrivateKey::PAYLOAD_LEN],
+                TokenType::FamilyGenerator => &[FamilyGenerator::PAYLOAD_LEN],
+                TokenType::SeedSecp256k1 | TokenType::SeedEd25519 => &[ENTROPY_LEN],
+            },
+            Preset::BitcoinP2pkh
+            | Preset::BitcoinP2sh
+            | Preset::BitcoinTestnetP2pkh
+            | Preset::BitcoinTestnetP2sh => &[20],
+            Preset::BitcoinWif | Preset::BitcoinTestnetWif => &[32, 33],
+        }
+    }
+
+    /// Encode a payload
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Preset;
+    ///
+    /// assert_eq!(
+    ///     Preset::BitcoinP2pkh.encode(&[0; 20]).unwrap(),
+    ///     "1111111111111111111114oLvT2"
+    /// );
+    /// assert!(Preset::BitcoinP2pkh.encode(&[0; 32]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadLength`] if `payload` length is not one of the
+    /// [`payload_lens`](Preset::payload_lens), and [`Error::DecodeError`]
+    /// for a compressed WIF private key not ending with `0x01`.
+    pub fn encode(self, payload: &[u8]) -> Result<String> {
+        self.check_payload(payload)?;
+
+        Ok(encode_bytes_with_prefix_in(
+            &self.alphabet(),
+            sha256::DEFAULT,
+            self.prefix(),
+            payload,
+        ))
+    }
+
+    /// Decode a string to its payload
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Error, Preset};
+    ///
+    /// let wif = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+    ///
+    /// assert_eq!(Preset::BitcoinWif.decode(wif).unwrap()[0], 0x0C);
+    /// assert_eq!(
+    ///     Preset::BitcoinTestnetWif.decode(wif),
+    ///     Err(Error::BadPrefix { expected: &[0xEF] })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] describing why the string is invalid, doesn't
+    /// start with the version bytes or its payload has an unexpected
+    /// length, and [`Error::DecodeError`] for a compressed WIF private
+    /// key not ending with `0x01`.
+    pub fn decode(self, s: &str) -> Result<Vec<u8>> {
+        with_decoded_bytes_in(&self.alphabet(), s, |decoded_bytes| {
+            let payload = first_match(self.payload_lens().iter().map(|&payload_len| {
+                get_payload_with_prefix(sha256::DEFAULT, decoded_bytes, self.prefix(), payload_len)
+            }))?;
+            self.check_payload(payload)?;
+
+            Ok(payload.to_vec())
+        })
+    }
+
+    fn check_payload(self, payload: &[u8]) -> Result<()> {
+        let payload_lens = self.payload_lens();
+        if !payload_lens.contains(&payload.len()) {
+            return Err(Error::BadLength {
+                expected: payload_lens[0],
+                actual: payload.len(),
+            });
+        }
+
+        let is_wif = matches!(self, Preset::BitcoinWif | Preset::BitcoinTestnetWif);
+        if is_wif && payload.len() == 33 && payload[32] != WIF_COMPRESSED {
+            return Err(Error::DecodeError);
+        }
+
+        Ok(())
+    }
+}
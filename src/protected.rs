@@ -0,0 +1,165 @@
+//! Seed entropy in locked memory
+//!
+//! With the `protected-memory` feature, every [`Seed`](crate::Seed)
+//! keeps its entropy on a dedicated page locked in RAM (`mlock` or
+//! `VirtualLock`), so it's never written to swap, and wipes it before
+//! the page is unlocked and freed.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use region::{LockGuard, Protection};
+use zeroize::Zeroize;
+
+use crate::{Entropy, ENTROPY_LEN};
+
+/// Entropy on its own locked page
+pub(crate) struct LockedEntropy {
+    // Unlocked before the allocation is freed, fields drop in order
+    _lock: LockGuard,
+    allocation: region::Allocation,
+}
+
+// SAFETY: the page is owned like a `Box<Entropy>` and only accessed through it
+unsafe impl Send for LockedEntropy {}
+// SAFETY: shared access is read-only, like for a `Box<Entropy>`
+unsafe impl Sync for LockedEntropy {}
+
+impl LockedEntropy {
+    /// Copy the entropy to a new locked page
+    ///
+    /// Panics if the page can't be allocated or locked, e.g. when the
+    /// locked memory limit (`ulimit -l`) is reached.
+    pub(crate) fn new(entropy: &Entropy) -> Self {
+        let mut allocation = region::alloc(ENTROPY_LEN, Protection::READ_WRITE)
+            .expect("failed to allocate a page for seed entropy");
+        let lock = region::lock(allocation.as_ptr::<u8>(), allocation.len())
+            .expect("failed to lock seed entropy in memory");
+
+        // SAFETY: the allocation is page aligned, writable and longer than the entropy
+        unsafe { allocation.as_mut_ptr::<Entropy>().write(*entropy) };
+
+        LockedEntropy {
+            _lock: lock,
+            allocation,
+        }
+    }
+}
+
+impl From<Entropy> for LockedEntropy {
+    fn from(mut entropy: Entropy) -> Self {
+        let locked = LockedEntropy::new(&entropy);
+        entropy.zeroize();
+
+        locked
+    }
+}
+
+impl Deref for LockedEntropy {
+    type Target = Entropy;
+
+    fn deref(&self) -> &Entropy {
+        // SAFETY: initialized in `new`, the allocation lives as long as `self`
+        unsafe { &*self.allocation.as_ptr::<Entropy>() }
+    }
+}
+
+impl DerefMut for LockedEntropy {
+    fn deref_mut(&mut self) -> &mut Entropy {
+        // SAFETY: initialized in `new`, the allocation lives as long as `self`
+        unsafe { &mut *self.allocation.as_mut_ptr::<Entropy>() }
+    }
+}
+
+impl Drop for LockedEntropy {
+    fn drop(&mut self) {
+        self.deref_mut().zeroize();
+    }
+}
+
+impl Clone for LockedEntropy {
+    fn clone(&self) -> Self {
+        LockedEntropy::new(self)
+    }
+}
+
+impl PartialEq for LockedEntropy {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for LockedEntropy {}
+
+impl PartialOrd for LockedEntropy {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LockedEntropy {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl Hash for LockedEntropy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl fmt::Debug for LockedEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LockedEntropy(****)")
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LockedEntropy {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Entropy::arbitrary(u).map(LockedEntropy::from)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for LockedEntropy {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        (**self).serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for LockedEntropy {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        Entropy::deserialize_reader(reader).map(LockedEntropy::from)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for LockedEntropy {
+    type Archived = rkyv::Archived<Entropy>;
+    type Resolver = rkyv::Resolver<Entropy>;
+
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        (**self).resolve(resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S: rkyv::rancor::Fallible + ?Sized> rkyv::Serialize<S> for LockedEntropy {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<D: rkyv::rancor::Fallible + ?Sized> rkyv::Deserialize<LockedEntropy, D> for [u8; ENTROPY_LEN] {
+    fn deserialize(&self, _: &mut D) -> Result<LockedEntropy, D::Error> {
+        Ok(LockedEntropy::from(*self))
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Read},
+    str,
+};
+
+use crate::{AccountId, Error, Result};
+
+/// Longest line kept in memory, far longer than any address
+///
+/// Longer lines are reported as invalid without being buffered, so memory
+/// use doesn't depend on the input.
+const MAX_LINE_LEN: usize = 256;
+
+/// Streaming decoder of newline-delimited classic addresses
+///
+/// Reads one line at a time with bounded memory and yields its 1-based
+/// line number with the decoded account id, or why the line isn't a
+/// valid address. Surrounding whitespace, including `\r` of CRLF line
+/// endings, is ignored and blank lines are skipped.
+///
+/// Lines that aren't UTF-8 are reported as [`Error::BadAlphabet`] at the
+/// first invalid byte, as `U+FFFD`. Lines longer than any address are
+/// reported as [`Error::DecodeError`] without being read into memory.
+///
+/// Iteration stops at the end of the input, or after yielding an I/O
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{AccountId, AddressReader, Error};
+///
+/// let input = "rrrrrrrrrrrrrrrrrrrrrhoLvTp\r\n\nrrrrrrrrrrrrrrrrrrrrrhoLvTr\n".as_bytes();
+/// let lines: Vec<_> = AddressReader::new(input).collect::<Result<_, _>>().unwrap();
+///
+/// assert_eq!(
+///     lines,
+///     [(1, Ok(AccountId::new([0; 20]))), (3, Err(Error::BadChecksum))]
+/// );
+/// ```
+pub struct AddressReader<R> {
+    input: BufReader<R>,
+    line: Vec<u8>,
+    number: u64,
+    failed: bool,
+}
+
+impl<R: Read> AddressReader<R> {
+    /// Decode the lines of `input`
+    ///
+    /// `input` is buffered, so there's no need to wrap it in a
+    /// [`BufReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AddressReader;
+    ///
+    /// assert_eq!(AddressReader::new("\n\n".as_bytes()).count(), 0);
+    /// ```
+    pub fn new(input: R) -> Self {
+        AddressReader {
+            input: BufReader::new(input),
+            line: Vec::with_capacity(MAX_LINE_LEN),
+            number: 0,
+            failed: false,
+        }
+    }
+
+    /// The wrapped reader
+    ///
+    /// Input buffered but not decoded yet is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::AddressReader;
+    ///
+    /// let input: &[u8] = b"rrrrrrrrrrrrrrrrrrrrrhoLvTp\n";
+    ///
+    /// assert_eq!(AddressReader::new(input).into_inner().len(), 28);
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.input.into_inner()
+    }
+
+    /// Read the next line into `self.line` without its line ending,
+    /// keeping at most [`MAX_LINE_LEN`] bytes
+    ///
+    /// Returns `None` at the end of the input, otherwise whether the line
+    /// was longer and got truncated.
+    fn read_line(&mut self) -> io::Result<Option<bool>> {
+        self.line.clear();
+        let mut truncated = false;
+        let mut read_any = false;
+
+        loop {
+            let available = match self.input.fill_buf() {
+                Ok(available) => available,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            if available.is_empty() {
+                return Ok(if read_any { Some(truncated) } else { None });
+            }
+            read_any = true;
+
+            let newline = available.iter().position(|&byte| byte == b'\n');
+            let chunk = &available[..newline.unwrap_or(available.len())];
+            let room = MAX_LINE_LEN - self.line.len();
+            truncated |= chunk.len() > room;
+            self.line.extend_from_slice(&chunk[..chunk.len().min(room)]);
+
+            let consumed = newline.map_or(available.len(), |newline| newline + 1);
+            self.input.consume(consumed);
+            if newline.is_some() {
+                return Ok(Some(truncated));
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for AddressReader<R> {
+    type Item = io::Result<(u64, Result<AccountId>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let truncated = match self.read_line() {
+                Ok(Some(truncated)) => truncated,
+                Ok(None) => return None,
+                Err(error) => {
+                    self.failed = true;
+                    return Some(Err(error));
+                }
+            };
+            self.number += 1;
+
+            let result = match str::from_utf8(&self.line) {
+                _ if truncated => Err(Error::DecodeError),
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => line.trim().parse(),
+                Err(error) => {
+                    let valid = str::from_utf8(&self.line[..error.valid_up_to()])
+                        .expect("bytes up to valid_up_to are UTF-8");
+                    let leading = valid.len() - valid.trim_start().len();
+
+                    Err(Error::BadAlphabet {
+                        character: char::REPLACEMENT_CHARACTER,
+                        index: valid[leading..].chars().count(),
+                    })
+                }
+            };
+
+            return Some(Ok((self.number, result)));
+        }
+
+        None
+    }
+}
+
+impl<R> fmt::Debug for AddressReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AddressReader")
+            .field("number", &self.number)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{wipe, Algorithm, Entropy, Error, Result, Seed, ENTROPY_LEN};
+
+use self::words::{FOUR_LETTER, WORDS};
+
+mod words;
+
+/// Words per 64-bit half of the entropy
+const HALF_WORDS: usize = 6;
+
+/// Encode a seed as 12 RFC 1751 words
+///
+/// This is the `master_key` of rippled's `wallet_propose`, also used for
+/// legacy `validation_key` secrets. Like rippled, the entropy is encoded
+/// in reverse byte order. The algorithm isn't encoded.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{seed_to_rfc1751, Seed};
+///
+/// let seed: Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+///
+/// assert_eq!(
+///     seed_to_rfc1751(&seed),
+///     "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE"
+/// );
+/// # }
+/// ```
+pub fn seed_to_rfc1751(seed: &Seed) -> String {
+    let mut key = *seed.entropy();
+    key.reverse();
+
+    let words = key
+        .chunks(8)
+        .flat_map(half_to_words)
+        .collect::<Vec<_>>()
+        .join(" ");
+    wipe(&mut key);
+
+    words
+}
+
+/// Decode a seed from 12 RFC 1751 words
+///
+/// The inverse of [`seed_to_rfc1751`]. Words are separated by whitespace
+/// and matched case-insensitively; as specified by RFC 1751, the digits
+/// `1`, `0` and `5` are read as the letters `L`, `O` and `S`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{rfc1751_to_seed, Secp256k1};
+///
+/// let seed = rfc1751_to_seed("i ire bond bow trio laid seat goal hen ibis ibis dare", Secp256k1).unwrap();
+///
+/// assert_eq!(seed.expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::DecodeError`] if there aren't exactly 12 words,
+/// [`Error::UnknownWord`] for a word outside the dictionary and
+/// [`Error::BadChecksum`] if the parity bits don't match.
+pub fn rfc1751_to_seed(words: &str, algorithm: Algorithm) -> Result<Seed> {
+    let words: Vec<&str> = words.split_whitespace().collect();
+    if words.len() != 2 * HALF_WORDS {
+        return Err(Error::DecodeError);
+    }
+
+    let mut key: Entropy = [0; ENTROPY_LEN];
+    for (index, (half, words)) in key.chunks_mut(8).zip(words.chunks(HALF_WORDS)).enumerate() {
+        if let Err(error) = words_to_half(words, index * HALF_WORDS, half) {
+            wipe(&mut key);
+            return Err(error);
+        }
+    }
+    key.reverse();
+
+    let seed = Seed::new(key, algorithm);
+    wipe(&mut key);
+
+    Ok(seed)
+}
+
+/// 6 words encoding 64 bits followed by 2 parity bits
+fn half_to_words(half: &[u8]) -> impl Iterator<Item = &'static str> {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(half);
+    let value = u64::from_be_bytes(bytes);
+    wipe(&mut bytes);
+
+    let bits = (u128::from(value) << 2) | u128::from(parity(value));
+
+    (0..HALF_WORDS).map(move |i| WORDS[(bits >> (11 * (HALF_WORDS - 1 - i))) as usize & 0x7FF])
+}
+
+fn words_to_half(words: &[&str], first_index: usize, half: &mut [u8]) -> Result<()> {
+    let mut bits: u128 = 0;
+    for (index, word) in words.iter().enumerate() {
+        let value = word_index(word).ok_or(Error::UnknownWord {
+            index: first_index + index,
+        })?;
+        bits = (bits << 11) | value as u128;
+    }
+
+    let value = (bits >> 2) as u64;
+    let matches = parity(value) == (bits & 0b11) as u8;
+    half.copy_from_slice(&value.to_be_bytes());
+
+    if matches {
+        Ok(())
+    } else {
+        Err(Error::BadChecksum)
+    }
+}
+
+/// Sum of the 2-bit pairs, modulo 4
+fn parity(value: u64) -> u8 {
+    (0..32)
+        .map(|pair| (value >> (2 * pair)) & 0b11)
+        .sum::<u64>() as u8
+        & 0b11
+}
+
+fn word_index(word: &str) -> Option<usize> {
+    if word.is_empty() || word.len() > 4 {
+        return None;
+    }
+
+    let mut normalized = [0; 4];
+    for (normalized, byte) in normalized.iter_mut().zip(word.bytes()) {
+        *normalized = match byte.to_ascii_uppercase() {
+            b'1' => b'L',
+            b'0' => b'O',
+            b'5' => b'S',
+            byte => byte,
+        };
+    }
+    let normalized = std::str::from_utf8(&normalized[..word.len()]).ok()?;
+
+    let (offset, group) = if word.len() == 4 {
+        (FOUR_LETTER, &WORDS[FOUR_LETTER..])
+    } else {
+        (0, &WORDS[..FOUR_LETTER])
+    };
+
+    group
+        .binary_search(&normalized)
+        .ok()
+        .map(|index| offset + index)
+}
@@ -0,0 +1,117 @@
+//! `JsonSchema`, available with the `schemars` feature
+//!
+//! Schemas describe the human-readable serde format: strings with the
+//! token's leading characters, base58 characters of the XRP Ledger
+//! alphabet and length bounds.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{AccountId, Algorithm, Seed, XAddress, ACCOUNT_ID_MAX_LEN};
+
+/// The XRP Ledger base58 alphabet as a regular expression character class
+const BASE58_CLASS: &str = "[1-9A-HJ-NP-Za-km-z]";
+
+/// Shortest classic address
+const ACCOUNT_ID_MIN_LEN: usize = 25;
+
+/// Secp256k1 seeds are 29 characters long, Ed25519 ones 31
+const SEED_LENS: (usize, usize) = (29, 31);
+
+/// Length of every X-address
+const XADDRESS_LEN: usize = 47;
+
+impl JsonSchema for AccountId {
+    fn schema_name() -> Cow<'static, str> {
+        "AccountId".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ripple_address_codec::AccountId".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base58_schema(
+            "XRP Ledger classic address",
+            format!(
+                "^r{}{{{},{}}}$",
+                BASE58_CLASS,
+                ACCOUNT_ID_MIN_LEN - 1,
+                ACCOUNT_ID_MAX_LEN - 1
+            ),
+            (ACCOUNT_ID_MIN_LEN, ACCOUNT_ID_MAX_LEN),
+        )
+    }
+}
+
+impl JsonSchema for Seed {
+    fn schema_name() -> Cow<'static, str> {
+        "Seed".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ripple_address_codec::Seed".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let (secp256k1_len, _) = SEED_LENS;
+
+        base58_schema(
+            "XRP Ledger seed, Ed25519 ones start with sEd",
+            format!("^s(Ed)?{}{{{}}}$", BASE58_CLASS, secp256k1_len - 1),
+            SEED_LENS,
+        )
+    }
+}
+
+impl JsonSchema for XAddress {
+    fn schema_name() -> Cow<'static, str> {
+        "XAddress".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ripple_address_codec::XAddress".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base58_schema(
+            "XRP Ledger X-address, test network ones start with T",
+            format!("^[XT]{}{{{}}}$", BASE58_CLASS, XADDRESS_LEN - 1),
+            (XADDRESS_LEN, XADDRESS_LEN),
+        )
+    }
+}
+
+impl JsonSchema for Algorithm {
+    fn schema_name() -> Cow<'static, str> {
+        "Algorithm".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ripple_address_codec::Algorithm".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names: Vec<String> = Algorithm::all()
+            .iter()
+            .map(|algorithm| algorithm.to_string())
+            .collect();
+
+        json_schema!({
+            "description": "Seed algorithm",
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+fn base58_schema(description: &str, pattern: String, (min_len, max_len): (usize, usize)) -> Schema {
+    json_schema!({
+        "description": description,
+        "type": "string",
+        "pattern": pattern,
+        "minLength": min_len,
+        "maxLength": max_len,
+    })
+}
@@ -0,0 +1,164 @@
+//! Sealing seeds to the enclave
+//!
+//! Available with the `mesalock_sgx` feature, inside enclaves only.
+//! A sealed seed can only be unsealed by the same enclave on the same
+//! CPU, so seeds can be persisted outside the enclave without ever
+//! leaving it in plaintext.
+//!
+//! The sealed blob encrypts the seed's entropy and authenticates, but
+//! doesn't encrypt, a header with [`SEALED_SEED_VERSION`] and the seed's
+//! algorithm.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use ripple_address_codec::{sealing, Ed25519, Seed};
+//!
+//! let seed = Seed::new([0; 16], Ed25519);
+//! let sealed = sealing::seal_seed(&seed).unwrap();
+//!
+//! assert_eq!(sealing::unseal_seed(&sealed), Ok(seed));
+//! ```
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{
+    convert::{TryFrom, TryInto},
+    error, fmt,
+};
+
+use sgx_tseal::SgxSealedData;
+use sgx_types::{sgx_sealed_data_t, sgx_status_t};
+
+use crate::{wipe, Algorithm, Entropy, Seed, ENTROPY_LEN};
+
+use SealingError::{Malformed, Sgx, UnsupportedVersion};
+
+/// Format version written in the header of sealed seeds
+pub const SEALED_SEED_VERSION: u8 = 1;
+
+/// Version and algorithm tag
+const HEADER_LEN: usize = 2;
+
+/// Sealing error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SealingError {
+    /// The SGX SDK failed to seal or unseal, e.g. the blob was sealed
+    /// by another enclave or tampered with
+    Sgx(sgx_status_t),
+    /// The blob was sealed with an unknown format version
+    UnsupportedVersion(u8),
+    /// The blob isn't a sealed seed
+    Malformed,
+}
+
+impl error::Error for SealingError {}
+
+impl fmt::Display for SealingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sgx(status) => write!(f, "SGX sealing failed: {}", status),
+            UnsupportedVersion(version) => {
+                write!(f, "unsupported sealed seed version {}", version)
+            }
+            Malformed => f.write_str("malformed sealed seed"),
+        }
+    }
+}
+
+/// Seal a seed to the enclave
+///
+/// # Examples
+///
+/// ```no_run
+/// use ripple_address_codec::{decode_seed, sealing::seal_seed, Seed};
+///
+/// let (entropy, algorithm) = decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+///
+/// // Store outside of the enclave
+/// let sealed: Vec<u8> = seal_seed(&Seed::new(entropy, algorithm)).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns [`SealingError::Sgx`] if the SGX SDK fails to seal.
+pub fn seal_seed(seed: &Seed) -> Result<Vec<u8>, SealingError> {
+    let header = [SEALED_SEED_VERSION, seed.algorithm().tag()];
+    let sealed = SgxSealedData::<[u8]>::seal_data(&header, seed.entropy()).map_err(Sgx)?;
+
+    let len =
+        SgxSealedData::<[u8]>::calc_raw_sealed_data_size(HEADER_LEN as u32, ENTROPY_LEN as u32);
+    let mut buffer = aligned_buffer(len as usize);
+
+    // SAFETY: the buffer is `len` bytes long and aligned for `sgx_sealed_data_t`
+    unsafe { sealed.to_raw_sealed_data_t(buffer.as_mut_ptr().cast::<sgx_sealed_data_t>(), len) }
+        .ok_or(Malformed)?;
+
+    Ok(buffer
+        .iter()
+        .flat_map(|word| word.to_ne_bytes())
+        .take(len as usize)
+        .collect())
+}
+
+/// Unseal a seed sealed by [`seal_seed`]
+///
+/// # Examples
+///
+/// ```no_run
+/// use ripple_address_codec::{sealing::unseal_seed, Ed25519};
+///
+/// # let sealed = Vec::new();
+/// let seed = unseal_seed(&sealed).unwrap();
+///
+/// assert_eq!(seed.algorithm(), Ed25519);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`SealingError::Sgx`] if the SGX SDK fails to unseal,
+/// [`SealingError::UnsupportedVersion`] for a blob of another format
+/// version and [`SealingError::Malformed`] if the blob isn't a sealed seed.
+pub fn unseal_seed(sealed: &[u8]) -> Result<Seed, SealingError> {
+    let len = u32::try_from(sealed.len()).map_err(|_| Malformed)?;
+    let mut buffer = aligned_buffer(sealed.len());
+
+    for (word, chunk) in buffer.iter_mut().zip(sealed.chunks(WORD_LEN)) {
+        let mut bytes = [0; WORD_LEN];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        *word = u64::from_ne_bytes(bytes);
+    }
+
+    // SAFETY: the buffer is `len` bytes long and aligned for `sgx_sealed_data_t`
+    let sealed = unsafe {
+        SgxSealedData::<[u8]>::from_raw_sealed_data_t(
+            buffer.as_mut_ptr().cast::<sgx_sealed_data_t>(),
+            len,
+        )
+    }
+    .ok_or(Malformed)?;
+    let unsealed = sealed.unseal_data().map_err(Sgx)?;
+
+    let algorithm = match *unsealed.get_additional_txt() {
+        [SEALED_SEED_VERSION, tag] => Algorithm::from_tag(tag).ok_or(Malformed)?,
+        [version, _] => return Err(UnsupportedVersion(version)),
+        _ => return Err(Malformed),
+    };
+    let mut entropy: Entropy = unsealed
+        .get_decrypt_txt()
+        .try_into()
+        .map_err(|_| Malformed)?;
+
+    let seed = Seed::new(entropy, algorithm);
+    wipe(&mut entropy);
+
+    Ok(seed)
+}
+
+const WORD_LEN: usize = 8;
+
+/// Zeroed buffer of at least `len` bytes, aligned for `sgx_sealed_data_t`
+fn aligned_buffer(len: usize) -> Vec<u64> {
+    vec![0; len.div_ceil(WORD_LEN)]
+}
@@ -0,0 +1,159 @@
+use crate::{
+    decode_any, hex::from_hex, passphrase_to_seed, rfc1751_to_seed, wipe, Algorithm, Error, Result,
+    Seed, XrplToken, ENTROPY_LEN,
+};
+
+/// Format of a secret, as accepted by rippled's `sign` RPC
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::SecretFormat;
+///
+/// assert_eq!(
+///     SecretFormat::detect("snoPBrXtMeMyMHUVTgbuqAfg1SUTb"),
+///     Some(SecretFormat::Seed)
+/// );
+/// assert_eq!(
+///     SecretFormat::detect("DEDCE9CE67B451D852FD4E846FCDE31C"),
+///     Some(SecretFormat::Hex)
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecretFormat {
+    /// Base58 seed (starting with s... or sEd...)
+    Seed,
+    /// 12 RFC 1751 words, like the `master_key` of `wallet_propose`
+    Rfc1751,
+    /// 32 hex digits of entropy, like the `master_seed_hex` of
+    /// `wallet_propose`
+    Hex,
+    /// Any other text, hashed into the entropy
+    ///
+    /// Never detected, only read when passed as the hint of
+    /// [`parse_secret`].
+    Passphrase,
+}
+
+impl SecretFormat {
+    /// The format [`parse_secret`] reads `secret` as, without a hint
+    ///
+    /// Unlike rippled, text that isn't one of the other formats isn't
+    /// taken for a passphrase, so a mistyped seed isn't silently read as
+    /// a low entropy one. Other tokens, such as addresses and keys,
+    /// aren't secrets either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::SecretFormat;
+    ///
+    /// assert_eq!(
+    ///     SecretFormat::detect("I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE"),
+    ///     Some(SecretFormat::Rfc1751)
+    /// );
+    /// assert_eq!(SecretFormat::detect("masterpassphrase"), None);
+    /// assert_eq!(SecretFormat::detect("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"), None);
+    /// ```
+    pub fn detect(secret: &str) -> Option<SecretFormat> {
+        detect(secret).ok()
+    }
+}
+
+/// Decode a secret in any of the formats rippled accepts
+///
+/// Without a `hint`, the format is [detected](SecretFormat::detect) in
+/// rippled's order, but unlike rippled, passphrases are only read with
+/// the [`SecretFormat::Passphrase`] hint, so a mistyped seed is an
+/// error rather than a valid low entropy seed. Passing the hint accepts
+/// the low entropy; for rippled's fallback with its warning, call
+/// [`passphrase_to_seed`](crate::passphrase_to_seed) when decoding
+/// fails.
+///
+/// Base58 seeds keep their algorithm, other formats don't have one and
+/// give secp256k1 seeds, rippled's default (Ed25519 ones in builds
+/// without secp256k1 seeds); see [`Seed::with_algorithm`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{parse_secret, Error, SecretFormat};
+///
+/// for secret in [
+///     "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+///     "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE",
+///     "DEDCE9CE67B451D852FD4E846FCDE31C",
+/// ] {
+///     assert_eq!(parse_secret(secret, None).unwrap().expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+/// }
+///
+/// let passphrase = parse_secret("masterpassphrase", Some(SecretFormat::Passphrase)).unwrap();
+///
+/// assert_eq!(passphrase.expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+/// assert!(parse_secret("masterpassphrase", None).is_err());
+/// assert_eq!(parse_secret("snoPBrXtMeMyMHUVTgbuqAfg1SUTc", None), Err(Error::BadChecksum));
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Without a hint, returns the error of decoding `secret` as a base58
+/// seed if it isn't in any format, such as [`Error::BadChecksum`] for a
+/// mistyped seed, [`Error::BadPrefix`] for other tokens, such as
+/// addresses and keys, and [`Error::DecodeError`] for an empty string.
+/// With a hint, returns the error of decoding that format.
+pub fn parse_secret(secret: &str, hint: Option<SecretFormat>) -> Result<Seed> {
+    let format = match hint {
+        Some(format) => format,
+        None => detect(secret)?,
+    };
+
+    match format {
+        SecretFormat::Seed => secret.parse(),
+        SecretFormat::Rfc1751 => rfc1751_to_seed(secret, default_algorithm()),
+        SecretFormat::Hex => {
+            let mut entropy = from_hex::<ENTROPY_LEN>(secret)?;
+            let seed = Seed::new(entropy, default_algorithm());
+            wipe(&mut entropy);
+
+            Ok(seed)
+        }
+        SecretFormat::Passphrase => {
+            Ok(passphrase_to_seed(secret, default_algorithm()).accept_low_entropy())
+        }
+    }
+}
+
+fn detect(secret: &str) -> Result<SecretFormat> {
+    if secret.is_empty() {
+        return Err(Error::DecodeError);
+    }
+
+    let error = match decode_any(secret) {
+        Ok(XrplToken::Seed { .. }) => return Ok(SecretFormat::Seed),
+        Ok(_) => return Err(Error::BadPrefix { expected: &[] }),
+        Err(error) => error,
+    };
+
+    if secret.len() == 2 * ENTROPY_LEN && secret.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        Ok(SecretFormat::Hex)
+    } else if rfc1751_to_seed(secret, default_algorithm()).is_ok() {
+        Ok(SecretFormat::Rfc1751)
+    } else {
+        Err(error)
+    }
+}
+
+/// rippled's default key type, if compiled in
+#[cfg(feature = "seed-secp256k1")]
+fn default_algorithm() -> Algorithm {
+    Algorithm::Secp256k1
+}
+
+#[cfg(not(feature = "seed-secp256k1"))]
+fn default_algorithm() -> Algorithm {
+    Algorithm::Ed25519
+}
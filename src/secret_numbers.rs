@@ -0,0 +1,118 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{convert::TryFrom, fmt::Write};
+
+use crate::{wipe, Entropy, Error, Result, ENTROPY_LEN};
+
+/// Number of rows, each encoding 2 bytes of entropy
+const ROWS: usize = ENTROPY_LEN / 2;
+/// Digits per row: a 5-digit value and a checksum digit
+const ROW_DIGITS: usize = 6;
+
+/// Encode entropy as 8 rows of 6 digits, as shown by Xaman (formerly XUMM)
+///
+/// Each row is a 2-byte chunk of the entropy as a 5-digit number,
+/// followed by a checksum digit. Rows are separated by spaces.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-secp256k1")] {
+/// use ripple_address_codec::{decode_seed, entropy_to_secret_numbers};
+///
+/// let (entropy, _) = decode_seed("sh1HiK7SwjS1VxFdXi7qeMHRedrYX").unwrap();
+///
+/// assert_eq!(
+///     entropy_to_secret_numbers(&entropy),
+///     "399150 474506 009147 088773 432160 282843 253738 605430"
+/// );
+/// # }
+/// ```
+pub fn entropy_to_secret_numbers(entropy: &Entropy) -> String {
+    let mut numbers = String::with_capacity(ROWS * (ROW_DIGITS + 1));
+
+    for (index, chunk) in entropy.chunks(2).enumerate() {
+        let mut value = u16::from_be_bytes([chunk[0], chunk[1]]);
+        if index > 0 {
+            numbers.push(' ');
+        }
+        write!(numbers, "{:05}{}", value, checksum(index, value)).expect("writing to a String");
+        wipe(&mut value);
+    }
+
+    numbers
+}
+
+/// Decode entropy from 8 rows of 6 digits
+///
+/// The inverse of [`entropy_to_secret_numbers`]. Whitespace is ignored,
+/// so rows may be separated by spaces or newlines, or not at all.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{secret_numbers_to_entropy, Error};
+///
+/// let entropy =
+///     secret_numbers_to_entropy("399150 474506 009147 088773 432160 282843 253738 605430").unwrap();
+///
+/// assert_eq!(entropy[..2], [0x9B, 0xEB]);
+///
+/// // The checksum digit of the second row is mistyped
+/// assert_eq!(
+///     secret_numbers_to_entropy("399150 474505 009147 088773 432160 282843 253738 605430"),
+///     Err(Error::BadSecretNumber { index: 1 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::DecodeError`] if the input isn't 48 digits and
+/// [`Error::BadSecretNumber`] for the first row with a checksum mismatch
+/// or a value above 65535.
+pub fn secret_numbers_to_entropy(numbers: &str) -> Result<Entropy> {
+    let mut digits = [0; ROWS * ROW_DIGITS];
+    let mut len = 0;
+
+    for character in numbers.chars().filter(|c| !c.is_whitespace()) {
+        match (digits.get_mut(len), character.to_digit(10)) {
+            (Some(digit), Some(value)) => *digit = value as u8,
+            _ => {
+                wipe(&mut digits);
+                return Err(Error::DecodeError);
+            }
+        }
+        len += 1;
+    }
+
+    if len != digits.len() {
+        wipe(&mut digits);
+        return Err(Error::DecodeError);
+    }
+
+    let mut entropy: Entropy = [0; ENTROPY_LEN];
+    for (index, row) in digits.chunks(ROW_DIGITS).enumerate() {
+        let value = row[..ROW_DIGITS - 1]
+            .iter()
+            .fold(0, |value, &digit| value * 10 + u32::from(digit));
+
+        match u16::try_from(value) {
+            Ok(value) if checksum(index, value) == row[ROW_DIGITS - 1] => {
+                entropy[2 * index..2 * index + 2].copy_from_slice(&value.to_be_bytes());
+            }
+            _ => {
+                wipe(&mut digits);
+                wipe(&mut entropy);
+                return Err(Error::BadSecretNumber { index });
+            }
+        }
+    }
+    wipe(&mut digits);
+
+    Ok(entropy)
+}
+
+fn checksum(index: usize, value: u16) -> u8 {
+    ((value as usize * (2 * index + 1)) % 9) as u8
+}
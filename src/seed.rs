@@ -0,0 +1,394 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{
+    convert::TryFrom,
+    fmt,
+    str::{self, FromStr},
+};
+
+#[cfg(feature = "rkyv")]
+use crate::ArchivedAlgorithm;
+#[cfg(any(feature = "mesalock_sgx", feature = "os-rng", feature = "rand"))]
+use crate::ENTROPY_LEN;
+use crate::{decode_seed, encode_seed_into, wipe, Algorithm, Entropy, Error, Result, SEED_MAX_LEN};
+
+/// A seed: entropy and the algorithm of the keys derived from it
+///
+/// Encoded as a string starting with s... (secp256k1)
+/// or sEd... (Ed25519).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{Ed25519, Seed};
+///
+/// let seed: Seed = "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".parse().unwrap();
+///
+/// assert_eq!(seed.entropy(), &[0; 16]);
+/// assert_eq!(seed.algorithm(), Ed25519);
+/// assert_eq!(seed.expose_secret(), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// # }
+/// ```
+///
+/// Seeds are ordered byte-wise by entropy, then by algorithm
+/// ([`Secp256k1`](crate::Secp256k1) first).
+///
+/// [`Debug`](fmt::Debug) and [`Display`](fmt::Display) output is
+/// redacted, so seeds don't leak into logs;
+/// [`expose_secret`](Seed::expose_secret) encodes the full seed.
+///
+/// With the `borsh` feature, it's serialized as its 16 entropy bytes
+/// followed by the algorithm as a single byte.
+///
+/// With the `rkyv` feature, it's archived as its 16 entropy bytes and
+/// algorithm. The archive holds the entropy in the clear, outside the
+/// reach of `zeroize` and `protected-memory`.
+///
+/// With the `serde` feature, it's serialized as the seed string in
+/// human-readable formats and as a tuple of its 16 entropy bytes and
+/// algorithm name in binary formats.
+///
+/// With the `xrpl-rust` feature, it can be read from an `xrpl-rust`
+/// `Wallet`.
+///
+/// With the `zeroize` feature, the entropy is overwritten with zeros on
+/// drop, as are the buffers used while encoding and decoding seeds.
+/// Seeds aren't `Copy`, so every copy is an explicit `clone`.
+///
+/// With the `protected-memory` feature, the entropy additionally lives
+/// on its own page locked in RAM, so it's never written to swap. Every
+/// seed locks a page, so processes holding many seeds at once may need
+/// a higher locked memory limit (`ulimit -l`); creating a seed panics
+/// when the page can't be locked. Inside enclaves, with `mesalock_sgx`,
+/// memory is already protected and `zeroize` is enough.
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{Ed25519, Seed};
+///
+/// assert_eq!(format!("{:?}", Seed::new([0; 16], Ed25519)), "Seed(sEd****)");
+/// # }
+/// ```
+// Not `Copy`, so secrets are only duplicated by explicit clones
+#[allow(missing_copy_implementations)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "uniffi",
+    derive(uniffi::Object),
+    uniffi::export(Debug, Eq, Hash)
+)]
+pub struct Seed {
+    entropy: SecretEntropy,
+    algorithm: Algorithm,
+}
+
+#[cfg(feature = "protected-memory")]
+type SecretEntropy = crate::protected::LockedEntropy;
+#[cfg(not(feature = "protected-memory"))]
+type SecretEntropy = Entropy;
+
+impl Seed {
+    /// Seed from entropy and algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{Secp256k1, Seed};
+    ///
+    /// // In the real world you **must** generate random entropy
+    /// let seed = Seed::new([0; 16], Secp256k1);
+    ///
+    /// assert_eq!(seed.expose_secret(), "sp6JS7f14BuwFY8Mw6bTtLKWauoUs");
+    /// # }
+    /// ```
+    pub fn new(entropy: Entropy, algorithm: Algorithm) -> Self {
+        Seed {
+            entropy: SecretEntropy::from(entropy),
+            algorithm,
+        }
+    }
+
+    /// The seed's entropy bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{Secp256k1, Seed};
+    ///
+    /// assert_eq!(Seed::new([1; 16], Secp256k1).entropy(), &[1; 16]);
+    /// # }
+    /// ```
+    pub fn entropy(&self) -> &Entropy {
+        &self.entropy
+    }
+
+    /// The algorithm of the keys derived from the seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{Secp256k1, Seed};
+    ///
+    /// assert_eq!(Seed::new([1; 16], Secp256k1).algorithm(), Secp256k1);
+    /// # }
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Encode the full seed (starting with s... or sEd...)
+    ///
+    /// The only way to get the seed string back, since
+    /// [`Display`](fmt::Display) is redacted. With the `zeroize` feature,
+    /// the encoding buffer is wiped, but the returned string is left to
+    /// the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{Ed25519, Seed};
+    ///
+    /// let seed = Seed::new([0; 16], Ed25519);
+    ///
+    /// assert_eq!(seed.expose_secret(), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+    /// assert_eq!(seed.to_string(), "sEd****");
+    /// # }
+    /// ```
+    pub fn expose_secret(&self) -> String {
+        let mut buffer = [0; SEED_MAX_LEN];
+        let len = encode_seed_into(&self.entropy, self.algorithm, &mut buffer)
+            .expect("buffer fits any seed");
+
+        let seed = str::from_utf8(&buffer[..len])
+            .expect("base58 is ASCII")
+            .to_owned();
+        wipe(&mut buffer);
+
+        seed
+    }
+
+    /// The same entropy tagged with another algorithm
+    ///
+    /// **Note:** keys derived from the returned seed, and so the
+    /// account, are entirely different from the original ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "seed-ed25519", feature = "seed-secp256k1"))] {
+    /// use ripple_address_codec::{Ed25519, Secp256k1, Seed};
+    ///
+    /// let seed = Seed::new([0; 16], Secp256k1).with_algorithm(Ed25519);
+    ///
+    /// assert_eq!(seed.expose_secret(), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+    /// # }
+    /// ```
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+}
+
+impl FromStr for Seed {
+    type Err = Error;
+
+    fn from_str(seed: &str) -> Result<Self> {
+        let (entropy, algorithm) = decode_seed(seed)?;
+
+        Ok(Seed::new(entropy, algorithm))
+    }
+}
+
+impl TryFrom<&str> for Seed {
+    type Error = Error;
+
+    fn try_from(seed: &str) -> Result<Self> {
+        seed.parse()
+    }
+}
+
+impl fmt::Debug for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Seed")
+            .field(&format_args!("{}", RedactedSeed(self.algorithm)))
+            .finish()
+    }
+}
+
+/// Prints the seed prefix only, like `sEd****`
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        RedactedSeed(self.algorithm).fmt(f)
+    }
+}
+
+/// The prefix of a seed of the algorithm followed by [`Redacted`]
+struct RedactedSeed(Algorithm);
+
+impl fmt::Display for RedactedSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.0 {
+            #[cfg(feature = "seed-secp256k1")]
+            Algorithm::Secp256k1 => "s",
+            #[cfg(feature = "seed-ed25519")]
+            Algorithm::Ed25519 => "sEd",
+        };
+
+        write!(f, "{}{}", prefix, Redacted)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedSeed {
+    /// The algorithm, read in place in the archive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{ArchivedSeed, Ed25519, Seed};
+    ///
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Seed::new([0; 16], Ed25519)).unwrap();
+    /// let archived = rkyv::access::<ArchivedSeed, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.algorithm(), Ed25519);
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        match self.algorithm {
+            #[cfg(feature = "seed-secp256k1")]
+            ArchivedAlgorithm::Secp256k1 => Algorithm::Secp256k1,
+            #[cfg(feature = "seed-ed25519")]
+            ArchivedAlgorithm::Ed25519 => Algorithm::Ed25519,
+        }
+    }
+}
+
+/// Redacted like [`Seed`]'s, since the archive holds the entropy
+#[cfg(feature = "rkyv")]
+impl fmt::Debug for ArchivedSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArchivedSeed")
+            .field(&format_args!("{}", RedactedSeed(self.algorithm())))
+            .finish()
+    }
+}
+
+/// Generate a seed from fresh random entropy
+///
+/// Returns the seed and its encoded string. Entropy comes from the
+/// CPU's RNG through `sgx_read_rand` with the `mesalock_sgx` feature
+/// and from the operating system with the `os-rng` feature otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_seed, generate_seed, Ed25519};
+///
+/// let (seed, encoded) = generate_seed(Ed25519);
+///
+/// assert_eq!(decode_seed(&encoded), Ok((*seed.entropy(), Ed25519)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the random number generator fails.
+#[cfg(any(feature = "mesalock_sgx", feature = "os-rng"))]
+pub fn generate_seed(algorithm: Algorithm) -> (Seed, String) {
+    let mut entropy: Entropy = [0; ENTROPY_LEN];
+    fill_random(&mut entropy);
+
+    let seed = Seed::new(entropy, algorithm);
+    wipe(&mut entropy);
+
+    let encoded = seed.expose_secret();
+    (seed, encoded)
+}
+
+/// Generate a seed from the entropy of the given random number generator
+///
+/// Available with the `rand` feature. A seeded generator gives
+/// reproducible seeds, e.g. in tests.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use ripple_address_codec::{generate_seed_with_rng, Secp256k1};
+///
+/// let seed = generate_seed_with_rng(&mut StdRng::seed_from_u64(7), Secp256k1);
+///
+/// assert_eq!(seed, generate_seed_with_rng(&mut StdRng::seed_from_u64(7), Secp256k1));
+/// ```
+#[cfg(feature = "rand")]
+pub fn generate_seed_with_rng<R: rand_core::RngCore + rand_core::CryptoRng>(
+    rng: &mut R,
+    algorithm: Algorithm,
+) -> Seed {
+    let mut entropy: Entropy = [0; ENTROPY_LEN];
+    rng.fill_bytes(&mut entropy);
+
+    let seed = Seed::new(entropy, algorithm);
+    wipe(&mut entropy);
+
+    seed
+}
+
+#[cfg(feature = "mesalock_sgx")]
+pub(crate) fn fill_random(bytes: &mut [u8]) {
+    sgx_trts::trts::rsgx_read_rand(bytes).expect("SGX random number generator failed");
+}
+
+#[cfg(all(feature = "os-rng", not(feature = "mesalock_sgx")))]
+pub(crate) fn fill_random(bytes: &mut [u8]) {
+    use rand_core::{OsRng, RngCore};
+
+    OsRng
+        .try_fill_bytes(bytes)
+        .expect("OS random number generator failed");
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Seed {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Seed {}
+
+/// Placeholder printed instead of secret material
+pub(crate) struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("****")
+    }
+}
+
+impl fmt::Display for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("****")
+    }
+}
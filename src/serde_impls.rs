@@ -0,0 +1,270 @@
+//! `Serialize`/`Deserialize`, available with the `serde` feature
+//!
+//! Human-readable formats (JSON, TOML, ...) use the token string.
+//! Binary formats (bincode, CBOR, ...) use the raw payload instead:
+//!
+//! * [`AccountId`]: its 20 bytes
+//! * [`Seed`]: a tuple of its 16 bytes of entropy and [`Algorithm`]
+//! * [`XAddress`]: a tuple of its account id, optional destination tag
+//!   (as `u64`) and whether it's for a test network
+//!
+//! [`Algorithm`] is its name in both. Deserialization validates the
+//! input like the matching `FromStr` implementation does. Note that a
+//! [`Seed`] serializes to the full secret.
+//!
+//! The borrowed [`ClassicAddressStr`], [`SeedStr`] and [`XAddressStr`]
+//! are strings in any format and, like `&str`, only deserialize from
+//! strings borrowed from the input, e.g. JSON without escapes.
+//!
+//! A [`WalletProposal`](crate::keypair::WalletProposal) serializes to a
+//! struct named like rippled's `wallet_propose` result, with the full seed.
+
+use std::{convert::TryFrom, fmt};
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+use serde::ser::SerializeStruct;
+use serde::{
+    de::{self, SeqAccess},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+use crate::keypair::WalletProposal;
+
+use crate::{
+    to_array, wipe, AccountId, Algorithm, ClassicAddressStr, Error, Network, Result as CrateResult,
+    Seed, SeedStr, Tag, XAddress, XAddressStr,
+};
+
+macro_rules! impl_serde_as_str {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor::<$t>::new(stringify!($t)))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_as_str!(Algorithm);
+
+impl Serialize for AccountId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new("AccountId"))
+        } else {
+            let ByteArray(bytes) = ByteArray::deserialize(deserializer)?;
+            Ok(AccountId::new(bytes))
+        }
+    }
+}
+
+impl Serialize for Seed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut seed = self.expose_secret();
+            let result = serializer.serialize_str(&seed);
+            wipe(&mut seed);
+
+            result
+        } else {
+            (RawBytes(self.entropy()), self.algorithm()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Seed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new("Seed"))
+        } else {
+            let (ByteArray(mut entropy), algorithm) = Deserialize::deserialize(deserializer)?;
+            let seed = Seed::new(entropy, algorithm);
+            wipe(&mut entropy);
+
+            Ok(seed)
+        }
+    }
+}
+
+impl Serialize for XAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let tag = self.tag().value();
+            (self.account_id(), tag, self.is_test()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for XAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new("XAddress"))
+        } else {
+            let (account_id, tag, test): (AccountId, Option<u64>, bool) =
+                Deserialize::deserialize(deserializer)?;
+            let tag = match tag {
+                None => Tag::None,
+                Some(tag) => Tag::U32(u32::try_from(tag).map_err(|_| {
+                    de::Error::custom(format_args!("invalid XAddress: {}", Error::UnsupportedTag))
+                })?),
+            };
+            let network = if test { Network::Test } else { Network::Main };
+
+            Ok(XAddress::new(account_id, tag, network))
+        }
+    }
+}
+
+#[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+impl Serialize for WalletProposal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut wallet = serializer.serialize_struct("WalletProposal", 5)?;
+        wallet.serialize_field("account_id", self.classic_address())?;
+        wallet.serialize_field("key_type", &self.algorithm())?;
+        wallet.serialize_field("master_seed", self.seed())?;
+        wallet.serialize_field("public_key_hex", self.public_key_hex())?;
+        wallet.serialize_field("x_address", self.xaddress())?;
+        wallet.end()
+    }
+}
+
+macro_rules! impl_serde_borrowed {
+    ($($t:ident),*) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(self.as_str())
+                }
+            }
+
+            impl<'de: 'a, 'a> Deserialize<'de> for &'a $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(BorrowedStrVisitor {
+                        expecting: stringify!($t),
+                        validate: $t::new,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_borrowed!(ClassicAddressStr, SeedStr, XAddressStr);
+
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        FromStrVisitor {
+            expecting,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string encoding {}", self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse()
+            .map_err(|error| E::custom(format_args!("invalid {}: {}", self.expecting, error)))
+    }
+}
+
+/// Validates a string borrowed from the input as an unsized `T`
+struct BorrowedStrVisitor<T: ?Sized + 'static> {
+    expecting: &'static str,
+    validate: fn(&str) -> CrateResult<&T>,
+}
+
+impl<'de, T: ?Sized + 'static> de::Visitor<'de> for BorrowedStrVisitor<T> {
+    type Value = &'de T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a borrowed string encoding {}", self.expecting)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, s: &'de str) -> Result<&'de T, E> {
+        (self.validate)(s)
+            .map_err(|error| E::custom(format_args!("invalid {}: {}", self.expecting, error)))
+    }
+}
+
+/// Serializes as bytes, rather than as a sequence of `u8`s
+struct RawBytes<'a>(&'a [u8]);
+
+impl Serialize for RawBytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Deserializes from bytes of length `N`, or a sequence of `N` `u8`s
+struct ByteArray<const N: usize>([u8; N]);
+
+impl<'de, const N: usize> Deserialize<'de> for ByteArray<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(ByteArrayVisitor)
+    }
+}
+
+struct ByteArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> de::Visitor<'de> for ByteArrayVisitor<N> {
+    type Value = ByteArray<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes", N)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        to_array(bytes).map(ByteArray).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0; N];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            wipe(&mut bytes);
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        Ok(ByteArray(bytes))
+    }
+}
@@ -0,0 +1,413 @@
+//! Field helpers for `#[serde(with = "...")]`, available with the `serde`
+//! feature
+//!
+//! Each module (de)serializes a plain field the way the matching wrapper
+//! type does, so structs keep their byte array fields.
+
+/// (De)serialize a `[u8; 20]` account id as a classic address
+///
+/// Same format as [`AccountId`](crate::AccountId): the address in
+/// human-readable formats, its 20 bytes in binary ones.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "ripple_address_codec::as_classic_address")]
+///     destination: [u8; 20],
+/// }
+///
+/// let json = r#"{"destination":"rrrrrrrrrrrrrrrrrrrrrhoLvTp"}"#;
+/// let payment: Payment = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(payment.destination, [0; 20]);
+/// assert_eq!(serde_json::to_string(&payment).unwrap(), json);
+/// ```
+pub mod as_classic_address {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{AccountId, Address, VersionedToken};
+
+    /// Serialize an account id as a classic address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::as_classic_address;
+    ///
+    /// assert_eq!(
+    ///     as_classic_address::serialize(&[0; 20], serde_json::value::Serializer).unwrap(),
+    ///     "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<S: Serializer>(
+        account_id: &[u8; Address::PAYLOAD_LEN],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        AccountId::new(*account_id).serialize(serializer)
+    }
+
+    /// Deserialize a classic address to its account id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::as_classic_address;
+    ///
+    /// assert_eq!(
+    ///     as_classic_address::deserialize(serde_json::json!("rrrrrrrrrrrrrrrrrrrrrhoLvTp")).unwrap(),
+    ///     [0; 20]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a valid classic address.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; Address::PAYLOAD_LEN], D::Error> {
+        AccountId::deserialize(deserializer).map(|account_id| *account_id.as_bytes())
+    }
+}
+
+/// (De)serialize an `(Entropy, Algorithm)` pair as a seed
+///
+/// Same format as [`Seed`](crate::Seed): the seed in human-readable
+/// formats, its entropy and algorithm in binary ones. Fields with the
+/// entropy of a known algorithm use the [`ed25519`](as_seed::ed25519)
+/// or [`secp256k1`](as_seed::secp256k1) module instead, which reject
+/// seeds of the other algorithm.
+///
+/// Note that the full secret is serialized.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{Algorithm, Entropy};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Wallet {
+///     #[serde(with = "ripple_address_codec::as_seed")]
+///     seed: (Entropy, Algorithm),
+/// }
+///
+/// let json = r#"{"seed":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#;
+/// let wallet: Wallet = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(wallet.seed, ([0; 16], Algorithm::Ed25519));
+/// assert_eq!(serde_json::to_string(&wallet).unwrap(), json);
+/// ```
+pub mod as_seed {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Algorithm, Entropy, Error, Seed};
+
+    /// Serialize entropy and its algorithm as a seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{as_seed, Ed25519};
+    ///
+    /// assert_eq!(
+    ///     as_seed::serialize(&([0; 16], Ed25519), serde_json::value::Serializer).unwrap(),
+    ///     "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<S: Serializer>(
+        (entropy, algorithm): &(Entropy, Algorithm),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Seed::new(*entropy, *algorithm).serialize(serializer)
+    }
+
+    /// Deserialize a seed to its entropy and algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{as_seed, Ed25519};
+    ///
+    /// assert_eq!(
+    ///     as_seed::deserialize(serde_json::json!("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE")).unwrap(),
+    ///     ([0; 16], Ed25519)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a valid seed.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(Entropy, Algorithm), D::Error> {
+        let seed = Seed::deserialize(deserializer)?;
+
+        Ok((*seed.entropy(), seed.algorithm()))
+    }
+
+    /// (De)serialize `Entropy` as an Ed25519 seed
+    ///
+    /// Available with the `seed-ed25519` feature, on by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Entropy;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Wallet {
+    ///     #[serde(with = "ripple_address_codec::as_seed::ed25519")]
+    ///     seed: Entropy,
+    /// }
+    ///
+    /// let wallet: Wallet = serde_json::from_str(r#"{"seed":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#).unwrap();
+    /// assert_eq!(wallet.seed, [0; 16]);
+    ///
+    /// assert!(serde_json::from_str::<Wallet>(r#"{"seed":"sp6JS7f14BuwFY8Mw6bTtLKWauoUs"}"#).is_err());
+    /// ```
+    #[cfg(feature = "seed-ed25519")]
+    pub mod ed25519 {
+        use serde::{Deserializer, Serializer};
+
+        use crate::{Algorithm, Entropy};
+
+        /// Serialize entropy as an Ed25519 seed
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ripple_address_codec::as_seed;
+        ///
+        /// assert_eq!(
+        ///     as_seed::ed25519::serialize(&[0; 16], serde_json::value::Serializer).unwrap(),
+        ///     "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"
+        /// );
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns the error of `serializer`.
+        pub fn serialize<S: Serializer>(
+            entropy: &Entropy,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::serialize(&(*entropy, Algorithm::Ed25519), serializer)
+        }
+
+        /// Deserialize an Ed25519 seed to its entropy
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ripple_address_codec::as_seed;
+        ///
+        /// assert_eq!(
+        ///     as_seed::ed25519::deserialize(serde_json::json!("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE")).unwrap(),
+        ///     [0; 16]
+        /// );
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the input isn't a valid seed, or is a
+        /// secp256k1 seed.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Entropy, D::Error> {
+            super::deserialize_entropy(deserializer, Algorithm::Ed25519)
+        }
+    }
+
+    /// (De)serialize `Entropy` as a secp256k1 seed
+    ///
+    /// Available with the `seed-secp256k1` feature, on by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Entropy;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Wallet {
+    ///     #[serde(with = "ripple_address_codec::as_seed::secp256k1")]
+    ///     seed: Entropy,
+    /// }
+    ///
+    /// let wallet: Wallet = serde_json::from_str(r#"{"seed":"sp6JS7f14BuwFY8Mw6bTtLKWauoUs"}"#).unwrap();
+    /// assert_eq!(wallet.seed, [0; 16]);
+    ///
+    /// assert!(serde_json::from_str::<Wallet>(r#"{"seed":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#).is_err());
+    /// ```
+    #[cfg(feature = "seed-secp256k1")]
+    pub mod secp256k1 {
+        use serde::{Deserializer, Serializer};
+
+        use crate::{Algorithm, Entropy};
+
+        /// Serialize entropy as a secp256k1 seed
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ripple_address_codec::as_seed;
+        ///
+        /// assert_eq!(
+        ///     as_seed::secp256k1::serialize(&[0; 16], serde_json::value::Serializer).unwrap(),
+        ///     "sp6JS7f14BuwFY8Mw6bTtLKWauoUs"
+        /// );
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns the error of `serializer`.
+        pub fn serialize<S: Serializer>(
+            entropy: &Entropy,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::serialize(&(*entropy, Algorithm::Secp256k1), serializer)
+        }
+
+        /// Deserialize a secp256k1 seed to its entropy
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ripple_address_codec::as_seed;
+        ///
+        /// assert_eq!(
+        ///     as_seed::secp256k1::deserialize(serde_json::json!("sp6JS7f14BuwFY8Mw6bTtLKWauoUs")).unwrap(),
+        ///     [0; 16]
+        /// );
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the input isn't a valid seed, or is an
+        /// Ed25519 seed.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Entropy, D::Error> {
+            super::deserialize_entropy(deserializer, Algorithm::Secp256k1)
+        }
+    }
+
+    /// Deserialize a seed, rejecting seeds of other algorithms than
+    /// `expected`
+    fn deserialize_entropy<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        expected: Algorithm,
+    ) -> Result<Entropy, D::Error> {
+        let seed = Seed::deserialize(deserializer)?;
+        let actual = seed.algorithm();
+        if actual != expected {
+            return Err(de::Error::custom(format_args!(
+                "invalid Seed: {}",
+                Error::WrongAlgorithm { expected, actual }
+            )));
+        }
+
+        Ok(*seed.entropy())
+    }
+}
+
+/// (De)serialize an `([u8; 20], Tag, Network)` triple as an X-address
+///
+/// Same format as [`XAddress`](crate::XAddress): the X-address in
+/// human-readable formats, its account id, tag and network in binary
+/// ones. The triple is what [`decode_xaddress`](crate::decode_xaddress)
+/// returns.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{Network, Tag};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "ripple_address_codec::as_xaddress")]
+///     destination: ([u8; 20], Tag, Network),
+/// }
+///
+/// let json = r#"{"destination":"X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"}"#;
+/// let payment: Payment = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(payment.destination, ([0; 20], Tag::None, Network::Main));
+/// assert_eq!(serde_json::to_string(&payment).unwrap(), json);
+/// ```
+pub mod as_xaddress {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{AccountId, Address, Network, Tag, VersionedToken, XAddress};
+
+    /// Serialize an account id, destination tag and network as an
+    /// X-address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{as_xaddress, Network, Tag};
+    ///
+    /// assert_eq!(
+    ///     as_xaddress::serialize(&([0; 20], Tag::None, Network::Main), serde_json::value::Serializer)
+    ///         .unwrap(),
+    ///     "X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<S: Serializer>(
+        (account_id, tag, network): &([u8; Address::PAYLOAD_LEN], Tag, Network),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        XAddress::new(AccountId::new(*account_id), *tag, *network).serialize(serializer)
+    }
+
+    /// Deserialize an X-address to its account id, destination tag and
+    /// network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{as_xaddress, Network, Tag};
+    ///
+    /// assert_eq!(
+    ///     as_xaddress::deserialize(serde_json::json!(
+    ///         "X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"
+    ///     ))
+    ///     .unwrap(),
+    ///     ([0; 20], Tag::None, Network::Main)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a valid X-address.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<([u8; Address::PAYLOAD_LEN], Tag, Network), D::Error> {
+        let xaddress = XAddress::deserialize(deserializer)?;
+
+        Ok((
+            *xaddress.account_id().as_bytes(),
+            xaddress.tag(),
+            xaddress.network(),
+        ))
+    }
+}
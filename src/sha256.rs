@@ -0,0 +1,129 @@
+//! Pluggable SHA-256 for checksums
+//!
+//! Checksums are the first 4 bytes of a double SHA-256. The free
+//! functions use [`DEFAULT`]; a [`Codec`](crate::codec::Codec) can use
+//! any [`Sha256`] implementation instead, e.g. a hardware-backed one
+//! provided by an enclave SDK.
+//!
+//! Built-in implementations are `Ring` (`ring` feature, enabled by
+//! default) and `Sha2` (`sha2` feature, pure Rust). To drop the `ring`
+//! dependency, disable default features and enable `sha2`.
+//!
+//! # Examples
+//!
+//! ```
+//! use ripple_address_codec::{codec::Codec, sha256::DEFAULT};
+//!
+//! let codec = Codec::new().with_sha256(|data: &[u8]| DEFAULT.digest(data));
+//!
+//! assert_eq!(codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"), Ok([0; 20]));
+//! ```
+
+use crate::CHECKSUM_LENGTH;
+
+#[cfg(not(any(feature = "ring", feature = "sha2")))]
+compile_error!("a SHA-256 implementation is required, enable the `ring` or `sha2` feature");
+
+/// SHA-256 digest length in bytes
+pub const DIGEST_LEN: usize = 32;
+
+/// SHA-256 implementation
+///
+/// Implemented for closures, so a function wrapping another library
+/// can be passed directly.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::sha256::{Sha256, DEFAULT, DIGEST_LEN};
+///
+/// #[derive(Debug)]
+/// struct Enclave;
+///
+/// impl Sha256 for Enclave {
+///     fn digest(&self, data: &[u8]) -> [u8; DIGEST_LEN] {
+///         // Call into the enclave SDK here
+///         DEFAULT.digest(data)
+///     }
+/// }
+///
+/// assert_eq!(Enclave.digest(b"")[..4], [0xE3, 0xB0, 0xC4, 0x42]);
+/// ```
+pub trait Sha256: Send + Sync {
+    /// SHA-256 digest of `data`
+    fn digest(&self, data: &[u8]) -> [u8; DIGEST_LEN];
+}
+
+impl<F> Sha256 for F
+where
+    F: Fn(&[u8]) -> [u8; DIGEST_LEN] + Send + Sync,
+{
+    fn digest(&self, data: &[u8]) -> [u8; DIGEST_LEN] {
+        self(data)
+    }
+}
+
+/// SHA-256 by the `ring` crate, available with the `ring` feature
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::sha256::{Ring, Sha256};
+///
+/// assert_eq!(Ring.digest(b"")[..4], [0xE3, 0xB0, 0xC4, 0x42]);
+/// ```
+#[cfg(feature = "ring")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ring;
+
+#[cfg(feature = "ring")]
+impl Sha256 for Ring {
+    fn digest(&self, data: &[u8]) -> [u8; DIGEST_LEN] {
+        let mut hash = [0; DIGEST_LEN];
+        hash.copy_from_slice(ring::digest::digest(&ring::digest::SHA256, data).as_ref());
+        hash
+    }
+}
+
+/// SHA-256 by the pure Rust `sha2` crate, available with the `sha2` feature
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::sha256::{Sha2, Sha256};
+///
+/// assert_eq!(Sha2.digest(b"")[..4], [0xE3, 0xB0, 0xC4, 0x42]);
+/// ```
+#[cfg(feature = "sha2")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sha2;
+
+#[cfg(feature = "sha2")]
+impl Sha256 for Sha2 {
+    fn digest(&self, data: &[u8]) -> [u8; DIGEST_LEN] {
+        use sha2::Digest;
+
+        sha2::Sha256::digest(data).into()
+    }
+}
+
+/// Implementation used by the free functions and by default by a
+/// [`Codec`](crate::codec::Codec): `Ring` if the `ring` feature is
+/// enabled, `Sha2` otherwise
+#[cfg(feature = "ring")]
+pub const DEFAULT: &dyn Sha256 = &Ring;
+
+/// Implementation used by the free functions and by default by a
+/// [`Codec`](crate::codec::Codec): `Ring` if the `ring` feature is
+/// enabled, `Sha2` otherwise
+#[cfg(all(feature = "sha2", not(feature = "ring")))]
+pub const DEFAULT: &dyn Sha256 = &Sha2;
+
+/// First 4 bytes of the double SHA-256 of `bytes`
+pub(crate) fn checksum(sha256: &dyn Sha256, bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let hash = sha256.digest(&sha256.digest(bytes));
+
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&hash[..CHECKSUM_LENGTH]);
+    checksum
+}
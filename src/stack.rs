@@ -0,0 +1,79 @@
+//! Encoding into fixed-capacity strings on the stack
+//!
+//! Available with the `heapless` feature. The decoders don't allocate
+//! either, so together they handle tokens without touching the heap.
+
+use heapless::String;
+
+use crate::{
+    encode_account_id_into, encode_seed_into, encode_xaddress_into, Address, Algorithm, Entropy,
+    Network, Tag, VersionedToken,
+};
+
+/// Capacity for classic addresses
+pub const ACCOUNT_ID_CAPACITY: usize = 35;
+/// Capacity for seeds of either algorithm
+pub const SEED_CAPACITY: usize = 31;
+/// Capacity for X-addresses
+pub const XADDRESS_CAPACITY: usize = 47;
+
+/// Encode bytes as a classic address in a stack string
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::stack::encode_account_id;
+///
+/// assert_eq!(encode_account_id(&[0; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+pub fn encode_account_id(bytes: &[u8; Address::PAYLOAD_LEN]) -> String<ACCOUNT_ID_CAPACITY> {
+    encode_with(|out| encode_account_id_into(bytes, out))
+}
+
+/// Encode the given entropy as a seed in a stack string
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{stack::encode_seed, Ed25519};
+///
+/// assert_eq!(encode_seed(&[0; 16], Ed25519), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// ```
+pub fn encode_seed(entropy: &Entropy, algorithm: Algorithm) -> String<SEED_CAPACITY> {
+    encode_with(|out| encode_seed_into(entropy, algorithm, out))
+}
+
+/// Encode an account id, destination tag and network as an X-address
+/// in a stack string
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{stack::encode_xaddress, Network, Tag};
+///
+/// assert_eq!(
+///     encode_xaddress(&[0; 20], Tag::None, Network::Main),
+///     "X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"
+/// );
+/// ```
+pub fn encode_xaddress(
+    account_id: &[u8; Address::PAYLOAD_LEN],
+    tag: Tag,
+    network: Network,
+) -> String<XADDRESS_CAPACITY> {
+    encode_with(|out| encode_xaddress_into(account_id, tag, network, out))
+}
+
+fn encode_with<const N: usize>(
+    encode_into: impl FnOnce(&mut [u8]) -> crate::Result<usize>,
+) -> String<N> {
+    let mut out = [0; N];
+    let len = encode_into(&mut out).expect("capacity fits the longest encoding");
+
+    let mut encoded = String::new();
+    encoded
+        .push_str(std::str::from_utf8(&out[..len]).expect("base58 alphabets are ASCII"))
+        .expect("capacity fits the longest encoding");
+
+    encoded
+}
@@ -0,0 +1,48 @@
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::{decode_account_id, Address, Result, VersionedToken};
+
+/// Validate the classic addresses of an async stream
+///
+/// Available with the `futures` feature. Yields every item with its
+/// decoded account id, or why it isn't a valid address, in input order.
+///
+/// Items already available are validated together, up to `concurrency`
+/// at a time, so a burst from a message queue is handled in one poll
+/// without waiting for more items, while bounding the work done before
+/// yielding to the executor. `concurrency` is at least 1.
+///
+/// # Examples
+///
+/// ```
+/// use futures::{executor::block_on, stream, StreamExt};
+/// use ripple_address_codec::{validate_account_ids_stream, Error};
+///
+/// let addresses = stream::iter(["rrrrrrrrrrrrrrrrrrrrrhoLvTp", "rrrrrrrrrrrrrrrrrrrrrhoLvTr"]);
+/// let validated: Vec<_> = block_on(validate_account_ids_stream(addresses, 16).collect());
+///
+/// assert_eq!(
+///     validated,
+///     [
+///         ("rrrrrrrrrrrrrrrrrrrrrhoLvTp", Ok([0; 20])),
+///         ("rrrrrrrrrrrrrrrrrrrrrhoLvTr", Err(Error::BadChecksum))
+///     ]
+/// );
+/// ```
+pub fn validate_account_ids_stream<St>(
+    addresses: St,
+    concurrency: usize,
+) -> impl Stream<Item = (St::Item, Result<[u8; Address::PAYLOAD_LEN]>)>
+where
+    St: Stream,
+    St::Item: AsRef<str>,
+{
+    addresses
+        .ready_chunks(concurrency.max(1))
+        .flat_map(|chunk| {
+            stream::iter(chunk.into_iter().map(|address| {
+                let decoded = decode_account_id(address.as_ref());
+                (address, decoded)
+            }))
+        })
+}
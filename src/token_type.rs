@@ -0,0 +1,175 @@
+use crate::{
+    xaddress::XAddressMain, AccountPrivateKey, AccountPublicKey, Address, Algorithm,
+    FamilyGenerator, NodePrivateKey, NodePublicKey, SeedEd25519, SeedSecP256K1, VersionedToken,
+};
+
+/// Kind of XRP Ledger token
+///
+/// With the metadata of its encoding, for tooling that renders or
+/// documents identifiers.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::TokenType;
+///
+/// for token_type in TokenType::all() {
+///     println!(
+///         "{:<20} {:<4} {:02X?} {} bytes",
+///         token_type.type_name(),
+///         token_type.human_prefix(),
+///         token_type.prefix_bytes(),
+///         token_type.payload_len()
+///     );
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenType {
+    /// Classic address (starting with r...)
+    AccountId,
+    /// X-address (starting with X... or T...)
+    XAddress,
+    /// Node public key (starting with n...)
+    NodePublicKey,
+    /// Account public key (starting with a...)
+    AccountPublicKey,
+    /// Node private key (starting with p...)
+    NodePrivateKey,
+    /// Account private key (starting with p...)
+    AccountPrivateKey,
+    /// Family generator (starting with f...)
+    FamilyGenerator,
+    /// Seed for secp256k1 keys (starting with s...)
+    SeedSecp256k1,
+    /// Seed for Ed25519 keys (starting with sEd...)
+    SeedEd25519,
+}
+
+impl TokenType {
+    /// All token types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::TokenType;
+    ///
+    /// assert_eq!(TokenType::all().len(), 9);
+    /// ```
+    pub const fn all() -> &'static [TokenType] {
+        &[
+            TokenType::AccountId,
+            TokenType::XAddress,
+            TokenType::NodePublicKey,
+            TokenType::AccountPublicKey,
+            TokenType::NodePrivateKey,
+            TokenType::AccountPrivateKey,
+            TokenType::FamilyGenerator,
+            TokenType::SeedSecp256k1,
+            TokenType::SeedEd25519,
+        ]
+    }
+
+    /// Version prefix bytes, the mainnet ones for X-addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::TokenType;
+    ///
+    /// assert_eq!(TokenType::SeedEd25519.prefix_bytes(), [0x01, 0xE1, 0x4B]);
+    /// ```
+    pub fn prefix_bytes(self) -> &'static [u8] {
+        match self {
+            TokenType::AccountId => Address::PREFIX,
+            TokenType::XAddress => XAddressMain::PREFIX,
+            TokenType::NodePublicKey => NodePublicKey::PREFIX,
+            TokenType::AccountPublicKey => AccountPublicKey::PREFIX,
+            TokenType::NodePrivateKey => NodePrivateKey::PREFIX,
+            TokenType::AccountPrivateKey => AccountPrivateKey::PREFIX,
+            TokenType::FamilyGenerator => FamilyGenerator::PREFIX,
+            TokenType::SeedSecp256k1 => SeedSecP256K1::PREFIX,
+            TokenType::SeedEd25519 => SeedEd25519::PREFIX,
+        }
+    }
+
+    /// Payload length in bytes, without prefix and checksum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::TokenType;
+    ///
+    /// assert_eq!(TokenType::AccountId.payload_len(), 20);
+    /// ```
+    pub fn payload_len(self) -> usize {
+        match self {
+            TokenType::AccountId => Address::PAYLOAD_LEN,
+            TokenType::XAddress => XAddressMain::PAYLOAD_LEN,
+            TokenType::NodePublicKey => NodePublicKey::PAYLOAD_LEN,
+            TokenType::AccountPublicKey => AccountPublicKey::PAYLOAD_LEN,
+            TokenType::NodePrivateKey => NodePrivateKey::PAYLOAD_LEN,
+            TokenType::AccountPrivateKey => AccountPrivateKey::PAYLOAD_LEN,
+            TokenType::FamilyGenerator => FamilyGenerator::PAYLOAD_LEN,
+            TokenType::SeedSecp256k1 => SeedSecP256K1::PAYLOAD_LEN,
+            TokenType::SeedEd25519 => SeedEd25519::PAYLOAD_LEN,
+        }
+    }
+
+    /// Stable snake_case name, for reports and logs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::TokenType;
+    ///
+    /// assert_eq!(TokenType::NodePublicKey.type_name(), "node_public_key");
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        match self {
+            TokenType::AccountId => "account_id",
+            TokenType::XAddress => "xaddress",
+            TokenType::NodePublicKey => "node_public_key",
+            TokenType::AccountPublicKey => "account_public_key",
+            TokenType::NodePrivateKey => "node_private_key",
+            TokenType::AccountPrivateKey => "account_private_key",
+            TokenType::FamilyGenerator => "family_generator",
+            TokenType::SeedSecp256k1 => "seed_secp256k1",
+            TokenType::SeedEd25519 => "seed_ed25519",
+        }
+    }
+
+    /// Leading characters of every encoded token, the mainnet ones for
+    /// X-addresses (test network ones start with `T`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-ed25519")] {
+    /// use ripple_address_codec::{encode_seed, TokenType, Ed25519};
+    ///
+    /// assert!(encode_seed(&[0xFF; 16], Ed25519).starts_with(TokenType::SeedEd25519.human_prefix()));
+    /// # }
+    /// ```
+    pub fn human_prefix(self) -> &'static str {
+        match self {
+            TokenType::AccountId => "r",
+            TokenType::XAddress => "X",
+            TokenType::NodePublicKey => "n",
+            TokenType::AccountPublicKey => "a",
+            TokenType::NodePrivateKey | TokenType::AccountPrivateKey => "p",
+            TokenType::FamilyGenerator => "f",
+            TokenType::SeedSecp256k1 => "s",
+            TokenType::SeedEd25519 => "sEd",
+        }
+    }
+
+    pub(crate) fn seed(algorithm: Algorithm) -> Self {
+        match algorithm {
+            #[cfg(feature = "seed-secp256k1")]
+            Algorithm::Secp256k1 => TokenType::SeedSecp256k1,
+            #[cfg(feature = "seed-ed25519")]
+            Algorithm::Ed25519 => TokenType::SeedEd25519,
+        }
+    }
+}
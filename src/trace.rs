@@ -0,0 +1,36 @@
+//! Decode failure events, emitted with the `tracing` feature
+
+use crate::Result;
+
+/// Pass through the `result` of decoding `input` as `token_type`,
+/// emitting a `debug` event if it failed
+///
+/// Events record the token type attempted, the input length and the
+/// [error code](crate::Error::code), never the input itself: it may be
+/// a seed or private key.
+#[cfg(feature = "tracing")]
+pub(crate) fn decoded<T, I: AsRef<[u8]> + ?Sized>(
+    token_type: &'static str,
+    input: &I,
+    result: Result<T>,
+) -> Result<T> {
+    if let Err(error) = &result {
+        tracing::debug!(
+            token_type,
+            input_len = input.as_ref().len(),
+            error = error.code(),
+            "decode failed"
+        );
+    }
+
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn decoded<T, I: AsRef<[u8]> + ?Sized>(
+    _token_type: &'static str,
+    _input: &I,
+    result: Result<T>,
+) -> Result<T> {
+    result
+}
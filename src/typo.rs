@@ -0,0 +1,78 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use crate::{base58, get_checked_bytes, sha256, with_decoded_bytes, ALPHABET, MAX_TOKEN_LEN};
+
+/// Longest known token, in characters
+const MAX_TOKEN_CHARS: usize = base58::max_encoded_len(MAX_TOKEN_LEN);
+
+/// Suggest a correction for a token with a single mistyped character
+///
+/// Tries every single character substitution and every swap of two
+/// adjacent characters, and returns the candidate if exactly one of
+/// them passes the checksum. Meant to be called after decoding failed
+/// with [`Error::BadChecksum`](crate::Error::BadChecksum),
+/// [`Error::BadAlphabet`](crate::Error::BadAlphabet) or
+/// [`Error::Confusable`](crate::Error::Confusable); returns `None`
+/// if the token is already valid, the correction is ambiguous or the
+/// token is longer than any known token type.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::suggest_correction;
+///
+/// assert_eq!(
+///     suggest_correction("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"),
+///     Some("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN".to_string())
+/// );
+/// assert_eq!(suggest_correction("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"), None);
+/// ```
+pub fn suggest_correction(token: &str) -> Option<String> {
+    // Every candidate is decoded in quadratic time, bound the input
+    if token.chars().nth(MAX_TOKEN_CHARS).is_some() || has_valid_checksum(token) {
+        return None;
+    }
+
+    let chars: Vec<char> = token.chars().collect();
+    let mut candidates = substitutions(&chars)
+        .chain(transpositions(&chars))
+        .filter(|candidate| has_valid_checksum(candidate));
+
+    let candidate = candidates.next()?;
+
+    match candidates.next() {
+        None => Some(candidate),
+        Some(_) => None,
+    }
+}
+
+fn substitutions(chars: &[char]) -> impl Iterator<Item = String> + '_ {
+    (0..chars.len()).flat_map(move |index| {
+        ALPHABET
+            .chars()
+            .filter(move |&character| character != chars[index])
+            .map(move |character| {
+                let mut candidate = chars.to_vec();
+                candidate[index] = character;
+                candidate.into_iter().collect()
+            })
+    })
+}
+
+fn transpositions(chars: &[char]) -> impl Iterator<Item = String> + '_ {
+    (1..chars.len())
+        .filter(move |&index| chars[index - 1] != chars[index])
+        .map(move |index| {
+            let mut candidate = chars.to_vec();
+            candidate.swap(index - 1, index);
+            candidate.into_iter().collect()
+        })
+}
+
+fn has_valid_checksum(token: &str) -> bool {
+    with_decoded_bytes(token, |decoded_bytes| {
+        get_checked_bytes(sha256::DEFAULT, decoded_bytes).map(|_| ())
+    })
+    .is_ok()
+}
@@ -0,0 +1,134 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{error, fmt, sync::Arc};
+
+use crate::{to_array, AccountId, Algorithm, Error, Network, Seed, Tag, XAddress};
+
+type Result<T> = std::result::Result<T, CodecError>;
+
+/// Error thrown by the bindings
+///
+/// Not `Error`, which would shadow `Exception` in Kotlin and `Error` in Swift.
+#[derive(Debug, uniffi::Error)]
+pub(crate) enum CodecError {
+    /// Invalid input
+    Invalid {
+        /// Stable error code, like `bad_checksum`
+        code: String,
+        /// Human readable description
+        description: String,
+    },
+}
+
+impl error::Error for CodecError {}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Invalid { description, .. } => f.write_str(description),
+        }
+    }
+}
+
+impl From<Error> for CodecError {
+    fn from(error: Error) -> Self {
+        CodecError::Invalid {
+            code: error.code().to_owned(),
+            description: error.to_string(),
+        }
+    }
+}
+
+#[uniffi::export]
+impl AccountId {
+    /// Account id from its 20 raw bytes
+    #[uniffi::constructor(name = "from_bytes")]
+    fn uniffi_from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Ok(AccountId::new(to_array(&bytes)?))
+    }
+
+    /// Decode a classic address (starting with r...)
+    #[uniffi::constructor(name = "parse")]
+    fn uniffi_parse(address: String) -> Result<Self> {
+        Ok(address.parse()?)
+    }
+
+    /// The 20 raw bytes
+    #[uniffi::method(name = "bytes")]
+    fn uniffi_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+#[uniffi::export]
+impl Seed {
+    /// Seed from 16 bytes of entropy and algorithm
+    #[uniffi::constructor(name = "from_entropy")]
+    fn uniffi_from_entropy(entropy: Vec<u8>, algorithm: Algorithm) -> Result<Self> {
+        Ok(Seed::new(to_array(&entropy)?, algorithm))
+    }
+
+    /// Decode a seed (starting with s...)
+    #[uniffi::constructor(name = "parse")]
+    fn uniffi_parse(seed: String) -> Result<Self> {
+        Ok(seed.parse()?)
+    }
+
+    /// The 16 bytes of entropy
+    #[uniffi::method(name = "entropy")]
+    fn uniffi_entropy(&self) -> Vec<u8> {
+        self.entropy().to_vec()
+    }
+
+    /// The algorithm the seed is intended to be used with
+    #[uniffi::method(name = "algorithm")]
+    fn uniffi_algorithm(&self) -> Algorithm {
+        self.algorithm()
+    }
+
+    /// Encode the seed (starting with s...)
+    ///
+    /// The string representation is redacted, so that logging a seed
+    /// doesn't reveal it.
+    #[uniffi::method(name = "encode")]
+    fn uniffi_encode(&self) -> String {
+        self.expose_secret()
+    }
+}
+
+#[uniffi::export]
+impl XAddress {
+    /// X-address from an account id, optional destination tag and network
+    #[uniffi::constructor(name = "new")]
+    fn uniffi_new(account_id: Arc<AccountId>, tag: Option<u32>, network: Network) -> Self {
+        XAddress::new(*account_id, tag.into(), network)
+    }
+
+    /// Decode an X-address
+    #[uniffi::constructor(name = "parse")]
+    fn uniffi_parse(xaddress: String) -> Result<Self> {
+        Ok(xaddress.parse()?)
+    }
+
+    /// The account id
+    #[uniffi::method(name = "account_id")]
+    fn uniffi_account_id(&self) -> Arc<AccountId> {
+        Arc::new(self.account_id())
+    }
+
+    /// The destination tag, if any
+    #[uniffi::method(name = "tag")]
+    fn uniffi_tag(&self) -> Option<u32> {
+        match self.tag() {
+            Tag::None => None,
+            Tag::U32(tag) => Some(tag),
+        }
+    }
+
+    /// The network
+    #[uniffi::method(name = "network")]
+    fn uniffi_network(&self) -> Network {
+        self.network()
+    }
+}
@@ -0,0 +1,488 @@
+use std::{convert::TryFrom, fmt, ops::Deref};
+
+use crate::{
+    decode_account_id, decode_seed, decode_xaddress, seed::Redacted, AccountId, Address, Algorithm,
+    Error, Network, Result, Seed, Tag, TokenType, VersionedToken, XAddress,
+};
+
+/// [`ClassicAddressStr`] under the name of its owned counterpart,
+/// [`AccountId`], like [`Path`](std::path::Path) is to
+/// [`PathBuf`](std::path::PathBuf)
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{AccountId, AccountIdStr};
+///
+/// let address = AccountIdStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+///
+/// assert_eq!(address.to_account_id(), AccountId::new([0; 20]));
+/// ```
+pub type AccountIdStr = ClassicAddressStr;
+
+/// A string slice proven to be a valid classic address (starting with r...)
+///
+/// This is an unsized type, like [`str`], and is always used behind a
+/// reference. It borrows the original string, so it lets APIs accept
+/// an already validated address without allocating or decoding it to
+/// bytes again.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::ClassicAddressStr;
+///
+/// let address = ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+///
+/// assert_eq!(address.as_str(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// assert_eq!(address.account_id(), [0; 20]);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ClassicAddressStr(str);
+
+impl ClassicAddressStr {
+    /// Validate a string slice as a classic address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::ClassicAddressStr;
+    ///
+    /// assert!(ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_ok());
+    /// assert!(ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTP").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) describing why address is invalid.
+    pub fn new(address: &str) -> Result<&Self> {
+        decode_account_id(address)?;
+
+        Ok(Self::from_str_unchecked(address))
+    }
+
+    /// Borrow the address as a string slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::ClassicAddressStr;
+    ///
+    /// let address = ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    ///
+    /// assert_eq!(address.as_str(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Decode the address to its raw account id bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::ClassicAddressStr;
+    ///
+    /// let address = ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    ///
+    /// assert_eq!(address.account_id(), [0; 20]);
+    /// ```
+    pub fn account_id(&self) -> [u8; Address::PAYLOAD_LEN] {
+        decode_account_id(&self.0).expect("validated on construction")
+    }
+
+    /// Decode the address to an owned [`AccountId`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ClassicAddressStr};
+    ///
+    /// let address = ClassicAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    ///
+    /// assert_eq!(address.to_account_id(), AccountId::new([0; 20]));
+    /// ```
+    pub fn to_account_id(&self) -> AccountId {
+        AccountId::new(self.account_id())
+    }
+
+    fn from_str_unchecked(address: &str) -> &Self {
+        // SAFETY: `ClassicAddressStr` is a `#[repr(transparent)]` wrapper
+        // around `str`, so both references have the same layout.
+        unsafe { &*(address as *const str as *const Self) }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a ClassicAddressStr {
+    type Error = Error;
+
+    fn try_from(address: &'a str) -> Result<Self> {
+        ClassicAddressStr::new(address)
+    }
+}
+
+impl Deref for ClassicAddressStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ClassicAddressStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for ClassicAddressStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl fmt::Display for ClassicAddressStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&ClassicAddressStr> for AccountId {
+    fn from(address: &ClassicAddressStr) -> Self {
+        address.to_account_id()
+    }
+}
+
+/// A string slice proven to be a valid X-address (starting with X... or T...)
+///
+/// The borrowed counterpart of [`XAddress`], like [`ClassicAddressStr`]
+/// is of [`AccountId`].
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{Network, Tag, XAddressStr};
+///
+/// let xaddress = XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+///
+/// assert_eq!(xaddress.as_str(), "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC");
+/// assert_eq!(xaddress.tag(), Tag::U32(1));
+/// assert_eq!(xaddress.network(), Network::Main);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct XAddressStr(str);
+
+impl XAddressStr {
+    /// Validate a string slice as an X-address of either network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::XAddressStr;
+    ///
+    /// assert!(XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").is_ok());
+    /// assert!(XAddressStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) describing why X-address is invalid.
+    pub fn new(xaddress: &str) -> Result<&Self> {
+        decode_xaddress(xaddress)?;
+
+        Ok(Self::from_str_unchecked(xaddress))
+    }
+
+    /// Borrow the X-address as a string slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::XAddressStr;
+    ///
+    /// let xaddress = XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    ///
+    /// assert_eq!(xaddress.as_str(), "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Decode the X-address to its raw account id bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{encode_account_id, XAddressStr};
+    ///
+    /// let xaddress = XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    ///
+    /// assert_eq!(
+    ///     encode_account_id(&xaddress.account_id()),
+    ///     "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"
+    /// );
+    /// ```
+    pub fn account_id(&self) -> [u8; Address::PAYLOAD_LEN] {
+        self.decode().0
+    }
+
+    /// Decode the destination tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Tag, XAddressStr};
+    ///
+    /// let xaddress = XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    ///
+    /// assert_eq!(xaddress.tag(), Tag::U32(1));
+    /// ```
+    pub fn tag(&self) -> Tag {
+        self.decode().1
+    }
+
+    /// Decode the network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Network, XAddressStr};
+    ///
+    /// let xaddress = XAddressStr::new("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+    ///
+    /// assert_eq!(xaddress.network(), Network::Test);
+    /// ```
+    pub fn network(&self) -> Network {
+        self.decode().2
+    }
+
+    /// Decode the X-address to an owned [`XAddress`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{XAddress, XAddressStr};
+    ///
+    /// let xaddress = XAddressStr::new("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    ///
+    /// assert_eq!(
+    ///     xaddress.to_xaddress(),
+    ///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC".parse::<XAddress>().unwrap()
+    /// );
+    /// ```
+    pub fn to_xaddress(&self) -> XAddress {
+        let (account_id, tag, network) = self.decode();
+
+        XAddress::new(AccountId::new(account_id), tag, network)
+    }
+
+    fn decode(&self) -> ([u8; Address::PAYLOAD_LEN], Tag, Network) {
+        decode_xaddress(&self.0).expect("validated on construction")
+    }
+
+    fn from_str_unchecked(xaddress: &str) -> &Self {
+        // SAFETY: `XAddressStr` is a `#[repr(transparent)]` wrapper around
+        // `str`, so both references have the same layout.
+        unsafe { &*(xaddress as *const str as *const Self) }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a XAddressStr {
+    type Error = Error;
+
+    fn try_from(xaddress: &'a str) -> Result<Self> {
+        XAddressStr::new(xaddress)
+    }
+}
+
+impl Deref for XAddressStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for XAddressStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for XAddressStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl fmt::Display for XAddressStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&XAddressStr> for XAddress {
+    fn from(xaddress: &XAddressStr) -> Self {
+        xaddress.to_xaddress()
+    }
+}
+
+/// A string slice proven to be a valid seed (starting with s... or sEd...)
+///
+/// The borrowed counterpart of [`Seed`], like [`ClassicAddressStr`] is
+/// of [`AccountId`]: request types can hold an already validated seed
+/// without copying it. Like [`Seed`], neither `Debug` nor `Display` show
+/// the seed, [`SeedStr::as_str`] does.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "seed-ed25519")] {
+/// use ripple_address_codec::{Ed25519, SeedStr};
+///
+/// let seed = SeedStr::new("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+///
+/// assert_eq!(seed.algorithm(), Ed25519);
+/// assert_eq!(format!("{:?}", seed), "SeedStr(sEd****)");
+/// assert_eq!(seed.to_string(), "sEd****");
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SeedStr(str);
+
+impl SeedStr {
+    /// Validate a string slice as a seed of either algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::SeedStr;
+    ///
+    /// assert!(SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfL").is_ok());
+    /// assert!(SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfM").is_err());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) describing why seed is invalid.
+    pub fn new(seed: &str) -> Result<&Self> {
+        decode_seed(seed)?;
+
+        Ok(Self::from_str_unchecked(seed))
+    }
+
+    /// Borrow the seed as a string slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::SeedStr;
+    ///
+    /// let seed = SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+    ///
+    /// assert_eq!(seed.as_str(), "sn259rEFXrQrWyx3Q7XneWcwV6dfL");
+    /// # }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The algorithm of the keys derived from the seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{Secp256k1, SeedStr};
+    ///
+    /// let seed = SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+    ///
+    /// assert_eq!(seed.algorithm(), Secp256k1);
+    /// # }
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.to_seed().algorithm()
+    }
+
+    /// Decode the seed to an owned [`Seed`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "seed-secp256k1")] {
+    /// use ripple_address_codec::{Seed, SeedStr};
+    ///
+    /// let seed = SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+    ///
+    /// assert_eq!(seed.to_seed(), "sn259rEFXrQrWyx3Q7XneWcwV6dfL".parse::<Seed>().unwrap());
+    /// # }
+    /// ```
+    pub fn to_seed(&self) -> Seed {
+        self.0.parse().expect("validated on construction")
+    }
+
+    fn from_str_unchecked(seed: &str) -> &Self {
+        // SAFETY: `SeedStr` is a `#[repr(transparent)]` wrapper around
+        // `str`, so both references have the same layout.
+        unsafe { &*(seed as *const str as *const Self) }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a SeedStr {
+    type Error = Error;
+
+    fn try_from(seed: &'a str) -> Result<Self> {
+        SeedStr::new(seed)
+    }
+}
+
+impl Deref for SeedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SeedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for SeedStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl fmt::Debug for SeedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = TokenType::seed(self.algorithm()).human_prefix();
+
+        f.debug_tuple("SeedStr")
+            .field(&format_args!("{}{}", prefix, Redacted))
+            .finish()
+    }
+}
+
+/// Prints the seed prefix only, like `sEd****`
+impl fmt::Display for SeedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = TokenType::seed(self.algorithm()).human_prefix();
+
+        write!(f, "{}{}", prefix, Redacted)
+    }
+}
+
+impl From<&SeedStr> for Seed {
+    fn from(seed: &SeedStr) -> Self {
+        seed.to_seed()
+    }
+}
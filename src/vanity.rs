@@ -0,0 +1,370 @@
+//! Vanity address search
+//!
+//! Available with the `vanity` feature. [`VanitySearch`] generates
+//! seeds from fresh random entropy, derives their classic addresses and
+//! stops at the first one matching a [`Pattern`], on several threads.
+//!
+//! Every character of a pattern multiplies the expected number of
+//! attempts by about 58, or about 34 when ignoring case. Some patterns
+//! can't match at all, e.g. ones longer than an address, so
+//! [`VanitySearch::with_max_attempts`] bounds the search.
+//!
+//! # Examples
+//!
+//! ```
+//! use ripple_address_codec::{
+//!     keypair,
+//!     vanity::{Pattern, VanitySearch},
+//!     Ed25519,
+//! };
+//!
+//! let (seed, address) = VanitySearch::new(Pattern::suffix("x").unwrap().ignore_case())
+//!     .with_algorithm(Ed25519)
+//!     .run()
+//!     .unwrap();
+//!
+//! assert!(address.to_lowercase().ends_with('x'));
+//! assert_eq!(keypair::derive(&seed).address(), address);
+//! ```
+
+use std::{
+    convert::TryInto,
+    str,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+use crate::{
+    encode_account_id_into, keypair, seed::fill_random, wipe, Algorithm, Error, Result, Secp256k1,
+    Seed, ALPHABET, ENTROPY_LEN, MAX_TOKEN_LEN,
+};
+
+/// Seeds generated per call to the random number generator
+const BATCH_LEN: usize = 64;
+
+/// Where a [`Pattern`] has to occur in an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Position {
+    Prefix,
+    Suffix,
+    Anywhere,
+}
+
+/// What a vanity address has to look like
+///
+/// The text only contains characters of the XRPL base58 alphabet, which
+/// has no `0`, `O`, `I` or `l`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::vanity::Pattern;
+///
+/// let pattern = Pattern::prefix("rXRP").unwrap();
+///
+/// assert!(pattern.matches("rXRPa8ynVkH7uBQEdjhkbHcbPZ1AzGpN1"));
+/// assert!(!pattern.matches("rxrpa8ynVkH7uBQEdjhkbHcbPZ1AzGpN1"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    text: String,
+    position: Position,
+    ignore_case: bool,
+}
+
+impl Pattern {
+    /// Addresses starting with `text`, which includes the leading `r`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{vanity::Pattern, Error};
+    ///
+    /// assert!(Pattern::prefix("rBob").is_ok());
+    /// assert_eq!(Pattern::prefix("Bob"), Err(Error::BadPrefix { expected: b"r" }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadPrefix`] if `text` doesn't start with `r` and
+    /// [`Error::BadAlphabet`] if it has a character outside the alphabet.
+    pub fn prefix(text: &str) -> Result<Self> {
+        if !text.starts_with('r') {
+            return Err(Error::BadPrefix { expected: b"r" });
+        }
+
+        Self::new(text, Position::Prefix)
+    }
+
+    /// Addresses ending with `text`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::Pattern;
+    ///
+    /// assert!(Pattern::suffix("XRP").unwrap().matches("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY3XRP"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadAlphabet`] if `text` has a character outside
+    /// the alphabet.
+    pub fn suffix(text: &str) -> Result<Self> {
+        Self::new(text, Position::Suffix)
+    }
+
+    /// Addresses containing `text` anywhere
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{vanity::Pattern, Error};
+    ///
+    /// assert!(Pattern::contains("XRP").unwrap().matches("rJrRMgiRgrU6hDXRPF4pgu5DXQdWyPbY3"));
+    /// assert_eq!(
+    ///     Pattern::contains("l0l"),
+    ///     Err(Error::BadAlphabet { character: 'l', index: 0 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadAlphabet`] if `text` has a character outside
+    /// the alphabet.
+    pub fn contains(text: &str) -> Result<Self> {
+        Self::new(text, Position::Anywhere)
+    }
+
+    fn new(text: &str, position: Position) -> Result<Self> {
+        if let Some((index, character)) = text
+            .chars()
+            .enumerate()
+            .find(|(_, character)| !ALPHABET.contains(*character))
+        {
+            return Err(Error::BadAlphabet { character, index });
+        }
+
+        Ok(Pattern {
+            text: text.to_owned(),
+            position,
+            ignore_case: false,
+        })
+    }
+
+    /// Match ASCII letters regardless of case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::Pattern;
+    ///
+    /// let pattern = Pattern::prefix("rXRP").unwrap().ignore_case();
+    ///
+    /// assert!(pattern.matches("rxrpa8ynVkH7uBQEdjhkbHcbPZ1AzGpN1"));
+    /// ```
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Whether `address` matches the pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::Pattern;
+    ///
+    /// assert!(!Pattern::suffix("XRP").unwrap().matches("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+    /// ```
+    pub fn matches(&self, address: &str) -> bool {
+        let (address, text) = (address.as_bytes(), self.text.as_bytes());
+        let eq = |a: &[u8]| {
+            if self.ignore_case {
+                a.eq_ignore_ascii_case(text)
+            } else {
+                a == text
+            }
+        };
+        if address.len() < text.len() {
+            return false;
+        }
+
+        match self.position {
+            Position::Prefix => eq(&address[..text.len()]),
+            Position::Suffix => eq(&address[address.len() - text.len()..]),
+            Position::Anywhere => text.is_empty() || address.windows(text.len()).any(eq),
+        }
+    }
+}
+
+/// Multithreaded search for a seed whose address matches a [`Pattern`]
+///
+/// Searches for secp256k1 seeds on every available core by default.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::vanity::{Pattern, VanitySearch};
+///
+/// let search = VanitySearch::new(Pattern::contains("XRP").unwrap()).with_threads(2);
+///
+/// assert!(format!("{:?}", search).contains("XRP"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VanitySearch {
+    pattern: Pattern,
+    algorithm: Algorithm,
+    threads: usize,
+    max_attempts: Option<u64>,
+}
+
+impl VanitySearch {
+    /// Search for addresses matching `pattern`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::{Pattern, VanitySearch};
+    ///
+    /// let (_, address) = VanitySearch::new(Pattern::suffix("A").unwrap()).run().unwrap();
+    ///
+    /// assert!(address.ends_with('A'));
+    /// ```
+    pub fn new(pattern: Pattern) -> Self {
+        VanitySearch {
+            pattern,
+            algorithm: Secp256k1,
+            threads: thread::available_parallelism().map_or(1, usize::from),
+            max_attempts: None,
+        }
+    }
+
+    /// Generate seeds for `algorithm`, secp256k1 by default
+    ///
+    /// Ed25519 keys are several times faster to derive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{
+    ///     vanity::{Pattern, VanitySearch},
+    ///     Ed25519,
+    /// };
+    ///
+    /// let (seed, _) = VanitySearch::new(Pattern::contains("").unwrap())
+    ///     .with_algorithm(Ed25519)
+    ///     .run()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(seed.algorithm(), Ed25519);
+    /// ```
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Search on `threads` threads, at least one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::{Pattern, VanitySearch};
+    ///
+    /// let search = VanitySearch::new(Pattern::suffix("r").unwrap()).with_threads(1);
+    ///
+    /// assert!(search.run().unwrap().1.ends_with('r'));
+    /// ```
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Give up after about `max_attempts` seeds, rounded up to whole
+    /// batches of random entropy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::{Pattern, VanitySearch};
+    ///
+    /// let too_long = Pattern::contains(&"r".repeat(36)).unwrap();
+    /// let search = VanitySearch::new(too_long).with_max_attempts(100);
+    ///
+    /// assert!(search.run().is_none());
+    /// ```
+    pub fn with_max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Run the search, returning the first matching seed and its address
+    ///
+    /// Returns `None` if the maximum number of attempts is reached first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::vanity::{Pattern, VanitySearch};
+    ///
+    /// let (seed, address) = VanitySearch::new(Pattern::prefix("r").unwrap()).run().unwrap();
+    ///
+    /// assert!(address.starts_with('r'));
+    /// assert_ne!(seed.expose_secret(), address);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails.
+    pub fn run(&self) -> Option<(Seed, String)> {
+        let stop = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| scope.spawn(|| self.search(&stop, &attempts)))
+                .collect();
+
+            workers
+                .into_iter()
+                .filter_map(|worker| worker.join().expect("vanity search thread panicked"))
+                .next()
+        })
+    }
+
+    /// Search on the current thread until a match, `stop` or the maximum
+    /// number of attempts
+    fn search(&self, stop: &AtomicBool, attempts: &AtomicU64) -> Option<(Seed, String)> {
+        let mut entropy = [0; ENTROPY_LEN * BATCH_LEN];
+        let mut buffer = [0; MAX_TOKEN_LEN];
+        let mut found = None;
+
+        while found.is_none() && !stop.load(Ordering::Relaxed) {
+            let attempted = attempts.fetch_add(BATCH_LEN as u64, Ordering::Relaxed);
+            if self.max_attempts.is_some_and(|max| attempted >= max) {
+                break;
+            }
+
+            fill_random(&mut entropy);
+            found = entropy.chunks_exact(ENTROPY_LEN).find_map(|entropy| {
+                let seed = Seed::new(
+                    entropy.try_into().expect("chunks are entropy long"),
+                    self.algorithm,
+                );
+                let len = encode_account_id_into(&keypair::derive(&seed).account_id(), &mut buffer)
+                    .expect("buffer fits any account id");
+                let address = str::from_utf8(&buffer[..len]).expect("base58 is ASCII");
+
+                self.pattern
+                    .matches(address)
+                    .then(|| (seed, address.to_owned()))
+            });
+        }
+        if found.is_some() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        wipe(&mut entropy);
+
+        found
+    }
+}
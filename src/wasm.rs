@@ -0,0 +1,395 @@
+//! WebAssembly bindings
+//!
+//! Available with the `wasm` feature. Exports the [`compat`] functions
+//! to JavaScript under their names in the JavaScript ripple-address-codec
+//! package, so browser wallets can replace it with the implementation
+//! used in enclaves. Bytes are passed as `Uint8Array`s and errors are
+//! thrown as `Error`s with the message of the [`Error`](crate::Error).
+//!
+//! `ring` doesn't build for `wasm32-unknown-unknown`, so build with the
+//! pure Rust SHA-256:
+//!
+//! ```text
+//! cargo rustc --release --target wasm32-unknown-unknown --no-default-features --features wasm,sha2,seed-ed25519,seed-secp256k1 --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/ripple_address_codec.wasm
+//! ```
+//!
+//! ```js
+//! import init, { encodeAccountID, decodeSeed } from './pkg/ripple_address_codec.js';
+//!
+//! await init();
+//! encodeAccountID(new Uint8Array(20)); // 'rrrrrrrrrrrrrrrrrrrrrhoLvTp'
+//! decodeSeed('sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE').type; // 'ed25519'
+//! ```
+
+#![allow(non_snake_case)]
+
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::fmt;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{compat, seed::Redacted, to_array, Algorithm, Entropy};
+
+/// Decoded seed, shaped like the object returned by JavaScript `decodeSeed`
+///
+/// [`Debug`](fmt::Debug) output redacts the entropy.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct DecodedSeed {
+    bytes: Entropy,
+    algorithm: Algorithm,
+}
+
+#[wasm_bindgen]
+impl DecodedSeed {
+    /// Seed entropy
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    /// Algorithm the seed is intended to be used with, `"ed25519"` or
+    /// `"secp256k1"`
+    #[wasm_bindgen(getter = type)]
+    pub fn r#type(&self) -> String {
+        self.algorithm.to_string()
+    }
+}
+
+impl fmt::Debug for DecodedSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodedSeed")
+            .field("bytes", &Redacted)
+            .field("type", &self.algorithm)
+            .finish()
+    }
+}
+
+/// Decoded X-address, shaped like the object returned by JavaScript `decodeXAddress`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct DecodedXAddress {
+    decoded: compat::DecodedXAddress,
+}
+
+#[wasm_bindgen]
+impl DecodedXAddress {
+    /// Account id bytes
+    #[wasm_bindgen(getter)]
+    pub fn accountId(&self) -> Vec<u8> {
+        self.decoded.accountId.to_vec()
+    }
+
+    /// Destination tag, `undefined` if there's none
+    #[wasm_bindgen(getter)]
+    pub fn tag(&self) -> Option<u32> {
+        self.decoded.tag
+    }
+
+    /// Whether the X-address is for a test network
+    #[wasm_bindgen(getter)]
+    pub fn test(&self) -> bool {
+        self.decoded.test
+    }
+}
+
+/// Classic address, shaped like the object returned by JavaScript `xAddressToClassicAddress`
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct ClassicAddress {
+    decoded: compat::ClassicAddress,
+}
+
+#[wasm_bindgen]
+impl ClassicAddress {
+    /// Classic address (starting with r...)
+    #[wasm_bindgen(getter)]
+    pub fn classicAddress(&self) -> String {
+        self.decoded.classicAddress.clone()
+    }
+
+    /// Destination tag, `undefined` if there's none
+    #[wasm_bindgen(getter)]
+    pub fn tag(&self) -> Option<u32> {
+        self.decoded.tag
+    }
+
+    /// Whether the X-address was for a test network
+    #[wasm_bindgen(getter)]
+    pub fn test(&self) -> bool {
+        self.decoded.test
+    }
+}
+
+/// Same as JavaScript `encodeAccountID`, see [`compat::encodeAccountID`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::encodeAccountID;
+///
+/// assert_eq!(encodeAccountID(&[0; 20]).unwrap(), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+///
+/// # Errors
+///
+/// Throws if `bytes` isn't 20 bytes long.
+#[wasm_bindgen]
+pub fn encodeAccountID(bytes: &[u8]) -> Result<String, JsError> {
+    Ok(compat::encodeAccountID(&to_array(bytes)?))
+}
+
+/// Same as JavaScript `decodeAccountID`, see [`compat::decodeAccountID`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::decodeAccountID;
+///
+/// assert_eq!(decodeAccountID("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap(), [0; 20]);
+/// ```
+///
+/// # Errors
+///
+/// Throws if the classic address is invalid.
+#[wasm_bindgen]
+pub fn decodeAccountID(accountId: &str) -> Result<Vec<u8>, JsError> {
+    Ok(compat::decodeAccountID(accountId)?.to_vec())
+}
+
+/// Same as JavaScript `encodeNodePublic`, see [`compat::encodeNodePublic`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::encodeNodePublic;
+///
+/// assert!(encodeNodePublic(&[2; 33]).unwrap().starts_with('n'));
+/// ```
+///
+/// # Errors
+///
+/// Throws if `bytes` isn't 33 bytes long.
+#[wasm_bindgen]
+pub fn encodeNodePublic(bytes: &[u8]) -> Result<String, JsError> {
+    Ok(compat::encodeNodePublic(&to_array(bytes)?))
+}
+
+/// Same as JavaScript `decodeNodePublic`, see [`compat::decodeNodePublic`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::{decodeNodePublic, encodeNodePublic};
+///
+/// assert_eq!(decodeNodePublic(&encodeNodePublic(&[2; 33]).unwrap()).unwrap(), [2; 33]);
+/// ```
+///
+/// # Errors
+///
+/// Throws if the node public key is invalid.
+#[wasm_bindgen]
+pub fn decodeNodePublic(base58string: &str) -> Result<Vec<u8>, JsError> {
+    Ok(compat::decodeNodePublic(base58string)?.to_vec())
+}
+
+/// Same as JavaScript `encodeAccountPublic`, see [`compat::encodeAccountPublic`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::encodeAccountPublic;
+///
+/// assert!(encodeAccountPublic(&[2; 33]).unwrap().starts_with('a'));
+/// ```
+///
+/// # Errors
+///
+/// Throws if `bytes` isn't 33 bytes long.
+#[wasm_bindgen]
+pub fn encodeAccountPublic(bytes: &[u8]) -> Result<String, JsError> {
+    Ok(compat::encodeAccountPublic(&to_array(bytes)?))
+}
+
+/// Same as JavaScript `decodeAccountPublic`, see [`compat::decodeAccountPublic`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::{decodeAccountPublic, encodeAccountPublic};
+///
+/// assert_eq!(decodeAccountPublic(&encodeAccountPublic(&[2; 33]).unwrap()).unwrap(), [2; 33]);
+/// ```
+///
+/// # Errors
+///
+/// Throws if the account public key is invalid.
+#[wasm_bindgen]
+pub fn decodeAccountPublic(base58string: &str) -> Result<Vec<u8>, JsError> {
+    Ok(compat::decodeAccountPublic(base58string)?.to_vec())
+}
+
+/// Same as JavaScript `encodeSeed`, see [`compat::encodeSeed`]
+///
+/// `type` is `"ed25519"` or `"secp256k1"`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::encodeSeed;
+///
+/// assert_eq!(encodeSeed(&[0; 16], "ed25519").unwrap(), "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE");
+/// ```
+///
+/// # Errors
+///
+/// Throws if `entropy` isn't 16 bytes long or `type` is unknown.
+#[wasm_bindgen]
+pub fn encodeSeed(entropy: &[u8], r#type: &str) -> Result<String, JsError> {
+    Ok(compat::encodeSeed(&to_array(entropy)?, r#type.parse()?))
+}
+
+/// Same as JavaScript `decodeSeed`, see [`compat::decodeSeed`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::decodeSeed;
+///
+/// let decoded = decodeSeed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+///
+/// assert_eq!(decoded.bytes(), [0; 16]);
+/// assert_eq!(decoded.r#type(), "ed25519");
+/// ```
+///
+/// # Errors
+///
+/// Throws if the seed is invalid.
+#[wasm_bindgen]
+pub fn decodeSeed(seed: &str) -> Result<DecodedSeed, JsError> {
+    let decoded = compat::decodeSeed(seed)?;
+
+    Ok(DecodedSeed {
+        bytes: decoded.bytes,
+        algorithm: decoded.r#type,
+    })
+}
+
+/// Same as JavaScript `isValidClassicAddress`
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::isValidClassicAddress;
+///
+/// assert!(isValidClassicAddress("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// ```
+#[wasm_bindgen]
+pub fn isValidClassicAddress(address: &str) -> bool {
+    compat::isValidClassicAddress(address)
+}
+
+/// Same as JavaScript `encodeXAddress`, see [`compat::encodeXAddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::encodeXAddress;
+///
+/// assert!(encodeXAddress(&[0; 20], Some(1), true).unwrap().starts_with('T'));
+/// ```
+///
+/// # Errors
+///
+/// Throws if `accountId` isn't 20 bytes long.
+#[wasm_bindgen]
+pub fn encodeXAddress(accountId: &[u8], tag: Option<u32>, test: bool) -> Result<String, JsError> {
+    Ok(compat::encodeXAddress(&to_array(accountId)?, tag, test))
+}
+
+/// Same as JavaScript `decodeXAddress`, see [`compat::decodeXAddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::decodeXAddress;
+///
+/// let decoded = decodeXAddress("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+///
+/// assert_eq!(decoded.tag(), Some(1));
+/// assert!(decoded.test());
+/// ```
+///
+/// # Errors
+///
+/// Throws if the X-address is invalid.
+#[wasm_bindgen]
+pub fn decodeXAddress(xAddress: &str) -> Result<DecodedXAddress, JsError> {
+    Ok(DecodedXAddress {
+        decoded: compat::decodeXAddress(xAddress)?,
+    })
+}
+
+/// Same as JavaScript `isValidXAddress`
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::isValidXAddress;
+///
+/// assert!(!isValidXAddress("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// ```
+#[wasm_bindgen]
+pub fn isValidXAddress(xAddress: &str) -> bool {
+    compat::isValidXAddress(xAddress)
+}
+
+/// Same as JavaScript `classicAddressToXAddress`, see [`compat::classicAddressToXAddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::classicAddressToXAddress;
+///
+/// assert_eq!(
+///     classicAddressToXAddress("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", None, false).unwrap(),
+///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Throws if the classic address is invalid.
+#[wasm_bindgen]
+pub fn classicAddressToXAddress(
+    classicAddress: &str,
+    tag: Option<u32>,
+    test: bool,
+) -> Result<String, JsError> {
+    Ok(compat::classicAddressToXAddress(classicAddress, tag, test)?)
+}
+
+/// Same as JavaScript `xAddressToClassicAddress`, see [`compat::xAddressToClassicAddress`]
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::wasm::xAddressToClassicAddress;
+///
+/// let decoded = xAddressToClassicAddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb").unwrap();
+///
+/// assert_eq!(decoded.classicAddress(), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+/// ```
+///
+/// # Errors
+///
+/// Throws if the X-address is invalid.
+#[wasm_bindgen]
+pub fn xAddressToClassicAddress(xAddress: &str) -> Result<ClassicAddress, JsError> {
+    Ok(ClassicAddress {
+        decoded: compat::xAddressToClassicAddress(xAddress)?,
+    })
+}
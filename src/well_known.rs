@@ -0,0 +1,150 @@
+use crate::AccountId;
+
+/// `rrrrrrrrrrrrrrrrrrrrrhoLvTp`, the all-zero account id
+///
+/// Used by rippled as the issuer of XRP. Nobody holds its keys.
+pub const ACCOUNT_ZERO: AccountId = AccountId::new([0; 20]);
+
+/// `rrrrrrrrrrrrrrrrrrrrBZbvji`, the account id with value one
+///
+/// Used by rippled as a placeholder for trust line issuers. Nobody holds
+/// its keys.
+pub const ACCOUNT_ONE: AccountId = AccountId::new([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01,
+]);
+
+/// `rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh`, the genesis account
+///
+/// Holds all XRP in a new ledger, its seed is `masterpassphrase`.
+pub const GENESIS_ACCOUNT: AccountId = AccountId::new([
+    0xB5, 0xF7, 0x62, 0x79, 0x8A, 0x53, 0xD5, 0x43, 0xA0, 0x14, 0xCA, 0xF8, 0xB2, 0x97, 0xCF, 0xF8,
+    0xF2, 0xF9, 0x37, 0xE8,
+]);
+
+/// `rrrrrrrrrrrrrrrrrNAMEtxvNvQ`, the Ripple name reservation black hole
+///
+/// XRP was sent here to reserve Ripple names. Nobody holds its keys.
+pub const NAME_RESERVATION_ACCOUNT: AccountId = AccountId::new([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x6F, 0xC6, 0x9D,
+]);
+
+/// `rrrrrrrrrrrrrrrrrrrn5RM1rHd`, the NaN address
+///
+/// The result of decoding the string `NaN` in old versions of the
+/// JavaScript libraries. Nobody holds its keys.
+pub const NAN_ACCOUNT: AccountId = AccountId::new([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x09, 0x77,
+]);
+
+/// Special account with a known role in the XRP Ledger
+///
+/// Transfers to any of them but the genesis account can never be spent.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{is_well_known, AccountId, WellKnownAccount};
+///
+/// let account_id: AccountId = "rrrrrrrrrrrrrrrrrNAMEtxvNvQ".parse().unwrap();
+///
+/// assert_eq!(is_well_known(&account_id), Some(WellKnownAccount::NameReservation));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WellKnownAccount {
+    /// [`ACCOUNT_ZERO`]
+    Zero,
+    /// [`ACCOUNT_ONE`]
+    One,
+    /// [`GENESIS_ACCOUNT`]
+    Genesis,
+    /// [`NAME_RESERVATION_ACCOUNT`]
+    NameReservation,
+    /// [`NAN_ACCOUNT`]
+    NaN,
+}
+
+impl WellKnownAccount {
+    /// All well-known accounts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::WellKnownAccount;
+    ///
+    /// assert_eq!(WellKnownAccount::all().len(), 5);
+    /// ```
+    pub const fn all() -> &'static [WellKnownAccount] {
+        &[
+            WellKnownAccount::Zero,
+            WellKnownAccount::One,
+            WellKnownAccount::Genesis,
+            WellKnownAccount::NameReservation,
+            WellKnownAccount::NaN,
+        ]
+    }
+
+    /// Its account id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::WellKnownAccount;
+    ///
+    /// assert_eq!(
+    ///     WellKnownAccount::Genesis.account_id().to_string(),
+    ///     "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+    /// );
+    /// ```
+    pub const fn account_id(self) -> AccountId {
+        match self {
+            WellKnownAccount::Zero => ACCOUNT_ZERO,
+            WellKnownAccount::One => ACCOUNT_ONE,
+            WellKnownAccount::Genesis => GENESIS_ACCOUNT,
+            WellKnownAccount::NameReservation => NAME_RESERVATION_ACCOUNT,
+            WellKnownAccount::NaN => NAN_ACCOUNT,
+        }
+    }
+
+    /// Stable snake_case name, for reports and logs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::WellKnownAccount;
+    ///
+    /// assert_eq!(WellKnownAccount::NaN.name(), "nan");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            WellKnownAccount::Zero => "account_zero",
+            WellKnownAccount::One => "account_one",
+            WellKnownAccount::Genesis => "genesis",
+            WellKnownAccount::NameReservation => "name_reservation",
+            WellKnownAccount::NaN => "nan",
+        }
+    }
+}
+
+/// Which well-known account `account_id` is, if any
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{is_well_known, AccountId, WellKnownAccount};
+///
+/// assert_eq!(
+///     is_well_known(&AccountId::new([0; 20])),
+///     Some(WellKnownAccount::Zero)
+/// );
+/// assert_eq!(is_well_known(&AccountId::new([7; 20])), None);
+/// ```
+pub fn is_well_known(account_id: &AccountId) -> Option<WellKnownAccount> {
+    WellKnownAccount::all()
+        .iter()
+        .find(|account| account.account_id() == *account_id)
+        .copied()
+}
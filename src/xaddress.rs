@@ -0,0 +1,716 @@
+#[cfg(all(feature = "mesalock_sgx", not(target_env = "sgx")))]
+use std::prelude::v1::*;
+
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    str::FromStr,
+};
+
+#[cfg(feature = "rkyv")]
+use crate::ArchivedAccountId;
+use crate::{
+    decode_account_id, display, encode_account_id, first_match, sha256, trace, with_decoded_bytes,
+    AccountId, Address, Error, Result, Token, VersionedToken, ADDRESS,
+};
+
+const TAG_LEN: usize = 8;
+const FLAG_NO_TAG: u8 = 0x00;
+const FLAG_32_BIT_TAG: u8 = 0x01;
+
+/// The network an X-address is intended for
+///
+/// Encoded in the X-address prefix: mainnet addresses start with X...,
+/// test network ones with T...
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Clone, Copy, PartialEq, Eq, Hash), compare(PartialEq))
+)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum Network {
+    /// The production XRP Ledger
+    #[default]
+    Main,
+    /// Any test network (testnet, devnet)
+    Test,
+}
+
+impl Network {
+    /// Whether this is a test network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Network;
+    ///
+    /// assert!(Network::Test.is_test());
+    /// assert!(!Network::Main.is_test());
+    /// ```
+    pub fn is_test(&self) -> bool {
+        *self == Network::Test
+    }
+}
+
+/// Destination tag of an X-address
+///
+/// X-addresses reserve room for 64-bit tags, which are not enabled on
+/// the XRP Ledger yet. This enum is non-exhaustive so that a 64-bit
+/// variant can be added once they are, without breaking callers.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(
+        archived = ArchivedDestinationTag,
+        derive(Debug, Clone, Copy, PartialEq, Eq, Hash),
+        compare(PartialEq)
+    )
+)]
+pub enum Tag {
+    /// No destination tag
+    #[default]
+    None,
+    /// 32-bit destination tag
+    U32(u32),
+}
+
+impl Tag {
+    /// The tag value, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::Tag;
+    ///
+    /// assert_eq!(Tag::U32(7).value(), Some(7));
+    /// assert_eq!(Tag::None.value(), None);
+    /// ```
+    pub fn value(&self) -> Option<u64> {
+        match self {
+            Tag::None => None,
+            Tag::U32(tag) => Some(u64::from(*tag)),
+        }
+    }
+
+    fn flag_and_bytes(&self) -> (u8, [u8; TAG_LEN]) {
+        match self {
+            Tag::None => (FLAG_NO_TAG, [0; TAG_LEN]),
+            Tag::U32(tag) => (FLAG_32_BIT_TAG, u64::from(*tag).to_le_bytes()),
+        }
+    }
+}
+
+impl From<u32> for Tag {
+    fn from(tag: u32) -> Self {
+        Tag::U32(tag)
+    }
+}
+
+impl From<Option<u32>> for Tag {
+    fn from(tag: Option<u32>) -> Self {
+        tag.map_or(Tag::None, Tag::U32)
+    }
+}
+
+/// Encode an account id and optional destination tag as an X-address
+///
+/// Mainnet X-addresses start with X..., test network ones
+/// with T... The format is specified in [XLS-5d].
+///
+/// [XLS-5d]: https://github.com/XRPLF/XRPL-Standards/issues/6
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_account_id, encode_xaddress, Network, Tag};
+///
+/// let account_id = decode_account_id("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap();
+///
+/// assert_eq!(
+///     encode_xaddress(&account_id, Tag::None, Network::Main),
+///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+/// );
+/// assert_eq!(
+///     encode_xaddress(&account_id, Tag::U32(4294967295), Network::Main),
+///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi"
+/// );
+/// ```
+pub fn encode_xaddress(
+    account_id: &[u8; Address::PAYLOAD_LEN],
+    tag: Tag,
+    network: Network,
+) -> String {
+    token(network).encode(&payload(account_id, tag))
+}
+
+/// Encode an account id, destination tag and network as an X-address
+/// into `out` without allocating, returning the encoded length
+///
+/// X-addresses are 47 characters long.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{encode_xaddress_into, Network, Tag, XADDRESS_MAX_LEN};
+///
+/// let mut out = [0; XADDRESS_MAX_LEN];
+/// let len = encode_xaddress_into(&[0; 20], Tag::None, Network::Main, &mut out).unwrap();
+///
+/// assert_eq!(&out[..len], b"X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] if `out` is too small, leaving its
+/// contents unspecified.
+pub fn encode_xaddress_into(
+    account_id: &[u8; Address::PAYLOAD_LEN],
+    tag: Tag,
+    network: Network,
+    out: &mut [u8],
+) -> Result<usize> {
+    token(network).encode_into(&payload(account_id, tag), out)
+}
+
+/// Version bytes of the X-addresses of `network`
+pub(crate) fn prefix(network: Network) -> &'static [u8] {
+    token(network).prefix
+}
+
+pub(crate) fn token(network: Network) -> Token<PAYLOAD_LEN> {
+    match network {
+        Network::Main => XADDRESS_MAIN,
+        Network::Test => XADDRESS_TEST,
+    }
+}
+
+pub(crate) fn payload(account_id: &[u8; Address::PAYLOAD_LEN], tag: Tag) -> [u8; PAYLOAD_LEN] {
+    let (flag, tag) = tag.flag_and_bytes();
+
+    let mut payload = [0; PAYLOAD_LEN];
+    let (account_id_bytes, flag_and_tag) = payload.split_at_mut(Address::PAYLOAD_LEN);
+    account_id_bytes.copy_from_slice(account_id);
+    flag_and_tag[0] = flag;
+    flag_and_tag[1..].copy_from_slice(&tag);
+
+    payload
+}
+
+/// Split a payload into account id and flag and tag bytes
+pub(crate) fn split_payload(
+    payload: &[u8; PAYLOAD_LEN],
+) -> ([u8; Address::PAYLOAD_LEN], [u8; 1 + TAG_LEN]) {
+    let mut account_id = [0; Address::PAYLOAD_LEN];
+    let mut flag_and_tag = [0; 1 + TAG_LEN];
+    account_id.copy_from_slice(&payload[..Address::PAYLOAD_LEN]);
+    flag_and_tag.copy_from_slice(&payload[Address::PAYLOAD_LEN..]);
+
+    (account_id, flag_and_tag)
+}
+
+/// Decode an X-address into a tuple with account id, destination tag
+/// and network
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{decode_xaddress, encode_account_id, Network, Tag};
+///
+/// let (account_id, tag, network) =
+///     decode_xaddress("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+///
+/// assert_eq!(encode_account_id(&account_id), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+/// assert_eq!(tag, Tag::U32(1));
+/// assert_eq!(network, Network::Test);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why X-address is invalid and
+/// [`Error::UnsupportedTag`] if its tag uses a reserved flag or reserved bits
+/// (this includes 64-bit tags, which are not enabled yet).
+pub fn decode_xaddress(xaddress: &str) -> Result<([u8; Address::PAYLOAD_LEN], Tag, Network)> {
+    trace::decoded("xaddress", xaddress, decode_xaddress_untraced(xaddress))
+}
+
+/// [`decode_xaddress`] without a failure event, for callers trying
+/// several token types
+pub(crate) fn decode_xaddress_untraced(
+    xaddress: &str,
+) -> Result<([u8; Address::PAYLOAD_LEN], Tag, Network)> {
+    with_decoded_bytes(xaddress, |decoded_bytes| {
+        let (payload, network) = first_match([
+            XADDRESS_MAIN
+                .decode_bytes(sha256::DEFAULT, decoded_bytes)
+                .map(|payload| (payload, Network::Main)),
+            XADDRESS_TEST
+                .decode_bytes(sha256::DEFAULT, decoded_bytes)
+                .map(|payload| (payload, Network::Test)),
+        ])?;
+        let (account_id, flag_and_tag) = split_payload(&payload);
+
+        Ok((account_id, decode_tag(flag_and_tag)?, network))
+    })
+}
+
+/// Convert a classic address (starting with r...) and optional
+/// destination tag to an X-address
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{classic_address_to_xaddress, Network, Tag};
+///
+/// assert_eq!(
+///     classic_address_to_xaddress("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", Tag::U32(1), Network::Main),
+///     Ok("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC".to_string())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why classic address is invalid.
+pub fn classic_address_to_xaddress(
+    classic_address: &str,
+    tag: Tag,
+    network: Network,
+) -> Result<String> {
+    let account_id = decode_account_id(classic_address)?;
+
+    Ok(encode_xaddress(&account_id, tag, network))
+}
+
+/// Convert an X-address to a tuple with classic address (starting with r...),
+/// destination tag and network
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{xaddress_to_classic_address, Network, Tag};
+///
+/// assert_eq!(
+///     xaddress_to_classic_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"),
+///     Ok(("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".to_string(), Tag::U32(1), Network::Main))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_xaddress`].
+pub fn xaddress_to_classic_address(xaddress: &str) -> Result<(String, Tag, Network)> {
+    let (account_id, tag, network) = decode_xaddress(xaddress)?;
+
+    Ok((encode_account_id(&account_id), tag, network))
+}
+
+/// Decode and re-encode an X-address, returning its canonical string form
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::normalize_xaddress;
+///
+/// assert_eq!(
+///     normalize_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"),
+///     Ok("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC".to_string())
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Same as [`decode_xaddress`].
+pub fn normalize_xaddress(xaddress: &str) -> Result<String> {
+    let (account_id, tag, network) = decode_xaddress(xaddress)?;
+
+    Ok(encode_xaddress(&account_id, tag, network))
+}
+
+/// Check whether a string is a valid X-address
+///
+/// Rejects strings too long to be an X-address before decoding, and
+/// otherwise agrees with [`decode_xaddress`] without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::is_valid_xaddress;
+///
+/// assert!(is_valid_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"));
+/// assert!(!is_valid_xaddress("rrrrrrrrrrrrrrrrrrrrrhoLvTp"));
+/// ```
+pub fn is_valid_xaddress(xaddress: &str) -> bool {
+    XADDRESS_MAIN.fits(xaddress) && decode_xaddress(xaddress).is_ok()
+}
+
+/// Parse either a classic address (starting with r...) or an X-address
+/// to a tuple with account id, destination tag and network
+///
+/// Classic addresses carry neither tag nor network, so they are parsed
+/// with [`Tag::None`] and [`Network::Main`].
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{parse_account, Network, Tag};
+///
+/// let (account_id, tag, network) = parse_account("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap();
+///
+/// assert_eq!(tag, Tag::None);
+/// assert_eq!(network, Network::Main);
+/// assert_eq!(
+///     parse_account("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"),
+///     Ok((account_id, Tag::U32(1), Network::Test))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`](crate::Error) describing why the string is
+/// neither a valid classic address nor a valid X-address.
+pub fn parse_account(account: &str) -> Result<(AccountId, Tag, Network)> {
+    let parsed = first_match([
+        ADDRESS
+            .decode(account)
+            .map(|account_id| (account_id, Tag::None, Network::Main)),
+        decode_xaddress_untraced(account),
+    ]);
+
+    trace::decoded("account", account, parsed)
+        .map(|(account_id, tag, network)| (AccountId::new(account_id), tag, network))
+}
+
+/// Parse an `address:tag` destination string to its account id and
+/// optional destination tag
+///
+/// The address is a classic address and the tag, if any, follows the
+/// first `:` as decimal digits fitting in a `u32`.
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{parse_destination, AccountId, Error};
+///
+/// let account_id: AccountId = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+///
+/// assert_eq!(
+///     parse_destination("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf:12345"),
+///     Ok((account_id, Some(12345)))
+/// );
+/// assert_eq!(
+///     parse_destination("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"),
+///     Ok((account_id, None))
+/// );
+/// assert_eq!(
+///     parse_destination("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf:4294967296"),
+///     Err(Error::UnsupportedTag)
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::DecodeError`] if the tag is empty or has anything
+/// but decimal digits, [`Error::UnsupportedTag`] if it doesn't fit in a
+/// `u32`, and otherwise an [`Error`] describing why the address is
+/// invalid.
+pub fn parse_destination(destination: &str) -> Result<(AccountId, Option<u32>)> {
+    let (address, tag) = match destination.split_once(':') {
+        Some((address, tag)) => (address, Some(parse_tag(tag)?)),
+        None => (destination, None),
+    };
+
+    Ok((AccountId::new(decode_account_id(address)?), tag))
+}
+
+/// Parse a decimal destination tag, without sign or whitespace
+pub(crate) fn parse_tag(tag: &str) -> Result<u32> {
+    if tag.is_empty() || !tag.bytes().all(|digit| digit.is_ascii_digit()) {
+        return Err(Error::DecodeError);
+    }
+
+    tag.parse().map_err(|_| Error::UnsupportedTag)
+}
+
+/// An X-address: account id, destination tag and network
+///
+/// # Examples
+///
+/// ```
+/// use ripple_address_codec::{Tag, XAddress};
+///
+/// let xaddress: XAddress = "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw".parse().unwrap();
+///
+/// assert_eq!(xaddress.account_id().to_string(), "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+/// assert_eq!(xaddress.tag(), Tag::U32(1));
+/// assert!(xaddress.is_test());
+/// assert_eq!(xaddress.to_string(), "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw");
+/// ```
+///
+/// X-addresses are ordered by account id, then tag ([`Tag::None`] first,
+/// then by value), then network ([`Network::Main`] first).
+///
+/// With the `borsh` feature, it's serialized as the raw account id bytes,
+/// tag and network, not as the encoded string.
+///
+/// With the `rkyv` feature, it's archived the same way, so
+/// `ArchivedXAddress` reads the account id, tag and network in place.
+///
+/// With the `serde` feature, it's serialized as the X-address string in
+/// human-readable formats and as a tuple of the account id bytes, optional
+/// `u64` tag and test network flag in binary formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Clone, Copy, PartialEq, Eq, Hash), compare(PartialEq))
+)]
+#[cfg_attr(
+    feature = "uniffi",
+    derive(uniffi::Object),
+    uniffi::export(Display, Eq, Hash)
+)]
+pub struct XAddress {
+    account_id: AccountId,
+    tag: Tag,
+    network: Network,
+}
+
+impl XAddress {
+    /// X-address from its parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(
+    ///     "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap(),
+    ///     Tag::None,
+    ///     Network::Main,
+    /// );
+    ///
+    /// assert_eq!(xaddress.to_string(), "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb");
+    /// ```
+    pub const fn new(account_id: AccountId, tag: Tag, network: Network) -> Self {
+        XAddress {
+            account_id,
+            tag,
+            network,
+        }
+    }
+
+    /// The account id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, Network, Tag, XAddress};
+    ///
+    /// let account_id = AccountId::new([0; 20]);
+    ///
+    /// assert_eq!(XAddress::new(account_id, Tag::None, Network::Main).account_id(), account_id);
+    /// ```
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// The destination tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([0; 20]), Tag::U32(7), Network::Main);
+    ///
+    /// assert_eq!(xaddress.tag(), Tag::U32(7));
+    /// ```
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// The network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([0; 20]), Tag::None, Network::Test);
+    ///
+    /// assert_eq!(xaddress.network(), Network::Test);
+    /// ```
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Whether the X-address is for a test network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([0; 20]), Tag::None, Network::Test);
+    ///
+    /// assert!(xaddress.is_test());
+    /// ```
+    pub fn is_test(&self) -> bool {
+        self.network.is_test()
+    }
+
+    /// The X-address shortened to its first 6 and last 5 characters, for
+    /// UIs
+    ///
+    /// The `…` between them isn't in the base58 alphabet, so the short
+    /// form never decodes as an address, and can't be pasted as one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::XAddress;
+    ///
+    /// let xaddress: XAddress = "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC".parse().unwrap();
+    ///
+    /// assert_eq!(xaddress.display_short(), "XVLhHM…6gwDC");
+    /// ```
+    pub fn display_short(&self) -> String {
+        display::shorten(&self.to_string())
+    }
+}
+
+impl FromStr for XAddress {
+    type Err = Error;
+
+    fn from_str(xaddress: &str) -> Result<Self> {
+        let (account_id, tag, network) = decode_xaddress(xaddress)?;
+
+        Ok(XAddress::new(AccountId::new(account_id), tag, network))
+    }
+}
+
+impl TryFrom<&str> for XAddress {
+    type Error = Error;
+
+    fn try_from(xaddress: &str) -> Result<Self> {
+        xaddress.parse()
+    }
+}
+
+impl fmt::Display for XAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&encode_xaddress(
+            self.account_id.as_bytes(),
+            self.tag,
+            self.network,
+        ))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedXAddress {
+    /// Borrow the account id, in place in the archive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ArchivedXAddress, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([1; 20]), Tag::U32(7), Network::Test);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&xaddress).unwrap();
+    /// let archived = rkyv::access::<ArchivedXAddress, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.account_id().as_bytes(), &[1; 20]);
+    /// ```
+    pub fn account_id(&self) -> &ArchivedAccountId {
+        &self.account_id
+    }
+
+    /// The destination tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ArchivedXAddress, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([1; 20]), Tag::U32(7), Network::Test);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&xaddress).unwrap();
+    /// let archived = rkyv::access::<ArchivedXAddress, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.tag(), Tag::U32(7));
+    /// ```
+    pub fn tag(&self) -> Tag {
+        match self.tag {
+            ArchivedDestinationTag::None => Tag::None,
+            ArchivedDestinationTag::U32(tag) => Tag::U32(tag.to_native()),
+        }
+    }
+
+    /// The network
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ripple_address_codec::{AccountId, ArchivedXAddress, Network, Tag, XAddress};
+    ///
+    /// let xaddress = XAddress::new(AccountId::new([1; 20]), Tag::U32(7), Network::Test);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&xaddress).unwrap();
+    /// let archived = rkyv::access::<ArchivedXAddress, rkyv::rancor::Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.network(), Network::Test);
+    /// ```
+    pub fn network(&self) -> Network {
+        match self.network {
+            ArchivedNetwork::Main => Network::Main,
+            ArchivedNetwork::Test => Network::Test,
+        }
+    }
+}
+
+pub(crate) struct XAddressMain;
+
+impl VersionedToken for XAddressMain {
+    const PREFIX: &'static [u8] = &[0x05, 0x44];
+    const PAYLOAD_LEN: usize = Address::PAYLOAD_LEN + 1 + TAG_LEN;
+}
+
+pub(crate) struct XAddressTest;
+
+impl VersionedToken for XAddressTest {
+    const PREFIX: &'static [u8] = &[0x04, 0x93];
+    const PAYLOAD_LEN: usize = Address::PAYLOAD_LEN + 1 + TAG_LEN;
+}
+
+/// Payload length of both networks: account id, flag and tag
+pub(crate) const PAYLOAD_LEN: usize = XAddressMain::PAYLOAD_LEN;
+
+const XADDRESS_MAIN: Token<PAYLOAD_LEN> = Token::new(XAddressMain::PREFIX);
+const XADDRESS_TEST: Token<PAYLOAD_LEN> = Token::new(XAddressTest::PREFIX);
+
+pub(crate) fn decode_tag(flag_and_tag: [u8; 1 + TAG_LEN]) -> Result<Tag> {
+    let [flag, tag @ ..] = flag_and_tag;
+    let tag = u64::from_le_bytes(tag);
+
+    match (flag, tag.try_into()) {
+        (FLAG_NO_TAG, Ok(0)) => Ok(Tag::None),
+        (FLAG_32_BIT_TAG, Ok(tag)) => Ok(Tag::U32(tag)),
+        _ => Err(Error::UnsupportedTag),
+    }
+}
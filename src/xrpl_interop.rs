@@ -0,0 +1,88 @@
+//! Conversions to and from the types of the `xrpl_types` and `xrpl-rust`
+//! crates, available with the `xrpl-types` and `xrpl-rust` features
+//!
+//! Account ids convert by copying their 20 bytes. Conversions from
+//! `xrpl-rust` types that hold strings or unchecked bytes validate them
+//! and are fallible.
+
+#[cfg(feature = "xrpl-rust")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "xrpl-rust")]
+use xrpl::{
+    constants::CryptoAlgorithm,
+    core::binarycodec::types::{AccountId as XrplAccountId, XRPLType},
+    wallet::Wallet,
+};
+
+use crate::AccountId;
+#[cfg(feature = "xrpl-rust")]
+use crate::{Algorithm, Error, Seed};
+
+#[cfg(feature = "xrpl-types")]
+impl From<AccountId> for xrpl_types::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        xrpl_types::AccountId(account_id.into_bytes())
+    }
+}
+
+#[cfg(feature = "xrpl-types")]
+impl From<xrpl_types::AccountId> for AccountId {
+    fn from(account_id: xrpl_types::AccountId) -> Self {
+        AccountId::new(account_id.0)
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl From<AccountId> for XrplAccountId {
+    fn from(account_id: AccountId) -> Self {
+        XrplAccountId::new(Some(account_id.as_bytes())).expect("account ids are 20 bytes")
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl TryFrom<&XrplAccountId> for AccountId {
+    type Error = Error;
+
+    fn try_from(account_id: &XrplAccountId) -> Result<Self, Error> {
+        AccountId::try_from(account_id.as_ref())
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl From<Algorithm> for CryptoAlgorithm {
+    fn from(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Secp256k1 => CryptoAlgorithm::SECP256K1,
+            Algorithm::Ed25519 => CryptoAlgorithm::ED25519,
+        }
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl From<CryptoAlgorithm> for Algorithm {
+    fn from(algorithm: CryptoAlgorithm) -> Self {
+        match algorithm {
+            CryptoAlgorithm::SECP256K1 => Algorithm::Secp256k1,
+            CryptoAlgorithm::ED25519 => Algorithm::Ed25519,
+        }
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl TryFrom<&Wallet> for Seed {
+    type Error = Error;
+
+    fn try_from(wallet: &Wallet) -> Result<Self, Error> {
+        wallet.seed.parse()
+    }
+}
+
+#[cfg(feature = "xrpl-rust")]
+impl TryFrom<&Wallet> for AccountId {
+    type Error = Error;
+
+    fn try_from(wallet: &Wallet) -> Result<Self, Error> {
+        wallet.classic_address.parse()
+    }
+}
@@ -2,10 +2,11 @@ use ripple_address_codec as api;
 
 use utils::*;
 
+mod fixtures;
+
 mod utils {
     use std::convert::TryInto;
 
-    use hex;
     use rand::{thread_rng, Rng};
 
     pub fn to_bytes(hex: &str) -> Vec<u8> {
@@ -20,6 +21,14 @@ mod utils {
         to_bytes(hex).try_into().unwrap()
     }
 
+    pub fn to_32_bytes(hex: &str) -> [u8; 32] {
+        to_bytes(hex).try_into().unwrap()
+    }
+
+    pub fn to_33_bytes(hex: &str) -> [u8; 33] {
+        to_bytes(hex).try_into().unwrap()
+    }
+
     pub fn to_hex(bytes: &[u8]) -> String {
         hex::encode_upper(bytes)
     }
@@ -52,23 +61,58 @@ mod account_id {
     fn decode_bad_alphabet() {
         assert_eq!(
             api::decode_account_id("r_000").unwrap_err(),
-            api::DecodeError
+            api::Error::BadAlphabet {
+                character: '_',
+                index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn decode_bad_alphabet_after_non_ascii() {
+        assert_eq!(
+            api::decode_account_id("réé0").unwrap_err(),
+            api::Error::BadAlphabet {
+                character: 'é',
+                index: 1
+            }
         );
     }
 
+    #[test]
+    fn contains_only_xrpl_alphabet() {
+        const ALPHABET: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+        for character in (0..0x300).filter_map(char::from_u32) {
+            assert_eq!(
+                api::contains_only_xrpl_alphabet(&format!("r{}p", character)),
+                ALPHABET.contains(character),
+                "{:?}",
+                character
+            );
+        }
+        assert!(api::contains_only_xrpl_alphabet(""));
+        assert!(api::contains_only_xrpl_alphabet(&api::encode_account_id(
+            &get_20_random_bytes()
+        )));
+    }
+
     #[test]
     fn decode_bad_lenght() {
         assert_eq!(
-            api::decode_account_id("rJrRMgWyPbY35ErN").unwrap_err(),
-            api::DecodeError
+            api::decode_account_id("rpB2uKyCqQq7tW7wP3Tos").unwrap_err(),
+            api::Error::BadLength {
+                expected: 20,
+                actual: 10
+            }
         );
     }
 
     #[test]
     fn decode_bad_prefix() {
         assert_eq!(
-            api::decode_account_id("bJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN").unwrap_err(),
-            api::DecodeError
+            api::decode_account_id("n5jhBt3b7zJLszMKJJ2DYuQHSXDyQCD2Xk").unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x00] }
         );
     }
 
@@ -76,7 +120,7 @@ mod account_id {
     fn decode_bad_checksum() {
         assert_eq!(
             api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err(),
-            api::DecodeError
+            api::Error::BadChecksum
         );
     }
 
@@ -91,6 +135,30 @@ mod account_id {
         assert_eq!(bytes, decoded_bytes);
     }
 
+    #[test]
+    fn encode_slice() {
+        let bytes = get_20_random_bytes();
+
+        assert_eq!(
+            api::encode_account_id_slice(&bytes),
+            Ok(api::encode_account_id(&bytes))
+        );
+        assert_eq!(
+            api::encode_account_id_slice(&bytes[1..]),
+            Err(api::Error::BadLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+        assert_eq!(
+            api::encode_account_id_slice(&[]),
+            Err(api::Error::BadLength {
+                expected: 20,
+                actual: 0
+            })
+        );
+    }
+
     #[test]
     fn encode() {
         assert_eq!(
@@ -106,29 +174,180 @@ mod account_id {
             to_20_bytes("BA8E78626EE42C41B46D46C3048DF3A1C3C87072")
         );
     }
+
+    #[test]
+    fn normalize() {
+        let encoded = api::encode_account_id(&get_20_random_bytes());
+
+        assert_eq!(api::normalize_account_id(&encoded), Ok(encoded));
+        assert_eq!(
+            api::normalize_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn encode_into() {
+        let bytes = get_20_random_bytes();
+        let encoded = api::encode_account_id(&bytes);
+        let mut out = [0; 35];
+
+        let len = api::encode_account_id_into(&bytes, &mut out).unwrap();
+
+        assert_eq!(&out[..len], encoded.as_bytes());
+        assert_eq!(
+            api::encode_account_id_into(&bytes, &mut out[..len]),
+            Ok(len)
+        );
+    }
+
+    #[test]
+    fn encode_into_too_small() {
+        let mut out = [0; 26];
+
+        assert_eq!(
+            api::encode_account_id_into(&[0; 20], &mut out),
+            Err(api::Error::BufferTooSmall {
+                required: 27,
+                available: 26
+            })
+        );
+    }
+}
+
+mod typed_account_id {
+    use super::*;
+
+    use std::collections::{BTreeSet, HashSet};
+    use std::convert::TryFrom;
+
+    use api::AccountId;
+
+    #[test]
+    fn parse_and_display() {
+        let account_id: AccountId = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN".parse().unwrap();
+
+        assert_eq!(
+            account_id.into_bytes(),
+            to_20_bytes("BA8E78626EE42C41B46D46C3048DF3A1C3C87072")
+        );
+        assert_eq!(account_id.to_string(), "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN");
+    }
+
+    #[test]
+    fn parse_bad_checksum() {
+        assert_eq!(
+            "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA".parse::<AccountId>(),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn compare_with_str() {
+        let account_id = AccountId::new(to_20_bytes("BA8E78626EE42C41B46D46C3048DF3A1C3C87072"));
+
+        assert_eq!(account_id, "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN");
+        assert_ne!(account_id, "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+        assert_ne!(account_id, "not an address");
+    }
+
+    #[test]
+    fn as_ref_bytes() {
+        let bytes = get_20_random_bytes();
+
+        assert_eq!(AccountId::new(bytes).as_ref(), &bytes[..]);
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            AccountId::try_from("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Ok(AccountId::new([0; 20]))
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let bytes = get_20_random_bytes();
+
+        assert_eq!(AccountId::try_from(&bytes[..]), Ok(AccountId::from(bytes)));
+        assert_eq!(
+            AccountId::try_from(&bytes[1..]),
+            Err(api::Error::BadLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+    }
+
+    #[test]
+    fn into_bytes() {
+        let bytes = get_20_random_bytes();
+        let unwrapped: [u8; 20] = AccountId::from(bytes).into();
+
+        assert_eq!(unwrapped, bytes);
+    }
+
+    #[test]
+    fn ordered_byte_wise() {
+        let mut account_ids = vec![
+            AccountId::new([2; 20]),
+            AccountId::new([0; 20]),
+            AccountId::new([1; 20]),
+        ];
+        account_ids.sort();
+
+        assert_eq!(
+            account_ids,
+            [
+                AccountId::new([0; 20]),
+                AccountId::new([1; 20]),
+                AccountId::new([2; 20])
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_in_sets() {
+        let account_id = AccountId::new(get_20_random_bytes());
+        let duplicates = [account_id, account_id];
+
+        assert_eq!(duplicates.iter().collect::<HashSet<_>>().len(), 1);
+        assert_eq!(duplicates.iter().collect::<BTreeSet<_>>().len(), 1);
+    }
 }
 
+#[cfg(feature = "seed-secp256k1")]
 mod secp256k1_seed {
     use super::*;
 
     #[test]
     fn decode_bad_alphabet() {
-        assert_eq!(api::decode_seed("s_000").unwrap_err(), api::DecodeError);
+        assert_eq!(
+            api::decode_seed("s_000").unwrap_err(),
+            api::Error::BadAlphabet {
+                character: '_',
+                index: 1
+            }
+        );
     }
 
     #[test]
     fn decode_bad_lenght() {
         assert_eq!(
-            api::decode_seed("sn259rEFXrQrWcwV6dfL").unwrap_err(),
-            api::DecodeError
+            api::decode_seed("AiAQtT3BygyxThhEqhzN").unwrap_err(),
+            api::Error::BadLength {
+                expected: 16,
+                actual: 10
+            }
         );
     }
 
     #[test]
     fn decode_bad_prefix() {
         assert_eq!(
-            api::decode_seed("Sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap_err(),
-            api::DecodeError
+            api::decode_seed("hv2SMcTLVBJ5nCPYrChQFGXkp5vkk").unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
         );
     }
 
@@ -136,19 +355,58 @@ mod secp256k1_seed {
     fn decode_bad_checksum() {
         assert_eq!(
             api::decode_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfA").unwrap_err(),
-            api::DecodeError
+            api::Error::BadChecksum
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn decode_strict() {
+        let entropy = get_16_random_bytes();
+
+        assert_eq!(
+            api::decode_secp256k1_seed(&api::encode_seed(&entropy, api::Secp256k1)),
+            Ok(entropy)
+        );
+        assert_eq!(
+            api::decode_secp256k1_seed(&api::encode_seed(&entropy, api::Ed25519)),
+            Err(api::Error::WrongAlgorithm {
+                expected: api::Secp256k1,
+                actual: api::Ed25519
+            })
+        );
+        assert_eq!(
+            api::decode_secp256k1_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfA"),
+            Err(api::Error::BadChecksum)
         );
     }
 
     #[test]
     fn encode_random() {
         let bytes = get_16_random_bytes();
-        let encoded = api::encode_seed(&bytes, &api::Secp256k1);
+        let encoded = api::encode_seed(&bytes, api::Secp256k1);
         let (decoded_bytes, decoded_kind) = api::decode_seed(&encoded).unwrap();
 
         assert!(encoded.starts_with("s"));
         assert_eq!(decoded_bytes, bytes);
-        assert_eq!(decoded_kind, &api::Secp256k1);
+        assert_eq!(decoded_kind, api::Secp256k1);
+    }
+
+    #[test]
+    fn encode_slice() {
+        let bytes = get_16_random_bytes();
+
+        assert_eq!(
+            api::encode_seed_slice(&bytes, api::Secp256k1),
+            Ok(api::encode_seed(&bytes, api::Secp256k1))
+        );
+        assert_eq!(
+            api::encode_seed_slice(&[0; 17], api::Secp256k1),
+            Err(api::Error::BadLength {
+                expected: 16,
+                actual: 17
+            })
+        );
     }
 
     #[test]
@@ -156,7 +414,7 @@ mod secp256k1_seed {
         assert_eq!(
             api::encode_seed(
                 &to_16_bytes("CF2DE378FBDD7E2EE87D486DFB5A7BFF"),
-                &api::Secp256k1
+                api::Secp256k1
             ),
             "sn259rEFXrQrWyx3Q7XneWcwV6dfL"
         );
@@ -168,28 +426,56 @@ mod secp256k1_seed {
 
         assert_eq!(to_hex(&bytes), "CF2DE378FBDD7E2EE87D486DFB5A7BFF");
 
-        assert_eq!(kind, &api::Secp256k1)
+        assert_eq!(kind, api::Secp256k1)
+    }
+
+    #[test]
+    fn encode_into() {
+        let entropy = get_16_random_bytes();
+        let mut out = [0; 31];
+
+        for algorithm in api::Algorithm::all() {
+            let len = api::encode_seed_into(&entropy, *algorithm, &mut out).unwrap();
+
+            assert_eq!(
+                &out[..len],
+                api::encode_seed(&entropy, *algorithm).as_bytes()
+            );
+        }
     }
 }
 
+#[cfg(feature = "seed-ed25519")]
 mod ed25519_seed {
     use super::*;
 
     #[test]
     fn decode_bad_alphabet() {
-        assert_eq!(api::decode_seed("sEd_000").unwrap_err(), api::DecodeError);
+        assert_eq!(
+            api::decode_seed("sEd_000").unwrap_err(),
+            api::Error::BadAlphabet {
+                character: '_',
+                index: 3
+            }
+        );
     }
 
     #[test]
     fn decode_bad_lenght() {
-        assert_eq!(api::decode_seed("sEdTM1uX8").unwrap_err(), api::DecodeError);
+        assert_eq!(
+            api::decode_seed("wn8KrtYEFeSBrDgqW").unwrap_err(),
+            api::Error::BadLength {
+                expected: 16,
+                actual: 6
+            }
+        );
     }
 
     #[test]
     fn decode_bad_prefix() {
         assert_eq!(
-            api::decode_seed("SEdTM1uX8pu2do5XvTnutH6HsouMaM2").unwrap_err(),
-            api::DecodeError
+            api::decode_seed("sEdWvjeVnF42xxoV4RARNCboWmVTaAK").unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
         );
     }
 
@@ -197,19 +483,45 @@ mod ed25519_seed {
     fn decode_bad_checksum() {
         assert_eq!(
             api::decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaMA").unwrap_err(),
-            api::DecodeError
+            api::Error::BadChecksum
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn decode_strict() {
+        let entropy = get_16_random_bytes();
+        let error =
+            api::decode_ed25519_seed(&api::encode_seed(&entropy, api::Secp256k1)).unwrap_err();
+
+        assert_eq!(
+            api::decode_ed25519_seed(&api::encode_seed(&entropy, api::Ed25519)),
+            Ok(entropy)
+        );
+        assert_eq!(
+            error,
+            api::Error::WrongAlgorithm {
+                expected: api::Ed25519,
+                actual: api::Secp256k1
+            }
+        );
+        assert_eq!(error.code(), "wrong_algorithm");
+        assert_eq!(error.to_string(), "secp256k1 seed, expected ed25519");
+        assert_eq!(
+            api::decode_ed25519_seed("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Err(api::Error::BadPrefix { expected: &[] })
         );
     }
 
     #[test]
     fn encode_random() {
         let bytes = get_16_random_bytes();
-        let encoded = api::encode_seed(&bytes, &api::Ed25519);
+        let encoded = api::encode_seed(&bytes, api::Ed25519);
         let (decoded_bytes, decoded_kind) = api::decode_seed(&encoded).unwrap();
 
         assert!(encoded.starts_with("sEd"));
         assert_eq!(decoded_bytes, bytes);
-        assert_eq!(decoded_kind, &api::Ed25519);
+        assert_eq!(decoded_kind, api::Ed25519);
     }
 
     #[test]
@@ -217,7 +529,7 @@ mod ed25519_seed {
         assert_eq!(
             api::encode_seed(
                 &to_16_bytes("4C3A1D213FBDFB14C7C28D609469B341"),
-                &api::Ed25519
+                api::Ed25519
             ),
             "sEdTM1uX8pu2do5XvTnutH6HsouMaM2"
         );
@@ -229,6 +541,6723 @@ mod ed25519_seed {
 
         assert_eq!(to_hex(&bytes), "4C3A1D213FBDFB14C7C28D609469B341");
 
-        assert_eq!(kind, &api::Ed25519)
+        assert_eq!(kind, api::Ed25519)
+    }
+
+    #[test]
+    fn normalize() {
+        let encoded = api::encode_seed(&get_16_random_bytes(), api::Ed25519);
+
+        assert_eq!(api::normalize_seed(&encoded), Ok(encoded));
+        assert_eq!(
+            api::normalize_seed("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+}
+
+mod algorithm {
+    use super::*;
+
+    use api::Algorithm;
+
+    #[test]
+    fn display_parse_round_trip() {
+        for algorithm in Algorithm::all() {
+            assert_eq!(algorithm.to_string().parse(), Ok(*algorithm));
+        }
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn parse_ignores_case() {
+        assert_eq!("SECP256K1".parse(), Ok(api::Secp256k1));
+        assert_eq!("eD25519".parse(), Ok(api::Ed25519));
+    }
+
+    #[test]
+    fn parse_unknown() {
+        assert_eq!("ed448".parse::<Algorithm>(), Err(api::DecodeError));
+        assert_eq!(" ed25519".parse::<Algorithm>(), Err(api::DecodeError));
+    }
+
+    #[test]
+    fn seed_prefix_round_trip() {
+        for &algorithm in Algorithm::all() {
+            let seed = api::encode_seed(&[0; 16], algorithm);
+
+            assert_eq!(
+                Algorithm::from_seed_prefix(algorithm.seed_prefix()),
+                Some(algorithm)
+            );
+            assert_eq!(
+                api::decode_versioned(&seed, algorithm.seed_prefix(), 16),
+                Ok(vec![0; 16])
+            );
+        }
+    }
+
+    #[test]
+    fn seed_prefixes_are_distinct() {
+        for &a in Algorithm::all() {
+            for &b in Algorithm::all() {
+                if a != b {
+                    assert!(!a.seed_prefix().starts_with(b.seed_prefix()));
+                }
+            }
+        }
+        assert_eq!(Algorithm::from_seed_prefix(&[0x01, 0xE1]), None);
+    }
+}
+
+mod typed_seed {
+    use super::*;
+
+    #[cfg(feature = "seed-ed25519")]
+    use std::convert::TryFrom;
+
+    use api::Seed;
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn parse_and_display() {
+        for algorithm in &[api::Secp256k1, api::Ed25519] {
+            let entropy = get_16_random_bytes();
+            let encoded = api::encode_seed(&entropy, *algorithm);
+            let seed: Seed = encoded.parse().unwrap();
+
+            assert_eq!(seed, Seed::new(entropy, *algorithm));
+            assert_eq!(seed.expose_secret(), encoded);
+        }
+    }
+
+    #[test]
+    fn parse_bad_checksum() {
+        assert_eq!(
+            "sEdTM1uX8pu2do5XvTnutH6HsouMaM3".parse::<Seed>(),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn with_algorithm_keeps_entropy() {
+        let seed: Seed = "sn259rEFXrQrWyx3Q7XneWcwV6dfL".parse().unwrap();
+        let retagged = seed.clone().with_algorithm(api::Ed25519);
+
+        assert_eq!(retagged.entropy(), seed.entropy());
+        assert_eq!(retagged.algorithm(), api::Ed25519);
+        assert!(retagged.expose_secret().starts_with("sEd"));
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            Seed::try_from("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+            Ok(Seed::new([0; 16], api::Ed25519))
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn ordered_by_entropy_then_algorithm() {
+        assert!(Seed::new([0; 16], api::Ed25519) < Seed::new([1; 16], api::Secp256k1));
+        assert!(Seed::new([0; 16], api::Secp256k1) < Seed::new([0; 16], api::Ed25519));
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn debug_redacted() {
+        let seed = Seed::new(get_16_random_bytes(), api::Secp256k1);
+        let debug = format!("{:?}", seed);
+
+        assert_eq!(debug, "Seed(s****)");
+        assert!(!debug.contains(&seed.expose_secret()));
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn display_redacted() {
+        let seed = Seed::new(get_16_random_bytes(), api::Ed25519);
+
+        assert_eq!(seed.to_string(), "sEd****");
+        assert_eq!(Seed::new([0; 16], api::Secp256k1).to_string(), "s****");
+        assert_eq!(seed.expose_secret().parse(), Ok(seed));
+    }
+
+    #[cfg(feature = "protected-memory")]
+    #[test]
+    fn protected_memory() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Seed>();
+
+        let seeds: Vec<Seed> = (0..64)
+            .map(|_| Seed::new(get_16_random_bytes(), api::Secp256k1))
+            .collect();
+
+        for seed in &seeds {
+            let clone = seed.clone().with_algorithm(api::Ed25519);
+
+            assert_eq!(clone.entropy(), seed.entropy());
+            assert_eq!(clone.expose_secret().parse(), Ok(clone));
+        }
+    }
+
+    #[cfg(feature = "os-rng")]
+    #[test]
+    fn generate_seed() {
+        for &algorithm in api::Algorithm::all() {
+            let (seed, encoded) = api::generate_seed(algorithm);
+            let (other, _) = api::generate_seed(algorithm);
+
+            assert_eq!(encoded.parse(), Ok(seed.clone()));
+            assert_eq!(seed.algorithm(), algorithm);
+            assert_ne!(seed.entropy(), other.entropy());
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_seed_with_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let seed = api::generate_seed_with_rng(&mut rng, api::Ed25519);
+        let next = api::generate_seed_with_rng(&mut rng, api::Ed25519);
+
+        assert_eq!(seed.algorithm(), api::Ed25519);
+        assert_ne!(seed, next);
+        assert_eq!(
+            api::generate_seed_with_rng(&mut StdRng::seed_from_u64(42), api::Ed25519),
+            seed
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<Seed>();
+
+        let mut seed = Seed::new(get_16_random_bytes(), api::Ed25519);
+        seed.zeroize();
+
+        assert_eq!(seed.entropy(), &[0; 16]);
+        assert_eq!(seed.algorithm(), api::Ed25519);
+    }
+}
+
+mod node_public_key {
+    use super::*;
+
+    #[test]
+    fn decode_bad_lenght() {
+        assert_eq!(
+            api::decode_node_public_key("UG82bQv2YqUe2Ms8aQmFndv3emPCzRSviP").unwrap_err(),
+            api::Error::BadLength {
+                expected: 33,
+                actual: 20
+            }
+        );
+    }
+
+    #[test]
+    fn decode_bad_prefix() {
+        assert_eq!(
+            api::decode_node_public_key("aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3")
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x1C] }
+        );
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_node_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TA")
+                .unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(
+            api::encode_node_public_key(&to_33_bytes(
+                "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828"
+            )),
+            "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH"
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let bytes =
+            api::decode_node_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH")
+                .unwrap();
+
+        assert_eq!(
+            to_hex(&bytes),
+            "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828"
+        );
+    }
+}
+
+mod account_public_key {
+    use super::*;
+
+    #[test]
+    fn decode_bad_lenght() {
+        assert_eq!(
+            api::decode_account_public_key("EapFUKkZYnxwZR1wgt3HsZbr9rmffVAjTh").unwrap_err(),
+            api::Error::BadLength {
+                expected: 33,
+                actual: 20
+            }
+        );
+    }
+
+    #[test]
+    fn decode_bad_prefix() {
+        assert_eq!(
+            api::decode_account_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH")
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x23] }
+        );
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_account_public_key("aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMSA")
+                .unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(
+            api::encode_account_public_key(&to_33_bytes(
+                "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6"
+            )),
+            "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3"
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let bytes =
+            api::decode_account_public_key("aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3")
+                .unwrap();
+
+        assert_eq!(
+            to_hex(&bytes),
+            "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6"
+        );
+    }
+}
+
+mod account_id_from_public_key {
+    use super::*;
+
+    #[test]
+    fn secp256k1() {
+        let key = to_33_bytes("0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020");
+
+        assert_eq!(
+            api::account_id_from_public_key(&key).map(|id| api::encode_account_id(&id)),
+            Ok("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".to_owned())
+        );
+    }
+
+    #[test]
+    fn ed25519() {
+        let key = to_33_bytes("ED9434799226374926EDA3B54B1B461B4ABF7237962EAE18528FEA67595397FA32");
+
+        assert_eq!(
+            api::account_id_from_public_key(&key).map(|id| api::encode_account_id(&id)),
+            Ok("rDTXLQ7ZKZVKz33zJbHjgVShjsBnqMBhmN".to_owned())
+        );
+    }
+
+    #[test]
+    fn bad_length() {
+        assert_eq!(
+            api::account_id_from_public_key(&[0xED; 32]),
+            Err(api::Error::BadLength {
+                expected: 33,
+                actual: 32
+            })
+        );
+    }
+
+    #[test]
+    fn bad_key_type() {
+        assert_eq!(
+            api::account_id_from_public_key(&[0x04; 33]),
+            Err(api::Error::InvalidKey)
+        );
+    }
+
+    #[test]
+    fn encode_address() {
+        for hex in &[
+            "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+            "ED9434799226374926EDA3B54B1B461B4ABF7237962EAE18528FEA67595397FA32",
+        ] {
+            let key = to_bytes(hex);
+
+            assert_eq!(
+                api::encode_address_from_public_key(&key),
+                api::account_id_from_public_key(&key).map(|id| api::encode_account_id(&id))
+            );
+        }
+
+        assert_eq!(
+            api::encode_address_from_public_key(&[]),
+            Err(api::Error::BadLength {
+                expected: 33,
+                actual: 0
+            })
+        );
+    }
+}
+
+mod private_keys {
+    use super::*;
+
+    const KEY: &str = "1ACAAEDECE405B2A958212629E16F2EB46B153EEE94CDD350FDEFF52795525B7";
+
+    #[test]
+    fn encode_node_private_key() {
+        assert_eq!(
+            api::encode_node_private_key(&to_32_bytes(KEY)),
+            "pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpN2"
+        );
+    }
+
+    #[test]
+    fn decode_node_private_key() {
+        assert_eq!(
+            api::decode_node_private_key("pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpN2"),
+            Ok(to_32_bytes(KEY))
+        );
+    }
+
+    #[test]
+    fn encode_account_private_key() {
+        assert_eq!(
+            api::encode_account_private_key(&to_32_bytes(KEY)),
+            "p9JfM6HHi64m6mvB6v5k7G2b1cXzGmYiCNJf6GHPKvFTWdeRVjh"
+        );
+    }
+
+    #[test]
+    fn decode_account_private_key() {
+        assert_eq!(
+            api::decode_account_private_key("p9JfM6HHi64m6mvB6v5k7G2b1cXzGmYiCNJf6GHPKvFTWdeRVjh"),
+            Ok(to_32_bytes(KEY))
+        );
+    }
+
+    #[test]
+    fn decode_bad_prefix() {
+        assert_eq!(
+            api::decode_node_private_key("p9JfM6HHi64m6mvB6v5k7G2b1cXzGmYiCNJf6GHPKvFTWdeRVjh")
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x20] }
+        );
+        assert_eq!(
+            api::decode_account_private_key("pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpN2")
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x22] }
+        );
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_node_private_key("pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpNA")
+                .unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+}
+
+mod family_generator {
+    use super::*;
+
+    const GENERATOR: &str = "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828";
+
+    #[test]
+    fn decode_bad_prefix() {
+        assert_eq!(
+            api::decode_family_generator("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH")
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[0x29] }
+        );
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_family_generator("fht5yrLWh3P8DrJgQuVNDPQVXGTMyPpgRHFKGQzFQ66o3ssesk3A")
+                .unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(
+            api::encode_family_generator(&to_33_bytes(GENERATOR)),
+            "fht5yrLWh3P8DrJgQuVNDPQVXGTMyPpgRHFKGQzFQ66o3ssesk3o"
+        );
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(
+            api::decode_family_generator("fht5yrLWh3P8DrJgQuVNDPQVXGTMyPpgRHFKGQzFQ66o3ssesk3o"),
+            Ok(to_33_bytes(GENERATOR))
+        );
+    }
+}
+
+mod versioned {
+    use super::*;
+
+    const ED25519_VERSION: [u8; 3] = [0x01, 0xE1, 0x4B];
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn encode_matches_seed_encoder() {
+        let entropy = get_16_random_bytes();
+
+        assert_eq!(
+            api::encode_versioned(&entropy, &ED25519_VERSION),
+            api::encode_seed(&entropy, api::Ed25519)
+        );
+    }
+
+    #[test]
+    fn decode_seed() {
+        assert_eq!(
+            api::decode_versioned("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", &ED25519_VERSION, 16),
+            Ok(to_bytes("4C3A1D213FBDFB14C7C28D609469B341"))
+        );
+    }
+
+    #[test]
+    fn round_trip_custom_version() {
+        let payload = get_20_random_bytes();
+        let encoded = api::encode_versioned(&payload, &[0x12, 0x34]);
+
+        assert_eq!(
+            api::decode_versioned(&encoded, &[0x12, 0x34], 20),
+            Ok(payload.to_vec())
+        );
+    }
+
+    #[test]
+    fn decode_bad_version() {
+        assert_eq!(
+            api::decode_versioned("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", &[0x21], 16).unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
+        );
+    }
+
+    #[test]
+    fn decode_bad_len() {
+        assert_eq!(
+            api::decode_versioned("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", &ED25519_VERSION, 15)
+                .unwrap_err(),
+            api::Error::BadLength {
+                expected: 15,
+                actual: 16
+            }
+        );
+    }
+
+    #[test]
+    fn decode_multi_first_match() {
+        let versions: [&[u8]; 3] = [&[0x00], &[0x21], &ED25519_VERSION];
+
+        assert_eq!(
+            api::decode_multi_versioned("sp6JS7f14BuwFY8Mw6bTtLKWauoUs", &versions, 16),
+            Ok((1, vec![0; 16]))
+        );
+        assert_eq!(
+            api::decode_multi_versioned("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE", &versions, 16),
+            Ok((2, vec![0; 16]))
+        );
+    }
+
+    struct Ed25519Seed;
+
+    impl api::VersionedToken for Ed25519Seed {
+        const PREFIX: &'static [u8] = &ED25519_VERSION;
+        const PAYLOAD_LEN: usize = 16;
+    }
+
+    struct KeyMaterial;
+
+    impl api::VersionedToken for KeyMaterial {
+        const PREFIX: &'static [u8] = &[0x42];
+        const PAYLOAD_LEN: usize = 33;
+        const PAYLOAD_LENS: &'static [usize] = &[32, 33];
+    }
+
+    #[test]
+    fn token_multiple_lens() {
+        for len in &[32, 33] {
+            let payload = vec![7; *len];
+            let encoded = api::encode_token::<KeyMaterial>(&payload).unwrap();
+
+            assert_eq!(api::decode_token::<KeyMaterial>(&encoded), Ok(payload));
+        }
+    }
+
+    #[test]
+    fn decode_token_unlisted_len() {
+        let encoded = api::encode_versioned(&[7; 31], &[0x42]);
+
+        assert_eq!(
+            api::decode_token::<KeyMaterial>(&encoded).unwrap_err(),
+            api::Error::BadLength {
+                expected: 32,
+                actual: 31
+            }
+        );
+    }
+
+    #[test]
+    fn decode_token_unlisted_len_nearest() {
+        let encoded = api::encode_versioned(&[7; 34], &[0x42]);
+
+        assert_eq!(
+            api::decode_token::<KeyMaterial>(&encoded).unwrap_err(),
+            api::Error::BadLength {
+                expected: 33,
+                actual: 34
+            }
+        );
+    }
+
+    #[test]
+    fn encode_token_unlisted_len_nearest() {
+        for &(len, expected) in &[(31, 32), (34, 33), (0, 32)] {
+            assert_eq!(
+                api::encode_token::<KeyMaterial>(&vec![7; len]).unwrap_err(),
+                api::Error::BadLength {
+                    expected,
+                    actual: len
+                }
+            );
+        }
+    }
+
+    struct NoPayload;
+
+    impl api::VersionedToken for NoPayload {
+        const PREFIX: &'static [u8] = &[0x42];
+        const PAYLOAD_LEN: usize = 0;
+        const PAYLOAD_LENS: &'static [usize] = &[];
+    }
+
+    #[test]
+    fn token_without_payload_lens() {
+        assert_eq!(
+            api::encode_token::<NoPayload>(&[7; 4]).unwrap_err(),
+            api::Error::BadLength {
+                expected: 0,
+                actual: 4
+            }
+        );
+        assert!(api::decode_token::<NoPayload>(&api::encode_versioned(&[7; 4], &[0x42])).is_err());
+    }
+
+    #[test]
+    fn encode_token() {
+        assert_eq!(
+            api::encode_token::<Ed25519Seed>(&[0; 16]),
+            Ok("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".to_string())
+        );
+    }
+
+    #[test]
+    fn encode_token_bad_len() {
+        assert_eq!(
+            api::encode_token::<Ed25519Seed>(&[0; 15]).unwrap_err(),
+            api::Error::BadLength {
+                expected: 16,
+                actual: 15
+            }
+        );
+    }
+
+    #[test]
+    fn decode_token() {
+        assert_eq!(
+            api::decode_token::<Ed25519Seed>("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+            Ok(vec![0; 16])
+        );
+    }
+
+    #[test]
+    fn decode_multi_no_match() {
+        assert_eq!(
+            api::decode_multi_versioned("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE", &[&[0x21]], 16)
+                .unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
+        );
+    }
+
+    #[test]
+    fn verify_checksum() {
+        let decoded = to_bytes("00AA066C988C712815CC37AF71472B7CBBBD4E2A0A");
+        let checksum = api::calc_checksum(&decoded);
+
+        assert!(api::verify_checksum(&decoded, &checksum).is_ok());
+        assert_eq!(
+            api::verify_checksum(&decoded[1..], &checksum),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn raw_leading_zeros() {
+        assert_eq!(api::encode_raw(&[]), "");
+        assert_eq!(api::encode_raw(&[0, 0]), "rr");
+        assert_eq!(api::encode_raw(&[0, 0, 57]), "rrz");
+        assert_eq!(api::encode_raw(&[0, 0, 58]), "rrpr");
+        assert_eq!(api::decode_raw(""), Ok(vec![]));
+        assert_eq!(api::decode_raw("rrpr"), Ok(vec![0, 0, 58]));
+    }
+
+    #[test]
+    fn raw_round_trip_long() {
+        for len in &[79, 80, 81, 200, 1000] {
+            let bytes: Vec<u8> = (0..*len).map(|_| rand::random()).collect();
+
+            assert_eq!(api::decode_raw(&api::encode_raw(&bytes)), Ok(bytes));
+        }
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let bytes = get_20_random_bytes();
+
+        assert_eq!(
+            api::decode_raw(&api::encode_raw(&bytes)),
+            Ok(bytes.to_vec())
+        );
+    }
+
+    #[test]
+    fn decode_raw_keeps_checksum() {
+        let decoded = api::decode_raw("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+        let (bytes, checksum) = decoded.split_at(decoded.len() - api::CHECKSUM_LENGTH);
+
+        assert_eq!(bytes, &[0; 21][..]);
+        assert_eq!(checksum, &api::calc_checksum(bytes)[..]);
+    }
+
+    #[test]
+    fn decode_raw_bad_alphabet() {
+        assert_eq!(
+            api::decode_raw("r0"),
+            Err(api::Error::BadAlphabet {
+                character: '0',
+                index: 1
+            })
+        );
+    }
+}
+
+mod preset {
+    use super::*;
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    use api::TokenType;
+    use api::{Alphabet, Error, Preset};
+
+    const KEY: &str = "0C28FCA386C7A227600B2FE50B7CAE11EC86D3BF1FBE471BE89827E19D72AA1D";
+
+    #[test]
+    fn bitcoin_wif() {
+        let key = to_bytes(KEY);
+        let mut compressed = key.clone();
+        compressed.push(0x01);
+
+        let uncompressed_wif = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+        let compressed_wif = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+
+        assert_eq!(Preset::BitcoinWif.encode(&key).unwrap(), uncompressed_wif);
+        assert_eq!(
+            Preset::BitcoinWif.encode(&compressed).unwrap(),
+            compressed_wif
+        );
+        assert_eq!(Preset::BitcoinWif.decode(uncompressed_wif), Ok(key.clone()));
+        assert_eq!(Preset::BitcoinWif.decode(compressed_wif), Ok(compressed));
+
+        let mut bad_suffix = key;
+        bad_suffix.push(0x02);
+        assert_eq!(
+            Preset::BitcoinWif.encode(&bad_suffix),
+            Err(Error::DecodeError)
+        );
+        let encoded = api::encode_versioned(&bad_suffix, &[0x80]);
+        let bad_suffix_wif: String = encoded
+            .chars()
+            .map(|character| {
+                let index = Alphabet::XRPL.as_str().find(character).unwrap();
+                Alphabet::BITCOIN.as_str().as_bytes()[index] as char
+            })
+            .collect();
+        assert_eq!(
+            Preset::BitcoinWif.decode(&bad_suffix_wif),
+            Err(Error::DecodeError)
+        );
+    }
+
+    #[test]
+    fn bitcoin_addresses() {
+        let cases = [
+            (Preset::BitcoinP2pkh, "1"),
+            (Preset::BitcoinP2sh, "3"),
+            (Preset::BitcoinTestnetP2pkh, "mn"),
+            (Preset::BitcoinTestnetP2sh, "2"),
+        ];
+
+        for (preset, first_chars) in cases {
+            for _ in 0..20 {
+                let hash = get_20_random_bytes();
+                let encoded = preset.encode(&hash).unwrap();
+
+                assert!(
+                    first_chars.contains(&encoded[..1]),
+                    "{:?} {}",
+                    preset,
+                    encoded
+                );
+                assert_eq!(preset.decode(&encoded), Ok(hash.to_vec()));
+            }
+        }
+
+        assert_eq!(
+            Preset::BitcoinP2sh.decode(&Preset::BitcoinP2pkh.encode(&[0; 20]).unwrap()),
+            Err(Error::BadPrefix { expected: &[0x05] })
+        );
+        assert_eq!(
+            Preset::BitcoinP2pkh.encode(&[0; 21]),
+            Err(Error::BadLength {
+                expected: 20,
+                actual: 21
+            })
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn xrpl_presets_match_versioned_codec() {
+        assert_eq!(Preset::default(), Preset::Xrpl(TokenType::AccountId));
+
+        for &token_type in TokenType::all() {
+            let preset = Preset::Xrpl(token_type);
+            let payload = vec![0; token_type.payload_len()];
+            let encoded = preset.encode(&payload).unwrap();
+
+            assert_eq!(preset.payload_lens(), &[token_type.payload_len()]);
+            assert_eq!(
+                encoded,
+                api::encode_versioned(&payload, token_type.prefix_bytes())
+            );
+            assert_eq!(api::decode_any(&encoded).unwrap().token_type(), token_type);
+            assert_eq!(preset.decode(&encoded), Ok(payload));
+        }
+    }
+
+    #[test]
+    fn alphabets_are_not_mixed() {
+        let p2pkh = Preset::BitcoinP2pkh.encode(&[0; 20]).unwrap();
+
+        assert!(Preset::default().decode(&p2pkh).is_err());
+        assert!(Preset::BitcoinP2pkh
+            .decode("rrrrrrrrrrrrrrrrrrrrrhoLvTp")
+            .is_err());
+    }
+}
+
+mod max_encoded_len {
+    use super::*;
+
+    use api::{Network, Tag};
+
+    #[test]
+    fn bounds_raw_encodings() {
+        for len in 0..=64 {
+            let random: Vec<u8> = (0..len).map(|_| rand::random()).collect();
+
+            assert!(api::encode_raw(&random).len() <= api::max_encoded_len(len));
+            assert!(api::encode_raw(&vec![0xFF; len]).len() <= api::max_encoded_len(len));
+        }
+    }
+
+    #[test]
+    fn bounds_tokens() {
+        let seeds = api::Algorithm::all()
+            .iter()
+            .map(|&algorithm| (api::encode_seed(&[0xFF; 16], algorithm), api::SEED_MAX_LEN));
+        let longest = vec![
+            (api::encode_account_id(&[0xFF; 20]), api::ACCOUNT_ID_MAX_LEN),
+            (
+                api::encode_xaddress(&[0xFF; 20], Tag::U32(u32::MAX), Network::Main),
+                api::XADDRESS_MAX_LEN,
+            ),
+            (
+                api::encode_xaddress(&[0xFF; 20], Tag::U32(u32::MAX), Network::Test),
+                api::XADDRESS_MAX_LEN,
+            ),
+            (
+                api::encode_node_public_key(&[0xFF; 33]),
+                api::NODE_PUBLIC_KEY_MAX_LEN,
+            ),
+            (
+                api::encode_account_public_key(&[0xFF; 33]),
+                api::ACCOUNT_PUBLIC_KEY_MAX_LEN,
+            ),
+            (
+                api::encode_node_private_key(&[0xFF; 32]),
+                api::NODE_PRIVATE_KEY_MAX_LEN,
+            ),
+            (
+                api::encode_account_private_key(&[0xFF; 32]),
+                api::ACCOUNT_PRIVATE_KEY_MAX_LEN,
+            ),
+            (
+                api::encode_family_generator(&[0xFF; 33]),
+                api::FAMILY_GENERATOR_MAX_LEN,
+            ),
+        ];
+
+        for (encoded, max_len) in longest.into_iter().chain(seeds) {
+            assert!(
+                encoded.len() <= max_len,
+                "{} longer than {}",
+                encoded,
+                max_len
+            );
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn sizes_buffers_at_compile_time() {
+        let mut out = [0; api::SEED_MAX_LEN];
+        let len = api::encode_seed_into(&[0xFF; 16], api::Ed25519, &mut out).unwrap();
+
+        assert_eq!(
+            &out[..len],
+            api::encode_seed(&[0xFF; 16], api::Ed25519).as_bytes()
+        );
+        assert_eq!(api::ACCOUNT_ID_MAX_LEN, api::max_encoded_len(1 + 20 + 4));
+    }
+}
+
+mod any {
+    use super::*;
+
+    use api::{TokenType, XrplToken};
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn classify() {
+        let cases = [
+            ("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN", TokenType::AccountId),
+            (
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+                TokenType::XAddress,
+            ),
+            ("sn259rEFXrQrWyx3Q7XneWcwV6dfL", TokenType::SeedSecp256k1),
+            ("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", TokenType::SeedEd25519),
+            (
+                "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH",
+                TokenType::NodePublicKey,
+            ),
+            (
+                "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+                TokenType::AccountPublicKey,
+            ),
+            (
+                "pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpN2",
+                TokenType::NodePrivateKey,
+            ),
+            (
+                "p9JfM6HHi64m6mvB6v5k7G2b1cXzGmYiCNJf6GHPKvFTWdeRVjh",
+                TokenType::AccountPrivateKey,
+            ),
+            (
+                "fht5yrLWh3P8DrJgQuVNDPQVXGTMyPpgRHFKGQzFQ66o3ssesk3o",
+                TokenType::FamilyGenerator,
+            ),
+        ];
+
+        for (token, token_type) in &cases {
+            assert_eq!(api::decode_any(token).unwrap().token_type(), *token_type);
+        }
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn token_type_metadata() {
+        for &token_type in TokenType::all() {
+            let zeros = vec![0; token_type.payload_len()];
+            let ones = vec![0xFF; token_type.payload_len()];
+
+            for payload in [&zeros, &ones] {
+                let token = api::encode_versioned(payload, token_type.prefix_bytes());
+                assert!(
+                    token.starts_with(token_type.human_prefix()),
+                    "{} {}",
+                    token_type.type_name(),
+                    token
+                );
+            }
+
+            let token = api::encode_versioned(&zeros, token_type.prefix_bytes());
+            assert_eq!(api::decode_any(&token).unwrap().token_type(), token_type);
+        }
+    }
+
+    #[test]
+    fn token_type_names_are_distinct() {
+        let names: std::collections::HashSet<_> = TokenType::all()
+            .iter()
+            .map(|token_type| token_type.type_name())
+            .collect();
+
+        assert_eq!(names.len(), TokenType::all().len());
+    }
+
+    #[test]
+    fn decode_xaddress() {
+        assert_eq!(
+            api::decode_any("TVE26TYGhfLC7tQDno7G8dGtxSkYQnXGRtx9fzucypAhEJD"),
+            Ok(XrplToken::XAddress {
+                account_id: to_20_bytes("AA066C988C712815CC37AF71472B7CBBBD4E2A0A"),
+                tag: api::Tag::U32(11747),
+                network: api::Network::Test,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_any("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn free_decoders_reject_long_input() {
+        let megabyte = "r".repeat(1 << 20);
+        let too_long = api::Error::InputTooLong {
+            max_len: api::codec::DEFAULT_MAX_INPUT_LEN,
+            actual: 1 << 20,
+        };
+
+        assert_eq!(api::decode_account_id(&megabyte), Err(too_long));
+        assert_eq!(api::decode_seed(&megabyte), Err(too_long));
+        assert_eq!(api::decode_xaddress(&megabyte), Err(too_long));
+        assert_eq!(api::decode_node_public_key(&megabyte), Err(too_long));
+        assert_eq!(api::decode_account_private_key(&megabyte), Err(too_long));
+        assert_eq!(api::decode_any(&megabyte), Err(too_long));
+        assert_eq!(
+            api::decode_account_id_bytes(megabyte.as_bytes()),
+            Err(too_long)
+        );
+        assert_eq!(api::decode_versioned(&megabyte, &[0x00], 20), Err(too_long));
+        assert!(api::decode_raw(&megabyte).is_ok());
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn debug_redacts_secrets() {
+        let seed = api::decode_any("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+        let private_key = XrplToken::NodePrivateKey([7; 32]);
+
+        assert_eq!(
+            format!("{:?}", seed),
+            "Seed { entropy: ****, algorithm: Ed25519 }"
+        );
+        assert_eq!(format!("{:?}", private_key), "NodePrivateKey(****)");
+    }
+}
+
+mod identifier {
+    use super::*;
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    use api::{AccountId, Seed};
+    use api::{TokenType, XAddress, XrplIdentifier};
+
+    const TOKENS: [&str; 10] = [
+        "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN",
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnXGRtx9fzucypAhEJD",
+        "sn259rEFXrQrWyx3Q7XneWcwV6dfL",
+        "sEdTM1uX8pu2do5XvTnutH6HsouMaM2",
+        "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH",
+        "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+        "pnQdrNPN9ipKS3kmtm7kZdPhsPJYyM1fnx9uUxubLcogXPEBpN2",
+        "p9JfM6HHi64m6mvB6v5k7G2b1cXzGmYiCNJf6GHPKvFTWdeRVjh",
+        "fht5yrLWh3P8DrJgQuVNDPQVXGTMyPpgRHFKGQzFQ66o3ssesk3o",
+    ];
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    fn check(identifier: &dyn XrplIdentifier, token: &str) {
+        assert_eq!(identifier.encode(), token);
+        assert_eq!(
+            identifier.payload().len(),
+            identifier.token_type().payload_len()
+        );
+        assert_eq!(
+            api::encode_versioned(&identifier.payload(), identifier.prefix()),
+            token
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn decoded_tokens() {
+        for token in TOKENS {
+            let decoded = api::decode_any(token).unwrap();
+
+            check(&decoded, token);
+            assert_eq!(XrplIdentifier::token_type(&decoded), decoded.token_type());
+        }
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn typed_identifiers_agree_with_decoded_tokens() {
+        let identifiers: Vec<(Box<dyn XrplIdentifier>, &str)> = vec![
+            (Box::new(TOKENS[0].parse::<AccountId>().unwrap()), TOKENS[0]),
+            (Box::new(TOKENS[1].parse::<XAddress>().unwrap()), TOKENS[1]),
+            (Box::new(TOKENS[2].parse::<XAddress>().unwrap()), TOKENS[2]),
+            (Box::new(TOKENS[3].parse::<Seed>().unwrap()), TOKENS[3]),
+            (Box::new(TOKENS[4].parse::<Seed>().unwrap()), TOKENS[4]),
+        ];
+
+        for (identifier, token) in &identifiers {
+            let decoded = api::decode_any(token).unwrap();
+
+            check(identifier.as_ref(), token);
+            assert_eq!(identifier.token_type(), decoded.token_type());
+            assert_eq!(identifier.payload(), decoded.payload());
+            assert_eq!(identifier.prefix(), decoded.prefix());
+        }
+    }
+
+    #[test]
+    fn test_network_xaddress_prefix() {
+        let xaddress: XAddress = TOKENS[2].parse().unwrap();
+
+        assert_eq!(xaddress.token_type(), TokenType::XAddress);
+        assert_ne!(xaddress.prefix(), TokenType::XAddress.prefix_bytes());
+        assert_eq!(&xaddress.payload()[..20], xaddress.account_id().as_bytes());
+    }
+}
+
+#[cfg(feature = "cli")]
+mod cli {
+    use std::{
+        convert::TryInto,
+        io::Write,
+        process::{Command, Output, Stdio},
+    };
+
+    fn xrpl_codec(args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_xrpl-codec"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(args: &[&str]) -> String {
+        let output = xrpl_codec(args, "");
+        assert!(output.status.success(), "{:?}", output);
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(
+            stdout(&["decode", "rrrrrrrrrrrrrrrrrrrrrhoLvTp"]),
+            "type: account_id\n\
+             payload: 0000000000000000000000000000000000000000\n\
+             classic_address: rrrrrrrrrrrrrrrrrrrrrhoLvTp\n"
+        );
+        assert_eq!(
+            stdout(&[
+                "--json",
+                "decode",
+                "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+            ]),
+            r#"{"type":"xaddress","payload":"AA066C988C712815CC37AF71472B7CBBBD4E2A0A","classic_address":"rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf","tag":1,"network":"test"}"#
+                .to_owned()
+                + "\n"
+        );
+    }
+
+    #[test]
+    fn encode_account_id() {
+        assert_eq!(
+            stdout(&[
+                "encode-account-id",
+                "0xAA066C988C712815CC37AF71472B7CBBBD4E2A0A"
+            ]),
+            "account_id: AA066C988C712815CC37AF71472B7CBBBD4E2A0A\n\
+             address: rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf\n"
+        );
+    }
+
+    #[test]
+    fn seed_from_stdin() {
+        let output = xrpl_codec(&["decode-seed", "-"], "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE\n");
+
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "algorithm: ed25519\nentropy: 00000000000000000000000000000000\n"
+        );
+        assert_eq!(
+            stdout(&[
+                "encode-seed",
+                "00000000000000000000000000000000",
+                "--algorithm",
+                "ed25519"
+            ]),
+            "algorithm: ed25519\nseed: sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE\n"
+        );
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(
+            stdout(&[
+                "convert",
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                "--to-xaddress",
+                "--tag",
+                "1",
+                "--test"
+            ]),
+            "classic_address: rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf\n\
+             xaddress: TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw\n"
+        );
+        assert_eq!(
+            stdout(&[
+                "convert",
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+                "--to-classic"
+            ]),
+            "classic_address: rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf\ntag: none\nnetwork: main\n"
+        );
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        let output = xrpl_codec(&["validate", "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"], "");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stderr.contains("did you mean rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN?"));
+
+        let output = xrpl_codec(&["--json", "encode-account-id", "00"], "");
+        assert_eq!(output.status.code(), Some(1));
+        assert!(String::from_utf8(output.stdout)
+            .unwrap()
+            .starts_with(r#"{"valid":false,"error":"bad_length""#));
+
+        assert!(
+            !xrpl_codec(&["convert", "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"], "")
+                .status
+                .success()
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            stdout(&["validate", "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"]),
+            "valid: true\n\
+             type: account_id\n\
+             payload: BA8E78626EE42C41B46D46C3048DF3A1C3C87072\n\
+             classic_address: rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN\n"
+        );
+    }
+
+    #[test]
+    fn json_withholds_secrets_when_validating() {
+        let seed = "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE";
+
+        assert_eq!(
+            stdout(&["--json", "validate", seed]),
+            r#"{"valid":true,"type":"seed","algorithm":"ed25519"}"#.to_owned() + "\n"
+        );
+        assert_eq!(
+            stdout(&["--json", "decode", seed]),
+            r#"{"type":"seed","payload":"00000000000000000000000000000000","algorithm":"ed25519"}"#
+                .to_owned()
+                + "\n"
+        );
+    }
+
+    #[test]
+    fn validate_lines() {
+        let input = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN\r\n\n rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA\n";
+        let output = xrpl_codec(&["validate-lines"], input);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "1\tvalid\taccount_id\n\
+             3\tinvalid\tbad_checksum\tchecksum mismatch, did you mistype a character?\n"
+        );
+        assert_eq!(
+            String::from_utf8(output.stderr).unwrap(),
+            "2 lines, 1 invalid\n"
+        );
+
+        let output = xrpl_codec(&["--json", "validate-lines", "--invalid-only", "-"], input);
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            r#"{"line":3,"valid":false,"error":"bad_checksum","message":"checksum mismatch, did you mistype a character?","suggestion":"rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"}"#
+                .to_owned()
+                + "\n"
+        );
+
+        let output = xrpl_codec(&["validate-lines"], "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN");
+        assert_eq!(output.status.code(), Some(0));
+    }
+
+    #[test]
+    fn report() {
+        let input = "id,Address,amount\n\
+                     1,rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN,10\n\
+                     2, TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw ,5\n\
+                     3,sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE,0\n\
+                     4,rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA,1\n";
+        let output = xrpl_codec(&["report"], input);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "id,Address,amount,canonical,tag,type,error,message\n\
+             1,rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN,10,rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN,,account_id,,\n\
+             2, TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw ,5,rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf,1,xaddress,,\n\
+             3,sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE,0,,,seed,,\n\
+             4,rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA,1,,,,bad_checksum,\"checksum mismatch, did you mistype a character?\"\n"
+        );
+        assert_eq!(
+            String::from_utf8(output.stderr).unwrap(),
+            "4 rows, 1 invalid\n"
+        );
+    }
+
+    #[test]
+    fn report_requires_the_column() {
+        let output = xrpl_codec(&["report", "--column", "account"], "address\n");
+
+        assert_eq!(output.status.code(), Some(2));
+        assert_eq!(
+            String::from_utf8(output.stderr).unwrap(),
+            "error: no \"account\" column in the header\n"
+        );
+    }
+
+    #[test]
+    fn qr() {
+        let address = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+        let png = std::env::temp_dir().join(format!("xrpl-codec-qr-{}.png", std::process::id()));
+
+        let rendered = stdout(&["qr", address, "--png", png.to_str().unwrap()]);
+        assert!(rendered.contains('█'));
+        assert!(rendered.ends_with(&format!("\n{}\n", address)));
+
+        let png_bytes = std::fs::read(&png).unwrap();
+        std::fs::remove_file(&png).unwrap();
+        assert_eq!(&png_bytes[..8], b"\x89PNG\r\n\x1a\n");
+        let width = u32::from_be_bytes(png_bytes[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png_bytes[20..24].try_into().unwrap());
+        assert_eq!(width, height);
+        assert_eq!(width % 8, 0);
+    }
+
+    #[test]
+    fn qr_refuses_invalid_tokens_and_secrets() {
+        let output = xrpl_codec(&["qr", "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"], "");
+        assert_eq!(output.status.code(), Some(1));
+        assert!(output.stdout.is_empty());
+
+        let output = xrpl_codec(&["--json", "qr", "-"], "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE\n");
+        assert_eq!(output.status.code(), Some(1));
+        assert!(String::from_utf8(output.stdout)
+            .unwrap()
+            .starts_with(r#"{"valid":false,"error":"unsupported_type""#));
+    }
+
+    #[test]
+    fn generate() {
+        let output = stdout(&["--json", "generate", "--algorithm", "ed25519"]);
+        let wallet: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let seed = wallet["seed"].as_str().unwrap().parse().unwrap();
+
+        assert_eq!(wallet["algorithm"], "ed25519");
+        assert_eq!(
+            wallet["classic_address"],
+            ripple_address_codec::keypair::derive(&seed).address()
+        );
+        assert!(wallet["public_key"].as_str().unwrap().starts_with("ED"));
+    }
+
+    #[test]
+    fn generate_writes_the_seed_to_a_new_file() {
+        let path = std::env::temp_dir().join(format!("xrpl-codec-seed-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let output = stdout(&["generate", "--seed-file", path]);
+        let seed = std::fs::read_to_string(path).unwrap();
+        assert!(!output.contains(seed.trim()));
+        assert!(output.starts_with(&format!("seed_file: {}\nalgorithm: secp256k1\n", path)));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let output = xrpl_codec(&["generate", "--seed-file", path], "");
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), seed);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn vanity() {
+        let output = stdout(&[
+            "--json",
+            "vanity",
+            "A",
+            "--suffix",
+            "--algorithm",
+            "ed25519",
+        ]);
+        let result: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let address = result["classic_address"].as_str().unwrap();
+
+        assert!(address.ends_with('A'));
+        assert_eq!(result["algorithm"], "ed25519");
+        assert_eq!(
+            ripple_address_codec::keypair::derive(
+                &result["seed"].as_str().unwrap().parse().unwrap()
+            )
+            .address(),
+            address
+        );
+
+        let output = xrpl_codec(
+            &[
+                "vanity",
+                "rrrrrrrrrrrrrrrrrrrrrrrrrrrrrrrrrrrr",
+                "--max-attempts",
+                "1",
+            ],
+            "",
+        );
+        assert_eq!(output.status.code(), Some(1));
+    }
+
+    #[test]
+    fn validate_lines_bounds_line_length() {
+        let input = format!("{}\n\u{FF}\n", "r".repeat(1 << 20));
+        let output = xrpl_codec(&["validate-lines"], &input);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "1\tinvalid\ttoo_long\tline is too long\n2\tinvalid\tbad_alphabet\tinvalid character 'ÿ' at position 0\n"
+        );
+    }
+
+    #[test]
+    fn validate_lines_missing_file() {
+        let output = xrpl_codec(&["validate-lines", "/nonexistent/addresses.txt"], "");
+
+        assert_eq!(output.status.code(), Some(2));
+    }
+}
+
+#[cfg(feature = "dev-tool")]
+mod fixture_generator {
+    use super::*;
+
+    use std::{fs, path::PathBuf, process::Command};
+
+    use api::TokenType;
+    use serde_json::Value;
+
+    /// Run `xrpl-fixtures` into a fresh directory, returning the fixture
+    /// of every token type
+    fn generate(name: &str, args: &[&str]) -> Vec<(TokenType, Value)> {
+        let out_dir: PathBuf = [env!("CARGO_TARGET_TMPDIR"), name].iter().collect();
+        let _ = fs::remove_dir_all(&out_dir);
+
+        let output = Command::new(env!("CARGO_BIN_EXE_xrpl-fixtures"))
+            .args(args)
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+
+        TokenType::all()
+            .iter()
+            .map(|&token_type| {
+                let path = out_dir.join(format!("{}.json", token_type.type_name()));
+                let fixture = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+                (token_type, fixture)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_token_type() {
+        for (token_type, fixture) in generate("every_token_type", &["--count", "20"]) {
+            assert_eq!(fixture["type"], token_type.type_name());
+            assert_eq!(fixture["prefix"], to_hex(token_type.prefix_bytes()));
+            assert_eq!(fixture["payload_len"], token_type.payload_len());
+
+            let vectors = fixture["vectors"].as_array().unwrap();
+            assert_eq!(vectors.len(), 20);
+
+            for vector in vectors {
+                let encoded = vector["encoded"].as_str().unwrap();
+                assert_eq!(api::decode_any(encoded).unwrap().token_type(), token_type);
+
+                if token_type == TokenType::XAddress {
+                    let (classic_address, tag, network) =
+                        api::xaddress_to_classic_address(encoded).unwrap();
+
+                    assert_eq!(vector["classic_address"], classic_address);
+                    assert_eq!(vector["tag"].as_u64(), tag.value());
+                    assert_eq!(
+                        vector["network"],
+                        if network.is_test() { "test" } else { "main" }
+                    );
+                } else {
+                    let payload = api::decode_versioned(
+                        encoded,
+                        token_type.prefix_bytes(),
+                        token_type.payload_len(),
+                    )
+                    .unwrap();
+
+                    assert_eq!(vector["payload"], to_hex(&payload));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edge_payloads_first() {
+        let fixtures = generate("edge_payloads_first", &["--count", "2"]);
+        let (_, account_ids) = &fixtures[0];
+
+        assert_eq!(
+            account_ids["vectors"][0]["encoded"],
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+        );
+        assert_eq!(account_ids["vectors"][1]["payload"], "FF".repeat(20));
+    }
+
+    #[test]
+    fn reproducible_with_rng_seed() {
+        let args = ["--count", "5", "--rng-seed", "7"];
+        let first = generate("reproducible_1", &args);
+
+        assert_eq!(first, generate("reproducible_2", &args));
+        assert_eq!(first[0].1["rng_seed"], 7);
+        assert_ne!(
+            first,
+            generate("reproducible_3", &["--count", "5", "--rng-seed", "8"])
+        );
+    }
+}
+
+#[cfg(any(feature = "xrpl-rust", feature = "xrpl-types"))]
+mod xrpl_interop {
+    use super::*;
+
+    use api::AccountId;
+
+    #[cfg(feature = "xrpl-types")]
+    #[test]
+    fn xrpl_types_account_id() {
+        let account_id: AccountId = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+        let converted = xrpl_types::AccountId::from(account_id);
+
+        assert_eq!(converted.to_address(), account_id.to_string());
+        assert_eq!(AccountId::from(converted), account_id);
+    }
+
+    #[cfg(feature = "xrpl-rust")]
+    #[test]
+    fn xrpl_rust_account_id() {
+        use std::convert::TryFrom;
+
+        use xrpl::core::binarycodec::types::AccountId as XrplAccountId;
+
+        let account_id: AccountId = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+        let converted = XrplAccountId::from(account_id);
+
+        assert_eq!(converted.to_string(), account_id.to_string());
+        assert_eq!(AccountId::try_from(&converted).unwrap(), account_id);
+    }
+
+    #[cfg(feature = "xrpl-rust")]
+    #[test]
+    fn xrpl_rust_algorithm() {
+        use xrpl::constants::CryptoAlgorithm;
+
+        for &algorithm in api::Algorithm::all() {
+            assert_eq!(
+                api::Algorithm::from(CryptoAlgorithm::from(algorithm)),
+                algorithm
+            );
+        }
+        assert_eq!(
+            CryptoAlgorithm::from(api::Ed25519),
+            CryptoAlgorithm::ED25519
+        );
+    }
+
+    #[cfg(feature = "xrpl-rust")]
+    #[test]
+    fn xrpl_rust_wallet() {
+        use std::convert::TryFrom;
+
+        use xrpl::wallet::Wallet;
+
+        let wallet = Wallet::new("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE", 0).unwrap();
+        let seed = api::Seed::try_from(&wallet).unwrap();
+
+        assert_eq!(seed.entropy(), &[0; 16]);
+        assert_eq!(seed.algorithm(), api::Ed25519);
+        assert_eq!(
+            AccountId::try_from(&wallet).unwrap().to_string(),
+            wallet.classic_address
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+
+    use api::{AccountId, Algorithm, Seed, XAddress};
+
+    #[test]
+    fn serialize_as_strings() {
+        let account_id = AccountId::new([0; 20]);
+        let seed = Seed::new([0; 16], api::Ed25519);
+
+        assert_eq!(
+            serde_json::to_string(&account_id).unwrap(),
+            r#""rrrrrrrrrrrrrrrrrrrrrhoLvTp""#
+        );
+        assert_eq!(
+            serde_json::to_string(&seed).unwrap(),
+            r#""sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE""#
+        );
+        assert_eq!(
+            serde_json::to_string(&api::Secp256k1).unwrap(),
+            r#""secp256k1""#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let xaddress: XAddress = "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+            .parse()
+            .unwrap();
+        let json = serde_json::to_string(&xaddress).unwrap();
+
+        assert_eq!(serde_json::from_str::<XAddress>(&json).unwrap(), xaddress);
+        assert_eq!(
+            serde_json::from_str::<Algorithm>(r#""Ed25519""#).unwrap(),
+            api::Ed25519
+        );
+    }
+
+    #[test]
+    fn deserialize_validates() {
+        let error = serde_json::from_str::<AccountId>(r#""rrrrrrrrrrrrrrrrrrrrrhoLvTP""#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.starts_with("invalid AccountId: checksum mismatch"));
+        assert!(serde_json::from_str::<Seed>("42").is_err());
+    }
+
+    #[test]
+    fn binary_formats_use_raw_payloads() {
+        let account_id = AccountId::new([7; 20]);
+        let bytes = bincode::serialize(&account_id).unwrap();
+
+        // u64 length prefix, then the raw bytes
+        assert_eq!(bytes.len(), 8 + 20);
+        assert_eq!(bytes[8..], [7; 20]);
+        assert_eq!(
+            bincode::deserialize::<AccountId>(&bytes).unwrap(),
+            account_id
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let seed = Seed::new([9; 16], api::Ed25519);
+        let bytes = bincode::serialize(&seed).unwrap();
+
+        assert_eq!(bytes[8..24], [9; 16]);
+        assert_eq!(bincode::deserialize::<Seed>(&bytes).unwrap(), seed);
+
+        for xaddress in &[
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+        ] {
+            let xaddress: XAddress = xaddress.parse().unwrap();
+            let bytes = bincode::serialize(&xaddress).unwrap();
+
+            assert_eq!(bincode::deserialize::<XAddress>(&bytes).unwrap(), xaddress);
+        }
+    }
+
+    #[test]
+    fn binary_deserialize_validates() {
+        let bytes = bincode::serialize(&[7u8; 19][..]).unwrap();
+        let error = bincode::deserialize::<AccountId>(&bytes)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("expected 20"));
+
+        let tag_too_large = ([0u8; 20], Some(u64::from(u32::MAX) + 1), false);
+        let bytes = bincode::serialize(&tag_too_large).unwrap();
+        assert!(bincode::deserialize::<XAddress>(&bytes).is_err());
+    }
+
+    #[test]
+    fn human_readable_formats_use_strings() {
+        let xaddress: XAddress = "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(xaddress).unwrap(),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+        );
+    }
+
+    /// Plain fields with the `with` helpers
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Wallet {
+        #[serde(with = "api::as_classic_address")]
+        account: [u8; 20],
+        #[serde(with = "api::as_seed")]
+        seed: ([u8; 16], Algorithm),
+        #[serde(with = "api::as_seed::secp256k1")]
+        secp256k1_seed: [u8; 16],
+        #[serde(with = "api::as_xaddress")]
+        destination: ([u8; 20], api::Tag, api::Network),
+    }
+
+    fn wallet() -> Wallet {
+        Wallet {
+            account: [0; 20],
+            seed: ([0; 16], api::Ed25519),
+            secp256k1_seed: [0; 16],
+            destination: api::decode_xaddress("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw")
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn with_helpers_match_wrapper_types() {
+        let wallet = wallet();
+        let json = serde_json::to_value(&wallet).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "account": AccountId::new(wallet.account),
+                "seed": Seed::new([0; 16], api::Ed25519),
+                "secp256k1_seed": "sp6JS7f14BuwFY8Mw6bTtLKWauoUs",
+                "destination": "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Wallet>(json).unwrap(), wallet);
+    }
+
+    #[test]
+    fn with_helpers_binary_round_trip() {
+        let wallet = wallet();
+        let bytes = bincode::serialize(&wallet).unwrap();
+
+        assert_eq!(bytes[8..28], [0; 20]);
+        assert_eq!(bincode::deserialize::<Wallet>(&bytes).unwrap(), wallet);
+    }
+
+    #[test]
+    fn with_helpers_validate() {
+        let mut json = serde_json::to_value(wallet()).unwrap();
+        json["account"] = "rrrrrrrrrrrrrrrrrrrrrhoLvTP".into();
+        let error = serde_json::from_value::<Wallet>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("invalid AccountId: checksum mismatch"));
+
+        let mut json = serde_json::to_value(wallet()).unwrap();
+        json["secp256k1_seed"] = "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".into();
+        let error = serde_json::from_value::<Wallet>(json)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "invalid Seed: ed25519 seed, expected secp256k1");
+    }
+
+    /// Validated references into the request body
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Request<'a> {
+        #[serde(borrow)]
+        destination: &'a api::AccountIdStr,
+        #[serde(borrow)]
+        secret: &'a api::SeedStr,
+    }
+
+    #[test]
+    fn borrowed_str_types() {
+        let json = r#"{"destination":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","secret":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#;
+        let request: Request = serde_json::from_str(json).unwrap();
+
+        assert!(json
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&request.destination.as_ptr()));
+        assert_eq!(request.destination.to_account_id(), AccountId::new([0; 20]));
+        assert_eq!(request.secret.to_seed(), Seed::new([0; 16], api::Ed25519));
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+    }
+
+    #[test]
+    fn borrowed_xaddress_str() {
+        let json = r#""XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC""#;
+        let xaddress: &api::XAddressStr = serde_json::from_str(json).unwrap();
+
+        assert_eq!(xaddress.tag(), api::Tag::U32(1));
+        assert_eq!(serde_json::to_string(xaddress).unwrap(), json);
+
+        let error = serde_json::from_str::<&api::XAddressStr>(r#""rrrrrrrrrrrrrrrrrrrrrhoLvTp""#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("invalid XAddressStr"), "{}", error);
+    }
+
+    #[test]
+    fn borrowed_str_types_invalid() {
+        let error = serde_json::from_str::<Request>(
+            r#"{"destination":"rrrrrrrrrrrrrrrrrrrrrhoLvTP","secret":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            error.starts_with("invalid ClassicAddressStr: checksum mismatch"),
+            "{}",
+            error
+        );
+
+        // Escapes leave nothing to borrow
+        let error = serde_json::from_str::<Request>(
+            r#"{"destination":"\u0072rrrrrrrrrrrrrrrrrrrrhoLvTp","secret":"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"}"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            error.contains("expected a borrowed string encoding ClassicAddressStr"),
+            "{}",
+            error
+        );
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_impls {
+    use super::*;
+
+    use api::{AccountId, Algorithm, Network, Seed, Tag, XAddress};
+    use schemars::{schema_for, JsonSchema};
+    use serde_json::{json, Value};
+
+    fn field<T: JsonSchema>(name: &str) -> Value {
+        schema_for!(T).get(name).cloned().unwrap_or(Value::Null)
+    }
+
+    /// Characters of the `[...]` class of a pattern, with ranges expanded
+    fn class_chars(pattern: &str) -> Vec<char> {
+        let start = pattern.rfind('[').unwrap();
+        let class: Vec<char> = pattern[start..]
+            .chars()
+            .skip(1)
+            .take_while(|&c| c != ']')
+            .collect();
+
+        class
+            .chunks(3)
+            .flat_map(|range| range[0]..=range[2])
+            .collect()
+    }
+
+    fn assert_matches<T: JsonSchema>(tokens: &[String]) {
+        let (min_len, max_len) = (
+            field::<T>("minLength").as_u64().unwrap() as usize,
+            field::<T>("maxLength").as_u64().unwrap() as usize,
+        );
+
+        for token in tokens {
+            assert!((min_len..=max_len).contains(&token.len()), "{}", token);
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        assert_eq!(
+            field::<AccountId>("pattern"),
+            "^r[1-9A-HJ-NP-Za-km-z]{24,34}$"
+        );
+        assert_eq!(field::<Seed>("pattern"), "^s(Ed)?[1-9A-HJ-NP-Za-km-z]{28}$");
+        assert_eq!(
+            field::<XAddress>("pattern"),
+            "^[XT][1-9A-HJ-NP-Za-km-z]{46}$"
+        );
+        assert_eq!(field::<AccountId>("type"), "string");
+    }
+
+    #[test]
+    fn character_class_is_the_alphabet() {
+        let mut alphabet: Vec<char> = api::Alphabet::XRPL.as_str().chars().collect();
+        alphabet.sort_unstable();
+
+        for pattern in [
+            field::<AccountId>("pattern"),
+            field::<Seed>("pattern"),
+            field::<XAddress>("pattern"),
+        ] {
+            assert_eq!(class_chars(pattern.as_str().unwrap()), alphabet);
+        }
+    }
+
+    #[test]
+    fn length_bounds() {
+        let addresses: Vec<String> = (0..1000)
+            .map(|_| api::encode_account_id(&get_20_random_bytes()))
+            .chain([
+                api::encode_account_id(&[0; 20]),
+                api::encode_account_id(&[0xFF; 20]),
+            ])
+            .collect();
+        let seeds: Vec<String> = Algorithm::all()
+            .iter()
+            .flat_map(|&algorithm| {
+                [[0; 16], [0xFF; 16], get_16_random_bytes()]
+                    .map(|entropy| api::encode_seed(&entropy, algorithm))
+            })
+            .collect();
+        let xaddresses: Vec<String> = [Network::Main, Network::Test]
+            .iter()
+            .flat_map(|&network| {
+                [([0; 20], Tag::None), ([0xFF; 20], Tag::U32(u32::MAX))]
+                    .map(|(account_id, tag)| api::encode_xaddress(&account_id, tag, network))
+            })
+            .collect();
+
+        assert_matches::<AccountId>(&addresses);
+        assert_matches::<Seed>(&seeds);
+        assert_matches::<XAddress>(&xaddresses);
+    }
+
+    #[test]
+    fn algorithm_names() {
+        assert_eq!(field::<Algorithm>("enum"), json!(["secp256k1", "ed25519"]));
+    }
+}
+
+#[cfg(feature = "borsh")]
+mod borsh_serialization {
+    use super::*;
+
+    use api::{AccountId, Seed, Tag, XAddress};
+
+    #[test]
+    fn account_id_raw_bytes() {
+        let bytes = get_20_random_bytes();
+        let serialized = borsh::to_vec(&AccountId::new(bytes)).unwrap();
+
+        assert_eq!(serialized, bytes);
+        assert_eq!(
+            borsh::from_slice::<AccountId>(&serialized).unwrap(),
+            AccountId::new(bytes)
+        );
+    }
+
+    #[test]
+    fn seed_entropy_and_algorithm() {
+        let serialized = borsh::to_vec(&Seed::new([7; 16], api::Ed25519)).unwrap();
+
+        assert_eq!(serialized, [&[7; 16][..], &[1]].concat());
+    }
+
+    #[test]
+    fn xaddress_round_trip() {
+        let xaddress = XAddress::new(
+            AccountId::new(get_20_random_bytes()),
+            Tag::U32(rand::random()),
+            api::Network::Test,
+        );
+        let serialized = borsh::to_vec(&xaddress).unwrap();
+
+        assert_eq!(serialized.len(), 20 + 1 + 4 + 1);
+        assert_eq!(
+            borsh::from_slice::<XAddress>(&serialized).unwrap(),
+            xaddress
+        );
+    }
+
+    #[test]
+    fn deserialize_bad_algorithm() {
+        assert!(borsh::from_slice::<Seed>(&[&[7; 16][..], &[2]].concat()).is_err());
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_archive {
+    use super::*;
+
+    use rkyv::rancor::Error;
+
+    use api::{AccountId, ArchivedAccountId, ArchivedSeed, ArchivedXAddress, Seed, Tag, XAddress};
+
+    #[test]
+    fn account_id_raw_bytes() {
+        let bytes = get_20_random_bytes();
+        let archive = rkyv::to_bytes::<Error>(&AccountId::new(bytes)).unwrap();
+
+        assert_eq!(&archive[..], bytes);
+
+        let archived = rkyv::access::<ArchivedAccountId, Error>(&archive).unwrap();
+
+        assert_eq!(archived.as_bytes(), &bytes);
+        assert_eq!(*archived, AccountId::new(bytes));
+        assert_eq!(
+            rkyv::from_bytes::<AccountId, Error>(&archive).unwrap(),
+            AccountId::new(bytes)
+        );
+    }
+
+    #[test]
+    fn account_ids_in_place() {
+        let account_ids: Vec<_> = (0..8)
+            .map(|_| AccountId::new(get_20_random_bytes()))
+            .collect();
+        let archive = rkyv::to_bytes::<Error>(&account_ids).unwrap();
+        let archived =
+            rkyv::access::<rkyv::vec::ArchivedVec<ArchivedAccountId>, Error>(&archive).unwrap();
+
+        assert_eq!(archived.len(), account_ids.len());
+        for (archived, account_id) in archived.iter().zip(&account_ids) {
+            assert_eq!(archived.as_bytes(), account_id.as_bytes());
+            assert_eq!(archived.to_account_id(), *account_id);
+        }
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        let seed = Seed::new([7; 16], api::Ed25519);
+        let archive = rkyv::to_bytes::<Error>(&seed).unwrap();
+        let archived = rkyv::access::<ArchivedSeed, Error>(&archive).unwrap();
+
+        assert_eq!(archived.algorithm(), api::Ed25519);
+        assert_eq!(format!("{:?}", archived), "ArchivedSeed(sEd****)");
+        assert_eq!(rkyv::from_bytes::<Seed, Error>(&archive).unwrap(), seed);
+    }
+
+    #[test]
+    fn xaddress_round_trip() {
+        let xaddress = XAddress::new(
+            AccountId::new(get_20_random_bytes()),
+            Tag::U32(rand::random()),
+            api::Network::Test,
+        );
+        let archive = rkyv::to_bytes::<Error>(&xaddress).unwrap();
+        let archived = rkyv::access::<ArchivedXAddress, Error>(&archive).unwrap();
+
+        assert_eq!(archived.account_id().to_account_id(), xaddress.account_id());
+        assert_eq!(archived.tag(), xaddress.tag());
+        assert_eq!(archived.network(), xaddress.network());
+        assert_eq!(*archived, xaddress);
+        assert_eq!(
+            rkyv::from_bytes::<XAddress, Error>(&archive).unwrap(),
+            xaddress
+        );
+    }
+
+    #[test]
+    fn access_bad_algorithm() {
+        let seed = Seed::new([7; 16], api::Ed25519);
+        let mut archive = rkyv::to_bytes::<Error>(&seed).unwrap();
+        archive[16] = 0xff;
+
+        assert!(rkyv::access::<ArchivedSeed, Error>(&archive).is_err());
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::*;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use api::{AccountId, Entropy, Seed, XAddress};
+
+    #[test]
+    fn generated_values_round_trip() {
+        let raw: Vec<u8> = (0..1024).map(|_| rand::random()).collect();
+        let mut u = Unstructured::new(&raw);
+
+        for _ in 0..10 {
+            let account_id = AccountId::arbitrary(&mut u).unwrap();
+            let entropy = Entropy::arbitrary(&mut u).unwrap();
+            let seed = Seed::arbitrary(&mut u).unwrap();
+            let xaddress = XAddress::arbitrary(&mut u).unwrap();
+
+            assert_eq!(account_id.to_string().parse(), Ok(account_id));
+            assert_eq!(
+                api::decode_seed(&api::encode_seed(&entropy, seed.algorithm())),
+                Ok((entropy, seed.algorithm()))
+            );
+            assert_eq!(seed.expose_secret().parse(), Ok(seed));
+            assert_eq!(xaddress.to_string().parse(), Ok(xaddress));
+        }
+    }
+}
+
+mod batch {
+    use super::*;
+
+    #[test]
+    fn account_ids_round_trip() {
+        let bytes = [get_20_random_bytes(), get_20_random_bytes()];
+
+        let encoded = api::encode_account_ids(&bytes);
+
+        for (account_id, encoded) in bytes.iter().zip(&encoded) {
+            assert_eq!(*encoded, api::encode_account_id(account_id));
+        }
+
+        let encoded: Vec<&str> = encoded.iter().map(String::as_str).collect();
+
+        assert_eq!(
+            api::decode_account_ids(&encoded),
+            [Ok(bytes[0]), Ok(bytes[1])]
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn seeds_round_trip() {
+        let bytes = [get_16_random_bytes(), get_16_random_bytes()];
+
+        let encoded = api::encode_seeds(&bytes, api::Secp256k1);
+        let encoded: Vec<&str> = encoded.iter().map(String::as_str).collect();
+
+        assert_eq!(
+            api::decode_seeds(&encoded),
+            [
+                Ok((bytes[0], api::Secp256k1)),
+                Ok((bytes[1], api::Secp256k1))
+            ]
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn decode_reports_errors_per_item() {
+        assert_eq!(
+            api::decode_account_ids(&["rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN", "0"]),
+            [
+                Ok(api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN").unwrap()),
+                Err(api::Error::BadAlphabet {
+                    character: '0',
+                    index: 0
+                })
+            ]
+        );
+        assert_eq!(
+            api::decode_seeds(&["", "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]),
+            [Err(api::Error::BadChecksum), Ok(([0; 16], api::Ed25519))]
+        );
+        assert!(api::decode_seeds(&[]).is_empty());
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn iterator_ext() {
+        use api::DecodeIteratorExt;
+
+        let addresses = api::encode_account_ids(&[get_20_random_bytes(), get_20_random_bytes()]);
+        let borrowed: Vec<&str> = addresses.iter().map(String::as_str).collect();
+
+        assert_eq!(
+            addresses.iter().decode_account_ids().collect::<Vec<_>>(),
+            api::decode_account_ids(&borrowed)
+        );
+        assert_eq!(
+            borrowed.iter().rev().decode_account_ids().next(),
+            addresses.iter().decode_account_ids().next_back()
+        );
+        assert_eq!(addresses.into_iter().decode_account_ids().len(), 2);
+
+        let mut seeds = ["", "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"]
+            .iter()
+            .decode_seeds();
+
+        assert_eq!(seeds.next(), Some(Err(api::Error::BadChecksum)));
+        assert_eq!(seeds.next(), Some(Ok(([0; 16], api::Ed25519))));
+        assert_eq!(seeds.next(), None);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let account_ids: Vec<[u8; 20]> = (0..3000).map(|_| get_20_random_bytes()).collect();
+        let entropies: Vec<[u8; 16]> = (0..3000).map(|_| get_16_random_bytes()).collect();
+
+        let addresses = api::par_encode_account_ids(&account_ids);
+        let seeds = api::par_encode_seeds(&entropies, api::Ed25519);
+
+        assert_eq!(addresses, api::encode_account_ids(&account_ids));
+        assert_eq!(seeds, api::encode_seeds(&entropies, api::Ed25519));
+
+        let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+        let seeds: Vec<&str> = seeds.iter().map(String::as_str).collect();
+
+        assert_eq!(
+            api::par_decode_account_ids(&addresses),
+            api::decode_account_ids(&addresses)
+        );
+        assert_eq!(api::par_decode_seeds(&seeds), api::decode_seeds(&seeds));
+    }
+}
+
+mod address_reader {
+    use super::*;
+
+    use std::io::{self, Read};
+
+    use api::{AccountId, AddressReader, Error};
+
+    #[test]
+    fn numbers_lines_and_skips_blank_ones() {
+        let bytes = [get_20_random_bytes(), get_20_random_bytes()];
+        let input = format!(
+            "  {}\t\n\n   \r\n{}",
+            api::encode_account_id(&bytes[0]),
+            api::encode_account_id(&bytes[1])
+        );
+
+        let lines: Vec<_> = AddressReader::new(input.as_bytes())
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(
+            lines,
+            [
+                (1, Ok(AccountId::new(bytes[0]))),
+                (4, Ok(AccountId::new(bytes[1])))
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let long_line = "r".repeat(10_000);
+        let input = [
+            "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE".as_bytes(),
+            long_line.as_bytes(),
+            b" rr\xFFr",
+            b"rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+        ]
+        .join(&b'\n');
+
+        let lines: Vec<_> = AddressReader::new(&input[..]).map(Result::unwrap).collect();
+
+        assert_eq!(
+            lines,
+            [
+                (1, Err(Error::BadPrefix { expected: &[0x00] })),
+                (2, Err(Error::DecodeError)),
+                (
+                    3,
+                    Err(Error::BadAlphabet {
+                        character: char::REPLACEMENT_CHARACTER,
+                        index: 2
+                    })
+                ),
+                (4, Ok(AccountId::new([0; 20])))
+            ]
+        );
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disconnected"))
+        }
+    }
+
+    #[test]
+    fn stops_after_an_io_error() {
+        let mut reader =
+            AddressReader::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp\n".as_bytes().chain(Failing));
+
+        assert_eq!(reader.next().unwrap().unwrap().0, 1);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().to_string(),
+            "disconnected"
+        );
+        assert!(reader.next().is_none());
+    }
+}
+
+#[cfg(feature = "cache")]
+mod cache {
+    use super::*;
+
+    use api::cache::CachedCodec;
+
+    #[test]
+    fn same_results_as_uncached() {
+        let mut codec = CachedCodec::new(8);
+        let inputs: Vec<String> = (0..32)
+            .map(|_| api::encode_account_id(&get_20_random_bytes()))
+            .chain(["rrrrrrrrrrrrrrrrrrrrrhoLvTr".to_string(), "".to_string()])
+            .chain(std::iter::once("r".repeat(100)))
+            .collect();
+
+        for _ in 0..3 {
+            for input in inputs.iter().chain(inputs.iter().rev()) {
+                assert_eq!(
+                    codec.decode_account_id(input),
+                    api::decode_account_id(input)
+                );
+                assert!(codec.len() <= codec.capacity());
+            }
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut codec = CachedCodec::new(2);
+        let a = api::encode_account_id(&[1; 20]);
+        let b = api::encode_account_id(&[2; 20]);
+        let c = api::encode_account_id(&[3; 20]);
+
+        codec.decode_account_id(&a).unwrap();
+        codec.decode_account_id(&b).unwrap();
+        codec.decode_account_id(&a).unwrap();
+        codec.decode_account_id(&c).unwrap();
+        assert_eq!((codec.hits(), codec.misses()), (1, 3));
+
+        codec.decode_account_id(&a).unwrap();
+        codec.decode_account_id(&c).unwrap();
+        assert_eq!((codec.hits(), codec.misses()), (3, 3));
+
+        codec.decode_account_id(&b).unwrap();
+        assert_eq!((codec.hits(), codec.misses()), (3, 4));
+        assert_eq!(codec.len(), 2);
+    }
+
+    #[test]
+    fn caches_invalid_inputs() {
+        let mut codec = CachedCodec::new(4);
+
+        for _ in 0..2 {
+            assert_eq!(
+                codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTr"),
+                Err(api::Error::BadChecksum)
+            );
+        }
+        assert_eq!((codec.hits(), codec.misses()), (1, 1));
+    }
+
+    #[test]
+    fn skips_long_inputs() {
+        let mut codec = CachedCodec::new(4);
+        let long = "r".repeat(100);
+
+        assert!(!codec.is_valid_account_id(&long));
+        assert!(codec.is_empty());
+    }
+}
+
+#[cfg(feature = "futures")]
+mod stream {
+    use super::*;
+
+    use futures::{channel::mpsc, executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn keeps_input_order() {
+        let addresses: Vec<String> = (0..100)
+            .map(|index| {
+                let address = api::encode_account_id(&get_20_random_bytes());
+                if index % 7 == 0 {
+                    address.replacen('r', "x", 1)
+                } else {
+                    address
+                }
+            })
+            .collect();
+
+        for concurrency in [0, 1, 8, 1000] {
+            let validated: Vec<_> = block_on(
+                api::validate_account_ids_stream(stream::iter(&addresses), concurrency).collect(),
+            );
+            let expected: Vec<_> = addresses
+                .iter()
+                .map(|address| (address, api::decode_account_id(address)))
+                .collect();
+
+            assert_eq!(validated, expected);
+        }
+    }
+
+    #[test]
+    fn yields_items_as_they_arrive() {
+        let (sender, receiver) = mpsc::unbounded::<&str>();
+        let mut validated = api::validate_account_ids_stream(receiver, 64);
+
+        sender
+            .unbounded_send("rrrrrrrrrrrrrrrrrrrrrhoLvTp")
+            .unwrap();
+
+        assert_eq!(
+            block_on(validated.next()),
+            Some(("rrrrrrrrrrrrrrrrrrrrrhoLvTp", Ok([0; 20])))
+        );
+
+        drop(sender);
+
+        assert_eq!(block_on(validated.next()), None);
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_events {
+    use super::*;
+
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    /// Subscriber recording the fields of every event as `name=value`
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Vec<String>>>>);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(Vec::new());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    struct Fields(Vec<String>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    fn events_of(f: impl FnOnce()) -> Vec<Vec<String>> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), f);
+
+        let events = recorder.0.lock().unwrap().clone();
+        events
+    }
+
+    #[test]
+    fn failure_event() {
+        let events = events_of(|| {
+            api::decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTr").unwrap_err();
+        });
+
+        assert_eq!(
+            events,
+            [[
+                "message=decode failed",
+                "token_type=\"account_id\"",
+                "input_len=27",
+                "error=\"bad_checksum\"",
+            ]]
+        );
+    }
+
+    #[test]
+    fn no_event_on_success() {
+        let events = events_of(|| {
+            api::decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+            api::decode_any("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+        });
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn never_records_input() {
+        let seed = "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqF";
+        let events = events_of(|| {
+            api::decode_seed(seed).unwrap_err();
+        });
+
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains(&"token_type=\"seed\"".to_owned()));
+        assert!(events[0].iter().all(|field| !field.contains(seed)));
+    }
+
+    #[test]
+    fn one_event_per_call() {
+        let events = events_of(|| {
+            api::decode_any("not a token").unwrap_err();
+            api::parse_account("rrrrrrrrrrrrrrrrrrrrrhoLvTr").unwrap_err();
+        });
+
+        assert_eq!(events.len(), 2);
+        assert!(events[0].contains(&"token_type=\"any\"".to_owned()));
+        assert!(events[1].contains(&"token_type=\"account\"".to_owned()));
+    }
+
+    #[test]
+    fn wrong_algorithm_event() {
+        let events = events_of(|| {
+            api::decode_secp256k1_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap_err();
+        });
+
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains(&"token_type=\"seed_secp256k1\"".to_owned()));
+        assert!(events[0].contains(&"error=\"wrong_algorithm\"".to_owned()));
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+mod arena {
+    use super::*;
+
+    use bumpalo::Bump;
+
+    #[test]
+    fn encode_account_ids_in() {
+        let bump = Bump::new();
+        let bytes = [get_20_random_bytes(), get_20_random_bytes()];
+
+        let encoded = api::arena::encode_account_ids_in(&bump, &bytes);
+
+        assert_eq!(encoded.len(), 2);
+
+        for (account_id, encoded) in bytes.iter().zip(encoded) {
+            assert_eq!(*encoded, api::encode_account_id(account_id));
+        }
+    }
+
+    #[test]
+    fn encode_seeds_in() {
+        let bump = Bump::new();
+        let bytes = [get_16_random_bytes(), get_16_random_bytes()];
+
+        let encoded = api::arena::encode_seeds_in(&bump, &bytes, api::Ed25519);
+
+        for (entropy, encoded) in bytes.iter().zip(encoded) {
+            assert_eq!(api::decode_seed(encoded), Ok((*entropy, api::Ed25519)));
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod stack {
+    use super::*;
+
+    #[test]
+    fn matches_heap_encoding() {
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+
+        assert_eq!(
+            api::stack::encode_account_id(&bytes),
+            api::encode_account_id(&bytes).as_str()
+        );
+
+        for &algorithm in api::Algorithm::all() {
+            assert_eq!(
+                api::stack::encode_seed(&entropy, algorithm),
+                api::encode_seed(&entropy, algorithm).as_str()
+            );
+        }
+
+        for &network in &[api::Network::Main, api::Network::Test] {
+            for &tag in &[api::Tag::None, api::Tag::U32(u32::MAX)] {
+                assert_eq!(
+                    api::stack::encode_xaddress(&bytes, tag, network),
+                    api::encode_xaddress(&bytes, tag, network).as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn longest_encodings_fit() {
+        assert_eq!(api::stack::encode_account_id(&[0xFF; 20]).len(), 34);
+        assert_eq!(api::stack::encode_seed(&[0xFF; 16], api::Ed25519).len(), 31);
+        assert_eq!(
+            api::stack::encode_xaddress(&[0xFF; 20], api::Tag::U32(u32::MAX), api::Network::Main)
+                .len(),
+            47
+        );
+    }
+}
+
+mod compat {
+    use super::*;
+
+    use api::compat::*;
+
+    #[test]
+    fn account_id_round_trip() {
+        let bytes = get_20_random_bytes();
+        let encoded = encodeAccountID(&bytes);
+
+        assert_eq!(encoded, api::encode_account_id(&bytes));
+        assert!(isValidClassicAddress(&encoded));
+        assert_eq!(decodeAccountID(&encoded), Ok(bytes));
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn decode_seed() {
+        let decoded = decodeSeed("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+
+        assert_eq!(to_hex(&decoded.bytes), "CF2DE378FBDD7E2EE87D486DFB5A7BFF");
+        assert_eq!(decoded.r#type, api::Secp256k1);
+    }
+
+    #[test]
+    fn is_valid_classic_address_bad_checksum() {
+        assert!(!isValidClassicAddress("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"));
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn decoded_seed_debug_redacted() {
+        let decoded = decodeSeed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+
+        assert_eq!(
+            format!("{:?}", decoded),
+            "DecodedSeed { bytes: ****, type: Ed25519 }"
+        );
+    }
+}
+
+mod container {
+    use super::*;
+
+    use api::container::SeedContainer;
+    #[cfg(feature = "seed-ed25519")]
+    use api::Error;
+
+    #[test]
+    fn encode_and_decode() {
+        let ciphertext = get_16_random_bytes();
+
+        for &algorithm in api::Algorithm::all() {
+            let container = SeedContainer::new(algorithm, &ciphertext, [9; 16]).unwrap();
+            let encoded = container.encode();
+
+            assert_eq!(encoded[..8], container.header());
+            assert_eq!(SeedContainer::decode(&encoded), Ok(container));
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn empty_ciphertext() {
+        let encoded = SeedContainer::new(api::Ed25519, &[], [0; 16])
+            .unwrap()
+            .encode();
+
+        assert_eq!(encoded.len(), 24);
+        assert!(SeedContainer::decode(&encoded)
+            .unwrap()
+            .ciphertext()
+            .is_empty());
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn ciphertext_too_long() {
+        let ciphertext = vec![0; 65536];
+
+        assert!(SeedContainer::new(api::Ed25519, &ciphertext[..65535], [0; 16]).is_ok());
+        assert_eq!(
+            SeedContainer::new(api::Ed25519, &ciphertext, [0; 16]),
+            Err(Error::InputTooLong {
+                max_len: 65535,
+                actual: 65536
+            })
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn bad_magic() {
+        let mut encoded = SeedContainer::new(api::Ed25519, &[0; 16], [0; 16])
+            .unwrap()
+            .encode();
+        encoded[0] = b'Y';
+
+        assert_eq!(
+            SeedContainer::decode(&encoded),
+            Err(Error::BadPrefix { expected: b"XRPS" })
+        );
+        assert_eq!(
+            SeedContainer::decode(b""),
+            Err(Error::BadPrefix { expected: b"XRPS" })
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn unsupported_version() {
+        let mut encoded = SeedContainer::new(api::Ed25519, &[0; 16], [0; 16])
+            .unwrap()
+            .encode();
+        encoded[4] = 0;
+
+        let error = SeedContainer::decode(&encoded).unwrap_err();
+
+        assert_eq!(error, Error::UnsupportedVersion { version: 0 });
+        assert_eq!(error.code(), "unsupported_version");
+        assert_eq!(error.to_string(), "unsupported format version 0");
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn unknown_algorithm() {
+        let mut encoded = SeedContainer::new(api::Ed25519, &[0; 16], [0; 16])
+            .unwrap()
+            .encode();
+        encoded[5] = 2;
+
+        assert_eq!(SeedContainer::decode(&encoded), Err(Error::DecodeError));
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn bad_length() {
+        let encoded = SeedContainer::new(api::Ed25519, &[0; 16], [0; 16])
+            .unwrap()
+            .encode();
+
+        assert_eq!(
+            SeedContainer::decode(&encoded[..39]),
+            Err(Error::BadLength {
+                expected: 40,
+                actual: 39
+            })
+        );
+        assert_eq!(
+            SeedContainer::decode(&encoded[..6]),
+            Err(Error::BadLength {
+                expected: 24,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            SeedContainer::decode(&[&encoded[..], &[0]].concat()),
+            Err(Error::BadLength {
+                expected: 40,
+                actual: 41
+            })
+        );
+    }
+}
+
+mod classic_address_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn new() {
+        let bytes = get_20_random_bytes();
+        let encoded = api::encode_account_id(&bytes);
+        let address = api::ClassicAddressStr::new(&encoded).unwrap();
+
+        assert_eq!(address.as_str(), encoded);
+        assert_eq!(address.account_id(), bytes);
+    }
+
+    #[test]
+    fn new_bad_checksum() {
+        assert_eq!(
+            api::ClassicAddressStr::new("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let address =
+            <&api::ClassicAddressStr>::try_from("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN").unwrap();
+
+        assert_eq!(
+            address.account_id(),
+            to_20_bytes("BA8E78626EE42C41B46D46C3048DF3A1C3C87072")
+        );
+    }
+
+    #[test]
+    fn to_account_id() {
+        let bytes = get_20_random_bytes();
+        let encoded = api::encode_account_id(&bytes);
+        let address: &api::AccountIdStr = api::AccountIdStr::new(&encoded).unwrap();
+
+        assert_eq!(address.to_account_id(), api::AccountId::new(bytes));
+        assert_eq!(api::AccountId::from(address), api::AccountId::new(bytes));
+    }
+}
+
+mod xaddress_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn new() {
+        let bytes = get_20_random_bytes();
+        let encoded = api::encode_xaddress(&bytes, api::Tag::U32(7), api::Network::Test);
+        let xaddress = api::XAddressStr::new(&encoded).unwrap();
+
+        assert_eq!(xaddress.as_str(), encoded);
+        assert_eq!(xaddress.account_id(), bytes);
+        assert_eq!(xaddress.tag(), api::Tag::U32(7));
+        assert_eq!(xaddress.network(), api::Network::Test);
+    }
+
+    #[test]
+    fn new_classic_address() {
+        assert_eq!(
+            api::XAddressStr::new("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN").unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let xaddress =
+            <&api::XAddressStr>::try_from("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC")
+                .unwrap();
+
+        assert_eq!(
+            &**xaddress,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+        );
+        assert_eq!(
+            xaddress.to_string(),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+        );
+    }
+
+    #[test]
+    fn to_xaddress() {
+        let account_id = api::AccountId::new(get_20_random_bytes());
+        let owned = api::XAddress::new(account_id, api::Tag::None, api::Network::Main);
+        let encoded = owned.to_string();
+        let xaddress = api::XAddressStr::new(&encoded).unwrap();
+
+        assert_eq!(xaddress.to_xaddress(), owned);
+        assert_eq!(api::XAddress::from(xaddress), owned);
+    }
+}
+
+mod seed_str {
+    use super::*;
+
+    #[cfg(feature = "seed-ed25519")]
+    use std::convert::TryFrom;
+
+    use api::{Seed, SeedStr};
+
+    #[test]
+    fn new() {
+        for &algorithm in api::Algorithm::all() {
+            let entropy = get_16_random_bytes();
+            let encoded = api::encode_seed(&entropy, algorithm);
+            let seed = SeedStr::new(&encoded).unwrap();
+
+            assert_eq!(seed.as_str(), encoded);
+            assert_eq!(seed.algorithm(), algorithm);
+            assert_eq!(seed.to_seed(), Seed::new(entropy, algorithm));
+            assert_eq!(Seed::from(seed), Seed::new(entropy, algorithm));
+        }
+    }
+
+    #[test]
+    fn display_hides_the_seed() {
+        for &algorithm in api::Algorithm::all() {
+            let encoded = api::encode_seed(&get_16_random_bytes(), algorithm);
+            let seed = SeedStr::new(&encoded).unwrap();
+
+            assert!(!format!("{}", seed).contains(&encoded));
+            assert_eq!(seed.to_string(), seed.to_seed().to_string());
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn new_invalid() {
+        assert_eq!(
+            SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfM").unwrap_err(),
+            api::Error::BadChecksum
+        );
+        assert!(SeedStr::new("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_err());
+        assert!(<&SeedStr>::try_from("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").is_ok());
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn debug_hides_the_seed() {
+        let secp256k1 = SeedStr::new("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+        let ed25519 = SeedStr::new("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+
+        assert_eq!(format!("{:?}", secp256k1), "SeedStr(s****)");
+        assert_eq!(format!("{:?}", ed25519), "SeedStr(sEd****)");
+    }
+}
+
+mod derivation {
+    use super::*;
+
+    #[cfg(feature = "seed-secp256k1")]
+    use api::derivation::derive_seed;
+    use api::derivation::{derive_entropy, MIN_MASTER_SECRET_LEN};
+
+    #[test]
+    fn derive_entropy_vector() {
+        assert_eq!(
+            to_hex(&derive_entropy(&[7; 32], &["tenant-1", "deposits"]).unwrap()),
+            "9888B01FA130C92DA11D756BFD2BF1C1"
+        );
+    }
+
+    #[test]
+    fn derive_entropy_labels_are_length_prefixed() {
+        assert_eq!(
+            to_hex(&derive_entropy(&[7; 32], &["ab", "c"]).unwrap()),
+            "36EC9B545FEA4441CF9847CA147E1615"
+        );
+        assert_ne!(
+            derive_entropy(&[7; 32], &["ab", "c"]),
+            derive_entropy(&[7; 32], &["a", "bc"])
+        );
+    }
+
+    #[test]
+    fn derive_entropy_long_master_secret() {
+        // Longer than the SHA-256 block, hashed to make the HMAC key
+        assert_eq!(
+            to_hex(&derive_entropy(&[7; 100], &["tenant-1"]).unwrap()),
+            "8DA3281C8ED3AAF12F6A722192701D6E"
+        );
+        assert_ne!(
+            derive_entropy(&[7; 100], &["tenant-1"]),
+            derive_entropy(&[7; 64], &["tenant-1"])
+        );
+    }
+
+    #[test]
+    fn derive_entropy_short_master_secret() {
+        assert_eq!(
+            derive_entropy(&[7; MIN_MASTER_SECRET_LEN - 1], &["tenant-1"]),
+            Err(api::Error::BadLength {
+                expected: MIN_MASTER_SECRET_LEN,
+                actual: MIN_MASTER_SECRET_LEN - 1
+            })
+        );
+        assert!(derive_entropy(&[7; MIN_MASTER_SECRET_LEN], &["tenant-1"]).is_ok());
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn derive_seed_round_trip() {
+        let master_secret = get_20_random_bytes();
+        let seed = derive_seed(&master_secret, &["tenant-1"], api::Secp256k1).unwrap();
+
+        assert_eq!(
+            api::decode_seed(&seed),
+            Ok((
+                derive_entropy(&master_secret, &["tenant-1"]).unwrap(),
+                api::Secp256k1
+            ))
+        );
+    }
+}
+
+#[cfg(any(feature = "secp256k1", feature = "ed25519"))]
+mod keypair {
+    use super::*;
+
+    #[cfg(feature = "ed25519")]
+    use api::keypair::derive_ed25519;
+    #[cfg(feature = "secp256k1")]
+    use api::keypair::{derive_secp256k1, derive_secp256k1_root};
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519() {
+        for (seed, private_key, public_key, address) in &[
+            (
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+                "B4C4E046826BD26190D09715FC31F4E6A728204EADD112905B08B14B7F15C4F3",
+                "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63",
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+            ),
+            (
+                "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE",
+                "0B6CBAC838DFE7F47EA1BD0DF00EC282FDF45510C92161072CCFB84035390C4D",
+                "ED1A7C082846CFF58FF9A892BA4BA2593151CCF1DBA59F37714CC9ED39824AF85F",
+                "r9zRhGr7b6xPekLvT6wP4qNdWMryaumZS7",
+            ),
+        ] {
+            let (entropy, _) = api::decode_seed(seed).unwrap();
+            let keypair = derive_ed25519(&entropy);
+
+            assert_eq!(to_hex(keypair.private_key()), *private_key);
+            assert_eq!(to_hex(keypair.public_key()), *public_key);
+            assert_eq!(keypair.address(), *address);
+        }
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn secp256k1() {
+        for (seed, private_key, public_key, address) in &[
+            (
+                "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+                "1ACAAEDECE405B2A958212629E16F2EB46B153EEE94CDD350FDEFF52795525B7",
+                "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            ),
+            (
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+                "D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23",
+                "030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435",
+                "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+            ),
+        ] {
+            let (entropy, _) = api::decode_seed(seed).unwrap();
+            let keypair = derive_secp256k1(&entropy);
+
+            assert_eq!(to_hex(keypair.private_key()), *private_key);
+            assert_eq!(to_hex(keypair.public_key()), *public_key);
+            assert_eq!(keypair.address(), *address);
+        }
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn secp256k1_root() {
+        let (entropy, _) = api::decode_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        let keypair = derive_secp256k1_root(&entropy);
+
+        assert_eq!(
+            to_hex(keypair.private_key()),
+            "395898665728F57DE5D90F1DE102278A967D6941A45A6C9A98CB123394489E55"
+        );
+        assert_eq!(
+            to_hex(keypair.public_key()),
+            "03D49C56E1B185F1BE899AE66A02EFC17F78EA6FC53AF85E0FE54C6E8B7F8C71A8"
+        );
+    }
+
+    #[cfg(all(feature = "secp256k1", feature = "ed25519"))]
+    #[test]
+    fn wallet_proposal() {
+        let seed: api::Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+        let wallet = api::keypair::WalletProposal::from_seed(&seed);
+
+        assert_eq!(wallet.seed(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_eq!(wallet.algorithm(), api::Secp256k1);
+        assert_eq!(
+            wallet.public_key_hex(),
+            "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020"
+        );
+        assert_eq!(wallet.account_id(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(
+            wallet.classic_address(),
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+        );
+        assert_eq!(
+            wallet.xaddress(),
+            "XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc"
+        );
+        assert!(!format!("{:?}", wallet).contains(wallet.seed()));
+    }
+
+    #[cfg(all(feature = "secp256k1", feature = "ed25519", feature = "os-rng"))]
+    #[test]
+    fn propose_wallet() {
+        for &algorithm in api::Algorithm::all() {
+            let wallet = api::keypair::propose_wallet(algorithm);
+            let seed: api::Seed = wallet.seed().parse().unwrap();
+
+            assert_eq!(seed.algorithm(), algorithm);
+            assert_eq!(api::keypair::WalletProposal::from_seed(&seed), wallet);
+        }
+    }
+
+    #[cfg(all(feature = "secp256k1", feature = "ed25519", feature = "os-rng"))]
+    #[test]
+    fn generate_batch() {
+        let wallets = api::keypair::generate_batch(10, api::Ed25519);
+
+        assert_eq!(wallets.len(), 10);
+        for wallet in &wallets {
+            let seed: api::Seed = wallet.seed().parse().unwrap();
+
+            assert_eq!(api::keypair::WalletProposal::from_seed(&seed), *wallet);
+        }
+        assert_ne!(wallets[0], wallets[1]);
+    }
+
+    #[cfg(all(feature = "secp256k1", feature = "ed25519", feature = "serde"))]
+    #[test]
+    fn wallet_proposal_json() {
+        let seed: api::Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+        let wallet = api::keypair::WalletProposal::from_seed(&seed);
+
+        assert_eq!(
+            serde_json::to_value(&[wallet]).unwrap(),
+            serde_json::json!([{
+                "account_id": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "key_type": "secp256k1",
+                "master_seed": "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+                "public_key_hex": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+                "x_address": "XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc",
+            }])
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn check_ed25519_public_key() {
+        let keypair = derive_ed25519(&get_16_random_bytes());
+        let mut off_curve = [0; 33];
+        off_curve[0] = 0xED;
+        off_curve[1] = 2;
+
+        assert_eq!(api::keypair::check_public_key(keypair.public_key()), Ok(()));
+        assert_eq!(
+            api::keypair::check_public_key(&off_curve),
+            Err(api::Error::InvalidKey)
+        );
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn check_secp256k1_public_key() {
+        let keypair = derive_secp256k1(&get_16_random_bytes());
+        let mut off_curve = [0; 33];
+        off_curve[0] = 0x02;
+
+        assert_eq!(api::keypair::check_public_key(keypair.public_key()), Ok(()));
+        assert_eq!(
+            api::keypair::check_public_key(&off_curve),
+            Err(api::Error::InvalidKey)
+        );
+    }
+
+    #[test]
+    fn check_public_key_prefix() {
+        for prefix in [0x00, 0x04, 0xEC, 0xEE] {
+            let mut key = [0; 33];
+            key[0] = prefix;
+
+            assert_eq!(
+                api::keypair::check_public_key(&key),
+                Err(api::Error::InvalidKey)
+            );
+        }
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn decode_public_keys_checked() {
+        let mut off_curve = [0; 33];
+        off_curve[0] = 0x02;
+        let account_public_key = api::encode_account_public_key(&off_curve);
+        let node_public_key = api::encode_node_public_key(&off_curve);
+
+        assert_eq!(
+            api::decode_account_public_key(&account_public_key),
+            Ok(off_curve)
+        );
+        assert_eq!(
+            api::keypair::decode_account_public_key_checked(&account_public_key),
+            Err(api::Error::InvalidKey)
+        );
+        assert_eq!(
+            api::keypair::decode_node_public_key_checked(&node_public_key),
+            Err(api::Error::InvalidKey)
+        );
+        assert_eq!(
+            api::keypair::decode_node_public_key_checked(&account_public_key),
+            Err(api::Error::BadPrefix { expected: &[0x1C] })
+        );
+        assert_eq!(api::Error::InvalidKey.code(), "invalid_key");
+
+        let keypair = derive_secp256k1_root(&get_16_random_bytes());
+        let node_public_key = api::encode_node_public_key(keypair.public_key());
+
+        assert_eq!(
+            api::keypair::decode_node_public_key_checked(&node_public_key),
+            Ok(*keypair.public_key())
+        );
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn debug_redacted() {
+        let keypair = derive_secp256k1(&get_16_random_bytes());
+        let debug = format!("{:?}", keypair);
+
+        assert!(debug.starts_with("Keypair { private_key: ****, public_key: ["));
+    }
+}
+
+mod sha256 {
+    use super::*;
+
+    use api::sha256::DEFAULT;
+
+    #[test]
+    fn default_digest() {
+        assert_eq!(
+            hex::encode(DEFAULT.digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[cfg(all(feature = "ring", feature = "sha2"))]
+    #[test]
+    fn ring_and_sha2_agree() {
+        use api::sha256::{Ring, Sha2, Sha256};
+
+        for len in 0..200 {
+            let data: Vec<u8> = (0..len).map(|_| rand::random()).collect();
+
+            assert_eq!(Ring.digest(&data), Sha2.digest(&data));
+        }
+    }
+}
+
+mod codec {
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    use api::codec::{AuditEvent, Codec, Operation, Policy};
+    use api::TokenType;
+
+    type Events = Arc<Mutex<Vec<(Operation, Option<TokenType>, api::Result<()>)>>>;
+
+    fn recording_codec(policy: Policy) -> (Codec, Events) {
+        let events = Events::default();
+        let recorded = Arc::clone(&events);
+
+        let codec = Codec::new()
+            .with_policy(policy)
+            .with_hook(move |event: &AuditEvent<'_>| {
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push((event.operation, event.token_type, event.outcome))
+            });
+
+        (codec, events)
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn audit_decode_seed() {
+        let (codec, events) = recording_codec(Policy::new());
+
+        codec
+            .decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM2")
+            .unwrap();
+        codec
+            .decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaMA")
+            .unwrap_err();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                (Operation::Decode, Some(TokenType::SeedEd25519), Ok(())),
+                (Operation::Decode, None, Err(api::Error::BadChecksum)),
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn deny_token_type() {
+        let (codec, events) =
+            recording_codec(Policy::new().deny_token_types(&[TokenType::SeedSecp256k1]));
+
+        assert_eq!(
+            codec.encode_seed(&get_16_random_bytes(), api::Secp256k1),
+            Err(api::Error::Denied)
+        );
+        assert!(codec
+            .encode_seed(&get_16_random_bytes(), api::Ed25519)
+            .is_ok());
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                (
+                    Operation::Encode,
+                    Some(TokenType::SeedSecp256k1),
+                    Err(api::Error::Denied)
+                ),
+                (Operation::Encode, Some(TokenType::SeedEd25519), Ok(())),
+            ]
+        );
+    }
+
+    #[test]
+    fn allow_only_account_ids() {
+        let allowed = to_20_bytes("BA8E78626EE42C41B46D46C3048DF3A1C3C87072");
+        let (codec, _) = recording_codec(Policy::new().allow_only_account_ids(&[allowed]));
+
+        assert_eq!(
+            codec.decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"),
+            Ok(allowed)
+        );
+        assert_eq!(
+            codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Err(api::Error::Denied)
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn bitcoin_alphabet_round_trip() {
+        let codec = Codec::new().with_alphabet(api::Alphabet::BITCOIN);
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+
+        let account_id = codec.encode_account_id(&bytes).unwrap();
+        let seed = codec.encode_seed(&entropy, api::Ed25519).unwrap();
+
+        assert_eq!(codec.decode_account_id(&account_id), Ok(bytes));
+        assert_eq!(codec.decode_seed(&seed), Ok((entropy, api::Ed25519)));
+    }
+
+    #[test]
+    fn bitcoin_alphabet_rejects_xrpl_string() {
+        let codec = Codec::new().with_alphabet(api::Alphabet::BITCOIN);
+
+        assert_eq!(
+            codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn alphabet_new_rejects_non_ascii() {
+        assert_eq!(
+            api::Alphabet::new("rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyé"),
+            None
+        );
+    }
+
+    #[test]
+    fn alphabet_new_rejects_duplicates() {
+        assert_eq!(
+            api::Alphabet::new("rrshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz"),
+            None
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn strict_decoding_round_trip() {
+        let codec = Codec::new().with_strict_decoding(true);
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+
+        let account_id = api::encode_account_id(&bytes);
+        let seed = api::encode_seed(&entropy, api::Secp256k1);
+
+        assert_eq!(codec.decode_account_id(&account_id), Ok(bytes));
+        assert_eq!(codec.decode_seed(&seed), Ok((entropy, api::Secp256k1)));
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn lenient_decoding_strips_paste_artifacts() {
+        let codec = Codec::new().with_lenient_decoding(true);
+        let entropy = get_16_random_bytes();
+        let seed = api::encode_seed(&entropy, api::Ed25519);
+
+        for input in &[
+            format!("  {}\n", seed),
+            format!("\u{FEFF}{}\r\n", seed),
+            format!("\u{200B} {}\t", seed),
+            format!("{}\u{200C}{}", &seed[..5], &seed[5..]),
+        ] {
+            assert_eq!(codec.decode_seed(input), Ok((entropy, api::Ed25519)));
+        }
+    }
+
+    #[test]
+    fn lenient_decoding_disabled_by_default() {
+        assert_eq!(
+            Codec::new().decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp\n"),
+            Err(api::Error::BadAlphabet {
+                character: '\n',
+                index: 27
+            })
+        );
+    }
+
+    #[test]
+    fn lenient_decoding_keeps_inner_whitespace() {
+        let codec = Codec::new().with_lenient_decoding(true);
+
+        assert_eq!(
+            codec.decode_account_id("rrrrrrrrrrrrrrrrrrrrr hoLvTp"),
+            Err(api::Error::BadAlphabet {
+                character: ' ',
+                index: 21
+            })
+        );
+    }
+
+    #[test]
+    fn lenient_and_strict_decoding() {
+        let codec = Codec::new()
+            .with_lenient_decoding(true)
+            .with_strict_decoding(true);
+
+        assert_eq!(
+            codec.decode_account_id(" rrrrrrrrrrrrrrrrrrrrrhoLvTp\n"),
+            Ok([0; 20])
+        );
+    }
+
+    #[test]
+    fn strict_decoding_bad_checksum() {
+        let codec = Codec::new().with_strict_decoding(true);
+
+        assert_eq!(
+            codec.decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"),
+            Err(api::Error::BadChecksum)
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn custom_sha256() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use api::sha256::DEFAULT;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let codec = Codec::new().with_sha256(move |data: &[u8]| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            DEFAULT.digest(data)
+        });
+
+        let bytes = get_20_random_bytes();
+        let encoded = codec.encode_account_id(&bytes).unwrap();
+
+        assert_eq!(encoded, api::encode_account_id(&bytes));
+        assert_eq!(codec.decode_account_id(&encoded), Ok(bytes));
+        assert_eq!(
+            codec.decode_seed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+            Ok(([0; 16], api::Ed25519))
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 6);
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn checksums_follow_sha256() {
+        let codec = Codec::new().with_sha256(|_: &[u8]| [0; 32]);
+
+        let encoded = codec.encode_seed(&[0; 16], api::Secp256k1).unwrap();
+
+        assert_eq!(codec.decode_seed(&encoded), Ok(([0; 16], api::Secp256k1)));
+        assert_eq!(api::decode_seed(&encoded), Err(api::Error::BadChecksum));
+    }
+
+    #[test]
+    fn xaddress_matches_free_functions() {
+        let account_id = get_20_random_bytes();
+
+        for network in [api::Network::Main, api::Network::Test] {
+            for tag in [api::Tag::None, api::Tag::U32(7)] {
+                let codec = Codec::new().with_network(network);
+                let encoded = codec.encode_xaddress(&account_id, tag).unwrap();
+
+                assert_eq!(encoded, api::encode_xaddress(&account_id, tag, network));
+                assert_eq!(
+                    codec.decode_xaddress(&encoded),
+                    Ok((account_id, tag, network))
+                );
+                assert_eq!(
+                    Codec::new().decode_xaddress(&encoded),
+                    Ok((account_id, tag, network))
+                );
+            }
+        }
+
+        assert!(Codec::new()
+            .encode_xaddress(&account_id, api::Tag::None)
+            .unwrap()
+            .starts_with('X'));
+    }
+
+    #[test]
+    fn xaddress_network_restricted() {
+        let main = Codec::new().with_network(api::Network::Main);
+        let test = Codec::new().with_network(api::Network::Test);
+        let xaddress = "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC";
+
+        assert!(main.decode_xaddress(xaddress).is_ok());
+        assert_eq!(
+            test.decode_xaddress(xaddress),
+            Err(api::Error::BadPrefix {
+                expected: &[0x04, 0x93]
+            })
+        );
+        assert_eq!(
+            test.decode_xaddress("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"),
+            Err(api::Error::BadPrefix {
+                expected: &[0x04, 0x93]
+            })
+        );
+    }
+
+    #[test]
+    fn xaddress_policy_and_audit() {
+        let (codec, events) = recording_codec(Policy::new().deny_account_ids(&[[0; 20]]));
+        let xaddress = api::encode_xaddress(&[0; 20], api::Tag::None, api::Network::Main);
+
+        assert_eq!(
+            codec.encode_xaddress(&[0; 20], api::Tag::None),
+            Err(api::Error::Denied)
+        );
+        assert_eq!(codec.decode_xaddress(&xaddress), Err(api::Error::Denied));
+        assert!(codec.decode_xaddress(&xaddress[1..]).is_err());
+
+        let events = events.lock().unwrap();
+        assert_eq!(
+            events[..2],
+            [
+                (
+                    Operation::Encode,
+                    Some(TokenType::XAddress),
+                    Err(api::Error::Denied)
+                ),
+                (
+                    Operation::Decode,
+                    Some(TokenType::XAddress),
+                    Err(api::Error::Denied)
+                ),
+            ]
+        );
+        assert_eq!(events[2].1, None);
+    }
+
+    #[test]
+    fn xaddress_lenient_and_strict() {
+        let codec = Codec::new()
+            .with_lenient_decoding(true)
+            .with_strict_decoding(true);
+
+        assert!(codec
+            .decode_xaddress(" XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC\n")
+            .is_ok());
+    }
+
+    #[test]
+    fn max_input_len() {
+        let (codec, events) = recording_codec(Policy::new());
+        let megabyte = "r".repeat(1 << 20);
+        let too_long = api::Error::InputTooLong {
+            max_len: api::codec::DEFAULT_MAX_INPUT_LEN,
+            actual: 1 << 20,
+        };
+
+        assert_eq!(codec.decode_account_id(&megabyte), Err(too_long));
+        assert_eq!(codec.decode_seed(&megabyte), Err(too_long));
+        assert_eq!(codec.decode_xaddress(&megabyte), Err(too_long));
+        assert_eq!(
+            events.lock().unwrap()[0],
+            (Operation::Decode, None, Err(too_long))
+        );
+    }
+
+    #[test]
+    fn max_input_len_counts_paste_artifacts() {
+        let address = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
+        let padded = format!("{:^40}", address);
+        let codec = Codec::new()
+            .with_lenient_decoding(true)
+            .with_max_input_len(address.len());
+
+        assert_eq!(codec.decode_account_id(address), Ok([0; 20]));
+        assert_eq!(
+            codec.decode_account_id(&padded),
+            Err(api::Error::InputTooLong {
+                max_len: address.len(),
+                actual: 40
+            })
+        );
+        assert_eq!(
+            codec.with_max_input_len(40).decode_account_id(&padded),
+            Ok([0; 20])
+        );
+    }
+}
+
+mod xaddress {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    const ACCOUNT_ID: &str = "AA066C988C712815CC37AF71472B7CBBBD4E2A0A";
+
+    const MAIN: [(api::Tag, &str); 5] = [
+        (
+            api::Tag::None,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+        ),
+        (
+            api::Tag::U32(1),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+        ),
+        (
+            api::Tag::U32(14),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtVoD9z4jAcBVsnb97sM",
+        ),
+        (
+            api::Tag::U32(11747),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV1N75zgFKga4R1B9Mk",
+        ),
+        (
+            api::Tag::U32(4294967295),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi",
+        ),
+    ];
+
+    const TEST: [(api::Tag, &str); 2] = [
+        (
+            api::Tag::None,
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+        ),
+        (
+            api::Tag::U32(11747),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnXGRtx9fzucypAhEJD",
+        ),
+    ];
+
+    #[test]
+    fn encode() {
+        for (tag, xaddress) in &MAIN {
+            assert_eq!(
+                api::encode_xaddress(&to_20_bytes(ACCOUNT_ID), *tag, api::Network::Main),
+                *xaddress
+            );
+        }
+
+        for (tag, xaddress) in &TEST {
+            assert_eq!(
+                api::encode_xaddress(&to_20_bytes(ACCOUNT_ID), *tag, api::Network::Test),
+                *xaddress
+            );
+        }
+    }
+
+    #[test]
+    fn decode() {
+        for (tag, xaddress) in &MAIN {
+            assert_eq!(
+                api::decode_xaddress(xaddress),
+                Ok((to_20_bytes(ACCOUNT_ID), *tag, api::Network::Main))
+            );
+        }
+
+        for (tag, xaddress) in &TEST {
+            assert_eq!(
+                api::decode_xaddress(xaddress),
+                Ok((to_20_bytes(ACCOUNT_ID), *tag, api::Network::Test))
+            );
+        }
+    }
+
+    #[test]
+    fn encode_random() {
+        let bytes = get_20_random_bytes();
+        let encoded =
+            api::encode_xaddress(&bytes, api::Tag::U32(rand::random()), api::Network::Main);
+        let (decoded_bytes, _, network) = api::decode_xaddress(&encoded).unwrap();
+
+        assert!(encoded.starts_with("X"));
+        assert_eq!(decoded_bytes, bytes);
+        assert_eq!(network, api::Network::Main);
+    }
+
+    #[test]
+    fn decode_bad_checksum() {
+        assert_eq!(
+            api::decode_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXA").unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn decode_classic_address() {
+        assert_eq!(
+            api::decode_xaddress("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap_err(),
+            api::Error::BadPrefix { expected: &[] }
+        );
+    }
+
+    #[test]
+    fn classic_address_round_trip() {
+        let classic_address = api::encode_account_id(&get_20_random_bytes());
+        let tag = api::Tag::U32(rand::random());
+
+        let xaddress =
+            api::classic_address_to_xaddress(&classic_address, tag, api::Network::Test).unwrap();
+
+        assert!(xaddress.starts_with("T"));
+        assert_eq!(
+            api::xaddress_to_classic_address(&xaddress),
+            Ok((classic_address, tag, api::Network::Test))
+        );
+    }
+
+    #[test]
+    fn classic_address_to_xaddress_bad_checksum() {
+        assert_eq!(
+            api::classic_address_to_xaddress(
+                "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA",
+                api::Tag::None,
+                api::Network::Main
+            )
+            .unwrap_err(),
+            api::Error::BadChecksum
+        );
+    }
+
+    #[test]
+    fn decode_unsupported_tag_flag() {
+        assert_eq!(
+            api::decode_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8zeUygYrCgrPh").unwrap_err(),
+            api::Error::UnsupportedTag
+        );
+    }
+
+    #[test]
+    fn decode_tag_bytes_without_flag() {
+        assert_eq!(
+            api::decode_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV5Df4XtmCJRnnPGt5L").unwrap_err(),
+            api::Error::UnsupportedTag
+        );
+    }
+
+    #[test]
+    fn decode_64_bit_tag() {
+        assert_eq!(
+            api::decode_xaddress("XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4x8GQjraTqWQ").unwrap_err(),
+            api::Error::UnsupportedTag
+        );
+    }
+
+    #[test]
+    fn tag_from_option() {
+        assert_eq!(api::Tag::from(Some(14)), api::Tag::U32(14));
+        assert_eq!(api::Tag::from(None), api::Tag::None);
+    }
+
+    #[test]
+    fn typed_parse_and_display() {
+        for (tag, xaddress) in &MAIN {
+            let parsed: api::XAddress = xaddress.parse().unwrap();
+
+            assert_eq!(parsed.account_id().into_bytes(), to_20_bytes(ACCOUNT_ID));
+            assert_eq!(parsed.tag(), *tag);
+            assert!(!parsed.is_test());
+            assert_eq!(parsed.to_string(), *xaddress);
+        }
+    }
+
+    #[test]
+    fn typed_parse_classic_address() {
+        assert_eq!(
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse::<api::XAddress>(),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+
+    #[test]
+    fn typed_try_from_str() {
+        let (tag, xaddress) = TEST[1];
+
+        assert_eq!(
+            api::XAddress::try_from(xaddress),
+            Ok(api::XAddress::new(
+                to_20_bytes(ACCOUNT_ID).into(),
+                tag,
+                api::Network::Test
+            ))
+        );
+    }
+
+    #[test]
+    fn typed_ordered_by_tag() {
+        let xaddresses: Vec<api::XAddress> = MAIN.iter().map(|(_, x)| x.parse().unwrap()).collect();
+        let mut sorted = xaddresses.clone();
+        sorted.sort();
+
+        assert_eq!(sorted, xaddresses);
+    }
+
+    #[test]
+    fn parse_account_classic() {
+        let bytes = get_20_random_bytes();
+
+        assert_eq!(
+            api::parse_account(&api::encode_account_id(&bytes)),
+            Ok((
+                api::AccountId::new(bytes),
+                api::Tag::None,
+                api::Network::Main
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_account_xaddress() {
+        let bytes = get_20_random_bytes();
+        let tag = api::Tag::U32(rand::random());
+        let xaddress = api::encode_xaddress(&bytes, tag, api::Network::Test);
+
+        assert_eq!(
+            api::parse_account(&xaddress),
+            Ok((api::AccountId::new(bytes), tag, api::Network::Test))
+        );
+    }
+
+    #[test]
+    fn parse_account_errors() {
+        assert_eq!(
+            api::parse_account("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"),
+            Err(api::Error::BadChecksum)
+        );
+        assert_eq!(
+            api::parse_account("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+
+    #[test]
+    fn parse_destination() {
+        let bytes = get_20_random_bytes();
+        let address = api::encode_account_id(&bytes);
+
+        assert_eq!(
+            api::parse_destination(&format!("{}:{}", address, u32::MAX)),
+            Ok((api::AccountId::new(bytes), Some(u32::MAX)))
+        );
+        assert_eq!(
+            api::parse_destination(&format!("{}:0", address)),
+            Ok((api::AccountId::new(bytes), Some(0)))
+        );
+        assert_eq!(
+            api::parse_destination(&address),
+            Ok((api::AccountId::new(bytes), None))
+        );
+    }
+
+    #[test]
+    fn parse_destination_errors() {
+        const ADDRESS: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+
+        for tag in ["", "+1", "-1", " 1", "1 ", "0x1", "1:2"] {
+            assert_eq!(
+                api::parse_destination(&format!("{}:{}", ADDRESS, tag)),
+                Err(api::Error::DecodeError),
+                "{:?}",
+                tag
+            );
+        }
+        assert_eq!(
+            api::parse_destination(&format!("{}:99999999999999999999", ADDRESS)),
+            Err(api::Error::UnsupportedTag)
+        );
+        assert_eq!(
+            api::parse_destination("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpA:1"),
+            Err(api::Error::BadChecksum)
+        );
+        assert_eq!(
+            api::parse_destination("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"),
+            Err(api::Error::BadPrefix { expected: &[0x00] })
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let encoded = api::encode_xaddress(
+            &get_20_random_bytes(),
+            api::Tag::U32(rand::random()),
+            api::Network::Main,
+        );
+
+        assert_eq!(api::normalize_xaddress(&encoded), Ok(encoded));
+    }
+
+    #[test]
+    fn encode_into() {
+        let bytes = get_20_random_bytes();
+        let encoded = api::encode_xaddress(&bytes, api::Tag::U32(1), api::Network::Test);
+        let mut out = [0; 47];
+
+        let len = api::encode_xaddress_into(&bytes, api::Tag::U32(1), api::Network::Test, &mut out)
+            .unwrap();
+
+        assert_eq!(&out[..len], encoded.as_bytes());
+        assert_eq!(
+            api::encode_xaddress_into(&bytes, api::Tag::None, api::Network::Main, &mut out[..46]),
+            Err(api::Error::BufferTooSmall {
+                required: 47,
+                available: 46
+            })
+        );
+    }
+}
+
+mod deposit {
+    use super::*;
+
+    use api::{DepositCheck, Error, Tag, TagPolicy, WellKnownAccount};
+
+    const CLASSIC: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+    const TAGGED: &str = "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi";
+    const UNTAGGED: &str = "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb";
+
+    fn check(input: &str, policy: TagPolicy) -> DepositCheck {
+        api::validate_deposit_destination(input, policy)
+    }
+
+    #[test]
+    fn tag_policies() {
+        let account_id = CLASSIC.parse().unwrap();
+        let tag = Tag::U32(4294967295);
+
+        for input in [CLASSIC, UNTAGGED] {
+            assert_eq!(
+                check(input, TagPolicy::Any),
+                DepositCheck::Accepted {
+                    account_id,
+                    tag: Tag::None
+                }
+            );
+            assert_eq!(
+                check(input, TagPolicy::Forbidden),
+                DepositCheck::Accepted {
+                    account_id,
+                    tag: Tag::None
+                }
+            );
+            assert_eq!(
+                check(input, TagPolicy::Required),
+                DepositCheck::MissingTag { account_id }
+            );
+        }
+
+        assert_eq!(
+            check(TAGGED, TagPolicy::Any),
+            DepositCheck::Accepted { account_id, tag }
+        );
+        assert_eq!(
+            check(TAGGED, TagPolicy::Required),
+            DepositCheck::Accepted { account_id, tag }
+        );
+        assert_eq!(
+            check(TAGGED, TagPolicy::Forbidden),
+            DepositCheck::UnexpectedTag { account_id, tag }
+        );
+    }
+
+    #[test]
+    fn burn_accounts() {
+        for &account in WellKnownAccount::all() {
+            let address = account.account_id().to_string();
+            let verdict = check(&address, TagPolicy::Any);
+
+            if account == WellKnownAccount::Genesis {
+                assert!(verdict.is_accepted());
+            } else {
+                assert_eq!(verdict, DepositCheck::BurnAccount(account));
+            }
+        }
+
+        // Reported before the missing tag
+        assert_eq!(
+            check("rrrrrrrrrrrrrrrrrrrrBZbvji", TagPolicy::Required),
+            DepositCheck::BurnAccount(WellKnownAccount::One)
+        );
+    }
+
+    #[test]
+    fn test_network() {
+        let verdict = check(
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+            TagPolicy::Any,
+        );
+
+        assert_eq!(
+            verdict,
+            DepositCheck::TestNetwork {
+                account_id: CLASSIC.parse().unwrap()
+            }
+        );
+        assert_eq!(verdict.code(), "test_network");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            check("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpA", TagPolicy::Any),
+            DepositCheck::Invalid(Error::BadChecksum)
+        );
+        assert_eq!(
+            check(" rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", TagPolicy::Any).code(),
+            "invalid"
+        );
+        assert_eq!(
+            check("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", TagPolicy::Any).code(),
+            "invalid"
+        );
+        assert_eq!(
+            check(&format!("{}:1", CLASSIC), TagPolicy::Any).code(),
+            "invalid"
+        );
+    }
+}
+
+mod display {
+    use super::*;
+
+    use api::{AccountId, XAddress};
+
+    #[test]
+    fn short_forms_never_decode() {
+        for _ in 0..100 {
+            let account_id = AccountId::new(get_20_random_bytes());
+            let short = account_id.display_short();
+            let address = account_id.to_string();
+
+            assert_eq!(short.chars().count(), 12);
+            assert!(address.starts_with(&short[..6]));
+            assert!(address.ends_with(&short[short.len() - 5..]));
+            assert!(api::decode_any(&short).is_err());
+            assert!(!api::contains_only_xrpl_alphabet(&short));
+        }
+    }
+
+    #[test]
+    fn xaddress_short_form() {
+        let xaddress: XAddress = "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+            .parse()
+            .unwrap();
+
+        assert_eq!(xaddress.display_short(), "TVE26T…zSpyw");
+        assert!(api::decode_any(&xaddress.display_short()).is_err());
+    }
+
+    #[test]
+    fn grouped() {
+        let seed = "sEdTM1uX8pu2do5XvTnutH6HsouMaM2";
+
+        assert_eq!(
+            api::format_grouped(seed, 5),
+            "sEdTM 1uX8p u2do5 XvTnu tH6Hs ouMaM 2"
+        );
+        assert_eq!(api::format_grouped(seed, 0), seed);
+        assert_eq!(api::format_grouped(seed, 100), seed);
+        assert_eq!(api::format_grouped("", 4), "");
+        assert_eq!(api::format_grouped(seed, 4).replace(' ', ""), seed);
+        assert!(api::decode_any(&api::format_grouped(seed, 4)).is_err());
+    }
+}
+
+mod payment_uri {
+    use super::*;
+
+    use api::{AccountId, Error, Network, PaymentRequest, Tag};
+
+    const ADDRESS: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+    const ISSUER: &str = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
+
+    fn parse(uri: &str) -> api::Result<PaymentRequest> {
+        uri.parse()
+    }
+
+    #[test]
+    fn round_trip() {
+        for uri in [
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=4294967295",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=0&amount=0.000001",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=10&currency=USD&issuer=rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?currency=0158415500000000C1F76FF6ECB0BAC600000000",
+            "xrpl:TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw?amount=1",
+        ] {
+            assert_eq!(parse(uri).unwrap().to_string(), uri);
+        }
+    }
+
+    #[test]
+    fn parts() {
+        let request = parse(
+            "ripple:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=5&amount=1.5&currency=EUR\
+             &issuer=rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+        )
+        .unwrap();
+
+        assert_eq!(request.destination(), ADDRESS.parse::<AccountId>().unwrap());
+        assert_eq!(request.tag(), Tag::U32(5));
+        assert_eq!(request.network(), Network::Main);
+        assert_eq!(request.amount(), Some("1.5"));
+        assert_eq!(request.currency(), Some("EUR"));
+        assert_eq!(request.issuer(), Some(ISSUER.parse().unwrap()));
+    }
+
+    #[test]
+    fn builder_matches_parser() {
+        let request = PaymentRequest::new(ADDRESS.parse().unwrap())
+            .with_tag(Tag::U32(5))
+            .with_amount("1.5")
+            .unwrap()
+            .with_issued_currency("EUR", Some(ISSUER.parse().unwrap()))
+            .unwrap();
+
+        assert_eq!(parse(&request.to_string()), Ok(request));
+    }
+
+    #[test]
+    fn xaddress_destinations() {
+        let main = parse("xrpl:XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi").unwrap();
+        let test = parse("xrpl:TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+
+        assert_eq!(main.tag(), Tag::U32(4294967295));
+        assert_eq!(
+            main.to_string(),
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=4294967295"
+        );
+        assert_eq!(test.network(), Network::Test);
+        assert_eq!(
+            api::decode_xaddress(test.to_string().trim_start_matches("xrpl:")),
+            Ok((*test.destination().as_bytes(), Tag::U32(1), Network::Test))
+        );
+    }
+
+    #[test]
+    fn unknown_parameters() {
+        let request = parse("xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?label=Shop&amount=1").unwrap();
+
+        assert_eq!(request.amount(), Some("1"));
+        assert_eq!(
+            parse("xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?req-memo=x"),
+            Err(Error::DecodeError)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for uri in [
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "bitcoin:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=1&dt=2",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=-1",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=",
+            "xrpl:XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi?dt=1",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=1e6",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=.5",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?amount=1.2.3",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?currency=XRP",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?currency=US",
+            "xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?issuer=rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+        ] {
+            assert_eq!(parse(uri), Err(Error::DecodeError), "{}", uri);
+        }
+
+        assert_eq!(
+            parse("xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?dt=4294967296"),
+            Err(Error::UnsupportedTag)
+        );
+        assert_eq!(
+            parse("xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpA"),
+            Err(Error::BadChecksum)
+        );
+        assert_eq!(
+            parse("xrpl:rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf?currency=USD&issuer=rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpA"),
+            Err(Error::BadChecksum)
+        );
+    }
+}
+
+mod network_registry {
+    use super::*;
+
+    use api::{NetworkRegistry, Tag};
+
+    const SIDECHAIN: [u8; 2] = [0x05, 0x45];
+
+    #[test]
+    fn built_in_networks_match_free_functions() {
+        let networks = NetworkRegistry::new();
+        let account_id = get_20_random_bytes();
+
+        for (name, network) in [("main", api::Network::Main), ("test", api::Network::Test)] {
+            for tag in [Tag::None, Tag::U32(0), Tag::U32(u32::MAX)] {
+                let xaddress = api::encode_xaddress(&account_id, tag, network);
+
+                assert_eq!(
+                    networks.encode_xaddress(&account_id, tag, name),
+                    Ok(xaddress.clone())
+                );
+                assert_eq!(
+                    networks.decode_xaddress(&xaddress),
+                    Ok((account_id, tag, name))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sidechain_round_trip() {
+        let networks = NetworkRegistry::new().with_network("sidechain", SIDECHAIN);
+        let account_id = get_20_random_bytes();
+
+        let xaddress = networks
+            .encode_xaddress(&account_id, Tag::U32(7), "sidechain")
+            .unwrap();
+
+        assert_eq!(
+            networks.decode_xaddress(&xaddress),
+            Ok((account_id, Tag::U32(7), "sidechain"))
+        );
+        assert_eq!(
+            api::decode_xaddress(&xaddress),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+        assert_eq!(
+            NetworkRegistry::new().decode_xaddress(&xaddress),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+
+    #[test]
+    fn unknown_network_name() {
+        assert_eq!(
+            NetworkRegistry::new().encode_xaddress(&[0; 20], Tag::None, "sidechain"),
+            Err(api::Error::DecodeError)
+        );
+    }
+
+    #[test]
+    fn replaces_same_name_or_prefix() {
+        let networks = NetworkRegistry::new()
+            .with_network("sidechain", SIDECHAIN)
+            .with_network("sidechain", [0x05, 0x46])
+            .with_network("bridge", [0x05, 0x46]);
+
+        assert_eq!(networks.prefix("sidechain"), None);
+        assert_eq!(networks.prefix("bridge"), Some([0x05, 0x46]));
+        assert_eq!(
+            networks.names().collect::<Vec<_>>(),
+            ["main", "test", "bridge"]
+        );
+    }
+
+    #[test]
+    fn empty_registry() {
+        assert_eq!(
+            NetworkRegistry::empty()
+                .decode_xaddress("X7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS"),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+
+    #[test]
+    fn unsupported_tag() {
+        let networks = NetworkRegistry::new().with_network("sidechain", SIDECHAIN);
+        let mut payload = [0; 29];
+        payload[20] = 0x02;
+        let xaddress = api::encode_versioned(&payload, &SIDECHAIN);
+
+        assert_eq!(
+            networks.decode_xaddress(&xaddress),
+            Err(api::Error::UnsupportedTag)
+        );
+    }
+}
+
+mod error {
+    use super::*;
+
+    use api::Error;
+
+    #[test]
+    fn checksum_checked_before_prefix() {
+        assert_eq!(
+            api::decode_account_id("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TA"),
+            Err(Error::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn prefix_checked_before_length() {
+        assert_eq!(
+            api::decode_account_id("AiAQtT3BygyxThhEqhzN"),
+            Err(Error::BadPrefix { expected: &[0x00] })
+        );
+    }
+
+    #[test]
+    fn short_input() {
+        for input in &["", "r", "rrrr", "rrrrr"] {
+            assert_eq!(api::decode_account_id(input), Err(Error::BadChecksum));
+        }
+    }
+
+    #[test]
+    fn first_bad_character() {
+        assert_eq!(
+            api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35E0l"),
+            Err(Error::BadAlphabet {
+                character: '0',
+                index: 32
+            })
+        );
+    }
+
+    #[test]
+    fn bad_character_index_counts_chars() {
+        assert_eq!(
+            api::decode_account_id("rrré"),
+            Err(Error::BadAlphabet {
+                character: 'é',
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn cyrillic_lookalike() {
+        assert_eq!(
+            api::decode_account_id("rrrrrrrrrrrrrrrrrrrrrhоLvTp"),
+            Err(Error::Confusable {
+                character: 'о',
+                index: 22,
+                lookalike: Some('o')
+            })
+        );
+    }
+
+    #[test]
+    fn greek_and_fullwidth_lookalikes() {
+        assert_eq!(
+            api::decode_seed("sΕdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap_err(),
+            Error::Confusable {
+                character: 'Ε',
+                index: 1,
+                lookalike: Some('E')
+            }
+        );
+        assert_eq!(
+            api::decode_xaddress("Ｘ7TYFRtYHMcHtT2qNycMwgXzFbcRvEgLY6WDzQKYkjCp8GS").unwrap_err(),
+            Error::Confusable {
+                character: 'Ｘ',
+                index: 0,
+                lookalike: Some('X')
+            }
+        );
+    }
+
+    #[test]
+    fn invisible_character() {
+        for invisible in ['\u{200B}', '\u{200D}', '\u{2060}', '\u{202E}', '\u{FEFF}'] {
+            let address = format!("rrrrrrrrrrrrr{}rrrrrrrrhoLvTp", invisible);
+
+            assert_eq!(
+                api::decode_account_id(&address),
+                Err(Error::Confusable {
+                    character: invisible,
+                    index: 13,
+                    lookalike: None
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn first_bad_character_decides() {
+        assert_eq!(
+            api::decode_account_id("rrr0rrrrrrrrrrrrrrrrrhоLvTp"),
+            Err(Error::BadAlphabet {
+                character: '0',
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn decode_any_reports_length() {
+        assert_eq!(
+            api::decode_any("rpB2uKyCqQq7tW7wP3Tos"),
+            Err(Error::BadLength {
+                expected: 20,
+                actual: 10
+            })
+        );
+    }
+
+    #[test]
+    fn distinct_codes() {
+        let codes = [
+            api::decode_account_id("r0").unwrap_err().code(),
+            api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA")
+                .unwrap_err()
+                .code(),
+            api::decode_account_id("AiAQtT3BygyxThhEqhzN")
+                .unwrap_err()
+                .code(),
+            api::decode_account_id("rpB2uKyCqQq7tW7wP3Tos")
+                .unwrap_err()
+                .code(),
+        ];
+
+        assert_eq!(
+            codes,
+            ["bad_alphabet", "bad_checksum", "bad_prefix", "bad_length"]
+        );
+    }
+
+    #[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+    #[test]
+    fn numeric_codes() {
+        let errors = [
+            Error::DecodeError,
+            Error::BadAlphabet {
+                character: '0',
+                index: 0,
+            },
+            Error::BadChecksum,
+            Error::BadPrefix { expected: &[] },
+            Error::BadLength {
+                expected: 20,
+                actual: 10,
+            },
+            Error::Denied,
+            Error::UnsupportedTag,
+            Error::NonCanonical,
+            Error::BufferTooSmall {
+                required: 35,
+                available: 0,
+            },
+            Error::UnsupportedVersion { version: 9 },
+            Error::UnknownWord { index: 0 },
+            Error::BadSecretNumber { index: 0 },
+            Error::InvalidKey,
+            Error::WrongAlgorithm {
+                expected: api::Ed25519,
+                actual: api::Secp256k1,
+            },
+            Error::Confusable {
+                character: '\u{200B}',
+                index: 0,
+                lookalike: None,
+            },
+            Error::InputTooLong {
+                max_len: 256,
+                actual: 257,
+            },
+        ];
+
+        for (numeric_code, error) in (1..).zip(&errors) {
+            assert_eq!(error.numeric_code(), numeric_code);
+            assert_eq!(Error::code_from_numeric(numeric_code), Some(error.code()));
+        }
+        assert_eq!(Error::code_from_numeric(0), None);
+        assert_eq!(Error::code_from_numeric(errors.len() as u32 + 1), None);
+        assert_eq!(Error::code_from_numeric(u32::MAX), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::BadAlphabet {
+                character: '0',
+                index: 3
+            }
+            .to_string(),
+            "invalid character '0' at position 3"
+        );
+        assert_eq!(
+            Error::BadLength {
+                expected: 20,
+                actual: 10
+            }
+            .to_string(),
+            "payload is 10 bytes long, expected 20"
+        );
+        assert_eq!(
+            Error::BadPrefix { expected: &[] }.to_string(),
+            "unexpected version prefix"
+        );
+        assert_eq!(
+            Error::BadPrefix {
+                expected: &[0x01, 0xE1, 0x4B]
+            }
+            .to_string(),
+            "unexpected version prefix, expected [01, E1, 4B]"
+        );
+        assert_eq!(
+            Error::Confusable {
+                character: 'о',
+                index: 22,
+                lookalike: Some('o')
+            }
+            .to_string(),
+            "lookalike 'о' of 'o' at position 22"
+        );
+        assert_eq!(
+            Error::Confusable {
+                character: '\u{200B}',
+                index: 13,
+                lookalike: None
+            }
+            .to_string(),
+            "invisible character '\\u{200b}' at position 13"
+        );
+        assert_eq!(
+            Error::InputTooLong {
+                max_len: 256,
+                actual: 1000
+            }
+            .to_string(),
+            "input is 1000 bytes long, at most 256 accepted"
+        );
+    }
+}
+
+mod is_valid {
+    use super::*;
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn agrees_with_decoding() {
+        let account_id = api::encode_account_id(&get_20_random_bytes());
+        let seed = api::encode_seed(&get_16_random_bytes(), api::Ed25519);
+        let xaddress =
+            api::encode_xaddress(&get_20_random_bytes(), api::Tag::U32(7), api::Network::Main);
+
+        for token in &[
+            account_id.as_str(),
+            &seed,
+            &xaddress,
+            "",
+            "0",
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTr",
+        ] {
+            assert_eq!(
+                api::is_valid_account_id(token),
+                api::decode_account_id(token).is_ok()
+            );
+            assert_eq!(api::is_valid_seed(token), api::decode_seed(token).is_ok());
+            assert_eq!(
+                api::is_valid_xaddress(token),
+                api::decode_xaddress(token).is_ok()
+            );
+        }
+
+        assert!(api::is_valid_account_id(&account_id));
+        assert!(api::is_valid_seed(&seed));
+        assert!(api::is_valid_xaddress(&xaddress));
+    }
+
+    #[test]
+    fn rejects_long_input() {
+        let long = "r".repeat(1000);
+
+        assert!(!api::is_valid_account_id(&long));
+        assert!(!api::is_valid_seed(&long));
+        assert!(!api::is_valid_xaddress(&long));
+    }
+}
+
+mod never_panic {
+    use super::*;
+
+    use api::codec::Codec;
+
+    const PREFIXES: [&[u8]; 11] = [
+        &[0x00],
+        &[0x1C],
+        &[0x23],
+        &[0x20],
+        &[0x22],
+        &[0x29],
+        &[0x21],
+        &[0x01, 0xE1, 0x4B],
+        &[0x05, 0x44],
+        &[0x04, 0x93],
+        &[],
+    ];
+
+    fn decode_all(input: &str) {
+        let codec = Codec::new().with_strict_decoding(true);
+
+        let _ = api::decode_account_id(input);
+        let _ = api::decode_seed(input);
+        let _ = api::decode_node_public_key(input);
+        let _ = api::decode_account_public_key(input);
+        let _ = api::decode_node_private_key(input);
+        let _ = api::decode_account_private_key(input);
+        let _ = api::decode_family_generator(input);
+        let _ = api::decode_xaddress(input);
+        let _ = api::xaddress_to_classic_address(input);
+        let _ = api::classic_address_to_xaddress(input, api::Tag::None, api::Network::Main);
+        let _ = api::decode_any(input);
+        let _ = api::decode_raw(input);
+        let _ = api::decode_multi_versioned(input, &PREFIXES, 20);
+        let _ = api::ClassicAddressStr::new(input);
+        let _ = input.parse::<api::AccountId>();
+        let _ = input.parse::<api::Seed>();
+        let _ = input.parse::<api::XAddress>();
+        let _ = codec.decode_account_id(input);
+        let _ = codec.decode_seed(input);
+    }
+
+    #[test]
+    fn all_strings_up_to_two_chars() {
+        let chars: Vec<char> = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz0Oé "
+            .chars()
+            .collect();
+
+        decode_all("");
+
+        for first in &chars {
+            decode_all(&first.to_string());
+
+            for second in &chars {
+                decode_all(&[*first, *second].iter().collect::<String>());
+            }
+        }
+    }
+
+    #[test]
+    fn valid_checksum_any_payload_len() {
+        for prefix in &PREFIXES {
+            for len in 0..=40 {
+                for byte in &[0x00, 0x01, 0xFF] {
+                    decode_all(&api::encode_versioned(&vec![*byte; len], prefix));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn truncated_tokens() {
+        let tokens = [
+            api::encode_account_id(&get_20_random_bytes()),
+            api::encode_seed(&get_16_random_bytes(), api::Ed25519),
+            api::encode_xaddress(
+                &get_20_random_bytes(),
+                api::Tag::U32(rand::random()),
+                api::Network::Test,
+            ),
+        ];
+
+        for token in &tokens {
+            for len in 0..token.len() {
+                decode_all(&token[..len]);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "macros")]
+mod macros {
+    use super::*;
+
+    use api::{address, address_bytes, AccountId};
+
+    const ADDRESS: AccountId = address!("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN");
+
+    #[test]
+    fn address() {
+        assert_eq!(
+            ADDRESS,
+            "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"
+                .parse::<AccountId>()
+                .unwrap()
+        );
+        assert_eq!(
+            address!("rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            AccountId::new([0; 20])
+        );
+    }
+
+    #[test]
+    fn address_bytes() {
+        assert_eq!(
+            address_bytes!("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN"),
+            api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN").unwrap()
+        );
+        assert_eq!(
+            ADDRESS.into_bytes(),
+            address_bytes!("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN")
+        );
+    }
+}
+
+#[cfg(feature = "bip39")]
+mod bip39 {
+    use super::*;
+
+    use api::{Error, Seed};
+
+    #[test]
+    fn seed_to_bip39() {
+        let cases = [
+            (
+                "00000000000000000000000000000000",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            ),
+            (
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            ),
+            (
+                "80808080808080808080808080808080",
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffff",
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            ),
+        ];
+
+        for &(entropy, mnemonic) in &cases {
+            let seed = Seed::new(to_16_bytes(entropy), api::Secp256k1);
+
+            assert_eq!(api::seed_to_bip39(&seed), mnemonic);
+            assert_eq!(api::bip39_to_seed(mnemonic, api::Secp256k1), Ok(seed));
+        }
+    }
+
+    #[test]
+    fn algorithm_and_case() {
+        let seed = api::bip39_to_seed(
+            "  Legal WINNER thank year wave sausage worth useful legal winner thank yellow\n",
+            api::Ed25519,
+        )
+        .unwrap();
+
+        assert_eq!(seed, Seed::new([0x7F; 16], api::Ed25519));
+    }
+
+    #[test]
+    fn entropy_length() {
+        let mnemonic = format!("{} art", ["abandon"; 23].join(" "));
+
+        assert_eq!(
+            api::bip39_to_seed(&mnemonic, api::Secp256k1),
+            Err(Error::BadLength {
+                expected: 16,
+                actual: 32
+            })
+        );
+    }
+
+    #[test]
+    fn bad_mnemonic() {
+        let abandon = ["abandon"; 11].join(" ");
+
+        assert_eq!(
+            api::bip39_to_seed(&format!("{} abandon", abandon), api::Secp256k1),
+            Err(Error::BadChecksum)
+        );
+        assert_eq!(
+            api::bip39_to_seed(&format!("{} abracadabra", abandon), api::Secp256k1),
+            Err(Error::UnknownWord { index: 11 })
+        );
+        assert_eq!(
+            api::bip39_to_seed(&abandon, api::Secp256k1),
+            Err(Error::DecodeError)
+        );
+    }
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    use std::{ffi::CStr, os::raw::c_char, ptr};
+
+    use api::ffi::*;
+
+    use super::*;
+
+    fn encode_account_id(account_id: &[u8; 20], out_len: usize) -> (i32, usize, Vec<u8>) {
+        let mut out = vec![0x7F as c_char; out_len];
+        let mut written = 0;
+
+        let code = unsafe {
+            rac_encode_account_id(
+                account_id.as_ptr(),
+                out.as_mut_ptr(),
+                out.len(),
+                &mut written,
+            )
+        };
+
+        (code, written, out.into_iter().map(|c| c as u8).collect())
+    }
+
+    #[test]
+    fn buffer_sizes() {
+        let address = api::encode_account_id(&[0xFF; 20]);
+        assert!(address.len() < RAC_ACCOUNT_ID_BUFFER_LEN);
+
+        let (code, written, out) = encode_account_id(&[0xFF; 20], RAC_ACCOUNT_ID_BUFFER_LEN);
+        assert_eq!(code, RAC_OK);
+        assert_eq!(written, address.len());
+        assert_eq!(&out[..=written], format!("{}\0", address).as_bytes());
+
+        for out_len in &[0, 1, address.len()] {
+            let (code, written, _) = encode_account_id(&[0xFF; 20], *out_len);
+            assert_eq!(code, RAC_ERROR_BUFFER_TOO_SMALL);
+            assert_eq!(written, address.len() + 1);
+        }
+
+        assert!(api::encode_seed(&[0xFF; 16], api::Ed25519).len() < RAC_SEED_BUFFER_LEN);
+        assert_eq!(
+            api::encode_xaddress(&[0xFF; 20], api::Tag::U32(u32::MAX), api::Network::Test).len()
+                + 1,
+            RAC_XADDRESS_BUFFER_LEN
+        );
+    }
+
+    #[test]
+    fn error_codes() {
+        let mut account_id = [0; 20];
+        let decode = |address: &[u8], account_id: &mut [u8; 20]| unsafe {
+            rac_decode_account_id(address.as_ptr().cast(), account_id.as_mut_ptr())
+        };
+
+        assert_eq!(
+            decode(b"rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN\0", &mut account_id),
+            RAC_OK
+        );
+        assert_eq!(
+            decode(b"rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA\0", &mut account_id),
+            RAC_ERROR_BAD_CHECKSUM
+        );
+        assert_eq!(
+            decode(b"rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35Er0\0", &mut account_id),
+            RAC_ERROR_BAD_ALPHABET
+        );
+        assert_eq!(
+            decode(b"sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE\0", &mut account_id),
+            RAC_ERROR_BAD_PREFIX
+        );
+        assert_eq!(
+            decode(b"\xFF\0", &mut account_id),
+            RAC_ERROR_INVALID_ARGUMENT
+        );
+
+        let mut entropy = [0; 16];
+        let mut algorithm = 0;
+        assert_eq!(
+            unsafe { rac_decode_seed(ptr::null(), entropy.as_mut_ptr(), &mut algorithm) },
+            RAC_ERROR_INVALID_ARGUMENT
+        );
+        assert_eq!(
+            unsafe {
+                rac_decode_seed(
+                    b"sp6JS7f14BuwFY8Mw6bTtLKWauoUs\0".as_ptr().cast(),
+                    entropy.as_mut_ptr(),
+                    ptr::null_mut(),
+                )
+            },
+            RAC_ERROR_INVALID_ARGUMENT
+        );
+
+        let mut out = [0; RAC_SEED_BUFFER_LEN];
+        let mut written = 0;
+        assert_eq!(
+            unsafe {
+                rac_encode_seed(
+                    entropy.as_ptr(),
+                    2,
+                    out.as_mut_ptr(),
+                    out.len(),
+                    &mut written,
+                )
+            },
+            RAC_ERROR_INVALID_ARGUMENT
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let account_id = to_20_bytes("88A5A57C829F40F25EA83385BBDE6C3D8B4CA082");
+
+        let mut xaddress = [0; RAC_XADDRESS_BUFFER_LEN];
+        let mut written = 0;
+        assert_eq!(
+            unsafe {
+                rac_encode_xaddress(
+                    account_id.as_ptr(),
+                    false,
+                    7,
+                    false,
+                    xaddress.as_mut_ptr(),
+                    xaddress.len(),
+                    &mut written,
+                )
+            },
+            RAC_OK
+        );
+        let encoded = unsafe { CStr::from_ptr(xaddress.as_ptr()) };
+        assert_eq!(
+            encoded.to_str(),
+            Ok(api::encode_xaddress(&account_id, api::Tag::None, api::Network::Main).as_str())
+        );
+
+        let (mut decoded, mut has_tag, mut tag, mut test_network) = ([0; 20], true, 7, true);
+        assert_eq!(
+            unsafe {
+                rac_decode_xaddress(
+                    xaddress.as_ptr(),
+                    decoded.as_mut_ptr(),
+                    &mut has_tag,
+                    &mut tag,
+                    &mut test_network,
+                )
+            },
+            RAC_OK
+        );
+        assert_eq!(
+            (decoded, has_tag, tag, test_network),
+            (account_id, false, 0, false)
+        );
+
+        let mut seed = [0; RAC_SEED_BUFFER_LEN];
+        let (mut entropy, mut algorithm) = ([0; 16], -1);
+        unsafe {
+            assert_eq!(
+                rac_encode_seed(
+                    [7; 16].as_ptr(),
+                    RAC_SECP256K1,
+                    seed.as_mut_ptr(),
+                    seed.len(),
+                    &mut written
+                ),
+                RAC_OK
+            );
+            assert_eq!(
+                rac_decode_seed(seed.as_ptr(), entropy.as_mut_ptr(), &mut algorithm),
+                RAC_OK
+            );
+        }
+        assert_eq!((entropy, algorithm), ([7; 16], RAC_SECP256K1));
+    }
+
+    #[test]
+    fn error_messages() {
+        for code in (-15..=0).chain(vec![RAC_ERROR_INVALID_ARGUMENT, 1, i32::MIN]) {
+            let message = unsafe { CStr::from_ptr(rac_error_message(code)) };
+
+            assert!(!message.to_bytes().is_empty());
+        }
+    }
+
+    #[test]
+    fn error_codes_are_negated_numeric_codes() {
+        let cases = [
+            (RAC_ERROR_DECODE, "decode_error"),
+            (RAC_ERROR_BAD_ALPHABET, "bad_alphabet"),
+            (RAC_ERROR_BAD_CHECKSUM, "bad_checksum"),
+            (RAC_ERROR_BAD_PREFIX, "bad_prefix"),
+            (RAC_ERROR_BAD_LENGTH, "bad_length"),
+            (RAC_ERROR_DENIED, "denied"),
+            (RAC_ERROR_UNSUPPORTED_TAG, "unsupported_tag"),
+            (RAC_ERROR_NON_CANONICAL, "non_canonical"),
+            (RAC_ERROR_BUFFER_TOO_SMALL, "buffer_too_small"),
+            (RAC_ERROR_UNSUPPORTED_VERSION, "unsupported_version"),
+            (RAC_ERROR_UNKNOWN_WORD, "unknown_word"),
+            (RAC_ERROR_BAD_SECRET_NUMBER, "bad_secret_number"),
+            (RAC_ERROR_INVALID_KEY, "invalid_key"),
+            (RAC_ERROR_WRONG_ALGORITHM, "wrong_algorithm"),
+            (RAC_ERROR_CONFUSABLE, "confusable"),
+            (RAC_ERROR_INPUT_TOO_LONG, "input_too_long"),
+        ];
+
+        for (code, name) in cases {
+            assert_eq!(
+                api::Error::code_from_numeric(code.unsigned_abs()),
+                Some(name)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    // `JsError` can only be created on wasm32, so only the success paths are
+    // tested on the host; tests/wasm.js covers errors under node
+    use api::wasm;
+
+    use super::*;
+
+    #[test]
+    fn account_id_matches_compat() {
+        for _ in 0..100 {
+            let account_id = get_20_random_bytes();
+            let address = wasm::encodeAccountID(&account_id).unwrap();
+
+            assert_eq!(address, api::compat::encodeAccountID(&account_id));
+            assert_eq!(wasm::decodeAccountID(&address).unwrap(), account_id);
+            assert!(wasm::isValidClassicAddress(&address));
+            assert!(!wasm::isValidXAddress(&address));
+        }
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        for r#type in &["ed25519", "secp256k1"] {
+            let entropy = get_16_random_bytes();
+            let seed = wasm::encodeSeed(&entropy, r#type).unwrap();
+            let decoded = wasm::decodeSeed(&seed).unwrap();
+
+            assert_eq!(decoded.bytes(), entropy);
+            assert_eq!(decoded.r#type(), *r#type);
+        }
+    }
+
+    #[test]
+    fn decoded_seed_debug_is_redacted() {
+        let decoded = wasm::decodeSeed("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE").unwrap();
+
+        assert!(format!("{:?}", decoded).contains("****"));
+    }
+
+    #[test]
+    fn xaddress_round_trip() {
+        let account_id = to_20_bytes("b8a8d9d6a7e9a0d6f4a0f1e6d4e0a1a7c0a9d6b4");
+        let classic = api::encode_account_id(&account_id);
+
+        for &(tag, test) in &[(None, false), (Some(0), true), (Some(u32::MAX), false)] {
+            let x_address = wasm::encodeXAddress(&account_id, tag, test).unwrap();
+            let decoded = wasm::decodeXAddress(&x_address).unwrap();
+
+            assert_eq!(decoded.accountId(), account_id);
+            assert_eq!((decoded.tag(), decoded.test()), (tag, test));
+            assert!(wasm::isValidXAddress(&x_address));
+
+            assert_eq!(
+                wasm::classicAddressToXAddress(&classic, tag, test).unwrap(),
+                x_address
+            );
+            let converted = wasm::xAddressToClassicAddress(&x_address).unwrap();
+            assert_eq!(converted.classicAddress(), classic);
+            assert_eq!((converted.tag(), converted.test()), (tag, test));
+        }
+    }
+
+    #[test]
+    fn public_keys_round_trip() {
+        let public_key = [3; 33];
+
+        let node = wasm::encodeNodePublic(&public_key).unwrap();
+        assert_eq!(wasm::decodeNodePublic(&node).unwrap(), public_key);
+
+        let account = wasm::encodeAccountPublic(&public_key).unwrap();
+        assert_eq!(wasm::decodeAccountPublic(&account).unwrap(), public_key);
+    }
+}
+
+#[cfg(feature = "hd")]
+mod hd {
+    use super::*;
+
+    use api::hd::{DerivationError, ExtendedPrivateKey, HARDENED};
+
+    // Seed of the BIP39 mnemonic "abandon abandon ... about"
+    const MNEMONIC_SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+    fn keys(path: &str, algorithm: api::Algorithm) -> (String, String) {
+        let key =
+            ExtendedPrivateKey::master(&to_bytes("000102030405060708090a0b0c0d0e0f"), algorithm)
+                .derive_path(path)
+                .unwrap();
+
+        (
+            hex::encode(key.private_key()),
+            hex::encode(key.chain_code()),
+        )
+    }
+
+    #[test]
+    fn bip32_test_vector() {
+        let cases = [
+            (
+                "m",
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+                "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+            ),
+            (
+                "m/0'",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+            ),
+            (
+                "m/0'/1",
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+            ),
+            (
+                "m/0'/1/2'",
+                "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+                "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+            ),
+        ];
+
+        for &(path, private_key, chain_code) in &cases {
+            assert_eq!(
+                keys(path, api::Secp256k1),
+                (private_key.to_string(), chain_code.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn slip10_test_vector() {
+        let cases = [
+            (
+                "m",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+            ),
+            (
+                "m/0'",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+            ),
+            (
+                "m/0h/1h",
+                "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+                "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+            ),
+        ];
+
+        for &(path, private_key, chain_code) in &cases {
+            assert_eq!(
+                keys(path, api::Ed25519),
+                (private_key.to_string(), chain_code.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn xrpl_path() {
+        let master = ExtendedPrivateKey::master(&to_bytes(MNEMONIC_SEED), api::Secp256k1);
+        let account = master.derive_path("m/44'/144'/0'/0").unwrap();
+
+        let keypair = account.derive_child(0).unwrap().keypair();
+        assert_eq!(
+            hex::encode_upper(keypair.public_key()),
+            "031D68BC1A142E6766B2BDFB006CCFE135EF2E0E2E94ABB5CF5C9AB6104776FBAE"
+        );
+        assert_eq!(keypair.address(), "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+        assert_eq!(
+            account.derive_child(1).unwrap().keypair().address(),
+            "r3AgF9mMBFtaLhKcg96weMhbbEFLZ3mx17"
+        );
+        assert_eq!(
+            master
+                .derive_path("m/44'/144'/0'/0/1")
+                .unwrap()
+                .keypair()
+                .address(),
+            "r3AgF9mMBFtaLhKcg96weMhbbEFLZ3mx17"
+        );
+    }
+
+    #[test]
+    fn xrpl_path_ed25519() {
+        let keypair = ExtendedPrivateKey::master(&to_bytes(MNEMONIC_SEED), api::Ed25519)
+            .derive_path("m/44'/144'/0'/0'/0'")
+            .unwrap()
+            .keypair();
+
+        assert_eq!(
+            hex::encode_upper(keypair.public_key()),
+            "EDFD1D11E27606E92B154899CDDB390825AF288193CBE4ECCBA78052F534D8EF25"
+        );
+        assert_eq!(keypair.address(), "rP8Cn7F5SJP5SVzwwbriX4YGuzYSmms6Cg");
+    }
+
+    #[test]
+    fn not_hardened() {
+        let master = ExtendedPrivateKey::master(&[0; 64], api::Ed25519);
+
+        assert_eq!(
+            master.derive_path("m/44'/144'/0'/0/0").err(),
+            Some(DerivationError::NotHardened(0))
+        );
+        assert!(master.derive_child(HARDENED + 5).is_ok());
+        assert_eq!(
+            DerivationError::NotHardened(0).to_string(),
+            "Ed25519 child index 0 isn't hardened"
+        );
+    }
+
+    #[test]
+    fn bad_path() {
+        let master = ExtendedPrivateKey::master(&[0; 64], api::Secp256k1);
+
+        for path in &[
+            "",
+            "44'/144'",
+            "m/",
+            "m//0",
+            "m/-1",
+            "m/+1",
+            "m/0''",
+            "m/2147483648",
+            "m/4294967295'",
+            "M/0",
+            "m/0 ",
+        ] {
+            assert_eq!(
+                master.derive_path(path).err(),
+                Some(DerivationError::BadPath),
+                "{}",
+                path
+            );
+        }
+    }
+}
+
+#[cfg(feature = "vanity")]
+mod vanity {
+    use super::*;
+
+    use api::vanity::{Pattern, VanitySearch};
+
+    #[test]
+    fn patterns() {
+        let address = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN";
+
+        assert!(Pattern::prefix("rJrR").unwrap().matches(address));
+        assert!(Pattern::suffix("35ErN").unwrap().matches(address));
+        assert!(Pattern::contains("DXQd").unwrap().matches(address));
+        assert!(!Pattern::contains("dxqd").unwrap().matches(address));
+        assert!(Pattern::contains("dxqd")
+            .unwrap()
+            .ignore_case()
+            .matches(address));
+        assert!(!Pattern::suffix(&format!("r{}", address))
+            .unwrap()
+            .matches(address));
+
+        assert_eq!(
+            Pattern::prefix("J"),
+            Err(api::Error::BadPrefix { expected: b"r" })
+        );
+        assert_eq!(
+            Pattern::suffix("abc0"),
+            Err(api::Error::BadAlphabet {
+                character: '0',
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn finds_matching_seeds() {
+        for &algorithm in api::Algorithm::all() {
+            let pattern = Pattern::suffix("a").unwrap().ignore_case();
+            let (seed, address) = VanitySearch::new(pattern)
+                .with_algorithm(algorithm)
+                .with_threads(3)
+                .run()
+                .unwrap();
+
+            assert_eq!(seed.algorithm(), algorithm);
+            assert!(address.ends_with(['a', 'A']));
+            assert_eq!(api::keypair::derive(&seed).address(), address);
+        }
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let impossible = Pattern::prefix(&"r".repeat(40)).unwrap();
+
+        assert!(VanitySearch::new(impossible)
+            .with_threads(4)
+            .with_max_attempts(1)
+            .run()
+            .is_none());
+    }
+}
+
+mod rfc1751 {
+    use super::*;
+
+    #[cfg(feature = "seed-secp256k1")]
+    use api::{Error, Seed};
+
+    const WORDS: &str = "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE";
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn seed_to_rfc1751() {
+        let cases = [
+            ("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", WORDS),
+            (
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+                "DUE DUNE FLUE GUY LIVE AWL BOG ROB FIST BOG OS ADD",
+            ),
+            ("sp6JS7f14BuwFY8Mw6bTtLKWauoUs", "A A A A A A A A A A A A"),
+        ];
+
+        for &(encoded, words) in &cases {
+            let seed: Seed = encoded.parse().unwrap();
+
+            assert_eq!(api::seed_to_rfc1751(&seed), words);
+            assert_eq!(api::rfc1751_to_seed(words, api::Secp256k1), Ok(seed));
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn algorithm() {
+        let seed = api::rfc1751_to_seed(WORDS, api::Ed25519).unwrap();
+
+        assert_eq!(seed.algorithm(), api::Ed25519);
+        assert_eq!(api::seed_to_rfc1751(&seed), WORDS);
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn normalized_words() {
+        assert_eq!(
+            api::rfc1751_to_seed(
+                "  i ire b0nd bow trio 1aid\tseat goal hen ibis ibis dare\n",
+                api::Secp256k1
+            )
+            .unwrap()
+            .expose_secret(),
+            "snoPBrXtMeMyMHUVTgbuqAfg1SUTb"
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn word_count() {
+        assert_eq!(
+            api::rfc1751_to_seed("I IRE BOND BOW TRIO LAID", api::Secp256k1),
+            Err(Error::DecodeError)
+        );
+        assert_eq!(
+            api::rfc1751_to_seed(&format!("{} A", WORDS), api::Secp256k1),
+            Err(Error::DecodeError)
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn unknown_word() {
+        let error =
+            api::rfc1751_to_seed(&WORDS.replace("GOAL", "GOALS"), api::Secp256k1).unwrap_err();
+
+        assert_eq!(error, Error::UnknownWord { index: 7 });
+        assert_eq!(error.code(), "unknown_word");
+        assert_eq!(error.to_string(), "unknown word at position 7");
+        assert_eq!(
+            api::rfc1751_to_seed(&WORDS.replace("IRE", "XYZ"), api::Secp256k1),
+            Err(Error::UnknownWord { index: 1 })
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn bad_parity() {
+        assert_eq!(
+            api::rfc1751_to_seed(&WORDS.replace("DARE", "DART"), api::Secp256k1),
+            Err(Error::BadChecksum)
+        );
+    }
+}
+
+#[cfg(all(any(feature = "ring", feature = "sha2"), feature = "seed-secp256k1"))]
+mod passphrase {
+    use super::*;
+
+    #[test]
+    fn rippled_wallet_propose() {
+        let seed = api::passphrase_to_seed("masterpassphrase", api::Secp256k1).accept_low_entropy();
+
+        assert_eq!(to_hex(seed.entropy()), "DEDCE9CE67B451D852FD4E846FCDE31C");
+        assert_eq!(seed.expose_secret(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_eq!(
+            api::seed_to_rfc1751(&seed),
+            "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE"
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn algorithms_share_the_entropy() {
+        let secp256k1 = api::passphrase_to_seed("hunter2", api::Secp256k1).accept_low_entropy();
+        let ed25519 = api::passphrase_to_seed("hunter2", api::Ed25519).accept_low_entropy();
+
+        assert_eq!(ed25519.algorithm(), api::Ed25519);
+        assert_eq!(ed25519.entropy(), secp256k1.entropy());
+        assert!(ed25519.to_string().starts_with("sEd"));
+    }
+
+    #[test]
+    fn distinct_passphrases() {
+        let seed =
+            |passphrase| api::passphrase_to_seed(passphrase, api::Secp256k1).accept_low_entropy();
+
+        assert_ne!(seed(""), seed(" "));
+        assert_ne!(seed("masterpassphrase"), seed("Masterpassphrase"));
+    }
+
+    #[test]
+    fn warning() {
+        let seed = api::passphrase_to_seed("masterpassphrase", api::Secp256k1);
+
+        assert_eq!(
+            seed.warning(),
+            "This wallet was generated using a user-supplied passphrase that has low entropy \
+             and is vulnerable to brute-force attacks."
+        );
+        assert!(format!("{:?}", seed).starts_with("LowEntropy("));
+    }
+}
+
+mod parse_secret {
+    use super::*;
+
+    use api::{Error, SecretFormat};
+
+    const GENESIS: &str = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb";
+    #[cfg(feature = "seed-secp256k1")]
+    const WORDS: &str = "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DARE";
+    const HEX: &str = "DEDCE9CE67B451D852FD4E846FCDE31C";
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn rippled_genesis_secret_in_every_format() {
+        for (secret, format) in [
+            (GENESIS, SecretFormat::Seed),
+            (WORDS, SecretFormat::Rfc1751),
+            (HEX, SecretFormat::Hex),
+        ] {
+            assert_eq!(SecretFormat::detect(secret), Some(format), "{}", secret);
+            assert_eq!(
+                api::parse_secret(secret, None).unwrap().expose_secret(),
+                GENESIS
+            );
+            assert_eq!(
+                api::parse_secret(secret, Some(format))
+                    .unwrap()
+                    .expose_secret(),
+                GENESIS
+            );
+        }
+        assert_eq!(
+            api::parse_secret("masterpassphrase", Some(SecretFormat::Passphrase))
+                .unwrap()
+                .expose_secret(),
+            GENESIS
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn lowercase_hex_and_words() {
+        assert_eq!(
+            api::parse_secret(&HEX.to_lowercase(), None)
+                .unwrap()
+                .expose_secret(),
+            GENESIS
+        );
+        assert_eq!(
+            api::parse_secret(&WORDS.to_lowercase(), None)
+                .unwrap()
+                .expose_secret(),
+            GENESIS
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn seeds_keep_their_algorithm() {
+        let seed = api::parse_secret("sEdTM1uX8pu2do5XvTnutH6HsouMaM2", None).unwrap();
+
+        assert_eq!(seed.algorithm(), api::Ed25519);
+    }
+
+    #[test]
+    fn other_tokens_are_not_secrets() {
+        assert_eq!(
+            SecretFormat::detect("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            None
+        );
+        assert_eq!(
+            api::parse_secret("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", None),
+            Err(Error::BadPrefix { expected: &[] })
+        );
+        assert_eq!(api::parse_secret("", None), Err(Error::DecodeError));
+    }
+
+    #[test]
+    fn passphrases_need_the_hint() {
+        assert_eq!(SecretFormat::detect("masterpassphrase"), None);
+        assert!(api::parse_secret("masterpassphrase", None).is_err());
+    }
+
+    #[test]
+    fn near_misses_are_errors() {
+        // One hex digit short, and a mistyped word
+        for secret in [
+            &HEX[1..],
+            "I IRE BOND BOW TRIO LAID SEAT GOAL HEN IBIS IBIS DAREE",
+        ] {
+            assert_eq!(SecretFormat::detect(secret), None);
+            assert!(api::parse_secret(secret, None).is_err());
+        }
+    }
+
+    #[test]
+    fn mistyped_seed_reports_the_seed_error() {
+        let mistyped = GENESIS.replace('n', "m");
+
+        assert_eq!(SecretFormat::detect(&mistyped), None);
+        assert_eq!(api::parse_secret(&mistyped, None), Err(Error::BadChecksum));
+    }
+
+    #[test]
+    fn hint_reports_the_format_error() {
+        assert!(api::parse_secret("masterpassphrase", Some(SecretFormat::Seed)).is_err());
+        assert!(api::parse_secret("masterpassphrase", Some(SecretFormat::Hex)).is_err());
+        assert!(api::parse_secret(&HEX[1..], Some(SecretFormat::Hex)).is_err());
+        assert!(api::parse_secret(GENESIS, Some(SecretFormat::Rfc1751)).is_err());
+        assert_ne!(
+            api::parse_secret(HEX, Some(SecretFormat::Passphrase))
+                .unwrap()
+                .expose_secret(),
+            GENESIS
+        );
+    }
+}
+
+mod secret_numbers {
+    use super::*;
+
+    use api::Error;
+
+    const NUMBERS: &str = "399150 474506 009147 088773 432160 282843 253738 605430";
+
+    #[test]
+    fn entropy_to_secret_numbers() {
+        let cases = [
+            ("9bebb95a039222ada8d06e7c631dec7f", NUMBERS),
+            (
+                "00000000000000000000000000000000",
+                "000000 000000 000000 000000 000000 000000 000000 000000",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffff",
+                "655356 655350 655353 655356 655350 655353 655356 655350",
+            ),
+            (
+                "0102030405060708090a0b0c0d0e0f10",
+                "002586 007723 012864 018000 023140 028284 033423 038566",
+            ),
+        ];
+
+        for &(entropy, numbers) in &cases {
+            let entropy = to_16_bytes(entropy);
+
+            assert_eq!(api::entropy_to_secret_numbers(&entropy), numbers);
+            assert_eq!(api::secret_numbers_to_entropy(numbers), Ok(entropy));
+        }
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn family_seed() {
+        let entropy = api::secret_numbers_to_entropy(NUMBERS).unwrap();
+
+        assert_eq!(
+            api::encode_seed(&entropy, api::Secp256k1),
+            "sh1HiK7SwjS1VxFdXi7qeMHRedrYX"
+        );
+    }
+
+    #[test]
+    fn whitespace() {
+        let expected = api::secret_numbers_to_entropy(NUMBERS);
+
+        assert_eq!(
+            api::secret_numbers_to_entropy(&NUMBERS.replace(' ', "")),
+            expected
+        );
+        assert_eq!(
+            api::secret_numbers_to_entropy(&NUMBERS.replace(' ', "\n")),
+            expected
+        );
+        assert_eq!(
+            api::secret_numbers_to_entropy(
+                "399 150\t474506 009147 088773 432160 282843 253738 605430 "
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn bad_row() {
+        let error =
+            api::secret_numbers_to_entropy(&NUMBERS.replace("253738", "253737")).unwrap_err();
+
+        assert_eq!(error, Error::BadSecretNumber { index: 6 });
+        assert_eq!(error.code(), "bad_secret_number");
+        assert_eq!(error.to_string(), "invalid secret numbers row 6");
+
+        // 99999 doesn't fit in 2 bytes, even with a matching checksum digit
+        assert_eq!(
+            api::secret_numbers_to_entropy(&NUMBERS.replace("399150", "999990")),
+            Err(Error::BadSecretNumber { index: 0 })
+        );
+    }
+
+    #[test]
+    fn bad_input() {
+        for numbers in &[
+            "",
+            "399150 474506 009147 088773 432160 282843 253738",
+            "399150 474506 009147 088773 432160 282843 253738 6054300",
+            "399150-474506-009147-088773-432160-282843-253738-605430",
+            "399150 474506 009147 088773 432160 282843 253738 60543O",
+        ] {
+            assert_eq!(
+                api::secret_numbers_to_entropy(numbers),
+                Err(Error::DecodeError)
+            );
+        }
+    }
+}
+
+mod typo {
+    use super::*;
+
+    const ADDRESS: &str = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN";
+
+    #[test]
+    fn substituted_character() {
+        let mistyped = ADDRESS.replacen('U', "V", 1);
+
+        assert_eq!(
+            api::decode_account_id(&mistyped),
+            Err(api::Error::BadChecksum)
+        );
+        assert_eq!(
+            api::suggest_correction(&mistyped),
+            Some(ADDRESS.to_string())
+        );
+    }
+
+    #[test]
+    fn swapped_characters() {
+        let mistyped = ADDRESS.replacen("DXQ", "XDQ", 1);
+
+        assert_eq!(
+            api::suggest_correction(&mistyped),
+            Some(ADDRESS.to_string())
+        );
+    }
+
+    #[test]
+    fn character_outside_alphabet() {
+        let mistyped = ADDRESS.replacen('6', "0", 1);
+
+        assert_eq!(
+            api::suggest_correction(&mistyped),
+            Some(ADDRESS.to_string())
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn seed() {
+        let seed = api::encode_seed(&get_16_random_bytes(), api::Ed25519);
+        let last = seed.chars().last().unwrap();
+        let replacement = if last == 'r' { 'p' } else { 'r' };
+        let mistyped = format!("{}{}", &seed[..seed.len() - 1], replacement);
+
+        assert_eq!(api::suggest_correction(&mistyped), Some(seed));
+    }
+
+    #[test]
+    fn valid_token() {
+        assert_eq!(api::suggest_correction(ADDRESS), None);
+    }
+
+    #[test]
+    fn too_many_typos() {
+        assert_eq!(
+            api::suggest_correction("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35EAA"),
+            None
+        );
+        assert_eq!(api::suggest_correction(""), None);
+    }
+
+    #[test]
+    fn too_long() {
+        let mistyped = ADDRESS.replacen('U', "V", 1).repeat(100);
+
+        assert_eq!(api::suggest_correction(&mistyped), None);
+    }
+}
+
+mod ascii_bytes {
+    use super::*;
+
+    use api::Error;
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn same_as_str_decoders() {
+        let account_id = api::encode_account_id(&get_20_random_bytes());
+        let seed = api::encode_seed(&get_16_random_bytes(), api::Ed25519);
+        let xaddress =
+            api::encode_xaddress(&get_20_random_bytes(), api::Tag::U32(7), api::Network::Test);
+        let node_public_key = "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH";
+        let account_public_key = "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3";
+        let node_private_key = api::encode_node_private_key(&[1; 32]);
+        let account_private_key = api::encode_account_private_key(&[2; 32]);
+        let family_generator = api::encode_family_generator(&[3; 33]);
+
+        assert_eq!(
+            api::decode_account_id_bytes(account_id.as_bytes()),
+            api::decode_account_id(&account_id)
+        );
+        assert_eq!(
+            api::decode_seed_bytes(seed.as_bytes()),
+            api::decode_seed(&seed)
+        );
+        assert_eq!(
+            api::decode_xaddress_bytes(xaddress.as_bytes()),
+            api::decode_xaddress(&xaddress)
+        );
+        assert_eq!(
+            api::decode_node_public_key_bytes(node_public_key.as_bytes()),
+            api::decode_node_public_key(node_public_key)
+        );
+        assert_eq!(
+            api::decode_account_public_key_bytes(account_public_key.as_bytes()),
+            api::decode_account_public_key(account_public_key)
+        );
+        assert_eq!(
+            api::decode_node_private_key_bytes(node_private_key.as_bytes()),
+            api::decode_node_private_key(&node_private_key)
+        );
+        assert_eq!(
+            api::decode_account_private_key_bytes(account_private_key.as_bytes()),
+            api::decode_account_private_key(&account_private_key)
+        );
+        assert_eq!(
+            api::decode_family_generator_bytes(family_generator.as_bytes()),
+            api::decode_family_generator(&family_generator)
+        );
+
+        for token in &[&account_id, &seed, &xaddress, &family_generator] {
+            assert_eq!(
+                api::decode_any_bytes(token.as_bytes()),
+                api::decode_any(token)
+            );
+        }
+    }
+
+    #[test]
+    fn same_errors_as_str_decoders() {
+        for token in &[
+            "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA",
+            "r_000",
+            "",
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp\n",
+            "rrrrrrrrrrrrrrrrrrrrrhоLvTp",
+            "réé0",
+        ] {
+            assert_eq!(
+                api::decode_account_id_bytes(token.as_bytes()),
+                api::decode_account_id(token),
+                "{}",
+                token
+            );
+            assert_eq!(
+                api::decode_any_bytes(token.as_bytes()),
+                api::decode_any(token)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            api::decode_account_id_bytes(b"r\xFFrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Err(Error::BadAlphabet {
+                character: char::REPLACEMENT_CHARACTER,
+                index: 1
+            })
+        );
+        // Truncated character after a non-ASCII one
+        assert_eq!(
+            api::decode_seed_bytes(
+                "sé"
+                    .bytes()
+                    .chain([0xE2, 0x82])
+                    .collect::<Vec<_>>()
+                    .as_slice()
+            ),
+            Err(Error::BadAlphabet {
+                character: 'é',
+                index: 1
+            })
+        );
+        assert_eq!(
+            api::decode_xaddress_bytes(b"XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQT\xE2\x82"),
+            Err(Error::BadAlphabet {
+                character: char::REPLACEMENT_CHARACTER,
+                index: 37
+            })
+        );
+    }
+
+    #[test]
+    fn earlier_bad_character_first() {
+        assert_eq!(
+            api::decode_account_id_bytes(b"r0\xFF"),
+            Err(Error::BadAlphabet {
+                character: '0',
+                index: 1
+            })
+        );
+        assert!(matches!(
+            api::decode_any_bytes(
+                "rо\u{FF}"
+                    .as_bytes()
+                    .iter()
+                    .copied()
+                    .chain([0xFF])
+                    .collect::<Vec<_>>()
+                    .as_slice()
+            ),
+            Err(Error::Confusable { index: 1, .. })
+        ));
+    }
+}
+
+mod hex_form {
+    use super::*;
+
+    const ADDRESS: &str = "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN";
+    const ADDRESS_HEX: &str = "BA8E78626EE42C41B46D46C3048DF3A1C3C87072";
+
+    #[test]
+    fn account_id() {
+        assert_eq!(
+            api::decode_account_id_to_hex(ADDRESS),
+            Ok(ADDRESS_HEX.to_string())
+        );
+        assert_eq!(
+            api::encode_account_id_from_hex(ADDRESS_HEX),
+            Ok(ADDRESS.to_string())
+        );
+        assert_eq!(
+            api::encode_account_id_from_hex(&ADDRESS_HEX.to_lowercase()),
+            Ok(ADDRESS.to_string())
+        );
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        for &algorithm in api::Algorithm::all() {
+            let entropy = get_16_random_bytes();
+            let seed = api::encode_seed(&entropy, algorithm);
+            let (hex, decoded_algorithm) = api::decode_seed_to_hex(&seed).unwrap();
+
+            assert_eq!(hex, hex::encode_upper(entropy));
+            assert_eq!(decoded_algorithm, algorithm);
+            assert_eq!(api::encode_seed_from_hex(&hex, algorithm), Ok(seed));
+        }
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn invalid_hex() {
+        assert_eq!(
+            api::encode_account_id_from_hex(&ADDRESS_HEX[1..]),
+            Err(api::Error::DecodeError)
+        );
+        assert_eq!(
+            api::encode_account_id_from_hex(&ADDRESS_HEX.replacen('B', "G", 1)),
+            Err(api::Error::DecodeError)
+        );
+        assert_eq!(
+            api::encode_account_id_from_hex(&format!("0x{}", &ADDRESS_HEX[2..])),
+            Err(api::Error::DecodeError)
+        );
+        assert_eq!(
+            api::encode_seed_from_hex(ADDRESS_HEX, api::Ed25519),
+            Err(api::Error::BadLength {
+                expected: 16,
+                actual: 20
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_token() {
+        assert_eq!(
+            api::decode_account_id_to_hex("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA"),
+            Err(api::Error::BadChecksum)
+        );
+        assert_eq!(
+            api::decode_seed_to_hex(ADDRESS),
+            Err(api::Error::BadPrefix { expected: &[] })
+        );
+    }
+}
+
+mod well_known {
+    use super::*;
+
+    use api::WellKnownAccount;
+
+    #[test]
+    fn addresses() {
+        let addresses = [
+            (api::ACCOUNT_ZERO, "rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            (api::ACCOUNT_ONE, "rrrrrrrrrrrrrrrrrrrrBZbvji"),
+            (api::GENESIS_ACCOUNT, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            (api::NAME_RESERVATION_ACCOUNT, "rrrrrrrrrrrrrrrrrNAMEtxvNvQ"),
+            (api::NAN_ACCOUNT, "rrrrrrrrrrrrrrrrrrrn5RM1rHd"),
+        ];
+
+        for (account_id, address) in addresses {
+            assert_eq!(account_id.to_string(), address);
+        }
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn genesis_is_derived_from_masterpassphrase() {
+        let seed: api::Seed = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb".parse().unwrap();
+
+        assert_eq!(
+            api::keypair::derive_secp256k1(seed.entropy()).account_id(),
+            api::GENESIS_ACCOUNT.into_bytes()
+        );
+    }
+
+    #[test]
+    fn detection() {
+        for &account in WellKnownAccount::all() {
+            assert_eq!(api::is_well_known(&account.account_id()), Some(account));
+        }
+        assert_eq!(
+            api::is_well_known(&api::AccountId::new(get_20_random_bytes())),
+            None
+        );
+    }
+}
+
+/// Randomized cases compared with the JavaScript reference
+/// implementation, run by tests/differential.js
+#[cfg(all(feature = "seed-secp256k1", feature = "seed-ed25519"))]
+mod differential {
+    use super::*;
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        process::{Command, Stdio},
+        thread,
+    };
+
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use serde_json::{json, Value};
+
+    use api::{Algorithm, Network, Tag};
+
+    const ROUNDS: usize = 2_000;
+
+    /// `token` unchanged or with a typo: a substituted, deleted, inserted
+    /// or swapped character, or truncated
+    fn mutate(token: &str) -> String {
+        let mut rng = thread_rng();
+        let mut chars: Vec<char> = token.chars().collect();
+        let index = rng.gen_range(0..chars.len());
+        let character = char::from(
+            *api::Alphabet::XRPL
+                .as_str()
+                .as_bytes()
+                .choose(&mut rng)
+                .unwrap(),
+        );
+
+        match rng.gen_range(0..6) {
+            0 => chars[index] = character,
+            1 => {
+                chars.remove(index);
+            }
+            2 => chars.insert(index, character),
+            3 if index > 0 => chars.swap(index - 1, index),
+            4 => chars.truncate(index),
+            _ => {}
+        }
+
+        chars.into_iter().collect()
+    }
+
+    fn public_key() -> [u8; 33] {
+        let mut rng = thread_rng();
+        let mut key = [0; 33];
+        key[0] = *[0x02, 0x03, 0xED].choose(&mut rng).unwrap();
+        rng.fill(&mut key[1..]);
+        key
+    }
+
+    /// JavaScript form of a tag, `false` for none
+    fn tag_json(tag: Tag) -> Value {
+        tag.value().map_or(json!(false), |tag| json!(tag))
+    }
+
+    fn or_null<T>(result: api::Result<T>, to_json: impl FnOnce(T) -> Value) -> Value {
+        result.map_or(Value::Null, to_json)
+    }
+
+    /// `[function, ...arguments]` cases with the results of this crate
+    fn cases() -> Vec<(Value, Value)> {
+        let mut rng = thread_rng();
+        let mut cases = Vec::new();
+
+        for _ in 0..ROUNDS {
+            let account_id: [u8; 20] = rng.gen();
+            let address = api::encode_account_id(&account_id);
+            let typo = mutate(&address);
+            cases.extend([
+                (
+                    json!(["encodeAccountID", hex::encode(account_id)]),
+                    json!(address),
+                ),
+                (
+                    json!(["decodeAccountID", typo]),
+                    or_null(api::decode_account_id(&typo), |account_id| {
+                        json!(hex::encode(account_id))
+                    }),
+                ),
+                (
+                    json!(["isValidClassicAddress", typo]),
+                    json!(api::is_valid_account_id(&typo)),
+                ),
+            ]);
+
+            let entropy: [u8; 16] = rng.gen();
+            let algorithm = *[Algorithm::Ed25519, Algorithm::Secp256k1]
+                .choose(&mut rng)
+                .unwrap();
+            let seed = api::encode_seed(&entropy, algorithm);
+            let typo = mutate(&seed);
+            cases.extend([
+                (
+                    json!(["encodeSeed", hex::encode(entropy), algorithm.to_string()]),
+                    json!(seed),
+                ),
+                (
+                    json!(["decodeSeed", typo]),
+                    or_null(api::decode_seed(&typo), |(entropy, algorithm)| {
+                        json!([hex::encode(entropy), algorithm.to_string()])
+                    }),
+                ),
+            ]);
+
+            let key = public_key();
+            let node_public_key = api::encode_node_public_key(&key);
+            let account_public_key = api::encode_account_public_key(&key);
+            let (node_typo, account_typo) = (mutate(&node_public_key), mutate(&account_public_key));
+            cases.extend([
+                (
+                    json!(["encodeNodePublic", hex::encode(key)]),
+                    json!(node_public_key),
+                ),
+                (
+                    json!(["decodeNodePublic", node_typo]),
+                    or_null(api::decode_node_public_key(&node_typo), |key| {
+                        json!(hex::encode(key))
+                    }),
+                ),
+                (
+                    json!(["encodeAccountPublic", hex::encode(key)]),
+                    json!(account_public_key),
+                ),
+                (
+                    json!(["decodeAccountPublic", account_typo]),
+                    or_null(api::decode_account_public_key(&account_typo), |key| {
+                        json!(hex::encode(key))
+                    }),
+                ),
+            ]);
+
+            let tag = if rng.gen() {
+                Tag::U32(rng.gen())
+            } else {
+                Tag::None
+            };
+            let network = if rng.gen() {
+                Network::Test
+            } else {
+                Network::Main
+            };
+            let address_typo = mutate(&address);
+            let xaddress = api::encode_xaddress(&account_id, tag, network);
+            let typo = mutate(&xaddress);
+            cases.extend([
+                (
+                    json!([
+                        "classicAddressToXAddress",
+                        address_typo,
+                        tag_json(tag),
+                        network.is_test()
+                    ]),
+                    or_null(
+                        api::classic_address_to_xaddress(&address_typo, tag, network),
+                        |xaddress| json!(xaddress),
+                    ),
+                ),
+                (
+                    json!(["xAddressToClassicAddress", typo]),
+                    or_null(
+                        api::xaddress_to_classic_address(&typo),
+                        |(address, tag, network)| {
+                            json!([address, tag_json(tag), network.is_test()])
+                        },
+                    ),
+                ),
+                (
+                    json!(["isValidXAddress", typo]),
+                    json!(api::is_valid_xaddress(&typo)),
+                ),
+            ]);
+        }
+
+        cases
+    }
+
+    /// Results of the reference implementation, one per case
+    fn reference_results(cases: &[Value]) -> Vec<Value> {
+        let mut node = Command::new("node")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/differential.js"
+            ))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("node on the PATH");
+
+        let mut stdin = node.stdin.take().unwrap();
+        let input: String = cases.iter().map(|case| format!("{}\n", case)).collect();
+        let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+        let results = BufReader::new(node.stdout.take().unwrap())
+            .lines()
+            .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+            .collect();
+
+        assert!(
+            node.wait().unwrap().success(),
+            "tests/differential.js failed, is ripple-address-codec installed?"
+        );
+        writer.join().unwrap().unwrap();
+
+        results
+    }
+
+    #[test]
+    #[ignore = "needs node and the ripple-address-codec package, see tests/differential.js"]
+    fn matches_reference() {
+        let (cases, expected): (Vec<_>, Vec<_>) = cases().into_iter().unzip();
+        let results = reference_results(&cases);
+
+        assert_eq!(results.len(), cases.len());
+
+        let mismatches: Vec<String> = cases
+            .iter()
+            .zip(&expected)
+            .zip(&results)
+            .filter(|((_, expected), result)| expected != result)
+            .map(|((case, expected), result)| {
+                format!("{}: crate {}, reference {}", case, expected, result)
+            })
+            .collect();
+
+        assert!(
+            mismatches.is_empty(),
+            "{} of {} cases differ:\n{}",
+            mismatches.len(),
+            cases.len(),
+            mismatches[..mismatches.len().min(20)].join("\n")
+        );
+    }
+}
+
+mod js_fixtures {
+    use super::*;
+
+    use std::convert::TryInto;
+
+    use api::{Network, Tag};
+
+    use crate::fixtures::*;
+
+    #[test]
+    fn xaddresses() {
+        for &(address, tag, main_xaddress, test_xaddress) in &XADDRESSES {
+            let tag = Tag::from(tag);
+
+            for &(xaddress, network) in &[
+                (main_xaddress, Network::Main),
+                (test_xaddress, Network::Test),
+            ] {
+                assert_eq!(
+                    api::classic_address_to_xaddress(address, tag, network).as_deref(),
+                    Ok(xaddress)
+                );
+                assert_eq!(
+                    api::xaddress_to_classic_address(xaddress),
+                    Ok((address.to_owned(), tag, network))
+                );
+                assert!(api::is_valid_xaddress(xaddress));
+            }
+            assert!(api::is_valid_account_id(address));
+        }
+    }
+
+    #[test]
+    fn account_id() {
+        let (hex, address) = ACCOUNT_ID;
+        let bytes = to_bytes(hex).try_into().unwrap();
+
+        assert_eq!(api::encode_account_id(&bytes), address);
+        assert_eq!(api::decode_account_id(address), Ok(bytes));
+    }
+
+    #[test]
+    fn seeds() {
+        for &(hex, ed25519, seed) in &SEEDS {
+            let entropy = to_16_bytes(hex);
+            let name = if ed25519 { "ed25519" } else { "secp256k1" };
+            // Only the algorithms compiled in, see the seed-* features
+            let algorithm: api::Algorithm = match name.parse() {
+                Ok(algorithm) => algorithm,
+                Err(_) => continue,
+            };
+
+            assert_eq!(api::encode_seed(&entropy, algorithm), seed);
+            assert_eq!(api::decode_seed(seed), Ok((entropy, algorithm)));
+        }
+    }
+
+    #[test]
+    fn public_keys() {
+        let (hex, encoded) = NODE_PUBLIC_KEY;
+        let bytes = to_bytes(hex).try_into().unwrap();
+        assert_eq!(api::encode_node_public_key(&bytes), encoded);
+        assert_eq!(api::decode_node_public_key(encoded), Ok(bytes));
+
+        let (hex, encoded) = ACCOUNT_PUBLIC_KEY;
+        let bytes = to_bytes(hex).try_into().unwrap();
+        assert_eq!(api::encode_account_public_key(&bytes), encoded);
+        assert_eq!(api::decode_account_public_key(encoded), Ok(bytes));
+    }
+
+    #[test]
+    fn classic_address_validity() {
+        for address in &VALID_CLASSIC_ADDRESSES {
+            assert!(api::is_valid_account_id(address), "{}", address);
+        }
+        for address in &INVALID_CLASSIC_ADDRESSES {
+            assert!(!api::is_valid_account_id(address), "{:?}", address);
+        }
+    }
+
+    #[test]
+    fn xaddress_with_64_bit_tag() {
+        assert!(!api::is_valid_xaddress(XADDRESS_WITH_64_BIT_TAG));
+        assert_eq!(
+            api::decode_xaddress(XADDRESS_WITH_64_BIT_TAG),
+            Err(api::Error::UnsupportedTag)
+        );
+    }
+}
+
+mod properties {
+    use super::*;
+
+    use std::convert::TryInto;
+
+    use api::{Network, Tag};
+    use proptest::{prelude::*, sample::Index};
+
+    const ALPHABET: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+    fn bytes<const N: usize>() -> impl Strategy<Value = [u8; N]> {
+        prop::collection::vec(any::<u8>(), N).prop_map(|bytes| bytes.try_into().unwrap())
+    }
+
+    fn algorithm() -> impl Strategy<Value = api::Algorithm> {
+        prop::sample::select(api::Algorithm::all())
+    }
+
+    fn tag() -> impl Strategy<Value = Tag> {
+        prop_oneof![Just(Tag::None), any::<u32>().prop_map(Tag::U32)]
+    }
+
+    fn network() -> impl Strategy<Value = Network> {
+        prop_oneof![Just(Network::Main), Just(Network::Test)]
+    }
+
+    /// A valid encoding of any token type, with whether its decoder accepts a string
+    fn token() -> impl Strategy<Value = (String, fn(&str) -> bool)> {
+        prop_oneof![
+            bytes().prop_map(|bytes| (
+                api::encode_account_id(&bytes),
+                (|s| api::decode_account_id(s).is_ok()) as fn(&str) -> bool
+            )),
+            (bytes(), algorithm()).prop_map(|(entropy, algorithm)| (
+                api::encode_seed(&entropy, algorithm),
+                (|s| api::decode_seed(s).is_ok()) as fn(&str) -> bool
+            )),
+            (bytes(), tag(), network()).prop_map(|(account_id, tag, network)| (
+                api::encode_xaddress(&account_id, tag, network),
+                (|s| api::decode_xaddress(s).is_ok()) as fn(&str) -> bool
+            )),
+            bytes().prop_map(|bytes| (
+                api::encode_node_public_key(&bytes),
+                (|s| api::decode_node_public_key(s).is_ok()) as fn(&str) -> bool
+            )),
+            bytes().prop_map(|bytes| (
+                api::encode_account_public_key(&bytes),
+                (|s| api::decode_account_public_key(s).is_ok()) as fn(&str) -> bool
+            )),
+            bytes().prop_map(|bytes| (
+                api::encode_node_private_key(&bytes),
+                (|s| api::decode_node_private_key(s).is_ok()) as fn(&str) -> bool
+            )),
+            bytes().prop_map(|bytes| (
+                api::encode_account_private_key(&bytes),
+                (|s| api::decode_account_private_key(s).is_ok()) as fn(&str) -> bool
+            )),
+            bytes().prop_map(|bytes| (
+                api::encode_family_generator(&bytes),
+                (|s| api::decode_family_generator(s).is_ok()) as fn(&str) -> bool
+            )),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn account_id_round_trips(bytes in bytes()) {
+            prop_assert_eq!(api::decode_account_id(&api::encode_account_id(&bytes)), Ok(bytes));
+        }
+
+        #[test]
+        fn seed_round_trips(entropy in bytes(), algorithm in algorithm()) {
+            prop_assert_eq!(
+                api::decode_seed(&api::encode_seed(&entropy, algorithm)),
+                Ok((entropy, algorithm))
+            );
+        }
+
+        #[test]
+        fn xaddress_round_trips(account_id in bytes(), tag in tag(), network in network()) {
+            prop_assert_eq!(
+                api::decode_xaddress(&api::encode_xaddress(&account_id, tag, network)),
+                Ok((account_id, tag, network))
+            );
+        }
+
+        #[test]
+        fn node_public_key_round_trips(bytes in bytes()) {
+            prop_assert_eq!(
+                api::decode_node_public_key(&api::encode_node_public_key(&bytes)),
+                Ok(bytes)
+            );
+        }
+
+        #[test]
+        fn account_public_key_round_trips(bytes in bytes()) {
+            prop_assert_eq!(
+                api::decode_account_public_key(&api::encode_account_public_key(&bytes)),
+                Ok(bytes)
+            );
+        }
+
+        #[test]
+        fn node_private_key_round_trips(bytes in bytes()) {
+            prop_assert_eq!(
+                api::decode_node_private_key(&api::encode_node_private_key(&bytes)),
+                Ok(bytes)
+            );
+        }
+
+        #[test]
+        fn account_private_key_round_trips(bytes in bytes()) {
+            prop_assert_eq!(
+                api::decode_account_private_key(&api::encode_account_private_key(&bytes)),
+                Ok(bytes)
+            );
+        }
+
+        #[test]
+        fn family_generator_round_trips(bytes in bytes()) {
+            prop_assert_eq!(
+                api::decode_family_generator(&api::encode_family_generator(&bytes)),
+                Ok(bytes)
+            );
+        }
+
+        #[test]
+        fn versioned_round_trips(
+            payload in prop::collection::vec(any::<u8>(), 0..64),
+            version in prop::collection::vec(any::<u8>(), 1..4),
+        ) {
+            prop_assert_eq!(
+                api::decode_versioned(&api::encode_versioned(&payload, &version), &version, payload.len()),
+                Ok(payload)
+            );
+        }
+
+        #[test]
+        fn changing_any_character_fails_to_decode(
+            (encoded, decodes) in token(),
+            index: Index,
+            replacement in prop::sample::select(ALPHABET.chars().collect::<Vec<_>>()),
+        ) {
+            prop_assert!(decodes(&encoded));
+
+            let index = index.index(encoded.len());
+            prop_assume!(!encoded[index..].starts_with(replacement));
+
+            let mut mutated = encoded.clone();
+            mutated.replace_range(index..=index, replacement.encode_utf8(&mut [0; 4]));
+            prop_assert!(!decodes(&mutated), "{} decodes after changing it to {}", encoded, mutated);
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+mod simd {
+    use super::*;
+
+    use api::{codec::Codec, Alphabet};
+    use rand::{thread_rng, Rng};
+
+    fn random_bytes(len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+
+        thread_rng()
+            .try_fill(&mut bytes[..])
+            .expect("random generator error");
+
+        bytes
+    }
+
+    #[test]
+    fn raw_roundtrip_across_vector_widths() {
+        // Encodings from empty to past the longest input mapped on the stack
+        for len in 0..=120 {
+            let mut bytes = random_bytes(len);
+            bytes.iter_mut().take(len % 3).for_each(|byte| *byte = 0);
+
+            let encoded = api::encode_raw(&bytes);
+
+            assert_eq!(api::decode_raw(&encoded), Ok(bytes), "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn bad_alphabet_at_every_position() {
+        let encoded = api::encode_raw(&random_bytes(80));
+
+        for index in 0..encoded.len() {
+            for &character in &['0', 'l', '\u{7F}', 'é', '€'] {
+                let mut mutated: Vec<char> = encoded.chars().collect();
+                mutated[index] = character;
+                let mutated: String = mutated.into_iter().collect();
+
+                assert!(!api::contains_only_xrpl_alphabet(&mutated), "{}", mutated);
+                assert_eq!(
+                    api::decode_raw(&mutated),
+                    Err(api::Error::BadAlphabet { character, index }),
+                    "{}",
+                    mutated
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn custom_alphabet() {
+        let codec = Codec::new().with_alphabet(Alphabet::BITCOIN);
+
+        for _ in 0..100 {
+            let account_id = get_20_random_bytes();
+            let encoded = codec.encode_account_id(&account_id).unwrap();
+
+            assert_eq!(codec.decode_account_id(&encoded), Ok(account_id));
+            assert!(api::decode_account_id(&encoded).is_err());
+        }
+    }
+}
+
+mod allocations {
+    use super::*;
+
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn count_allocations(f: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        f();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn decode_fixed_size_tokens() {
+        let account_id = api::encode_account_id(&get_20_random_bytes());
+        let seed = api::encode_seed(&get_16_random_bytes(), api::Ed25519);
+        let xaddress =
+            api::encode_xaddress(&get_20_random_bytes(), api::Tag::U32(1), api::Network::Test);
+
+        assert_eq!(
+            count_allocations(|| {
+                api::decode_account_id(&account_id).unwrap();
+                api::decode_seed(&seed).unwrap();
+                api::decode_xaddress(&xaddress).unwrap();
+                api::decode_account_id("rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErA").unwrap_err();
+            }),
+            0
+        );
+    }
+
+    #[cfg(feature = "seed-secp256k1")]
+    #[test]
+    fn encode_into() {
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+        let mut out = [0; 47];
+
+        assert_eq!(
+            count_allocations(|| {
+                api::encode_account_id_into(&bytes, &mut out).unwrap();
+                api::encode_seed_into(&entropy, api::Secp256k1, &mut out).unwrap();
+                api::encode_xaddress_into(&bytes, api::Tag::None, api::Network::Main, &mut out)
+                    .unwrap();
+            }),
+            0
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn encode_on_the_stack() {
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+
+        assert_eq!(
+            count_allocations(|| {
+                api::stack::encode_account_id(&bytes);
+                api::stack::encode_seed(&entropy, api::Ed25519);
+                api::stack::encode_xaddress(&bytes, api::Tag::U32(1), api::Network::Test);
+            }),
+            0
+        );
+    }
+
+    #[cfg(feature = "seed-ed25519")]
+    #[test]
+    fn encode_allocates_only_the_string() {
+        let bytes = get_20_random_bytes();
+        let entropy = get_16_random_bytes();
+
+        assert_eq!(
+            count_allocations(|| drop(api::encode_account_id(&bytes))),
+            1
+        );
+        assert_eq!(
+            count_allocations(|| drop(api::encode_seed(&entropy, api::Ed25519))),
+            1
+        );
+    }
+
+    #[test]
+    fn encode_longer_than_any_token() {
+        let payload = [7; 60];
+        let encoded = api::encode_versioned(&payload, &[0x00]);
+
+        assert_eq!(
+            api::decode_versioned(&encoded, &[0x00], payload.len()),
+            Ok(payload.to_vec())
+        );
+    }
+
+    #[test]
+    fn decode_longer_than_any_token() {
+        let long = api::encode_versioned(&[7; 60], &[0x00]);
+
+        assert_eq!(
+            api::decode_account_id(&long),
+            Err(api::Error::BadLength {
+                expected: 20,
+                actual: 60
+            })
+        );
+        assert_eq!(
+            api::decode_account_id(&format!("{}r", long)),
+            Err(api::Error::BadChecksum)
+        );
     }
 }
@@ -0,0 +1,51 @@
+/* Runs the cases of the `differential` tests in tests/api.rs through the
+ * JavaScript reference implementation:
+ *
+ *     npm install --no-save ripple-address-codec
+ *     cargo test --test api differential -- --ignored
+ *
+ * Reads one JSON `[function, ...arguments]` case per line from stdin and
+ * writes one JSON result per line to stdout, `null` if the function threw.
+ * Bytes are passed and returned as hex strings.
+ */
+
+const readline = require('readline');
+const codec = require('ripple-address-codec');
+
+const fromHex = (hex) => Buffer.from(hex, 'hex');
+const toHex = (bytes) => Buffer.from(bytes).toString('hex');
+
+const functions = {
+  encodeAccountID: (hex) => codec.encodeAccountID(fromHex(hex)),
+  decodeAccountID: (address) => toHex(codec.decodeAccountID(address)),
+  isValidClassicAddress: (address) => codec.isValidClassicAddress(address),
+  encodeSeed: (hex, type) => codec.encodeSeed(fromHex(hex), type),
+  decodeSeed: (seed) => {
+    const { bytes, type } = codec.decodeSeed(seed);
+    return [toHex(bytes), type];
+  },
+  encodeNodePublic: (hex) => codec.encodeNodePublic(fromHex(hex)),
+  decodeNodePublic: (key) => toHex(codec.decodeNodePublic(key)),
+  encodeAccountPublic: (hex) => codec.encodeAccountPublic(fromHex(hex)),
+  decodeAccountPublic: (key) => toHex(codec.decodeAccountPublic(key)),
+  classicAddressToXAddress: (address, tag, test) =>
+    codec.classicAddressToXAddress(address, tag, test),
+  xAddressToClassicAddress: (xAddress) => {
+    const { classicAddress, tag, test } = codec.xAddressToClassicAddress(xAddress);
+    return [classicAddress, tag, test];
+  },
+  isValidXAddress: (xAddress) => codec.isValidXAddress(xAddress),
+};
+
+const lines = readline.createInterface({ input: process.stdin });
+
+lines.on('line', (line) => {
+  const [name, ...args] = JSON.parse(line);
+  let result;
+  try {
+    result = functions[name](...args);
+  } catch (error) {
+    result = null;
+  }
+  process.stdout.write(JSON.stringify(result) + '\n');
+});
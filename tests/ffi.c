@@ -0,0 +1,55 @@
+/* Smoke test of the C API, run by CI:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *     cc -Wall -Werror -Iinclude tests/ffi.c -Ltarget/release -lripple_address_codec -o target/ffi
+ *     LD_LIBRARY_PATH=target/release target/ffi
+ */
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "ripple_address_codec.h"
+
+int main(void) {
+    uint8_t account_id[RAC_ACCOUNT_ID_LEN] = {0};
+    char address[RAC_ACCOUNT_ID_BUFFER_LEN];
+    size_t written;
+
+    assert(rac_encode_account_id(account_id, address, sizeof address, &written) == RAC_OK);
+    assert(strcmp(address, "rrrrrrrrrrrrrrrrrrrrrhoLvTp") == 0);
+    assert(written == strlen(address));
+
+    account_id[0] = 0xFF;
+    assert(rac_decode_account_id(address, account_id) == RAC_OK);
+    assert(account_id[0] == 0);
+    assert(rac_decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTq", account_id) == RAC_ERROR_BAD_CHECKSUM);
+
+    char small[4];
+    assert(rac_encode_account_id(account_id, small, sizeof small, &written) == RAC_ERROR_BUFFER_TOO_SMALL);
+    assert(written == strlen(address) + 1);
+
+    uint8_t entropy[RAC_ENTROPY_LEN] = {0};
+    char seed[RAC_SEED_BUFFER_LEN];
+    int32_t algorithm;
+
+    assert(rac_encode_seed(entropy, RAC_ED25519, seed, sizeof seed, &written) == RAC_OK);
+    assert(strcmp(seed, "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE") == 0);
+    assert(rac_decode_seed(seed, entropy, &algorithm) == RAC_OK);
+    assert(algorithm == RAC_ED25519);
+
+    char xaddress[RAC_XADDRESS_BUFFER_LEN];
+    bool has_tag, test_network;
+    uint32_t tag;
+
+    assert(rac_encode_xaddress(account_id, true, 1, true, xaddress, sizeof xaddress, &written) == RAC_OK);
+    assert(written == 47);
+    assert(rac_decode_xaddress(xaddress, account_id, &has_tag, &tag, &test_network) == RAC_OK);
+    assert(has_tag && tag == 1 && test_network);
+
+    assert(rac_decode_seed(NULL, entropy, &algorithm) == RAC_ERROR_INVALID_ARGUMENT);
+    assert(strcmp(rac_error_message(RAC_ERROR_BAD_CHECKSUM), "bad checksum") == 0);
+
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,207 @@
+//! Test vectors of the reference JavaScript implementation
+//!
+//! Ported from the tests of `ripple-address-codec` in xrpl.js (ISC
+//! license). Plain constants, without file access or parsing, so an
+//! SGX test enclave can check them too.
+
+/// Classic address, tag, mainnet X-address and test network X-address
+pub const XADDRESSES: [(&str, Option<u32>, &str, &str); 22] = [
+    (
+        "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        None,
+        "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ",
+        "T719a5UwUCnEs54UsxG9CJYYDhwmFCqkr7wxCcNcfZ6p5GZ",
+    ),
+    (
+        "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        Some(1),
+        "X7AcgcsBL6XDcUb289X4mJ8djcdyKaGZMhc9YTE92ehJ2Fu",
+        "T719a5UwUCnEs54UsxG9CJYYDhwmFCvbJNZbi37gBGkRkbE",
+    ),
+    (
+        "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        Some(14),
+        "X7AcgcsBL6XDcUb289X4mJ8djcdyKaGo2K5VpXpmCqbV2gS",
+        "T719a5UwUCnEs54UsxG9CJYYDhwmFCvqXVCALUGJGSbNV3x",
+    ),
+    (
+        "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        Some(11747),
+        "X7AcgcsBL6XDcUb289X4mJ8djcdyKaLFuhLRuNXPrDeJd9A",
+        "T719a5UwUCnEs54UsxG9CJYYDhwmFCziiNHtUukubF2Mg6t",
+    ),
+    (
+        "rLczgQHxPhWtjkaQqn3Q6UM8AbRbbRvs5K",
+        None,
+        "XVZVpQj8YSVpNyiwXYSqvQoQqgBttTxAZwMcuJd4xteQHyt",
+        "TVVrSWtmQQssgVcmoMBcFQZKKf56QscyWLKnUyiuZW8ALU4",
+    ),
+    (
+        "rpZc4mVfWUif9CRoHRKKcmhu1nx2xktxBo",
+        None,
+        "X7YenJqxv3L66CwhBSfd3N8RzGXxYqPopMGMsCcpho79rex",
+        "T77wVQzA8ntj9wvCTNiQpNYLT5hmhRsFyXDoMLqYC4BzQtV",
+    ),
+    (
+        "rpZc4mVfWUif9CRoHRKKcmhu1nx2xktxBo",
+        Some(58),
+        "X7YenJqxv3L66CwhBSfd3N8RzGXxYqV56ZkTCa9UCzgaao1",
+        "T77wVQzA8ntj9wvCTNiQpNYLT5hmhR9kej6uxm4jGcQD7rZ",
+    ),
+    (
+        "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+        Some(23480),
+        "X7d3eHCXzwBeWrZec1yT24iZerQjYL8m8zCJ16ACxu1BrBY",
+        "T7YChPFWifjCAXLEtg5N74c7fSAYsvSokwcmBPBUZWhxH5P",
+    ),
+    (
+        "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+        Some(11747),
+        "X7d3eHCXzwBeWrZec1yT24iZerQjYLo2CJf8oVC5CMWey5m",
+        "T7YChPFWifjCAXLEtg5N74c7fSAYsvTcc7nEfwuEEvn5Q4w",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        None,
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(0),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4xcZj5whKbmc",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSy8RHqGHoGJ59spi2",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(1),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(2),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV8zpDURx7DzBCkrQE7",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnTryP9tG9TW8GeMBmd",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(32),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtVoYiC9UvKfjKar4LJe",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnT2oqaCDzMEuCDAj1j",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(276),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtVoKj3MnFGMXEFMnvJV",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnTMgJJYfAbsiPsc6Zg",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(65591),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtVozpjdhPQVdt3ghaWw",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQn7ryu2W6njw7mT1jmS",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(16781933),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtVqrDUk2vDpkTjPsY73",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnVsw45sDtGHhLi27Qa",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(4294967294),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV1kAsixQTdMjbWi39u",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnX8tDFQ53itLNqs6vU",
+    ),
+    (
+        "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+        Some(4294967295),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi",
+        "TVE26TYGhfLC7tQDno7G8dGtxSkYQnXoy6kSDh6rZzApc69",
+    ),
+    (
+        "rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY",
+        None,
+        "XV5sbjUmgPpvXv4ixFWZ5ptAYZ6PD2gYsjNFQLKYW33DzBm",
+        "TVd2rqMkYL2AyS97NdELcpeiprNBjwLZzuUG5rZnaewsahi",
+    ),
+    (
+        "rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY",
+        Some(0),
+        "XV5sbjUmgPpvXv4ixFWZ5ptAYZ6PD2m4Er6SnvjVLpMWPjR",
+        "TVd2rqMkYL2AyS97NdELcpeiprNBjwRQUBetPbyrvXSTuxU",
+    ),
+    (
+        "rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY",
+        Some(13371337),
+        "XV5sbjUmgPpvXv4ixFWZ5ptAYZ6PD2qwGkhgc48zzcx6Gkr",
+        "TVd2rqMkYL2AyS97NdELcpeiprNBjwVUDvp3vhpXbNhLwJi",
+    ),
+];
+
+/// Hex account id and classic address
+pub const ACCOUNT_ID: (&str, &str) = (
+    "BA8E78626EE42C41B46D46C3048DF3A1C3C87072",
+    "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN",
+);
+
+/// Hex entropy, whether the seed is for Ed25519 and the seed
+pub const SEEDS: [(&str, bool, &str); 6] = [
+    (
+        "CF2DE378FBDD7E2EE87D486DFB5A7BFF",
+        false,
+        "sn259rEFXrQrWyx3Q7XneWcwV6dfL",
+    ),
+    (
+        "4C3A1D213FBDFB14C7C28D609469B341",
+        true,
+        "sEdTM1uX8pu2do5XvTnutH6HsouMaM2",
+    ),
+    (
+        "00000000000000000000000000000000",
+        false,
+        "sp6JS7f14BuwFY8Mw6bTtLKWauoUs",
+    ),
+    (
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        false,
+        "saGwBRReqUNKuWNLpUAq8i8NkXEPN",
+    ),
+    (
+        "00000000000000000000000000000000",
+        true,
+        "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE",
+    ),
+    (
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        true,
+        "sEdV19BLfeQeKdEXyYA4NhjPJe6XBfG",
+    ),
+];
+
+/// Hex node public key and its encoding
+pub const NODE_PUBLIC_KEY: (&str, &str) = (
+    "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828",
+    "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH",
+);
+
+/// Hex account public key and its encoding
+pub const ACCOUNT_PUBLIC_KEY: (&str, &str) = (
+    "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6",
+    "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+);
+
+/// Classic addresses the reference implementation accepts
+pub const VALID_CLASSIC_ADDRESSES: [&str; 2] = [
+    // secp256k1
+    "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+    // Ed25519
+    "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+];
+
+/// Classic addresses the reference implementation rejects
+pub const INVALID_CLASSIC_ADDRESSES: [&str; 2] = ["rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw2", ""];
+
+/// X-address with a 64-bit tag, which the reference implementation rejects
+pub const XADDRESS_WITH_64_BIT_TAG: &str = "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8zeUygYrCgrPh";
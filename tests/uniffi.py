@@ -0,0 +1,48 @@
+# Smoke test of the UniFFI bindings, run by CI:
+#
+#     cargo rustc --release --features uniffi --crate-type cdylib
+#     cargo run --release -p uniffi-bindgen -- generate --library target/release/libripple_address_codec.so --language python --out-dir target/uniffi
+#     cp target/release/libripple_address_codec.so target/uniffi
+#     python3 tests/uniffi.py
+
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(__file__), "..", "target", "uniffi"))
+
+from ripple_address_codec import AccountId, Algorithm, CodecError, Network, Seed, XAddress
+
+account_id = AccountId.from_bytes(bytes(20))
+assert str(account_id) == "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+assert account_id.bytes() == bytes(20)
+assert AccountId.parse("rrrrrrrrrrrrrrrrrrrrrhoLvTp") == account_id
+
+try:
+    AccountId.parse("rrrrrrrrrrrrrrrrrrrrrhoLvTq")
+    assert False
+except CodecError.Invalid as error:
+    assert error.code == "bad_checksum"
+
+try:
+    AccountId.from_bytes(bytes(3))
+    assert False
+except CodecError.Invalid as error:
+    assert error.code == "bad_length"
+
+seed = Seed.parse("sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE")
+assert seed.entropy() == bytes(16)
+assert seed.algorithm() == Algorithm.ED25519
+assert seed.encode() == "sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE"
+assert "SJHS4oiAdz7w2X2ni1gFiqtbJHqE" not in repr(seed)
+assert Seed.from_entropy(bytes(16), Algorithm.ED25519) == seed
+
+xaddress = XAddress(AccountId.parse("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"), None, Network.MAIN)
+assert str(xaddress) == "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+assert xaddress.tag() is None
+
+xaddress = XAddress.parse("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw")
+assert xaddress.tag() == 1
+assert xaddress.network() == Network.TEST
+assert XAddress(xaddress.account_id(), 1, Network.TEST) == xaddress
+
+print("ok")
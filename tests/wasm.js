@@ -0,0 +1,41 @@
+/* Smoke test of the WebAssembly bindings, run by CI:
+ *
+ *     cargo rustc --release --target wasm32-unknown-unknown --no-default-features --features wasm,sha2,seed-ed25519,seed-secp256k1 --crate-type cdylib
+ *     wasm-bindgen --target nodejs --out-dir target/pkg target/wasm32-unknown-unknown/release/ripple_address_codec.wasm
+ *     node tests/wasm.js
+ */
+
+const assert = require('assert');
+const codec = require('../target/pkg/ripple_address_codec.js');
+
+const accountId = new Uint8Array(20);
+assert.strictEqual(codec.encodeAccountID(accountId), 'rrrrrrrrrrrrrrrrrrrrrhoLvTp');
+assert.deepStrictEqual(codec.decodeAccountID('rrrrrrrrrrrrrrrrrrrrrhoLvTp'), accountId);
+assert.throws(() => codec.decodeAccountID('rrrrrrrrrrrrrrrrrrrrrhoLvTq'), /checksum mismatch/);
+assert.throws(() => codec.encodeAccountID(new Uint8Array(3)), /expected 20/);
+assert(codec.isValidClassicAddress('rrrrrrrrrrrrrrrrrrrrrhoLvTp'));
+
+assert.strictEqual(codec.encodeSeed(new Uint8Array(16), 'ed25519'), 'sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE');
+const seed = codec.decodeSeed('sEdSJHS4oiAdz7w2X2ni1gFiqtbJHqE');
+assert.strictEqual(seed.type, 'ed25519');
+assert.deepStrictEqual(seed.bytes, new Uint8Array(16));
+assert.throws(() => codec.encodeSeed(new Uint8Array(16), 'rsa'));
+
+const xAddress = 'XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb';
+assert.strictEqual(codec.classicAddressToXAddress('rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf', undefined, false), xAddress);
+const classic = codec.xAddressToClassicAddress(xAddress);
+assert.strictEqual(classic.classicAddress, 'rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf');
+assert.strictEqual(classic.tag, undefined);
+assert.strictEqual(classic.test, false);
+
+const decoded = codec.decodeXAddress('TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw');
+assert.strictEqual(decoded.tag, 1);
+assert.strictEqual(decoded.test, true);
+assert.strictEqual(codec.encodeXAddress(decoded.accountId, 1, true), 'TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw');
+assert(codec.isValidXAddress(xAddress));
+
+const nodePublic = new Uint8Array(33).fill(2);
+assert.deepStrictEqual(codec.decodeNodePublic(codec.encodeNodePublic(nodePublic)), nodePublic);
+assert.deepStrictEqual(codec.decodeAccountPublic(codec.encodeAccountPublic(nodePublic)), nodePublic);
+
+console.log('ok');